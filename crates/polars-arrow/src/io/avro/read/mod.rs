@@ -8,7 +8,7 @@ use avro_schema::schema::Field as AvroField;
 
 mod deserialize;
 pub use deserialize::deserialize;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_bail};
 
 mod nested;
 mod schema;
@@ -66,3 +66,48 @@ impl<R: Read> Iterator for Reader<R> {
             .map(|maybe_block| deserialize(maybe_block?, fields, avro_fields, projection))
     }
 }
+
+/// The location of a block of an Avro file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockLocation {
+    /// The offset of the block in the file, including its header.
+    pub offset: usize,
+    /// The length of the block in bytes, including its header and its sync marker.
+    pub length: usize,
+    /// The number of rows in the block.
+    pub num_rows: usize,
+}
+
+/// Returns the locations of all blocks in `bytes`, starting at `offset`, which is the end of the
+/// header of the file. The blocks are neither decompressed nor decoded.
+pub fn read_block_locations(
+    bytes: &[u8],
+    mut offset: usize,
+    marker: [u8; 16],
+) -> PolarsResult<Vec<BlockLocation>> {
+    let mut blocks = vec![];
+
+    while offset < bytes.len() {
+        let mut reader = &bytes[offset..];
+        let num_rows = util::zigzag_i64(&mut reader)?;
+        let size = util::zigzag_i64(&mut reader)?;
+        let (Ok(num_rows), Ok(size)) = (usize::try_from(num_rows), usize::try_from(size)) else {
+            polars_bail!(oos = "avro block has a negative number of rows or size")
+        };
+
+        let header_length = bytes.len() - offset - reader.len();
+        if reader.get(size..size + marker.len()) != Some(marker.as_slice()) {
+            polars_bail!(oos = "avro block is truncated or does not end with the sync marker")
+        }
+
+        let length = header_length + size + marker.len();
+        blocks.push(BlockLocation {
+            offset,
+            length,
+            num_rows,
+        });
+        offset += length;
+    }
+
+    Ok(blocks)
+}
@@ -0,0 +1,285 @@
+//! # t-digest
+//!
+//! A mergeable sketch for approximate quantiles, based on the merging t-digest described in
+//! Dunning & Ertl, "Computing Extremely Accurate Quantiles Using t-Digests" (2019).
+//!
+//! The sketch summarizes the data as a sorted list of weighted centroids. Centroids near the
+//! tails are kept small, so that extreme quantiles (e.g. p99) are estimated accurately. Two
+//! sketches can be combined, which allows building them in parallel.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_compute::tdigest::*;
+//!     let mut digest = TDigest::default();
+//!     for x in 0..=10 {
+//!         digest.insert(x as f64);
+//!     }
+//!
+//!     assert_eq!(digest.quantile(0.5), Some(5.0));
+//! ```
+
+/// The default compression, which bounds the number of centroids to roughly twice this value.
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// Centroids sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values not yet merged into the centroids.
+    buffer: Vec<f64>,
+    /// Total weight of the centroids.
+    weight: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    pub fn new(compression: f64) -> Self {
+        assert!(compression >= 1.0);
+        Self {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            weight: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Number of values inserted into this sketch.
+    pub fn len(&self) -> usize {
+        self.weight as usize + self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts a value. NaN values are ignored.
+    #[inline]
+    pub fn insert(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+
+        self.buffer.push(value);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.flush(&[]);
+        }
+    }
+
+    /// Merges the values of `other` into this sketch.
+    pub fn combine(&mut self, other: &Self) {
+        if other.is_empty() {
+            return;
+        }
+
+        self.buffer.extend_from_slice(&other.buffer);
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.flush(&other.centroids);
+    }
+
+    /// Estimates the quantile `q` of the inserted values, where `q` is in `[0, 1]`. Returns
+    /// `None` if the sketch is empty.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.flush(&[]);
+
+        if self.centroids.is_empty() {
+            return None;
+        }
+        if q <= 0.0 {
+            return Some(self.min);
+        }
+        if q >= 1.0 {
+            return Some(self.max);
+        }
+
+        // Each centroid covers `weight` ranks and its mean is placed at the middle of those.
+        // Between the middles of adjacent centroids we interpolate linearly, and the outer halves
+        // of the first and last centroid are interpolated towards the minimum and maximum.
+        let target = q * self.weight;
+        let mut cum_weight = 0.0;
+        let mut prev: Option<(f64, f64)> = None;
+        for c in &self.centroids {
+            let center = cum_weight + c.weight / 2.0;
+            if target < center {
+                let (prev_mean, prev_center) = prev.unwrap_or((self.min, 0.0));
+                return Some(interpolate(
+                    prev_mean,
+                    c.mean,
+                    (target - prev_center) / (center - prev_center),
+                ));
+            }
+
+            prev = Some((c.mean, center));
+            cum_weight += c.weight;
+        }
+
+        let (prev_mean, prev_center) = prev.unwrap();
+        Some(interpolate(
+            prev_mean,
+            self.max,
+            (target - prev_center) / (self.weight - prev_center),
+        ))
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        (self.compression as usize) * 8
+    }
+
+    /// Merges the buffered values and `other` centroids into the centroids of this sketch.
+    fn flush(&mut self, other: &[Centroid]) {
+        if self.buffer.is_empty() && other.is_empty() {
+            return;
+        }
+
+        for &v in &self.buffer {
+            self.min = self.min.min(v);
+            self.max = self.max.max(v);
+        }
+
+        let mut all = std::mem::take(&mut self.centroids);
+        all.reserve(self.buffer.len() + other.len());
+        all.extend(
+            self.buffer
+                .drain(..)
+                .map(|mean| Centroid { mean, weight: 1.0 }),
+        );
+        all.extend_from_slice(other);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total_weight: f64 = all.iter().map(|c| c.weight).sum();
+        let mut merged = Vec::with_capacity(self.compression as usize * 2);
+
+        let mut all = all.into_iter();
+        let mut current = all.next().unwrap();
+        let mut weight_so_far = 0.0;
+        let mut weight_limit = total_weight * self.next_quantile_limit(0.0);
+        for c in all {
+            if weight_so_far + current.weight + c.weight <= weight_limit {
+                let weight = current.weight + c.weight;
+                current.mean += (c.mean - current.mean) * c.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                weight_limit =
+                    total_weight * self.next_quantile_limit(weight_so_far / total_weight);
+                merged.push(current);
+                current = c;
+            }
+        }
+        merged.push(current);
+
+        self.centroids = merged;
+        self.weight = total_weight;
+    }
+
+    /// The upper quantile bound of a centroid starting at quantile `q`, using the scale function
+    /// `k(q) = compression / (2 pi) * asin(2q - 1)`. Every centroid spans at most 1 in `k`-space.
+    fn next_quantile_limit(&self, q: f64) -> f64 {
+        use std::f64::consts::PI;
+
+        let k = self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin();
+        let k_next = k + 1.0;
+        if k_next >= self.compression / 4.0 {
+            1.0
+        } else {
+            ((k_next * 2.0 * PI / self.compression).sin() + 1.0) / 2.0
+        }
+    }
+}
+
+#[inline]
+fn interpolate(a: f64, b: f64, frac: f64) -> f64 {
+    a + (b - a) * frac.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic permutation of `0..n`.
+    fn shuffled(n: u64) -> Vec<f64> {
+        // 7919 is prime, so this is a bijection for `n` not divisible by it.
+        (0..n).map(|i| ((i * 7919) % n) as f64).collect()
+    }
+
+    fn assert_rank_error(digest: &mut TDigest, n: u64, max_error: f64) {
+        for q in [0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.95, 0.99, 0.999] {
+            let estimate = digest.quantile(q).unwrap();
+            let rank_error = (estimate / (n - 1) as f64 - q).abs();
+            assert!(
+                rank_error <= max_error,
+                "q={q}: estimate {estimate}, rank error {rank_error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut digest = TDigest::default();
+        assert!(digest.is_empty());
+        assert_eq!(digest.quantile(0.5), None);
+
+        digest.insert(f64::NAN);
+        assert!(digest.is_empty());
+    }
+
+    #[test]
+    fn test_small_is_exact() {
+        let mut digest = TDigest::default();
+        for x in [5.0, 1.0, 4.0, 2.0, 3.0] {
+            digest.insert(x);
+        }
+
+        assert_eq!(digest.len(), 5);
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(1.0), Some(5.0));
+    }
+
+    #[test]
+    fn test_uniform() {
+        let n = 100_000;
+        let mut digest = TDigest::default();
+        for x in shuffled(n) {
+            digest.insert(x);
+        }
+
+        assert_eq!(digest.len(), n as usize);
+        assert!(digest.centroids.len() <= 2 * DEFAULT_COMPRESSION as usize);
+        assert_rank_error(&mut digest, n, 0.005);
+    }
+
+    #[test]
+    fn test_combine() {
+        let n = 100_000;
+        let mut parts: Vec<TDigest> = (0..7).map(|_| TDigest::default()).collect();
+        for (i, x) in shuffled(n).into_iter().enumerate() {
+            parts[i % 7].insert(x);
+        }
+
+        let mut digest = TDigest::default();
+        for part in &parts {
+            digest.combine(part);
+        }
+
+        assert_eq!(digest.len(), n as usize);
+        assert_rank_error(&mut digest, n, 0.005);
+    }
+}
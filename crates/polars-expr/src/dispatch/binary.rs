@@ -2,6 +2,10 @@ use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::{Column, DataType, IntoColumn};
+#[cfg(feature = "binary_compression")]
+use polars_ops::prelude::BinaryCodec;
+#[cfg(feature = "binary_hash")]
+use polars_ops::prelude::BinaryHashAlgorithm;
 use polars_ops::prelude::BinaryNameSpaceImpl;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
 use polars_plan::plans::IRBinaryFunction;
@@ -29,6 +33,12 @@ pub fn function_expr_to_udf(func: IRBinaryFunction) -> SpecialEq<Arc<dyn Columns
         Size => map!(size_bytes),
         #[cfg(feature = "binary_encoding")]
         Reinterpret(dtype, is_little_endian) => map!(reinterpret, &dtype, is_little_endian),
+        #[cfg(feature = "binary_hash")]
+        Hash(algorithm) => map!(hash_digest, algorithm),
+        #[cfg(feature = "binary_compression")]
+        Compress(codec, level) => map!(compress, codec, level),
+        #[cfg(feature = "binary_compression")]
+        Decompress(codec, strict) => map!(decompress, codec, strict),
     }
 }
 
@@ -100,3 +110,27 @@ pub(super) fn reinterpret(
     ca.reinterpret(dtype, is_little_endian)
         .map(|val| val.into())
 }
+
+#[cfg(feature = "binary_hash")]
+pub(super) fn hash_digest(s: &Column, algorithm: BinaryHashAlgorithm) -> PolarsResult<Column> {
+    let out = match s.dtype() {
+        DataType::String => s.str()?.as_binary().hash_digest(algorithm),
+        _ => s.binary()?.hash_digest(algorithm),
+    };
+    Ok(out.into_column())
+}
+
+#[cfg(feature = "binary_compression")]
+pub(super) fn compress(s: &Column, codec: BinaryCodec, level: Option<i32>) -> PolarsResult<Column> {
+    let out = match s.dtype() {
+        DataType::String => s.str()?.as_binary().compress(codec, level)?,
+        _ => s.binary()?.compress(codec, level)?,
+    };
+    Ok(out.into_column())
+}
+
+#[cfg(feature = "binary_compression")]
+pub(super) fn decompress(s: &Column, codec: BinaryCodec, strict: bool) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.decompress(codec, strict).map(|ok| ok.into_column())
+}
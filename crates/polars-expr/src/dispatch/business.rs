@@ -1,10 +1,12 @@
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
-use polars_core::prelude::Column;
+use polars_core::prelude::{ChunkAgg, Column, DataType};
 use polars_ops::series::Roll;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
 use polars_plan::plans::IRBusinessFunction;
+use polars_time::{HolidayCalendar, merge_calendar_holidays};
 
 pub fn function_expr_to_udf(func: IRBusinessFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
     use IRBusinessFunction::*;
@@ -12,37 +14,114 @@ pub fn function_expr_to_udf(func: IRBusinessFunction) -> SpecialEq<Arc<dyn Colum
         BusinessDayCount {
             week_mask,
             holidays,
+            calendar,
         } => {
-            map_as_slice!(business_day_count, week_mask, &holidays)
+            map_as_slice!(business_day_count, week_mask, &holidays, calendar.as_ref())
         },
         AddBusinessDay {
             week_mask,
             holidays,
+            calendar,
             roll,
         } => {
-            map_as_slice!(add_business_days, week_mask, &holidays, roll)
+            map_as_slice!(
+                add_business_days,
+                week_mask,
+                &holidays,
+                calendar.as_ref(),
+                roll
+            )
         },
         IsBusinessDay {
             week_mask,
             holidays,
+            calendar,
         } => {
-            map_as_slice!(is_business_day, week_mask, &holidays)
+            map_as_slice!(is_business_day, week_mask, &holidays, calendar.as_ref())
         },
+        AddBusinessHours {
+            business_hours,
+            week_mask,
+            holidays,
+            calendar,
+        } => {
+            map_as_slice!(
+                add_business_hours,
+                business_hours,
+                week_mask,
+                &holidays,
+                calendar.as_ref()
+            )
+        },
+    }
+}
+
+/// Combine the explicit `holidays` with those of `calendar`, expanded over the dates in
+/// `columns`, widened by `padding` days on either side.
+fn resolve_holidays<'a>(
+    holidays: &'a [i32],
+    calendar: Option<&HolidayCalendar>,
+    columns: &[&Column],
+    padding: i64,
+) -> PolarsResult<Cow<'a, [i32]>> {
+    if calendar.is_none() {
+        return Ok(Cow::Borrowed(holidays));
     }
+    let mut bounds: Option<(i32, i32)> = None;
+    for c in columns {
+        let dates = c.cast(&DataType::Date)?;
+        let dates = dates.date()?.physical();
+        if let (Some(min), Some(max)) = (dates.min(), dates.max()) {
+            bounds = Some(match bounds {
+                Some((lo, hi)) => (lo.min(min), hi.max(max)),
+                None => (min, max),
+            });
+        }
+    }
+    let Some((min, max)) = bounds else {
+        return Ok(Cow::Borrowed(holidays));
+    };
+    let padding = padding.clamp(0, i32::MAX as i64) as i32;
+    Ok(merge_calendar_holidays(
+        holidays,
+        calendar,
+        min.saturating_sub(padding),
+        max.saturating_add(padding),
+    ))
+}
+
+/// The largest absolute value in `n`, used to bound how far results can land from their inputs.
+fn max_abs(n: &Column) -> PolarsResult<f64> {
+    let n = n.cast(&DataType::Float64)?;
+    let n = n.f64()?;
+    let max = n.max().unwrap_or(0.0).abs();
+    let min = n.min().unwrap_or(0.0).abs();
+    Ok(if max.is_finite() && min.is_finite() {
+        max.max(min)
+    } else {
+        0.0
+    })
+}
+
+/// The number of calendar days `n` business days can span, allowing for weekends and holidays.
+fn business_day_span(n: f64) -> i64 {
+    (n.ceil() * 10.0).min(i64::MAX as f64) as i64
 }
 
 pub(super) fn business_day_count(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
 ) -> PolarsResult<Column> {
     let start = &s[0];
     let end = &s[1];
+    let holidays = resolve_holidays(holidays, calendar, &[start, end], 0)?;
     polars_ops::prelude::business_day_count(
         start.as_materialized_series(),
         end.as_materialized_series(),
         week_mask,
-        holidays,
+        &holidays,
     )
     .map(Column::from)
 }
@@ -50,15 +129,22 @@ pub(super) fn add_business_days(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
     roll: Roll,
 ) -> PolarsResult<Column> {
     let start = &s[0];
     let n = &s[1];
+    let holidays = if calendar.is_some() {
+        let padding = business_day_span(max_abs(n)?) + 7;
+        resolve_holidays(holidays, calendar, &[start], padding)?
+    } else {
+        Cow::Borrowed(holidays)
+    };
     polars_ops::prelude::add_business_days(
         start.as_materialized_series(),
         n.as_materialized_series(),
         week_mask,
-        holidays,
+        &holidays,
         roll,
     )
     .map(Column::from)
@@ -68,8 +154,41 @@ pub(super) fn is_business_day(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
 ) -> PolarsResult<Column> {
     let dates = &s[0];
-    polars_ops::prelude::is_business_day(dates.as_materialized_series(), week_mask, holidays)
+    let holidays = resolve_holidays(holidays, calendar, &[dates], 0)?;
+    polars_ops::prelude::is_business_day(dates.as_materialized_series(), week_mask, &holidays)
         .map(Column::from)
 }
+
+pub(super) fn add_business_hours(
+    s: &[Column],
+    business_hours: [i64; 2],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
+) -> PolarsResult<Column> {
+    let start = &s[0];
+    let n = &s[1];
+    let holidays = if calendar.is_some() {
+        let hours_per_day = (business_hours[1] - business_hours[0]) as f64 / 3.6e12;
+        let days = if hours_per_day > 0.0 {
+            max_abs(n)? / hours_per_day
+        } else {
+            0.0
+        };
+        let padding = business_day_span(days) + 7;
+        resolve_holidays(holidays, calendar, &[start], padding)?
+    } else {
+        Cow::Borrowed(holidays)
+    };
+    polars_ops::prelude::add_business_hours(
+        start.as_materialized_series(),
+        n.as_materialized_series(),
+        business_hours,
+        week_mask,
+        &holidays,
+    )
+    .map(Column::from)
+}
@@ -36,6 +36,39 @@ pub(super) fn approx_n_unique(s: &Column) -> PolarsResult<Column> {
         .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
 }
 
+pub(super) fn approx_quantile(s: &Column, quantile: f64) -> PolarsResult<Column> {
+    use polars_compute::tdigest::TDigest;
+
+    let dtype = s.dtype();
+    polars_ensure!(
+        dtype.is_primitive_numeric() || dtype.is_decimal() || dtype.is_bool() || dtype.is_null(),
+        InvalidOperation: "`approx_quantile` operation not supported for dtype `{dtype}`"
+    );
+    if dtype.is_null() {
+        return Ok(Column::full_null(s.name().clone(), 1, &DataType::Null));
+    }
+
+    let mut digest = TDigest::default();
+    let values = s.cast(&DataType::Float64)?;
+    for arr in values.f64()?.downcast_iter() {
+        for x in arr.non_null_values_iter() {
+            digest.insert(x);
+        }
+    }
+
+    let out_dtype = if dtype == &DataType::Float32 {
+        DataType::Float32
+    } else {
+        DataType::Float64
+    };
+    Column::new_scalar(
+        s.name().clone(),
+        Scalar::new(DataType::Float64, digest.quantile(quantile).into()),
+        1,
+    )
+    .cast(&out_dtype)
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &[Column], null_behavior: NullBehavior) -> PolarsResult<Column> {
     let s1 = s[0].as_materialized_series();
@@ -78,6 +78,14 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         Normalize { form } => map!(strings::normalize, form.clone()),
         #[cfg(feature = "string_reverse")]
         Reverse => map!(strings::reverse),
+        #[cfg(feature = "string_similarity")]
+        Levenshtein => map_as_slice!(strings::levenshtein),
+        #[cfg(feature = "string_similarity")]
+        DamerauLevenshtein => map_as_slice!(strings::damerau_levenshtein),
+        #[cfg(feature = "string_similarity")]
+        Hamming => map_as_slice!(strings::hamming),
+        #[cfg(feature = "string_similarity")]
+        Similarity(metric) => map_as_slice!(strings::similarity, metric),
         Uppercase => map!(uppercase),
         Lowercase => map!(lowercase),
         #[cfg(feature = "nightly")]
@@ -738,6 +746,41 @@ pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.levenshtein(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn damerau_levenshtein(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "damerau_levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.damerau_levenshtein(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "hamming")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.hamming(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn similarity(
+    s: &[Column],
+    metric: polars_ops::prelude::StringSimilarityMetric,
+) -> PolarsResult<Column> {
+    _check_same_length(s, "similarity")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.similarity(other, metric).into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(
     s: &[Column],
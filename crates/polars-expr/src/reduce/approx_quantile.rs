@@ -0,0 +1,81 @@
+use polars_compute::tdigest::TDigest;
+
+use super::*;
+
+pub fn new_approx_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    use DataType::*;
+    use VecGroupedReduction as VGR;
+    Ok(match dtype {
+        Boolean => Box::new(VGR::new(dtype, ApproxQuantileReducer { quantile })),
+        _ if dtype.is_primitive_numeric() => {
+            Box::new(VGR::new(dtype, ApproxQuantileReducer { quantile }))
+        },
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => Box::new(VGR::new(dtype, ApproxQuantileReducer { quantile })),
+        Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
+            DataType::Null,
+        ))),
+        _ => {
+            polars_bail!(InvalidOperation: "`approx_quantile` operation not supported for dtype `{dtype}`")
+        },
+    })
+}
+
+#[derive(Clone)]
+struct ApproxQuantileReducer {
+    quantile: f64,
+}
+
+impl Reducer for ApproxQuantileReducer {
+    type Dtype = Float64Type;
+    type Value = TDigest;
+
+    fn init(&self) -> Self::Value {
+        TDigest::default()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        Cow::Owned(s.cast(&DataType::Float64).unwrap())
+    }
+
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<f64>, _seq_id: u64) {
+        if let Some(x) = b {
+            a.insert(x);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            for x in arr.non_null_values_iter() {
+                v.insert(x);
+            }
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let ca: Float64Chunked = v
+            .into_iter()
+            .map(|mut digest| digest.quantile(self.quantile))
+            .collect_ca(PlSmallStr::EMPTY);
+        let out = ca.into_series();
+        if dtype == &DataType::Float32 {
+            out.cast(&DataType::Float32)
+        } else {
+            Ok(out)
+        }
+    }
+}
@@ -2,6 +2,7 @@
 mod any_all;
 #[cfg(feature = "approx_unique")]
 mod approx_n_unique;
+mod approx_quantile;
 #[cfg(feature = "bitwise")]
 mod bitwise;
 mod convert;
@@ -4,10 +4,24 @@ use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroScanOptions;
+
+#[expect(clippy::derivable_impls)]
+impl Default for AvroScanOptions {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -70,6 +84,14 @@ impl<R: Read + Seek> AvroReader<R> {
     }
 }
 
+/// Count the rows of an Avro file from the headers of its blocks, without decoding them.
+pub fn count_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    let mut reader = std::io::Cursor::new(bytes);
+    let metadata = avro::avro_schema::read::read_metadata(&mut reader).map_err(to_compute_err)?;
+    let blocks = read::read_block_locations(bytes, reader.position() as usize, metadata.marker)?;
+    Ok(blocks.iter().map(|block| block.num_rows).sum())
+}
+
 impl<R> ArrowReader for read::Reader<R>
 where
     R: Read + Seek,
@@ -2,13 +2,119 @@ use std::io::Write;
 
 pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// Compression codec of the blocks of an Avro file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCodec {
+    Deflate,
+    Snappy,
+}
+
+impl From<AvroCodec> for AvroCompression {
+    fn from(value: AvroCodec) -> Self {
+        match value {
+            AvroCodec::Deflate => AvroCompression::Deflate,
+            AvroCodec::Snappy => AvroCompression::Snappy,
+        }
+    }
+}
+
+impl From<AvroCompression> for AvroCodec {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => AvroCodec::Deflate,
+            AvroCompression::Snappy => AvroCodec::Snappy,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Compression codec of the blocks.
+    pub compression: Option<AvroCodec>,
+    /// Name of the top-level record in the schema of the file.
+    pub name: PlSmallStr,
+}
+
+/// Encodes [`DataFrame`]s into the blocks of an Avro object container file.
+///
+/// All blocks of a file share the header written by [`AvroBlockEncoder::write_header`], so the
+/// blocks of different [`DataFrame`]s with the same schema can be encoded independently.
+#[derive(Clone)]
+pub struct AvroBlockEncoder {
+    record: Record,
+    compression: Option<AvroCompression>,
+}
+
+impl AvroBlockEncoder {
+    pub fn try_new(
+        schema: &Schema,
+        name: String,
+        compression: Option<AvroCompression>,
+    ) -> PolarsResult<Self> {
+        let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+        let record = write::to_record(&schema, name)?;
+        Ok(Self {
+            record,
+            compression,
+        })
+    }
+
+    /// Writes the header of the file, which contains the schema and the codec.
+    pub fn write_header<W: Write>(&self, writer: &mut W) -> PolarsResult<()> {
+        avro_schema::write::write_metadata(writer, self.record.clone(), self.compression)
+            .map_err(to_compute_err)
+    }
+
+    /// Writes one block per chunk of `df`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `df` is not rechunked.
+    pub fn write_blocks<W: Write>(&self, writer: &mut W, df: &DataFrame) -> PolarsResult<()> {
+        let mut data = vec![];
+        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
+            let mut serializers = chunk
+                .iter()
+                .zip(self.record.fields.iter())
+                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+                .collect::<Vec<_>>();
+
+            let mut block =
+                avro_schema::file::Block::new(chunk.height(), std::mem::take(&mut data));
+            write::serialize(&mut serializers, &mut block);
+            let _was_compressed =
+                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
+                    .map_err(to_compute_err)?;
+
+            avro_schema::write::write_block(writer, &compressed_block).map_err(to_compute_err)?;
+            // reuse block for next iteration.
+            data = block.data;
+            data.clear();
+
+            // reuse block for next iteration
+            compressed_block.data.clear();
+            compressed_block.number_of_rows = 0
+        }
+
+        Ok(())
+    }
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -64,39 +170,8 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
-
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
-        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
-            let mut serializers = chunk
-                .iter()
-                .zip(record.fields.iter())
-                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
-                .collect::<Vec<_>>();
-
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
-
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
-
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
-        }
-
-        Ok(())
+        let encoder = AvroBlockEncoder::try_new(df.schema(), self.name.clone(), self.compression)?;
+        encoder.write_header(&mut self.writer)?;
+        encoder.write_blocks(&mut self.writer, df)
     }
 }
@@ -0,0 +1,141 @@
+//! Options for reading and writing Parquet files with modular encryption.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+pub use polars_parquet::write::KeyRetriever;
+use polars_parquet::write::{EncryptionAlgorithm, FileEncryptionProperties};
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Resolves the keys of encrypted Parquet files.
+///
+/// The retriever is compared and hashed by its address, and cannot be serialized.
+#[derive(Clone)]
+pub struct ParquetKeyRetriever(pub Arc<dyn KeyRetriever>);
+
+impl ParquetKeyRetriever {
+    pub fn new(key_retriever: impl KeyRetriever + 'static) -> Self {
+        Self(Arc::new(key_retriever))
+    }
+}
+
+impl Debug for ParquetKeyRetriever {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parquet key retriever at 0x{:016x}",
+            Arc::as_ptr(&self.0) as *const () as usize
+        )
+    }
+}
+
+impl Eq for ParquetKeyRetriever {}
+
+impl PartialEq for ParquetKeyRetriever {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for ParquetKeyRetriever {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.0) as *const () as usize)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ParquetKeyRetriever {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom("cannot deserialize ParquetKeyRetriever"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ParquetKeyRetriever {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(format!("cannot serialize {self:?}")))
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for ParquetKeyRetriever {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ParquetKeyRetriever".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "ParquetKeyRetriever"))
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        Vec::<u8>::json_schema(generator)
+    }
+}
+
+/// The algorithm used to encrypt a Parquet file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ParquetEncryptionAlgorithm {
+    /// Encrypt all modules with AES-GCM.
+    #[default]
+    AesGcm,
+    /// Encrypt the page data with AES-CTR and all other modules with AES-GCM.
+    AesGcmCtr,
+}
+
+/// Encrypts the written Parquet file, including its footer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetEncryptionOptions {
+    pub key_retriever: ParquetKeyRetriever,
+    pub algorithm: ParquetEncryptionAlgorithm,
+    /// Key metadata of the footer key that is stored in the file.
+    pub footer_key_metadata: Option<Vec<u8>>,
+    /// Top-level columns that are encrypted with a key of their own, together with the key
+    /// metadata that is stored in the file. All other columns are encrypted with the footer key.
+    pub column_key_metadata: Vec<(PlSmallStr, Option<Vec<u8>>)>,
+    /// AAD prefix that is not stored in the file and must be supplied by readers.
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl ParquetEncryptionOptions {
+    pub fn new(key_retriever: ParquetKeyRetriever) -> Self {
+        Self {
+            key_retriever,
+            algorithm: ParquetEncryptionAlgorithm::default(),
+            footer_key_metadata: None,
+            column_key_metadata: Vec::new(),
+            aad_prefix: None,
+        }
+    }
+
+    pub fn to_properties(&self) -> FileEncryptionProperties {
+        FileEncryptionProperties {
+            algorithm: match self.algorithm {
+                ParquetEncryptionAlgorithm::AesGcm => EncryptionAlgorithm::AesGcmV1,
+                ParquetEncryptionAlgorithm::AesGcmCtr => EncryptionAlgorithm::AesGcmCtrV1,
+            },
+            key_retriever: self.key_retriever.0.clone(),
+            footer_key_metadata: self.footer_key_metadata.clone(),
+            column_key_metadata: self
+                .column_key_metadata
+                .iter()
+                .map(|(name, key_metadata)| (name.to_string(), key_metadata.clone()))
+                .collect(),
+            aad_prefix: self.aad_prefix.clone(),
+        }
+    }
+}
@@ -9,6 +9,7 @@ use polars_utils::plpath::PlPathRef;
 use crate::cloud::{
     CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_path_from_str,
 };
+use crate::parquet::encryption::ParquetKeyRetriever;
 use crate::parquet::metadata::FileMetadataRef;
 
 pub struct ParquetObjectStore {
@@ -17,6 +18,7 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    key_retriever: Option<ParquetKeyRetriever>,
 }
 
 impl ParquetObjectStore {
@@ -34,9 +36,16 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            key_retriever: None,
         })
     }
 
+    /// Set the key retriever used to decrypt encrypted files.
+    pub fn with_key_retriever(mut self, key_retriever: Option<ParquetKeyRetriever>) -> Self {
+        self.key_retriever = key_retriever;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -54,7 +63,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.key_retriever.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -98,6 +107,7 @@ pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    key_retriever: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<FileMetadata> {
     let footer_header_bytes = store
         .get_range(
@@ -117,7 +127,9 @@ pub async fn fetch_metadata(
         let footer_byte_size = read_i32le(reader).unwrap();
         let magic = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != polars_parquet::parquet::PARQUET_MAGIC {
+        if magic != polars_parquet::parquet::PARQUET_MAGIC
+            && magic != polars_parquet::parquet::encryption::PARQUET_ENCRYPTED_MAGIC
+        {
             return Err(polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "incorrect magic in parquet footer".to_string(),
             )
@@ -143,11 +155,8 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
-        std::io::Cursor::new(footer_bytes.as_ref()),
-        // TODO: Describe why this makes sense. Taken from the previous
-        // implementation which said "a highly nested but sparse struct could
-        // result in many allocations".
-        footer_bytes.as_ref().len() * 2 + 1024,
+    Ok(polars_parquet::parquet::read::deserialize_footer(
+        footer_bytes.as_ref(),
+        key_retriever.map(|k| &k.0),
     )?)
 }
@@ -9,6 +9,7 @@ use super::read_impl::read_parquet;
 use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
 use crate::RowIndex;
 use crate::mmap::MmapBytesReader;
+use crate::parquet::encryption::ParquetKeyRetriever;
 use crate::parquet::metadata::FileMetadataRef;
 use crate::prelude::*;
 
@@ -27,6 +28,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
+    key_retriever: Option<ParquetKeyRetriever>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -67,6 +69,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Set the key retriever used to decrypt encrypted files.
+    pub fn with_key_retriever(mut self, key_retriever: Option<ParquetKeyRetriever>) -> Self {
+        self.key_retriever = key_retriever;
+        self
+    }
+
     /// Checks that the file contains all the columns in `projected_arrow_schema` with the same
     /// dtype, and sets the projection indices.
     pub fn with_arrow_schema_projection(
@@ -169,7 +177,10 @@ impl<R: MmapBytesReader> ParquetReader<R> {
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            self.metadata = Some(Arc::new(read::read_metadata_with_key_retriever(
+                &mut self.reader,
+                self.key_retriever.as_ref().map(|k| &k.0),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -191,6 +202,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            key_retriever: None,
         }
     }
 
@@ -8,12 +8,26 @@ use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    SchemaDescriptor, WriteOptions, array_to_bloom_filters, array_to_columns,
+    schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
 use super::{KeyValueMetadata, ParquetMetadataContext};
 
+/// The compressed pages of the columns of a row group, with the (optional) bloom filter of each
+/// column chunk.
+pub type EncodedRowGroup = (
+    RowGroupIterColumns<'static, PolarsError>,
+    Vec<Option<Vec<u8>>>,
+);
+
+/// The compressed pages of the Parquet columns of a single array, with their bloom filters.
+type EncodedColumn = (
+    Vec<PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>>>,
+    Vec<Option<Vec<u8>>>,
+);
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
@@ -49,7 +63,7 @@ impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
@@ -81,7 +95,8 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (group, bloom_filters) = group?;
+            writer.write_with_bloom_filters(group, bloom_filters)?;
         }
         Ok(())
     }
@@ -91,14 +106,20 @@ impl<W: Write> BatchedWriter<W> {
         writer.parquet_schema()
     }
 
-    pub fn write_row_group(&mut self, rg: &[Vec<CompressedPage>]) -> PolarsResult<()> {
+    /// Write a row group of compressed pages, together with the (optional) bloom filter of each
+    /// of its columns.
+    pub fn write_row_group(
+        &mut self,
+        rg: &[Vec<CompressedPage>],
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
             Ok(DynStreamingIterator::new(
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(rg)?;
+        writer.write_with_bloom_filters(rg, bloom_filters)?;
         Ok(())
     }
 
@@ -106,14 +127,11 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
-        for group in rgs {
-            writer.write(group)?;
+        for (group, bloom_filters) in rgs {
+            writer.write_with_bloom_filters(group, bloom_filters)?;
         }
         Ok(())
     }
@@ -150,7 +168,7 @@ fn prepare_rg_iter<'a>(
     column_options: &'a [ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
@@ -199,9 +217,21 @@ fn array_to_pages_iter(
     type_: &ParquetType,
     column_options: &ColumnWriteOptions,
     options: WriteOptions,
-) -> Vec<PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>>> {
+) -> EncodedColumn {
+    let bloom_filters = array_to_bloom_filters(array.as_ref(), column_options);
     let encoded_columns = array_to_columns(array, type_.clone(), column_options, options).unwrap();
-    pages_iter_to_compressor(encoded_columns, options)
+    (
+        pages_iter_to_compressor(encoded_columns, options),
+        bloom_filters,
+    )
+}
+
+/// Packs the pages and bloom filters of the columns of a row group (which can each consist of
+/// multiple Parquet columns) together.
+fn to_encoded_row_group(columns: Vec<EncodedColumn>) -> EncodedRowGroup {
+    let (columns, bloom_filters): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+    let row_group = DynIter::new(columns.into_iter().flatten());
+    (row_group, bloom_filters.into_iter().flatten().collect())
 }
 
 fn create_serializer(
@@ -210,7 +240,7 @@ fn create_serializer(
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+) -> PolarsResult<EncodedRowGroup> {
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
@@ -223,7 +253,7 @@ fn create_serializer(
                 .par_iter()
                 .zip(fields)
                 .zip(column_options)
-                .flat_map(func)
+                .map(func)
                 .collect::<Vec<_>>()
         })
     } else {
@@ -232,13 +262,11 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(column_options)
-            .flat_map(func)
+            .map(func)
             .collect::<Vec<_>>()
     };
 
-    let row_group = DynIter::new(columns.into_iter());
-
-    Ok(row_group)
+    Ok(to_encoded_row_group(columns))
 }
 
 /// This serializer encodes and compresses all eagerly in memory.
@@ -248,7 +276,7 @@ fn create_eager_serializer(
     fields: &[ParquetType],
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+) -> PolarsResult<EncodedRowGroup> {
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
@@ -259,10 +287,8 @@ fn create_eager_serializer(
         .iter()
         .zip(fields)
         .zip(column_options)
-        .flat_map(func)
+        .map(func)
         .collect::<Vec<_>>();
 
-    let row_group = DynIter::new(columns.into_iter());
-
-    Ok(row_group)
+    Ok(to_encoded_row_group(columns))
 }
@@ -8,6 +8,7 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Serialize};
 
 use super::KeyValueMetadata;
+use crate::parquet::encryption::ParquetEncryptionOptions;
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -26,6 +27,9 @@ pub struct ParquetWriteOptions {
 
     /// Per-field overwrites for writing properties.
     pub field_overwrites: Vec<ParquetFieldOverwrites>,
+
+    /// Encrypt the file with Parquet modular encryption.
+    pub encryption: Option<ParquetEncryptionOptions>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -57,6 +61,8 @@ pub struct ParquetFieldOverwrites {
     pub required: Option<bool>,
     pub field_id: Option<i32>,
     pub metadata: Option<Vec<MetadataKeyValue>>,
+    /// Write a split-block bloom filter for every column chunk of this field.
+    pub bloom_filter: bool,
 }
 
 /// The compression strategy to use for writing Parquet files.
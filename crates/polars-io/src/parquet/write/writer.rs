@@ -5,14 +5,15 @@ use arrow::datatypes::PhysicalType;
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    ChildWriteOptions, ColumnWriteOptions, CompressionOptions, Encoding, FieldWriteOptions,
-    FileWriter, KeyValue, ListLikeFieldWriteOptions, StatisticsOptions, StructFieldWriteOptions,
-    Version, WriteOptions, to_parquet_schema,
+    BloomFilterOptions, ChildWriteOptions, ColumnWriteOptions, CompressionOptions, Encoding,
+    FieldWriteOptions, FileWriter, KeyValue, ListLikeFieldWriteOptions, StatisticsOptions,
+    StructFieldWriteOptions, Version, WriteOptions, to_parquet_schema,
 };
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
 use super::{KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetWriteOptions};
+use crate::parquet::encryption::ParquetEncryptionOptions;
 use crate::prelude::ChildFieldOverwrites;
 use crate::shared::schema_to_arrow_checked;
 
@@ -27,6 +28,8 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_overwrites(self.field_overwrites.clone())
+            .with_encryption(self.encryption.clone())
     }
 }
 
@@ -49,6 +52,8 @@ pub struct ParquetWriter<W> {
     key_value_metadata: Option<KeyValueMetadata>,
     /// Context info for the Parquet file being written.
     context_info: Option<PlHashMap<String, String>>,
+    /// Encrypt the file with Parquet modular encryption.
+    encryption: Option<ParquetEncryptionOptions>,
 }
 
 impl<W> ParquetWriter<W>
@@ -70,6 +75,7 @@ where
             field_overwrites: Vec::new(),
             key_value_metadata: None,
             context_info: None,
+            encryption: None,
         }
     }
 
@@ -107,6 +113,12 @@ where
         self
     }
 
+    /// Set per-field writing properties, such as bloom filters.
+    pub fn with_field_overwrites(mut self, field_overwrites: Vec<ParquetFieldOverwrites>) -> Self {
+        self.field_overwrites = field_overwrites;
+        self
+    }
+
     /// Set custom file-level key value metadata for the Parquet file
     pub fn with_key_value_metadata(mut self, key_value_metadata: Option<KeyValueMetadata>) -> Self {
         self.key_value_metadata = key_value_metadata;
@@ -119,17 +131,22 @@ where
         self
     }
 
+    /// Encrypt the file with Parquet modular encryption.
+    pub fn with_encryption(mut self, encryption: Option<ParquetEncryptionOptions>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
         let column_options = get_column_write_options(&schema, &self.field_overwrites);
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(
-            self.writer,
-            schema,
-            options,
-            &column_options,
-        )?);
+        let mut writer = FileWriter::try_new(self.writer, schema, options, &column_options)?;
+        if let Some(encryption) = &self.encryption {
+            writer = writer.with_encryption(&encryption.to_properties())?;
+        }
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
@@ -186,6 +203,7 @@ fn to_column_write_options_rec(
         // Dummy value.
         children: ChildWriteOptions::Leaf(FieldWriteOptions {
             encoding: Encoding::Plain,
+            bloom_filter: None,
         }),
     };
 
@@ -201,6 +219,9 @@ fn to_column_write_options_rec(
         | Dictionary(_) | LargeUtf8 | BinaryView | Utf8View => {
             column_options.children = ChildWriteOptions::Leaf(FieldWriteOptions {
                 encoding: encoding_map(field.dtype()),
+                bloom_filter: overwrites
+                    .is_some_and(|o| o.bloom_filter)
+                    .then(BloomFilterOptions::default),
             });
         },
         List | FixedSizeList | LargeList => {
@@ -230,6 +251,7 @@ fn to_column_write_options_rec(
                     // Allow empty structs by mapping to boolean array.
                     column_options.children = ChildWriteOptions::Leaf(FieldWriteOptions {
                         encoding: Encoding::Rle,
+                        bloom_filter: None,
                     });
                 } else {
                     let children_overwrites = overwrites.and_then(|o| match &o.children {
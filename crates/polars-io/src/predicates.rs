@@ -162,6 +162,69 @@ fn cast_to_parquet_scalar(scalar: Scalar) -> Option<ParquetScalar> {
     })
 }
 
+#[cfg(feature = "parquet")]
+impl SpecializedColumnPredicate {
+    /// Returns whether the split-block bloom filter `bitset` of a Parquet column chunk rules out
+    /// all values for which this predicate holds, meaning that the column chunk can be skipped.
+    pub fn is_excluded_by_bloom_filter(
+        &self,
+        bitset: &[u8],
+        physical_type: polars_parquet::parquet::schema::types::PhysicalType,
+    ) -> bool {
+        use polars_parquet::parquet::bloom_filter::is_in_set;
+
+        if bitset.is_empty() {
+            return false;
+        }
+
+        let may_contain = |scalar: &Scalar| {
+            bloom_filter_hash(scalar, physical_type).is_none_or(|hash| is_in_set(bitset, hash))
+        };
+
+        match self {
+            Self::Equal(scalar) => !may_contain(scalar),
+            Self::EqualOneOf(scalars) => !scalars.iter().any(may_contain),
+            _ => false,
+        }
+    }
+}
+
+/// The bloom filter hash of `scalar` in a column chunk with `physical_type`.
+///
+/// Returns `None` if the value can't be looked up in the bloom filter. Nulls are never inserted
+/// into bloom filters.
+#[cfg(feature = "parquet")]
+fn bloom_filter_hash(
+    scalar: &Scalar,
+    physical_type: polars_parquet::parquet::schema::types::PhysicalType,
+) -> Option<u64> {
+    use ParquetScalar as P;
+    use polars_parquet::parquet::bloom_filter::{hash_byte, hash_native};
+    use polars_parquet::parquet::schema::types::PhysicalType as T;
+
+    // Integers are hashed after the same (wrapping) cast to their physical type that is done
+    // when writing.
+    let v = match (cast_to_parquet_scalar(scalar.clone())?, physical_type) {
+        (P::String(v), T::ByteArray) => return Some(hash_byte(v.as_bytes())),
+        (P::Binary(v), T::ByteArray) => return Some(hash_byte(v)),
+        (P::Int8(v), _) => v as i64,
+        (P::Int16(v), _) => v as i64,
+        (P::Int32(v), _) => v as i64,
+        (P::Int64(v), _) => v,
+        (P::UInt8(v), _) => v as i64,
+        (P::UInt16(v), _) => v as i64,
+        (P::UInt32(v), _) => v as i64,
+        (P::UInt64(v), _) => v as i64,
+        _ => return None,
+    };
+
+    match physical_type {
+        T::Int32 => Some(hash_native(v as i32)),
+        T::Int64 => Some(hash_native(v)),
+        _ => None,
+    }
+}
+
 #[cfg(any(feature = "parquet", feature = "ipc"))]
 pub fn apply_predicate(
     df: &mut DataFrame,
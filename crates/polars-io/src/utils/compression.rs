@@ -1,13 +1,21 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
 use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
+use polars_utils::compression::{GzipLevel, ZstdLevel};
+use polars_utils::mmap::{MemReader, MemSlice};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
     GZIP,
     ZLIB,
     ZSTD,
+    BZIP2,
+    XZ,
+    /// LZ4 frame format.
+    LZ4,
 }
 
 impl SupportedCompression {
@@ -27,9 +35,35 @@ impl SupportedCompression {
             [0x78, 0x9c, _, _] => Some(Self::ZLIB),
             [0x78, 0xda, _, _] => Some(Self::ZLIB),
             [0x28, 0xb5, 0x2f, 0xfd] => Some(Self::ZSTD),
+            // "BZh" followed by the block size, and either the magic of the first block or the
+            // end-of-stream marker of an empty stream.
+            [0x42, 0x5a, 0x68, b'1'..=b'9']
+                if bytes[4..].starts_with(&[0x31, 0x41, 0x59, 0x26, 0x53, 0x59])
+                    || bytes[4..].starts_with(&[0x17, 0x72, 0x45, 0x38, 0x50, 0x90]) =>
+            {
+                Some(Self::BZIP2)
+            },
+            [0x04, 0x22, 0x4d, 0x18] => Some(Self::LZ4),
+            _ if bytes.starts_with(&[0xfd, 0x37, 0x7a, 0x58, 0x5a, 0x00]) => Some(Self::XZ),
             _ => None,
         }
     }
+
+    /// Wraps `reader` in a decoder for this compression.
+    #[cfg(feature = "decompress")]
+    pub fn decoder<'a, R: Read + Send + 'a>(
+        &self,
+        reader: R,
+    ) -> PolarsResult<Box<dyn Read + Send + 'a>> {
+        Ok(match self {
+            Self::GZIP => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+            Self::ZLIB => Box::new(flate2::read::ZlibDecoder::new(reader)),
+            Self::ZSTD => Box::new(zstd::Decoder::new(reader)?),
+            Self::BZIP2 => Box::new(bzip2::read::MultiBzDecoder::new(reader)),
+            Self::XZ => Box::new(liblzma::read::XzDecoder::new_multi_decoder(reader)),
+            Self::LZ4 => Box::new(lz4::Decoder::new(reader)?),
+        })
+    }
 }
 
 /// Decompress `bytes` if compression is detected, otherwise simply return it.
@@ -40,21 +74,9 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
 
     if let Some(algo) = SupportedCompression::check(bytes) {
         feature_gated!("decompress", {
-            match algo {
-                SupportedCompression::GZIP => {
-                    flate2::read::MultiGzDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZLIB => {
-                    flate2::read::ZlibDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZSTD => {
-                    zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
-                },
-            }
+            algo.decoder(bytes)?
+                .read_to_end(out)
+                .map_err(to_compute_err)?;
 
             Ok(out)
         })
@@ -62,3 +84,262 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Incrementally decompresses a file, so that it can be processed in chunks of bounded size
+/// rather than being inflated into memory all at once.
+pub struct StreamingDecompressor {
+    decoder: Box<dyn Read + Send>,
+    is_finished: bool,
+}
+
+impl StreamingDecompressor {
+    /// Returns `None` if no compression is detected in `bytes`.
+    pub fn try_new(bytes: MemSlice) -> PolarsResult<Option<Self>> {
+        let Some(algo) = SupportedCompression::check(&bytes) else {
+            return Ok(None);
+        };
+
+        feature_gated!("decompress", {
+            Ok(Some(Self {
+                decoder: algo.decoder(MemReader::new(bytes))?,
+                is_finished: false,
+            }))
+        })
+    }
+
+    /// Appends up to `n` decompressed bytes to `buf`, returning the number of bytes appended.
+    /// Fewer than `n` bytes are only appended once the end of the data is reached.
+    pub fn read_chunk(&mut self, buf: &mut Vec<u8>, n: usize) -> PolarsResult<usize> {
+        buf.reserve(n);
+
+        let n_read = (&mut self.decoder)
+            .take(n as u64)
+            .read_to_end(buf)
+            .map_err(to_compute_err)?;
+
+        self.is_finished |= n_read < n;
+
+        Ok(n_read)
+    }
+
+    /// Whether all data has been decompressed.
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+}
+
+/// Compression applied to a whole file when writing it, e.g. to produce `.csv.gz` files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FileCompression {
+    #[default]
+    Uncompressed,
+    Gzip(Option<GzipLevel>),
+    Zstd(Option<ZstdLevel>),
+}
+
+/// Writer that compresses everything written to it with a [`FileCompression`].
+///
+/// [`CompressedWriter::finish`] must be called to write the end of the compressed stream.
+pub enum CompressedWriter<W: Write> {
+    Uncompressed(W),
+    #[cfg(feature = "compress")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "compress")]
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn new(writer: W, compression: FileCompression) -> PolarsResult<Self> {
+        if compression == FileCompression::Uncompressed {
+            return Ok(Self::Uncompressed(writer));
+        }
+
+        feature_gated!("compress", {
+            Ok(match compression {
+                FileCompression::Uncompressed => unreachable!(),
+                FileCompression::Gzip(level) => {
+                    let level = level.unwrap_or_default().compression_level();
+                    Self::Gzip(flate2::write::GzEncoder::new(
+                        writer,
+                        flate2::Compression::new(level as u32),
+                    ))
+                },
+                FileCompression::Zstd(level) => {
+                    let level = level.unwrap_or_default().compression_level();
+                    Self::Zstd(zstd::Encoder::new(writer, level)?)
+                },
+            })
+        })
+    }
+
+    /// Returns the inner writer, which holds the output compressed so far.
+    pub fn get_mut(&mut self) -> &mut W {
+        match self {
+            Self::Uncompressed(writer) => writer,
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.get_mut(),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.get_mut(),
+        }
+    }
+
+    /// Finishes the compressed stream and returns the inner writer.
+    pub fn finish(self) -> PolarsResult<W> {
+        Ok(match self {
+            Self::Uncompressed(writer) => writer,
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.finish()?,
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.finish()?,
+        })
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Uncompressed(writer) => writer.write(buf),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Uncompressed(writer) => writer.flush(),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "decompress"))]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn compressed() -> Vec<(&'static str, Vec<u8>)> {
+        let data = b"a,b\n1,2\n3,4\n".repeat(100);
+
+        let mut gz = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        gz.write_all(&data).unwrap();
+
+        let mut bz = bzip2::write::BzEncoder::new(vec![], bzip2::Compression::default());
+        bz.write_all(&data).unwrap();
+
+        let mut xz = liblzma::write::XzEncoder::new(vec![], 6);
+        xz.write_all(&data).unwrap();
+
+        let mut lz4 = lz4::EncoderBuilder::new().build(vec![]).unwrap();
+        lz4.write_all(&data).unwrap();
+
+        vec![
+            ("gzip", gz.finish().unwrap()),
+            ("zstd", zstd::encode_all(data.as_slice(), 0).unwrap()),
+            ("bzip2", bz.finish().unwrap()),
+            ("xz", xz.finish().unwrap()),
+            ("lz4", lz4.finish().0),
+        ]
+    }
+
+    #[test]
+    fn test_maybe_decompress_bytes() {
+        let data = b"a,b\n1,2\n3,4\n".repeat(100);
+
+        for (name, bytes) in compressed() {
+            assert!(SupportedCompression::check(&bytes).is_some(), "{name}");
+
+            let mut out = vec![];
+            assert_eq!(
+                maybe_decompress_bytes(&bytes, &mut out).unwrap(),
+                data,
+                "{name}"
+            );
+        }
+
+        let mut out = vec![];
+        assert_eq!(maybe_decompress_bytes(&data, &mut out).unwrap(), data);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_check_bzip2() {
+        let mut bz = bzip2::write::BzEncoder::new(vec![], bzip2::Compression::default());
+        bz.write_all(b"").unwrap();
+        let empty = bz.finish().unwrap();
+        assert!(matches!(
+            SupportedCompression::check(&empty),
+            Some(SupportedCompression::BZIP2)
+        ));
+
+        // Text that happens to start with "BZh" and a digit is not compressed.
+        for bytes in [&b"BZh1"[..], b"BZh9,a,b\n1,2,3\n"] {
+            assert!(SupportedCompression::check(bytes).is_none());
+
+            let mut out = vec![];
+            assert_eq!(maybe_decompress_bytes(bytes, &mut out).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_streaming_decompressor() {
+        let data = b"a,b\n1,2\n3,4\n".repeat(100);
+
+        for (name, bytes) in compressed() {
+            let mut decompressor = StreamingDecompressor::try_new(MemSlice::from_vec(bytes))
+                .unwrap()
+                .unwrap();
+            let mut out = vec![];
+
+            while !decompressor.is_finished() {
+                let n_read = decompressor.read_chunk(&mut out, 7).unwrap();
+                assert!(n_read <= 7);
+            }
+
+            assert_eq!(out, data, "{name}");
+        }
+
+        assert!(
+            StreamingDecompressor::try_new(MemSlice::from_vec(data))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[cfg(feature = "compress")]
+    #[test]
+    fn test_compressed_writer_incremental() {
+        let data = b"a,b\n1,2\n3,4\n".repeat(100);
+
+        for compression in [
+            FileCompression::Uncompressed,
+            FileCompression::Gzip(None),
+            FileCompression::Gzip(Some(GzipLevel::try_new(9).unwrap())),
+            FileCompression::Zstd(None),
+            FileCompression::Zstd(Some(ZstdLevel::try_new(19).unwrap())),
+        ] {
+            // Take the output as it is produced, as the streaming sinks do.
+            let mut out = vec![];
+            let mut writer = CompressedWriter::new(vec![], compression).unwrap();
+            for part in data.chunks(100) {
+                writer.write_all(part).unwrap();
+                out.append(writer.get_mut());
+            }
+            out.extend(writer.finish().unwrap());
+
+            let mut decompressed = vec![];
+            assert_eq!(
+                maybe_decompress_bytes(&out, &mut decompressed).unwrap(),
+                data,
+                "{compression:?}"
+            );
+        }
+    }
+}
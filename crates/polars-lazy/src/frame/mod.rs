@@ -223,6 +223,12 @@ impl LazyFrame {
         self
     }
 
+    /// Reorder chains of inner joins so that the most selective joins run first.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::JOIN_REORDER, toggle);
+        self
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::EAGER, toggle);
@@ -1003,6 +1009,25 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an Avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(
+        self,
+        target: SinkTarget,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::Avro(options),
+            cloud_options,
+        }))
+    }
+
     /// Stream a query result into a parquet file in a partitioned manner. This is useful if the
     /// final result doesn't fit into memory. This methods will return an error if the query cannot
     /// be completely done in a streaming fashion.
@@ -1115,6 +1140,34 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an Avro file in a partitioned manner. This is useful if the
+    /// final result doesn't fit into memory. This methods will return an error if the query cannot
+    /// be completely done in a streaming fashion.
+    #[cfg(feature = "avro")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn sink_avro_partitioned(
+        self,
+        base_path: Arc<PlPath>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        variant: PartitionVariant,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+        per_partition_sort_by: Option<Vec<SortColumn>>,
+        finish_callback: Option<SinkFinishCallback>,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Partition(PartitionSinkType {
+            base_path,
+            file_path_cb,
+            sink_options,
+            variant,
+            file_type: FileType::Avro(options),
+            cloud_options,
+            per_partition_sort_by,
+            finish_callback,
+        }))
+    }
+
     pub fn sink_batches(
         self,
         function: PlanCallback<DataFrame, bool>,
@@ -1481,6 +1534,42 @@ impl LazyFrame {
         )
     }
 
+    /// Join this query with another lazy query on similar string keys.
+    ///
+    /// Pairs every row of `self` with the rows of `other` whose key is within the threshold of
+    /// `options`, and adds the score of every pair as a column. For more flexible join logic,
+    /// see [`join_builder`](LazyFrame::join_builder).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn dedupe_names(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         let options = FuzzyJoinOptions::new(
+    ///             StringSimilarityMetric::Levenshtein,
+    ///             FuzzyJoinThreshold::Distance(2),
+    ///         );
+    ///         ldf
+    ///         .fuzzy_join(other, col("name"), col("name"), options)
+    /// }
+    /// ```
+    #[cfg(feature = "fuzzy_join")]
+    pub fn fuzzy_join<E: Into<Expr>>(
+        self,
+        other: LazyFrame,
+        left_on: E,
+        right_on: E,
+        options: FuzzyJoinOptions,
+    ) -> LazyFrame {
+        self.join(
+            other,
+            [left_on.into()],
+            [right_on.into()],
+            JoinArgs::new(JoinType::Fuzzy(Box::new(options))),
+        )
+    }
+
     /// Creates the Cartesian product from both frames, preserving the order of the left keys.
     #[cfg(feature = "cross_join")]
     pub fn cross_join(self, other: LazyFrame, suffix: Option<PlSmallStr>) -> LazyFrame {
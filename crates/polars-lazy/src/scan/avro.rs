@@ -0,0 +1,33 @@
+use arrow::buffer::Buffer;
+use polars_core::prelude::*;
+use polars_io::avro::AvroScanOptions;
+use polars_utils::plpath::PlPath;
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an avro scan.
+    pub fn scan_avro(
+        path: PlPath,
+        options: AvroScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Self::scan_avro_sources(
+            ScanSources::Paths(Buffer::from_iter([path])),
+            options,
+            unified_scan_args,
+        )
+    }
+
+    pub fn scan_avro_sources(
+        sources: ScanSources,
+        options: AvroScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        let lf = DslBuilder::scan_avro(sources, options, unified_scan_args)?
+            .build()
+            .into();
+
+        Ok(lf)
+    }
+}
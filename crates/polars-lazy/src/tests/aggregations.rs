@@ -568,3 +568,26 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+fn test_approx_quantile_invalid_quantile() -> PolarsResult<()> {
+    let lf = df!["a" => [1.0, 2.0, 3.0]]?.lazy();
+
+    for quantile in [-0.1, 1.5, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let result = lf
+            .clone()
+            .select([col("a").approx_quantile(quantile)])
+            .collect();
+        assert!(matches!(result, Err(PolarsError::InvalidOperation(_))));
+    }
+
+    let out = lf
+        .select([
+            col("a").approx_quantile(0.0).alias("min"),
+            col("a").approx_quantile(1.0).alias("max"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("min")?.f64()?.get(0), Some(1.0));
+    assert_eq!(out.column("max")?.f64()?.get(0), Some(3.0));
+    Ok(())
+}
@@ -650,3 +650,301 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// The first column of the right input of every join in the left-deep chain at the root, from the
+/// bottom up.
+fn join_order(lp_arena: &Arena<IR>, mut node: Node) -> Vec<PlSmallStr> {
+    let mut order = vec![];
+    loop {
+        match lp_arena.get(node) {
+            IR::SimpleProjection { input, .. } => node = *input,
+            IR::Join {
+                input_left,
+                input_right,
+                ..
+            } => {
+                let schema = lp_arena.get(*input_right).schema(lp_arena);
+                order.push(schema.get_at_index(0).unwrap().0.clone());
+                node = *input_left;
+            },
+            _ => break,
+        }
+    }
+    order.reverse();
+    order
+}
+
+fn star_schema_query(num_sales: i64) -> LazyFrame {
+    let sales = df![
+        "store" => (0..num_sales).map(|i| i % 100).collect::<Vec<_>>(),
+        "product" => (0..num_sales).map(|i| (i * 7) % 5000).collect::<Vec<_>>(),
+        "date" => (0..num_sales).map(|i| (i * 13) % 3650).collect::<Vec<_>>(),
+        "amount" => (0..num_sales).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+    let stores = df![
+        "store_id" => (0..100i64).collect::<Vec<_>>(),
+        "region" => (0..100i64).map(|i| i % 5).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+    let products = df![
+        "product_id" => (0..5000i64).collect::<Vec<_>>(),
+        "category" => (0..5000i64).map(|i| i % 17).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+    let dates = df![
+        "date_id" => (0..3650i64).collect::<Vec<_>>(),
+        "year" => (0..3650i64).map(|i| 2000 + i / 365).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+
+    let inner = || JoinArgs::new(JoinType::Inner);
+    sales
+        .lazy()
+        .join(stores.lazy(), [col("store")], [col("store_id")], inner())
+        .join(
+            products.lazy(),
+            [col("product")],
+            [col("product_id")],
+            inner(),
+        )
+        .join(
+            dates.lazy().filter(col("year").eq(lit(2003i64))),
+            [col("date")],
+            [col("date_id")],
+            inner(),
+        )
+}
+
+#[test]
+fn test_join_reorder_star_schema() -> PolarsResult<()> {
+    use polars_plan::dsl::JoinBuildSide;
+
+    let q = star_schema_query(200_000);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    // The filtered dimension is joined first.
+    assert_eq!(
+        join_order(&lp_arena, root),
+        ["date_id", "store_id", "product_id"]
+    );
+    // Only the streaming engine uses the build side.
+    assert!(lp_arena.iter(root).all(|(_, lp)| match lp {
+        IR::Join { options, .. } => options.build_side.is_none(),
+        _ => true,
+    }));
+
+    let root = q
+        .clone()
+        .with_join_reorder(false)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    assert_eq!(
+        join_order(&lp_arena, root),
+        ["store_id", "product_id", "date_id"]
+    );
+
+    // The dimensions are much smaller than the sales, so they are used to build the hash tables.
+    let mut streaming = q.clone();
+    streaming.opt_state |= OptFlags::NEW_STREAMING;
+    let root = streaming.optimize(&mut lp_arena, &mut expr_arena)?;
+    let build_sides = lp_arena
+        .iter(root)
+        .filter_map(|(_, lp)| match lp {
+            IR::Join { options, .. } => Some(options.build_side),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(build_sides, [Some(JoinBuildSide::Right); 3]);
+
+    let sort = |df: DataFrame| df.sort(["amount"], Default::default());
+    let out = sort(q.clone().collect()?)?;
+    let expected = sort(q.with_join_reorder(false).collect()?)?;
+    assert_eq!(out.height(), 19_999);
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder_small_inputs() -> PolarsResult<()> {
+    // The order of the joins hardly matters, so they are kept in the order they were written in.
+    let q = star_schema_query(1000);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    assert_eq!(
+        join_order(&lp_arena, root),
+        ["store_id", "product_id", "date_id"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder_dependencies() -> PolarsResult<()> {
+    // The categories can only be joined once the products are.
+    let categories = df![
+        "category_id" => (0..17i64).collect::<Vec<_>>(),
+        "active" => (0..17i64).map(|i| i == 3).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+    let q = star_schema_query(200_000).join(
+        categories.lazy().filter(col("active")),
+        [col("category")],
+        [col("category_id")],
+        JoinArgs::new(JoinType::Inner),
+    );
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    let order = join_order(&lp_arena, root);
+    let position = |name: &str| order.iter().position(|n| n == name).unwrap();
+    assert_eq!(order.len(), 4);
+    assert!(position("product_id") < position("category_id"));
+    Ok(())
+}
+
+fn has_distinct(q: LazyFrame) -> PolarsResult<bool> {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    Ok(lp_arena
+        .iter(root)
+        .any(|(_, lp)| matches!(lp, IR::Distinct { .. })))
+}
+
+#[test]
+fn test_hint_unique_removes_distinct() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4],
+        "b" => [1, 1, 2, 2],
+    ]?;
+    let any = UniqueKeepStrategy::Any;
+
+    // A superset of the unique columns is unique as well.
+    let q = df
+        .clone()
+        .lazy()
+        .hint(HintIR::Unique(["a".into()].into()))?
+        .filter(col("b").gt(lit(0)))
+        .unique(Some(cols(["a", "b"])), any);
+    assert!(!has_distinct(q.clone())?);
+    assert!(q.collect()?.equals(&df));
+
+    let q = df
+        .clone()
+        .lazy()
+        .hint(HintIR::Unique(["a".into(), "b".into()].into()))?
+        .unique(Some(cols(["a"])), any);
+    assert!(has_distinct(q)?);
+
+    // The keys of a group-by are unique.
+    let q = df
+        .lazy()
+        .group_by([col("b")])
+        .agg([col("a").sum()])
+        .unique_stable(Some(cols(["b"])), any);
+    assert!(!has_distinct(q)?);
+    Ok(())
+}
+
+#[test]
+fn test_hint_cardinality_build_side() -> PolarsResult<()> {
+    use polars_plan::dsl::JoinBuildSide;
+
+    let build_side = |mut q: LazyFrame| -> PolarsResult<Option<JoinBuildSide>> {
+        q.opt_state |= OptFlags::NEW_STREAMING;
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let root = q.optimize(&mut lp_arena, &mut expr_arena)?;
+        Ok(lp_arena
+            .iter(root)
+            .find_map(|(_, lp)| match lp {
+                IR::Join { options, .. } => Some(options.build_side),
+                _ => None,
+            })
+            .unwrap())
+    };
+    let frame = |n: i64| {
+        df![
+            "key" => (0..n).map(|i| i % 10).collect::<Vec<_>>(),
+            "value" => (0..n).collect::<Vec<_>>(),
+        ]
+        .unwrap()
+        .lazy()
+    };
+    let join = |left: LazyFrame, right: LazyFrame| {
+        left.join(
+            right,
+            [col("key")],
+            [col("key")],
+            JoinArgs::new(JoinType::Inner),
+        )
+    };
+
+    // The inputs have a similar size.
+    assert_eq!(build_side(join(frame(1000), frame(1000)))?, None);
+
+    // The hinted row count overrides the size of the input.
+    let q = join(frame(1000), frame(1000).hint(HintIR::RowCount(100))?);
+    assert_eq!(build_side(q)?, Some(JoinBuildSide::Right));
+
+    // The number of groups follows from the distinct count of the keys.
+    let counts = [DistinctCount {
+        column: "key".into(),
+        count: 10,
+    }];
+    let groups = frame(1000)
+        .hint(HintIR::DistinctCount(counts.into()))?
+        .group_by([col("key")])
+        .agg([col("value").sum()]);
+    let q = join(groups, frame(1000));
+    assert_eq!(build_side(q)?, Some(JoinBuildSide::Left));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_hint_partitioned_sink() -> PolarsResult<()> {
+    let sink_variant = |q: LazyFrame| -> PolarsResult<&'static str> {
+        let q = q.sink_parquet_partitioned(
+            Arc::new(PlPath::new("/tmp/hint_partitioned")),
+            None,
+            PartitionVariant::ByKey {
+                key_exprs: vec![col("a")],
+                include_key: true,
+            },
+            ParquetWriteOptions::default(),
+            None,
+            SinkOptions::default(),
+            None,
+            None,
+        )?;
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let root = q.optimize(&mut lp_arena, &mut expr_arena)?;
+        match lp_arena.get(root) {
+            IR::Sink {
+                payload: SinkTypeIR::Partition(sink),
+                ..
+            } => Ok((&sink.variant).into()),
+            _ => unreachable!(),
+        }
+    };
+    let df = df![
+        "a" => [1, 1, 2, 3],
+        "b" => [4, 3, 2, 1],
+    ]?;
+
+    assert_eq!(sink_variant(df.clone().lazy())?, "ByKey");
+    let q = df
+        .clone()
+        .lazy()
+        .hint(HintIR::Partitioned(["a".into()].into()))?
+        .filter(col("b").gt(lit(1)));
+    assert_eq!(sink_variant(q)?, "Parted");
+    let sorted = [Sorted {
+        column: "a".into(),
+        descending: false,
+        nulls_last: false,
+    }];
+    let q = df.lazy().hint(HintIR::Sorted(sorted.into()))?;
+    assert_eq!(sink_variant(q)?, "Parted");
+    Ok(())
+}
@@ -324,6 +324,8 @@ fn create_physical_plan_impl(
                                         .with_row_group_size(options.row_group_size)
                                         .with_data_page_size(options.data_page_size)
                                         .with_key_value_metadata(options.key_value_metadata.clone())
+                                        .with_field_overwrites(options.field_overwrites.clone())
+                                        .with_encryption(options.encryption.clone())
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "ipc")]
@@ -339,7 +341,12 @@ fn create_physical_plan_impl(
                                 FileType::Csv(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::csv::write::CsvWriter;
-                                    CsvWriter::new(BufWriter::new(writer))
+                                    use polars_io::utils::compression::CompressedWriter;
+                                    let mut writer = CompressedWriter::new(
+                                        BufWriter::new(writer),
+                                        options.compression,
+                                    )?;
+                                    CsvWriter::new(&mut writer)
                                         .include_bom(options.include_bom)
                                         .include_header(options.include_header)
                                         .with_separator(options.serialize_options.separator)
@@ -367,15 +374,33 @@ fn create_physical_plan_impl(
                                         .with_null_value(options.serialize_options.null.clone())
                                         .with_quote_style(options.serialize_options.quote_style)
                                         .finish(&mut df)?;
+                                    writer.finish()?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
+                                    use polars_io::utils::compression::CompressedWriter;
 
-                                    JsonWriter::new(BufWriter::new(writer))
+                                    let mut writer = CompressedWriter::new(
+                                        BufWriter::new(writer),
+                                        options.compression,
+                                    )?;
+                                    JsonWriter::new(&mut writer)
                                         .with_json_format(JsonFormat::JsonLines)
                                         .finish(&mut df)?;
+                                    writer.finish()?;
+                                },
+                                #[cfg(feature = "avro")]
+                                FileType::Avro(options) => {
+                                    use polars_io::SerWriter;
+                                    use polars_io::avro::AvroWriter;
+
+                                    df.align_chunks_par();
+                                    AvroWriter::new(BufWriter::new(writer))
+                                        .with_compression(options.compression.map(Into::into))
+                                        .with_name(options.name.to_string())
+                                        .finish(&mut df)?;
                                 },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
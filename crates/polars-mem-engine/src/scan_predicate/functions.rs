@@ -479,6 +479,9 @@ where
                 metadata,
             } => *metadata = None,
 
+            #[cfg(feature = "avro")]
+            FileScanIR::Avro { options: _ } => {},
+
             #[cfg(feature = "csv")]
             FileScanIR::Csv { options: _ } => {},
 
@@ -523,6 +526,40 @@ where
 
             out.map(|x| DeletionFilesList::IcebergPositionDelete(Arc::new(x)))
         },
+        DeletionFilesList::IcebergEqualityDelete(deletions) => {
+            let mut out = None;
+
+            for (out_idx, source_idx) in selected_path_indices.clone().enumerate() {
+                if let Some(v) = deletions.get(&source_idx) {
+                    out.get_or_insert_with(|| {
+                        PlIndexMap::with_capacity(selected_path_indices.size_hint().0 - out_idx)
+                    })
+                    .insert(out_idx, v.clone());
+                }
+            }
+
+            out.map(|x| DeletionFilesList::IcebergEqualityDelete(Arc::new(x)))
+        },
+        DeletionFilesList::DeltaDeletionVector {
+            table_root,
+            vectors,
+        } => {
+            let mut out = None;
+
+            for (out_idx, source_idx) in selected_path_indices.clone().enumerate() {
+                if let Some(v) = vectors.get(&source_idx) {
+                    out.get_or_insert_with(|| {
+                        PlIndexMap::with_capacity(selected_path_indices.size_hint().0 - out_idx)
+                    })
+                    .insert(out_idx, v.clone());
+                }
+            }
+
+            out.map(|x| DeletionFilesList::DeltaDeletionVector {
+                table_root: table_root.clone(),
+                vectors: Arc::new(x),
+            })
+        },
     });
 
     *table_statistics = table_statistics.as_ref().map(|x| {
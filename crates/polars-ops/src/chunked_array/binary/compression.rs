@@ -0,0 +1,205 @@
+//! Per-value compression and decompression of binary values.
+//!
+//! Every value is an independent, self-contained payload, so a column compressed here can be
+//! decompressed by any other implementation of the same format and vice versa.
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use polars_core::prelude::*;
+
+/// The compression format used by [`BinaryNameSpaceImpl::compress`] and
+/// [`BinaryNameSpaceImpl::decompress`].
+///
+/// [`BinaryNameSpaceImpl::compress`]: super::BinaryNameSpaceImpl::compress
+/// [`BinaryNameSpaceImpl::decompress`]: super::BinaryNameSpaceImpl::decompress
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum BinaryCodec {
+    /// A gzip member (RFC 1952). Concatenated members are decompressed as one value.
+    Gzip,
+    /// A zstd frame.
+    Zstd,
+    /// An LZ4 frame.
+    Lz4,
+    /// A raw snappy block, without the framing format. This is the layout used by parquet and
+    /// most other containers.
+    Snappy,
+}
+
+impl BinaryCodec {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+            Self::Lz4 => "lz4",
+            Self::Snappy => "snappy",
+        }
+    }
+
+    /// Check that `level` is a valid compression level for this codec. `None` selects the
+    /// codec's default level.
+    pub fn validate_level(&self, level: Option<i32>) -> PolarsResult<()> {
+        let Some(level) = level else {
+            return Ok(());
+        };
+        let (min, max) = match self {
+            Self::Gzip => (0, 9),
+            Self::Zstd => (1, 22),
+            Self::Lz4 => (0, 12),
+            Self::Snappy => {
+                polars_bail!(InvalidOperation: "`snappy` compression does not support a compression level")
+            },
+        };
+        polars_ensure!(
+            (min..=max).contains(&level),
+            InvalidOperation: "invalid `{}` compression level {}, expected a value in {}..={}",
+            self.name(), level, min, max
+        );
+        Ok(())
+    }
+
+    fn compress_value(&self, level: Option<i32>, value: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip => {
+                let level = flate2::Compression::new(level.unwrap_or(6) as u32);
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), level);
+                encoder.write_all(value)?;
+                encoder.finish()
+            },
+            Self::Zstd => zstd::bulk::compress(value, level.unwrap_or(3)),
+            Self::Lz4 => {
+                let mut encoder = lz4::EncoderBuilder::new()
+                    .level(level.unwrap_or(0) as u32)
+                    .build(Vec::new())?;
+                encoder.write_all(value)?;
+                let (out, result) = encoder.finish();
+                result.map(|_| out)
+            },
+            Self::Snappy => Ok(snap::raw::Encoder::new().compress_vec(value)?),
+        }
+    }
+
+    fn decompress_value(&self, value: &[u8]) -> std::io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        match self {
+            Self::Gzip => {
+                flate2::read::MultiGzDecoder::new(value).read_to_end(&mut out)?;
+            },
+            Self::Zstd => out = zstd::stream::decode_all(value)?,
+            Self::Lz4 => {
+                lz4::Decoder::new(value)?.read_to_end(&mut out)?;
+            },
+            Self::Snappy => out = snap::raw::Decoder::new().decompress_vec(value)?,
+        }
+        Ok(out)
+    }
+}
+
+pub(super) fn compress(
+    ca: &BinaryChunked,
+    codec: BinaryCodec,
+    level: Option<i32>,
+) -> PolarsResult<BinaryChunked> {
+    codec.validate_level(level)?;
+    ca.try_apply_nonnull_values_generic(|s| {
+        codec.compress_value(level, s).map_err(|e| {
+            polars_err!(ComputeError: "failed to compress value with `{}`: {}", codec.name(), e)
+        })
+    })
+}
+
+pub(super) fn decompress(
+    ca: &BinaryChunked,
+    codec: BinaryCodec,
+    strict: bool,
+) -> PolarsResult<BinaryChunked> {
+    if strict {
+        ca.try_apply_nonnull_values_generic(|s| {
+            codec.decompress_value(s).map_err(|e| {
+                polars_err!(
+                    ComputeError:
+                    "invalid `{}` payload found ({}); try setting `strict=false` to ignore",
+                    codec.name(), e
+                )
+            })
+        })
+    } else {
+        Ok(ca.apply(|opt_s| opt_s.and_then(|s| codec.decompress_value(s).ok().map(Cow::Owned))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CODECS: [BinaryCodec; 4] = [
+        BinaryCodec::Gzip,
+        BinaryCodec::Zstd,
+        BinaryCodec::Lz4,
+        BinaryCodec::Snappy,
+    ];
+
+    fn values() -> BinaryChunked {
+        let long = b"polars ".repeat(1000);
+        BinaryChunked::from_slice_options(
+            PlSmallStr::EMPTY,
+            &[Some(b"".as_slice()), Some(b"abc"), None, Some(&long)],
+        )
+    }
+
+    #[test]
+    fn test_compress_roundtrip() {
+        let ca = values();
+        for codec in CODECS {
+            let compressed = compress(&ca, codec, None).unwrap();
+            assert_eq!(compressed.null_count(), 1);
+            // The repetitive value must actually shrink.
+            assert!(compressed.get(3).unwrap().len() < 1000);
+
+            let out = decompress(&compressed, codec, true).unwrap();
+            assert_eq!(
+                out.iter().collect::<Vec<_>>(),
+                ca.iter().collect::<Vec<_>>(),
+                "{codec:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compress_levels() {
+        let ca = values();
+        for (codec, level) in [
+            (BinaryCodec::Gzip, 0),
+            (BinaryCodec::Gzip, 9),
+            (BinaryCodec::Zstd, 22),
+            (BinaryCodec::Lz4, 12),
+        ] {
+            let compressed = compress(&ca, codec, Some(level)).unwrap();
+            let out = decompress(&compressed, codec, true).unwrap();
+            assert_eq!(
+                out.iter().collect::<Vec<_>>(),
+                ca.iter().collect::<Vec<_>>(),
+                "{codec:?} {level}"
+            );
+        }
+
+        assert!(compress(&ca, BinaryCodec::Gzip, Some(10)).is_err());
+        assert!(compress(&ca, BinaryCodec::Zstd, Some(0)).is_err());
+        assert!(compress(&ca, BinaryCodec::Lz4, Some(-1)).is_err());
+        assert!(compress(&ca, BinaryCodec::Snappy, Some(1)).is_err());
+    }
+
+    #[test]
+    fn test_decompress_invalid() {
+        let ca = BinaryChunked::from_slice_options(
+            PlSmallStr::EMPTY,
+            &[Some(b"not compressed".as_slice()), None],
+        );
+        for codec in CODECS {
+            assert!(decompress(&ca, codec, true).is_err(), "{codec:?}");
+            let out = decompress(&ca, codec, false).unwrap();
+            assert_eq!(out.null_count(), 2, "{codec:?}");
+        }
+    }
+}
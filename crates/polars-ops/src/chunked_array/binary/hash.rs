@@ -0,0 +1,235 @@
+//! Portable hash functions and checksums over binary values.
+//!
+//! Unlike the seeded hashes used internally for hash tables, these produce the same output as
+//! the reference implementations and are stable across versions, platforms and sessions.
+use std::borrow::Cow;
+
+use md5::Md5;
+use polars_core::prelude::*;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// The hash function computed by [`BinaryNameSpaceImpl::hash_digest`].
+///
+/// [`BinaryNameSpaceImpl::hash_digest`]: super::BinaryNameSpaceImpl::hash_digest
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum BinaryHashAlgorithm {
+    /// SHA-256 digest of 32 bytes.
+    Sha256,
+    /// SHA-1 digest of 20 bytes.
+    Sha1,
+    /// MD5 digest of 16 bytes.
+    Md5,
+    /// 64-bit xxHash (XXH64).
+    Xxh64 { seed: u64 },
+    /// CRC-32 checksum (ISO-HDLC polynomial, as used by zlib).
+    Crc32,
+    /// 32-bit MurmurHash3 (x86 variant), as in the reference implementation.
+    Murmur3 { seed: u32 },
+    /// The variant of 32-bit MurmurHash3 used by Spark's `hash` function, which differs from the
+    /// reference implementation in how trailing bytes are mixed.
+    SparkMurmur3 { seed: u32 },
+}
+
+impl BinaryHashAlgorithm {
+    pub fn output_dtype(&self) -> DataType {
+        use BinaryHashAlgorithm as H;
+        match self {
+            H::Sha256 | H::Sha1 | H::Md5 => DataType::Binary,
+            H::Xxh64 { .. } => DataType::UInt64,
+            H::Crc32 | H::Murmur3 { .. } => DataType::UInt32,
+            H::SparkMurmur3 { .. } => DataType::Int32,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        use BinaryHashAlgorithm as H;
+        match self {
+            H::Sha256 => "sha256",
+            H::Sha1 => "sha1",
+            H::Md5 => "md5",
+            H::Xxh64 { .. } => "xxh64",
+            H::Crc32 => "crc32",
+            H::Murmur3 { .. } => "murmur3",
+            H::SparkMurmur3 { .. } => "spark_murmur3",
+        }
+    }
+}
+
+pub(super) fn hash_digest(ca: &BinaryChunked, algorithm: BinaryHashAlgorithm) -> Series {
+    use BinaryHashAlgorithm as H;
+    fn digest<D: Digest>(ca: &BinaryChunked) -> Series {
+        ca.apply_values(|s| Cow::Owned(D::digest(s).to_vec()))
+            .into_series()
+    }
+
+    match algorithm {
+        H::Sha256 => digest::<Sha256>(ca),
+        H::Sha1 => digest::<Sha1>(ca),
+        H::Md5 => digest::<Md5>(ca),
+        H::Xxh64 { seed } => ca
+            .apply_nonnull_values_generic::<UInt64Type, _, _>(DataType::UInt64, |s| {
+                xxhash_rust::xxh64::xxh64(s, seed)
+            })
+            .into_series(),
+        H::Crc32 => ca
+            .apply_nonnull_values_generic::<UInt32Type, _, _>(DataType::UInt32, crc32fast::hash)
+            .into_series(),
+        H::Murmur3 { seed } => ca
+            .apply_nonnull_values_generic::<UInt32Type, _, _>(DataType::UInt32, |s| {
+                murmur3_32(s, seed)
+            })
+            .into_series(),
+        H::SparkMurmur3 { seed } => ca
+            .apply_nonnull_values_generic::<Int32Type, _, _>(DataType::Int32, |s| {
+                spark_murmur3_32(s, seed)
+            })
+            .into_series(),
+    }
+}
+
+#[inline(always)]
+fn murmur3_mix_k(k: u32) -> u32 {
+    k.wrapping_mul(0xcc9e_2d51)
+        .rotate_left(15)
+        .wrapping_mul(0x1b87_3593)
+}
+
+#[inline(always)]
+fn murmur3_mix_h(h: u32, k: u32) -> u32 {
+    (h ^ murmur3_mix_k(k))
+        .rotate_left(13)
+        .wrapping_mul(5)
+        .wrapping_add(0xe654_6b64)
+}
+
+#[inline(always)]
+fn murmur3_finalize(mut h: u32, len: usize) -> u32 {
+    h ^= len as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+    h
+}
+
+/// MurmurHash3 x86 32-bit, processing the input in little-endian blocks of 4 bytes.
+pub fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    let mut h = seed;
+    let mut blocks = data.chunks_exact(4);
+    for block in &mut blocks {
+        h = murmur3_mix_h(h, u32::from_le_bytes(block.try_into().unwrap()));
+    }
+
+    let tail = blocks.remainder();
+    if !tail.is_empty() {
+        let k = tail
+            .iter()
+            .rev()
+            .fold(0u32, |k, &byte| (k << 8) | byte as u32);
+        h ^= murmur3_mix_k(k);
+    }
+
+    murmur3_finalize(h, data.len())
+}
+
+/// MurmurHash3 x86 32-bit as computed by Spark (`Murmur3_x86_32.hashUnsafeBytes`).
+///
+/// Unlike [`murmur3_32`], each trailing byte is mixed as a full block on its own, after sign
+/// extension. Spark's `hash` uses a seed of 42 and hashes strings as their UTF-8 bytes.
+pub fn spark_murmur3_32(data: &[u8], seed: u32) -> i32 {
+    let mut h = seed;
+    let mut blocks = data.chunks_exact(4);
+    for block in &mut blocks {
+        h = murmur3_mix_h(h, u32::from_le_bytes(block.try_into().unwrap()));
+    }
+
+    for &byte in blocks.remainder() {
+        h = murmur3_mix_h(h, byte as i8 as i32 as u32);
+    }
+
+    murmur3_finalize(h, data.len()) as i32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hex_digest(ca: &BinaryChunked, algorithm: BinaryHashAlgorithm) -> Vec<Option<String>> {
+        let out = hash_digest(ca, algorithm);
+        out.binary()
+            .unwrap()
+            .iter()
+            .map(|opt| opt.map(|v| v.iter().map(|b| format!("{b:02x}")).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_hash_digest_reference_values() {
+        let ca = BinaryChunked::from_slice_options(
+            PlSmallStr::EMPTY,
+            &[Some(b"".as_slice()), Some(b"abc".as_slice()), None],
+        );
+
+        assert_eq!(
+            hex_digest(&ca, BinaryHashAlgorithm::Sha256),
+            [
+                Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".into()),
+                Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".into()),
+                None
+            ]
+        );
+        assert_eq!(
+            hex_digest(&ca, BinaryHashAlgorithm::Sha1)[1].as_deref(),
+            Some("a9993e364706816aba3e25717850c26c9cd0d89d")
+        );
+        assert_eq!(
+            hex_digest(&ca, BinaryHashAlgorithm::Md5)[1].as_deref(),
+            Some("900150983cd24fb0d6963f7d28e17f72")
+        );
+
+        let out = hash_digest(&ca, BinaryHashAlgorithm::Xxh64 { seed: 0 });
+        assert_eq!(
+            Vec::from(out.u64().unwrap()),
+            [Some(0xef46db3751d8e999), Some(0x44bc2cf5ad770999), None]
+        );
+        let out = hash_digest(&ca, BinaryHashAlgorithm::Crc32);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            [Some(0), Some(0x352441c2), None]
+        );
+    }
+
+    #[test]
+    fn test_murmur3_32() {
+        assert_eq!(murmur3_32(b"", 0), 0);
+        assert_eq!(murmur3_32(b"", 1), 0x514e28b7);
+        assert_eq!(murmur3_32(b"", 0xffffffff), 0x81f16f39);
+        assert_eq!(murmur3_32(b"Hello, world!", 1234), 0xfaf6cdb3);
+        assert_eq!(
+            murmur3_32(b"The quick brown fox jumps over the lazy dog", 0),
+            0x2e4ff723
+        );
+    }
+
+    #[test]
+    fn test_spark_murmur3_32() {
+        // Reference values of `hash(<value>)` in Spark, which uses a seed of 42.
+        assert_eq!(spark_murmur3_32(b"", 42), 142593372);
+        assert_eq!(spark_murmur3_32(b"Spark", 42), 228093765);
+
+        // Blocks of 4 bytes are hashed the same way as the reference implementation.
+        assert_eq!(
+            spark_murmur3_32(b"abcd", 42),
+            murmur3_32(b"abcd", 42) as i32
+        );
+        // Trailing bytes are sign-extended and mixed one at a time.
+        assert_eq!(
+            spark_murmur3_32(b"\xff", 42),
+            murmur3_finalize(murmur3_mix_h(42, u32::MAX), 1) as i32
+        );
+    }
+}
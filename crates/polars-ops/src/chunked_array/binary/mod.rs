@@ -1,5 +1,13 @@
+#[cfg(feature = "binary_compression")]
+mod compression;
+#[cfg(feature = "binary_hash")]
+mod hash;
 mod namespace;
 
+#[cfg(feature = "binary_compression")]
+pub use compression::BinaryCodec;
+#[cfg(feature = "binary_hash")]
+pub use hash::{BinaryHashAlgorithm, murmur3_32, spark_murmur3_32};
 pub use namespace::*;
 use polars_core::prelude::*;
 
@@ -155,6 +155,25 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         }
     }
 
+    /// Hash every value with a portable hash function or checksum.
+    #[cfg(feature = "binary_hash")]
+    fn hash_digest(&self, algorithm: BinaryHashAlgorithm) -> Series {
+        super::hash::hash_digest(self.as_binary(), algorithm)
+    }
+
+    /// Compress every value into an independent payload of the given format.
+    #[cfg(feature = "binary_compression")]
+    fn compress(&self, codec: BinaryCodec, level: Option<i32>) -> PolarsResult<BinaryChunked> {
+        super::compression::compress(self.as_binary(), codec, level)
+    }
+
+    /// Decompress every value. Values that are not valid payloads raise an error if `strict`,
+    /// and become null otherwise.
+    #[cfg(feature = "binary_compression")]
+    fn decompress(&self, codec: BinaryCodec, strict: bool) -> PolarsResult<BinaryChunked> {
+        super::compression::decompress(self.as_binary(), codec, strict)
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn reinterpret(&self, dtype: &DataType, is_little_endian: bool) -> PolarsResult<Series> {
         unsafe {
@@ -14,6 +14,8 @@ use super::*;
 use crate::chunked_array::binary::BinaryNameSpaceImpl;
 #[cfg(feature = "string_normalize")]
 use crate::prelude::strings::normalize::UnicodeForm;
+#[cfg(feature = "string_similarity")]
+use crate::prelude::strings::similarity::StringSimilarityMetric;
 
 // We need this to infer the right lifetimes for the match closure.
 #[inline(always)]
@@ -658,6 +660,38 @@ pub trait StringNameSpaceImpl: AsString {
         normalize::normalize(ca, form)
     }
 
+    /// Levenshtein distance to the values of `other`, counted in characters.
+    #[must_use]
+    #[cfg(feature = "string_similarity")]
+    fn levenshtein(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::levenshtein_distance(ca, other)
+    }
+
+    /// Damerau-Levenshtein distance to the values of `other`, counted in characters.
+    #[must_use]
+    #[cfg(feature = "string_similarity")]
+    fn damerau_levenshtein(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::damerau_levenshtein_distance(ca, other)
+    }
+
+    /// Hamming distance to the values of `other`, null where the lengths differ.
+    #[must_use]
+    #[cfg(feature = "string_similarity")]
+    fn hamming(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::hamming_distance(ca, other)
+    }
+
+    /// Normalized similarity in `[0, 1]` to the values of `other`.
+    #[must_use]
+    #[cfg(feature = "string_similarity")]
+    fn similarity(&self, other: &StringChunked, metric: StringSimilarityMetric) -> Float64Chunked {
+        let ca = self.as_string();
+        similarity::similarity(ca, other, metric)
+    }
+
     /// Reverses the string values
     #[must_use]
     #[cfg(feature = "string_reverse")]
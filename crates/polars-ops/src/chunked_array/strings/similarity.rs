@@ -0,0 +1,359 @@
+//! Edit distances and similarity scores between strings.
+//!
+//! All metrics operate on Unicode scalar values (`char`s), not on bytes. Strings that consist of
+//! ASCII only take a fast path that compares the bytes directly.
+use std::hash::Hash;
+
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+use polars_utils::aliases::PlHashMap;
+
+/// The metric used to compute a normalized similarity score in `[0, 1]`.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum StringSimilarityMetric {
+    /// `1 - levenshtein(a, b) / max(len(a), len(b))`.
+    Levenshtein,
+    /// `1 - damerau_levenshtein(a, b) / max(len(a), len(b))`.
+    DamerauLevenshtein,
+    /// `1 - hamming(a, b) / len(a)`, null if the lengths differ.
+    Hamming,
+    Jaro,
+    JaroWinkler,
+}
+
+/// Reusable buffers for decoding non-ASCII strings into `char`s.
+#[derive(Default)]
+pub struct StringSimilarityScratch {
+    a: Vec<char>,
+    b: Vec<char>,
+}
+
+impl StringSimilarityScratch {
+    /// Calls `f` with both strings as slices of comparable units.
+    #[inline]
+    fn with_units<R>(
+        &mut self,
+        a: &str,
+        b: &str,
+        f_bytes: impl FnOnce(&[u8], &[u8]) -> R,
+        f_chars: impl FnOnce(&[char], &[char]) -> R,
+    ) -> R {
+        if a.is_ascii() && b.is_ascii() {
+            f_bytes(a.as_bytes(), b.as_bytes())
+        } else {
+            self.a.clear();
+            self.a.extend(a.chars());
+            self.b.clear();
+            self.b.extend(b.chars());
+            f_chars(&self.a, &self.b)
+        }
+    }
+
+    pub fn levenshtein(&mut self, a: &str, b: &str) -> usize {
+        self.with_units(a, b, levenshtein, levenshtein)
+    }
+
+    pub fn damerau_levenshtein(&mut self, a: &str, b: &str) -> usize {
+        self.with_units(a, b, damerau_levenshtein, damerau_levenshtein)
+    }
+
+    pub fn hamming(&mut self, a: &str, b: &str) -> Option<usize> {
+        self.with_units(a, b, hamming, hamming)
+    }
+
+    pub fn jaro(&mut self, a: &str, b: &str) -> f64 {
+        self.with_units(a, b, jaro, jaro)
+    }
+
+    pub fn jaro_winkler(&mut self, a: &str, b: &str) -> f64 {
+        self.with_units(a, b, jaro_winkler, jaro_winkler)
+    }
+
+    /// Normalized similarity in `[0, 1]`, where `1` means equal.
+    pub fn similarity(&mut self, a: &str, b: &str, metric: StringSimilarityMetric) -> Option<f64> {
+        self.with_units(
+            a,
+            b,
+            |a, b| similarity_with(a, b, metric),
+            |a, b| similarity_with(a, b, metric),
+        )
+    }
+}
+
+fn similarity_with<T: Eq + Hash + Copy>(
+    a: &[T],
+    b: &[T],
+    metric: StringSimilarityMetric,
+) -> Option<f64> {
+    use StringSimilarityMetric as M;
+
+    let normalize = |dist: usize, len: usize| {
+        if len == 0 {
+            1.0
+        } else {
+            1.0 - dist as f64 / len as f64
+        }
+    };
+    let max_len = a.len().max(b.len());
+    match metric {
+        M::Levenshtein => Some(normalize(levenshtein(a, b), max_len)),
+        M::DamerauLevenshtein => Some(normalize(damerau_levenshtein(a, b), max_len)),
+        M::Hamming => hamming(a, b).map(|d| normalize(d, max_len)),
+        M::Jaro => Some(jaro(a, b)),
+        M::JaroWinkler => Some(jaro_winkler(a, b)),
+    }
+}
+
+/// Minimum number of insertions, deletions and substitutions to turn `a` into `b`.
+pub fn levenshtein<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    // Strip the common prefix and suffix, they don't contribute to the distance.
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    // Keep the shortest string in the inner loop.
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if b.is_empty() {
+        return a.len();
+    }
+
+    let mut row: Vec<usize> = (1..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        let mut diag = i;
+        let mut left = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let up = row[j];
+            left = if x == y {
+                diag
+            } else {
+                1 + diag.min(up).min(left)
+            };
+            diag = up;
+            row[j] = left;
+        }
+    }
+    row[b.len() - 1]
+}
+
+/// Like [`levenshtein`], but transpositions of two adjacent units also count as a single edit.
+///
+/// This is the unrestricted Damerau-Levenshtein distance, substrings may be edited more than once.
+pub fn damerau_levenshtein<T: Eq + Hash + Copy>(a: &[T], b: &[T]) -> usize {
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+
+    // `dist` has an extra leading row and column holding `max_dist`, this lets transpositions
+    // at the start of the strings fall out of the recurrence without special cases.
+    let width = b.len() + 2;
+    let max_dist = a.len() + b.len();
+    let mut dist = vec![0usize; (a.len() + 2) * width];
+    let idx = |i: usize, j: usize| i * width + j;
+
+    dist[idx(0, 0)] = max_dist;
+    for i in 0..=a.len() {
+        dist[idx(i + 1, 0)] = max_dist;
+        dist[idx(i + 1, 1)] = i;
+    }
+    for j in 0..=b.len() {
+        dist[idx(0, j + 1)] = max_dist;
+        dist[idx(1, j + 1)] = j;
+    }
+
+    // Last row in which each unit of `a` was seen.
+    let mut last_row: PlHashMap<T, usize> = PlHashMap::default();
+    for i in 1..=a.len() {
+        // Last column in this row where `a[i - 1]` matched.
+        let mut last_match_col = 0;
+        for j in 1..=b.len() {
+            let last_match_row = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let prev_match_col = last_match_col;
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            if cost == 0 {
+                last_match_col = j;
+            }
+
+            let substitution = dist[idx(i, j)] + cost;
+            let insertion = dist[idx(i + 1, j)] + 1;
+            let deletion = dist[idx(i, j + 1)] + 1;
+            let transposition = dist[idx(last_match_row, prev_match_col)]
+                + (i - last_match_row - 1)
+                + 1
+                + (j - prev_match_col - 1);
+            dist[idx(i + 1, j + 1)] = substitution.min(insertion).min(deletion).min(transposition);
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    dist[idx(a.len() + 1, b.len() + 1)]
+}
+
+/// Number of positions at which `a` and `b` differ, or `None` if their lengths differ.
+pub fn hamming<T: PartialEq>(a: &[T], b: &[T]) -> Option<usize> {
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(x, y)| x != y).count())
+}
+
+/// Jaro similarity in `[0, 1]`, where `1` means equal.
+pub fn jaro<T: PartialEq>(a: &[T], b: &[T]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len().min(b.len()));
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && *x == b[j] {
+                b_matched[j] = true;
+                a_matches.push(x);
+                break;
+            }
+        }
+    }
+
+    let matches = a_matches.len();
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let b_matches = b.iter().zip(&b_matched).filter_map(|(y, m)| m.then_some(y));
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(x, y)| **x != *y)
+        .count()
+        / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Jaro similarity boosted for strings that share a common prefix of up to 4 units.
+pub fn jaro_winkler<T: PartialEq>(a: &[T], b: &[T]) -> f64 {
+    const PREFIX_SCALE: f64 = 0.1;
+    const BOOST_THRESHOLD: f64 = 0.7;
+
+    let sim = jaro(a, b);
+    if sim <= BOOST_THRESHOLD {
+        return sim;
+    }
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+    sim + prefix as f64 * PREFIX_SCALE * (1.0 - sim)
+}
+
+fn distance_kernel(
+    ca: &StringChunked,
+    other: &StringChunked,
+    f: impl Fn(&mut StringSimilarityScratch, &str, &str) -> Option<usize>,
+) -> UInt32Chunked {
+    let mut scratch = StringSimilarityScratch::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        f(&mut scratch, a?, b?).map(|d| d as u32)
+    })
+}
+
+pub fn levenshtein_distance(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    distance_kernel(ca, other, |s, a, b| Some(s.levenshtein(a, b)))
+}
+
+pub fn damerau_levenshtein_distance(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    distance_kernel(ca, other, |s, a, b| Some(s.damerau_levenshtein(a, b)))
+}
+
+pub fn hamming_distance(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    distance_kernel(ca, other, |s, a, b| s.hamming(a, b))
+}
+
+pub fn similarity(
+    ca: &StringChunked,
+    other: &StringChunked,
+    metric: StringSimilarityMetric,
+) -> Float64Chunked {
+    let mut scratch = StringSimilarityScratch::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        scratch.similarity(a?, b?, metric)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        let mut s = StringSimilarityScratch::default();
+        assert_eq!(s.levenshtein("", ""), 0);
+        assert_eq!(s.levenshtein("abc", ""), 3);
+        assert_eq!(s.levenshtein("", "abc"), 3);
+        assert_eq!(s.levenshtein("kitten", "sitting"), 3);
+        assert_eq!(s.levenshtein("sitting", "kitten"), 3);
+        assert_eq!(s.levenshtein("flaw", "lawn"), 2);
+        assert_eq!(s.levenshtein("ca", "abc"), 3);
+        assert_eq!(s.levenshtein("über", "uber"), 1);
+    }
+
+    #[test]
+    fn test_damerau_levenshtein() {
+        let mut s = StringSimilarityScratch::default();
+        assert_eq!(s.damerau_levenshtein("", ""), 0);
+        assert_eq!(s.damerau_levenshtein("abc", ""), 3);
+        assert_eq!(s.damerau_levenshtein("ab", "ba"), 1);
+        assert_eq!(s.damerau_levenshtein("kitten", "sitting"), 3);
+        // Unrestricted, unlike the optimal string alignment distance which gives 3.
+        assert_eq!(s.damerau_levenshtein("ca", "abc"), 2);
+        assert_eq!(s.damerau_levenshtein("ähnlich", "hänlich"), 1);
+    }
+
+    #[test]
+    fn test_hamming() {
+        let mut s = StringSimilarityScratch::default();
+        assert_eq!(s.hamming("karolin", "kathrin"), Some(3));
+        assert_eq!(s.hamming("", ""), Some(0));
+        assert_eq!(s.hamming("abc", "ab"), None);
+        assert_eq!(s.hamming("äbc", "abc"), Some(1));
+    }
+
+    #[test]
+    fn test_jaro() {
+        let mut s = StringSimilarityScratch::default();
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-3;
+        assert_eq!(s.jaro("", ""), 1.0);
+        assert_eq!(s.jaro("abc", ""), 0.0);
+        assert_eq!(s.jaro("abc", "xyz"), 0.0);
+        assert!(close(s.jaro("martha", "marhta"), 0.944));
+        assert!(close(s.jaro("dixon", "dicksonx"), 0.767));
+        assert!(close(s.jaro_winkler("martha", "marhta"), 0.961));
+        assert!(close(s.jaro_winkler("dixon", "dicksonx"), 0.813));
+        assert!(close(s.jaro_winkler("dwayne", "duane"), 0.84));
+    }
+
+    #[test]
+    fn test_similarity() {
+        let mut s = StringSimilarityScratch::default();
+        use StringSimilarityMetric as M;
+        assert_eq!(s.similarity("", "", M::Levenshtein), Some(1.0));
+        assert_eq!(s.similarity("abcd", "abce", M::Levenshtein), Some(0.75));
+        assert_eq!(
+            s.similarity("abcd", "abdc", M::DamerauLevenshtein),
+            Some(0.75)
+        );
+        assert_eq!(s.similarity("abcd", "abce", M::Hamming), Some(0.75));
+        assert_eq!(s.similarity("abcd", "abc", M::Hamming), None);
+    }
+}
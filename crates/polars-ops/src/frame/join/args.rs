@@ -59,6 +59,8 @@ pub enum JoinType {
     IEJoin,
     // Options are set by optimizer/planner in Options
     Cross,
+    #[cfg(feature = "fuzzy_join")]
+    Fuzzy(Box<FuzzyJoinOptions>),
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
@@ -87,6 +89,8 @@ impl JoinCoalesce {
             #[cfg(feature = "iejoin")]
             IEJoin => false,
             Cross => false,
+            #[cfg(feature = "fuzzy_join")]
+            Fuzzy(_) => false,
             #[cfg(feature = "semi_anti_join")]
             Semi | Anti => false,
         }
@@ -218,6 +222,8 @@ impl Display for JoinType {
             #[cfg(feature = "iejoin")]
             IEJoin => "IEJOIN",
             Cross => "CROSS",
+            #[cfg(feature = "fuzzy_join")]
+            Fuzzy(_) => "FUZZY",
             #[cfg(feature = "semi_anti_join")]
             Semi => "SEMI",
             #[cfg(feature = "semi_anti_join")]
@@ -299,6 +305,17 @@ impl JoinType {
             false
         }
     }
+
+    pub fn is_fuzzy(&self) -> bool {
+        #[cfg(feature = "fuzzy_join")]
+        {
+            matches!(self, JoinType::Fuzzy(_))
+        }
+        #[cfg(not(feature = "fuzzy_join"))]
+        {
+            false
+        }
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Default, Hash)]
@@ -0,0 +1,586 @@
+//! Approximate string matching join.
+//!
+//! Pairs the rows of two frames whose string keys are within an edit distance or above a
+//! similarity threshold. To avoid comparing every pair of keys, the right keys are indexed by
+//! their q-grams. A candidate pair must share a minimum number of q-grams, which follows from the
+//! threshold and the lengths of both strings. Only those candidates are verified with the exact
+//! metric.
+use std::hash::{Hash, Hasher};
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::{_split_offsets, slice_slice};
+use polars_utils::aliases::PlHashMap;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::chunked_array::strings::{StringSimilarityMetric, StringSimilarityScratch};
+use crate::frame::_finish_join;
+
+/// Padding before the first and after the last character of a key, so that the characters at the
+/// borders are part of as many q-grams as the characters in the middle.
+const PAD: u64 = 0x1F_FFFF;
+const BITS_PER_CHAR: u32 = 21;
+/// Slack for the float comparisons of the filters, which may only ever admit more candidates.
+const EPS: f64 = 1e-9;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FuzzyJoinThreshold {
+    /// Match if the normalized similarity is at least this value, in `[0, 1]`.
+    Similarity(f64),
+    /// Match if the edit distance is at most this value. Only valid for the edit distance metrics.
+    Distance(u32),
+}
+
+impl Hash for FuzzyJoinThreshold {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Similarity(v) => v.to_bits().hash(state),
+            Self::Distance(v) => v.hash(state),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FuzzyJoinOptions {
+    pub metric: StringSimilarityMetric,
+    pub threshold: FuzzyJoinThreshold,
+    /// Name of the output column with the score of every matched pair. This is the similarity
+    /// for a [`FuzzyJoinThreshold::Similarity`] and the edit distance for a
+    /// [`FuzzyJoinThreshold::Distance`].
+    pub score_name: PlSmallStr,
+}
+
+impl FuzzyJoinOptions {
+    pub fn new(metric: StringSimilarityMetric, threshold: FuzzyJoinThreshold) -> Self {
+        Self {
+            metric,
+            threshold,
+            score_name: PlSmallStr::from_static("score"),
+        }
+    }
+
+    pub fn with_score_name(mut self, score_name: PlSmallStr) -> Self {
+        self.score_name = score_name;
+        self
+    }
+
+    pub fn validate(&self) -> PolarsResult<()> {
+        use StringSimilarityMetric as M;
+        match self.threshold {
+            FuzzyJoinThreshold::Similarity(t) => polars_ensure!(
+                (0.0..=1.0).contains(&t),
+                InvalidOperation: "fuzzy join similarity threshold must be in [0, 1], got {}", t
+            ),
+            FuzzyJoinThreshold::Distance(_) => polars_ensure!(
+                matches!(self.metric, M::Levenshtein | M::DamerauLevenshtein | M::Hamming),
+                InvalidOperation: "fuzzy join on a maximum distance requires an edit distance metric, got {:?}",
+                self.metric
+            ),
+        }
+        Ok(())
+    }
+
+    pub fn score_dtype(&self) -> DataType {
+        match self.threshold {
+            FuzzyJoinThreshold::Similarity(_) => DataType::Float64,
+            FuzzyJoinThreshold::Distance(_) => DataType::UInt32,
+        }
+    }
+
+    fn is_edit_distance(&self) -> bool {
+        !matches!(
+            self.metric,
+            StringSimilarityMetric::Jaro | StringSimilarityMetric::JaroWinkler
+        )
+    }
+
+    /// Length of the q-grams that are indexed. The Jaro metrics don't depend on the order of the
+    /// characters beyond their matching window, so they can only be filtered on single characters.
+    fn q(&self) -> usize {
+        if self.is_edit_distance() { 2 } else { 1 }
+    }
+
+    /// Maximum number of edits between two keys of which the longest has `len` characters.
+    fn max_edits(&self, len: usize) -> usize {
+        match self.threshold {
+            FuzzyJoinThreshold::Similarity(t) => ((1.0 - t) * len as f64 + EPS).floor() as usize,
+            FuzzyJoinThreshold::Distance(d) => d as usize,
+        }
+    }
+
+    /// Lower bound of the Jaro similarity of a match.
+    fn min_jaro(&self) -> f64 {
+        let FuzzyJoinThreshold::Similarity(t) = self.threshold else {
+            unreachable!()
+        };
+        match self.metric {
+            // The Winkler boost adds at most `0.4 * (1 - jaro)`.
+            StringSimilarityMetric::JaroWinkler => (t - 0.4) / 0.6,
+            _ => t,
+        }
+    }
+}
+
+/// Inverted q-gram index over the keys of the right side of a fuzzy join.
+pub struct FuzzyJoinIndex {
+    options: FuzzyJoinOptions,
+    keys: StringChunked,
+    /// Length of every key in characters, `u32::MAX` for nulls.
+    lengths: Vec<u32>,
+    /// Row indices of the keys per length in characters.
+    by_length: Vec<Vec<IdxSize>>,
+    /// The rows containing a q-gram, with the number of occurrences in that row.
+    postings: PlHashMap<u64, Vec<(IdxSize, u32)>>,
+}
+
+/// Matched row indices and their scores.
+#[derive(Default)]
+pub struct FuzzyJoinMatches {
+    pub left: Vec<IdxSize>,
+    pub right: Vec<IdxSize>,
+    scores: Vec<f64>,
+}
+
+impl FuzzyJoinMatches {
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    pub fn score_column(&self, options: &FuzzyJoinOptions) -> Column {
+        let name = options.score_name.clone();
+        match options.threshold {
+            FuzzyJoinThreshold::Similarity(_) => {
+                Float64Chunked::from_slice(name, &self.scores).into_column()
+            },
+            FuzzyJoinThreshold::Distance(_) => {
+                UInt32Chunked::from_iter_values(name, self.scores.iter().map(|d| *d as u32))
+                    .into_column()
+            },
+        }
+    }
+
+    fn extend(&mut self, other: FuzzyJoinMatches, left_offset: IdxSize) {
+        self.left
+            .extend(other.left.into_iter().map(|i| i + left_offset));
+        self.right.extend(other.right);
+        self.scores.extend(other.scores);
+    }
+
+    fn slice(self, offset: i64, len: usize) -> Self {
+        Self {
+            left: slice_slice(&self.left, offset, len).to_vec(),
+            right: slice_slice(&self.right, offset, len).to_vec(),
+            scores: slice_slice(&self.scores, offset, len).to_vec(),
+        }
+    }
+}
+
+/// Collects the q-grams of `s` with their number of occurrences.
+fn qgrams(s: &str, q: usize, grams: &mut Vec<(u64, u32)>) {
+    grams.clear();
+    if q == 1 {
+        grams.extend(s.chars().map(|c| (c as u64, 1)));
+    } else {
+        let mut prev = PAD;
+        for c in s.chars().map(|c| c as u64).chain(std::iter::once(PAD)) {
+            grams.push(((prev << BITS_PER_CHAR) | c, 1));
+            prev = c;
+        }
+    }
+    grams.sort_unstable_by_key(|(g, _)| *g);
+    grams.dedup_by(|(g, n), (prev_g, prev_n)| {
+        let dup = g == prev_g;
+        if dup {
+            *prev_n += *n;
+        }
+        dup
+    });
+}
+
+/// Buffers reused while probing the index.
+struct ProbeScratch {
+    similarity: StringSimilarityScratch,
+    grams: Vec<(u64, u32)>,
+    /// Number of shared q-grams per right row.
+    shared: Vec<u32>,
+    touched: Vec<IdxSize>,
+    /// Number of shared q-grams required per right key length, `u32::MAX` if no key of that
+    /// length can match.
+    required: Vec<u32>,
+    candidates: Vec<IdxSize>,
+}
+
+impl FuzzyJoinIndex {
+    pub fn new(keys: &StringChunked, options: FuzzyJoinOptions) -> PolarsResult<Self> {
+        options.validate()?;
+        let keys = keys.rechunk().into_owned();
+        let q = options.q();
+
+        let mut lengths = Vec::with_capacity(keys.len());
+        let mut by_length: Vec<Vec<IdxSize>> = Vec::new();
+        let mut postings: PlHashMap<u64, Vec<(IdxSize, u32)>> = PlHashMap::new();
+        let mut grams = Vec::new();
+        for (i, opt_s) in keys.iter().enumerate() {
+            let i = i as IdxSize;
+            let Some(s) = opt_s else {
+                lengths.push(u32::MAX);
+                continue;
+            };
+            let len = s.chars().count();
+            lengths.push(len as u32);
+            if by_length.len() <= len {
+                by_length.resize_with(len + 1, Vec::new);
+            }
+            by_length[len].push(i);
+
+            qgrams(s, q, &mut grams);
+            for &(g, n) in &grams {
+                postings.entry(g).or_default().push((i, n));
+            }
+        }
+
+        Ok(Self {
+            options,
+            keys,
+            lengths,
+            by_length,
+            postings,
+        })
+    }
+
+    pub fn options(&self) -> &FuzzyJoinOptions {
+        &self.options
+    }
+
+    /// Minimum number of q-grams that a key of `len_l` characters must share with a key of
+    /// `len_r` characters to possibly match. `None` if no such pair can match.
+    fn required_shared(&self, len_l: usize, len_r: usize) -> Option<i64> {
+        use StringSimilarityMetric as M;
+        let options = &self.options;
+        if options.is_edit_distance() {
+            if options.metric == M::Hamming && len_l != len_r {
+                return None;
+            }
+            let max_len = len_l.max(len_r);
+            let k = options.max_edits(max_len);
+            if len_l.abs_diff(len_r) > k {
+                return None;
+            }
+            // Every edit destroys at most `q` q-grams, and a transposition at most `q + 1`.
+            let q = options.q();
+            let per_edit = if options.metric == M::DamerauLevenshtein {
+                q + 1
+            } else {
+                q
+            };
+            Some((max_len + q - 1) as i64 - (k * per_edit) as i64)
+        } else {
+            // With `m` matching characters the Jaro similarity is at most
+            // `(m / len_l + m / len_r + 1) / 3`, and `m` is at most the number of shared
+            // characters.
+            if len_l + len_r == 0 {
+                return Some(0);
+            }
+            let m =
+                (3.0 * options.min_jaro() - 1.0) * (len_l * len_r) as f64 / (len_l + len_r) as f64;
+            let m = (m - EPS).ceil();
+            if m > len_l.min(len_r) as f64 {
+                return None;
+            }
+            Some(m as i64)
+        }
+    }
+
+    /// Exact score of a candidate pair, `None` if it doesn't pass the threshold.
+    fn score(
+        &self,
+        scratch: &mut StringSimilarityScratch,
+        left: &str,
+        right: &str,
+        max_len: usize,
+    ) -> Option<f64> {
+        use StringSimilarityMetric as M;
+        let options = &self.options;
+        if options.is_edit_distance() {
+            let dist = match options.metric {
+                M::Levenshtein => scratch.levenshtein(left, right),
+                M::DamerauLevenshtein => scratch.damerau_levenshtein(left, right),
+                _ => scratch.hamming(left, right)?,
+            };
+            if dist > options.max_edits(max_len) {
+                return None;
+            }
+            Some(match options.threshold {
+                FuzzyJoinThreshold::Distance(_) => dist as f64,
+                FuzzyJoinThreshold::Similarity(_) if max_len == 0 => 1.0,
+                FuzzyJoinThreshold::Similarity(_) => 1.0 - dist as f64 / max_len as f64,
+            })
+        } else {
+            let FuzzyJoinThreshold::Similarity(t) = options.threshold else {
+                unreachable!()
+            };
+            let sim = scratch.similarity(left, right, options.metric)?;
+            (sim >= t).then_some(sim)
+        }
+    }
+
+    /// Finds all matches of `keys` in the index. The matches are ordered by the left row and then
+    /// by the right row. Null keys never match.
+    pub fn probe(&self, keys: &StringChunked) -> FuzzyJoinMatches {
+        let mut out = FuzzyJoinMatches::default();
+        if self.by_length.is_empty() {
+            return out;
+        }
+
+        let mut scratch = ProbeScratch {
+            similarity: StringSimilarityScratch::default(),
+            grams: Vec::new(),
+            shared: vec![0; self.lengths.len()],
+            touched: Vec::new(),
+            required: vec![u32::MAX; self.by_length.len()],
+            candidates: Vec::new(),
+        };
+        let right_arr = self.keys.downcast_as_array();
+
+        for (i, opt_s) in keys.iter().enumerate() {
+            let Some(s) = opt_s else {
+                continue;
+            };
+            self.candidates(s, &mut scratch);
+
+            let len_l = s.chars().count();
+            for &r in &scratch.candidates {
+                // SAFETY: candidates are non-null rows of the index.
+                let right = unsafe { right_arr.value_unchecked(r as usize) };
+                let max_len = len_l.max(self.lengths[r as usize] as usize);
+                if let Some(score) = self.score(&mut scratch.similarity, s, right, max_len) {
+                    out.left.push(i as IdxSize);
+                    out.right.push(r);
+                    out.scores.push(score);
+                }
+            }
+        }
+        out
+    }
+
+    /// Fills `scratch.candidates` with the sorted right rows that may match `s`.
+    fn candidates(&self, s: &str, scratch: &mut ProbeScratch) {
+        let len_l = s.chars().count();
+        scratch.candidates.clear();
+
+        let mut needs_count = false;
+        for (len_r, rows) in self.by_length.iter().enumerate() {
+            scratch.required[len_r] = u32::MAX;
+            if rows.is_empty() {
+                continue;
+            }
+            match self.required_shared(len_l, len_r) {
+                None => {},
+                // The filter can't rule out any key of this length.
+                Some(n) if n <= 0 => scratch.candidates.extend_from_slice(rows),
+                Some(n) => {
+                    scratch.required[len_r] = n as u32;
+                    needs_count = true;
+                },
+            }
+        }
+
+        if needs_count {
+            qgrams(s, self.options.q(), &mut scratch.grams);
+            for (g, n) in &scratch.grams {
+                let Some(rows) = self.postings.get(g) else {
+                    continue;
+                };
+                for &(r, n_r) in rows {
+                    let shared = &mut scratch.shared[r as usize];
+                    if *shared == 0 {
+                        scratch.touched.push(r);
+                    }
+                    *shared += (*n).min(n_r);
+                }
+            }
+
+            for r in scratch.touched.drain(..) {
+                let shared = std::mem::take(&mut scratch.shared[r as usize]);
+                let required = scratch.required[self.lengths[r as usize] as usize];
+                if required != u32::MAX && shared >= required {
+                    scratch.candidates.push(r);
+                }
+            }
+        }
+        scratch.candidates.sort_unstable();
+    }
+}
+
+/// Builds the output of a fuzzy join from the matched rows.
+///
+/// The columns are those of `left`, then those of `right`, then the score.
+pub fn fuzzy_join_materialize(
+    left: &DataFrame,
+    right: &DataFrame,
+    matches: FuzzyJoinMatches,
+    options: &FuzzyJoinOptions,
+    suffix: Option<PlSmallStr>,
+) -> PolarsResult<DataFrame> {
+    let score = matches.score_column(options);
+    let left_idx = IdxCa::from_vec(PlSmallStr::EMPTY, matches.left);
+    let right_idx = IdxCa::from_vec(PlSmallStr::EMPTY, matches.right);
+    // SAFETY: the indices are produced by probing the keys of these frames.
+    let (join_left, join_right) = POOL.join(
+        || unsafe { left.take_unchecked(&left_idx) },
+        || unsafe { right.take_unchecked(&right_idx) },
+    );
+    let mut out = _finish_join(join_left, join_right, suffix)?;
+    out.hstack_mut(&[score])?;
+    Ok(out)
+}
+
+pub(super) fn fuzzy_join(
+    left: &DataFrame,
+    right: &DataFrame,
+    selected_left: Vec<Series>,
+    selected_right: Vec<Series>,
+    options: &FuzzyJoinOptions,
+    suffix: Option<PlSmallStr>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        selected_left.len() == 1 && selected_right.len() == 1,
+        InvalidOperation: "fuzzy join requires exactly one join key on each side"
+    );
+    let left_keys = selected_left[0].str()?;
+    let right_keys = selected_right[0].str()?;
+
+    let index = FuzzyJoinIndex::new(right_keys, options.clone())?;
+    let splits = _split_offsets(left_keys.len(), POOL.current_num_threads());
+    let partial = POOL.install(|| {
+        splits
+            .into_par_iter()
+            .map(|(offset, len)| (offset, index.probe(&left_keys.slice(offset as i64, len))))
+            .collect::<Vec<_>>()
+    });
+
+    let mut matches = FuzzyJoinMatches::default();
+    for (offset, m) in partial {
+        matches.extend(m, offset as IdxSize);
+    }
+    if let Some((offset, len)) = slice {
+        matches = matches.slice(offset, len);
+    }
+
+    fuzzy_join_materialize(left, right, matches, options, suffix)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn brute_force(
+        left: &StringChunked,
+        right: &StringChunked,
+        options: &FuzzyJoinOptions,
+    ) -> Vec<(IdxSize, IdxSize)> {
+        let index = FuzzyJoinIndex::new(right, options.clone()).unwrap();
+        let mut scratch = StringSimilarityScratch::default();
+        let mut out = vec![];
+        for (i, l) in left.iter().enumerate() {
+            for (j, r) in right.iter().enumerate() {
+                let (Some(l), Some(r)) = (l, r) else {
+                    continue;
+                };
+                let max_len = l.chars().count().max(r.chars().count());
+                if index.score(&mut scratch, l, r, max_len).is_some() {
+                    out.push((i as IdxSize, j as IdxSize));
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_fuzzy_join_matches_brute_force() {
+        let words = [
+            "",
+            "a",
+            "ab",
+            "ba",
+            "abc",
+            "acb",
+            "kitten",
+            "sitting",
+            "smitten",
+            "mitten",
+            "martha",
+            "marhta",
+            "dwayne",
+            "duane",
+            "dixon",
+            "dicksonx",
+            "jon smith",
+            "john smith",
+            "jon smyth",
+            "über",
+            "uber",
+            "ubre",
+        ];
+        let mut keys: Vec<Option<&str>> = words.iter().copied().map(Some).collect();
+        keys.push(None);
+        let ca = StringChunked::from_iter_options(PlSmallStr::EMPTY, keys.into_iter());
+
+        use {FuzzyJoinThreshold as T, StringSimilarityMetric as M};
+        let cases = [
+            (M::Levenshtein, T::Distance(0)),
+            (M::Levenshtein, T::Distance(1)),
+            (M::Levenshtein, T::Distance(2)),
+            (M::Levenshtein, T::Similarity(0.7)),
+            (M::DamerauLevenshtein, T::Distance(1)),
+            (M::DamerauLevenshtein, T::Similarity(0.75)),
+            (M::Hamming, T::Distance(1)),
+            (M::Hamming, T::Similarity(0.5)),
+            (M::Jaro, T::Similarity(0.8)),
+            (M::JaroWinkler, T::Similarity(0.9)),
+            (M::JaroWinkler, T::Similarity(0.0)),
+        ];
+        for (metric, threshold) in cases {
+            let options = FuzzyJoinOptions::new(metric, threshold);
+            let index = FuzzyJoinIndex::new(&ca, options.clone()).unwrap();
+            let matches = index.probe(&ca);
+            let found = matches
+                .left
+                .iter()
+                .copied()
+                .zip(matches.right.iter().copied())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                found,
+                brute_force(&ca, &ca, &options),
+                "{metric:?} {threshold:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_join_invalid_options() {
+        let opts = FuzzyJoinOptions::new(
+            StringSimilarityMetric::JaroWinkler,
+            FuzzyJoinThreshold::Distance(1),
+        );
+        assert!(opts.validate().is_err());
+        let opts = FuzzyJoinOptions::new(
+            StringSimilarityMetric::Levenshtein,
+            FuzzyJoinThreshold::Similarity(1.5),
+        );
+        assert!(opts.validate().is_err());
+    }
+}
@@ -301,11 +301,7 @@ pub(super) fn iejoin_par(
             r[0].set_sorted_flag(sorted_flag);
 
             // Compute the row indexes
-            let (idx_l, idx_r) = if options.operator2.is_some() {
-                iejoin_tuples(l, r, options, None)
-            } else {
-                piecewise_merge_join_tuples(l, r, options, None)
-            }?;
+            let (idx_l, idx_r) = iejoin_row_indices(l, r, options)?;
 
             if idx_l.is_empty() {
                 return Ok(None);
@@ -357,6 +353,21 @@ pub(super) fn iejoin(
     unsafe { materialize_join(left, right, &left_row_idx, &right_row_idx, suffix) }
 }
 
+/// Computes the row indices of all matching pairs on the calling thread.
+///
+/// Expects one key per inequality operator in `options`. Rows with a null key never match.
+pub fn iejoin_row_indices(
+    selected_left: Vec<Series>,
+    selected_right: Vec<Series>,
+    options: &IEJoinOptions,
+) -> PolarsResult<(IdxCa, IdxCa)> {
+    if options.operator2.is_some() {
+        iejoin_tuples(selected_left, selected_right, options, None)
+    } else {
+        piecewise_merge_join_tuples(selected_left, selected_right, options, None)
+    }
+}
+
 unsafe fn materialize_join(
     left: &DataFrame,
     right: &DataFrame,
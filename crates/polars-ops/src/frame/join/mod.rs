@@ -3,6 +3,8 @@ mod args;
 mod asof;
 mod cross_join;
 mod dispatch_left_right;
+#[cfg(feature = "fuzzy_join")]
+mod fuzzy;
 mod general;
 mod hash_join;
 #[cfg(feature = "iejoin")]
@@ -21,13 +23,17 @@ pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy};
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
+#[cfg(feature = "fuzzy_join")]
+pub use fuzzy::{
+    FuzzyJoinIndex, FuzzyJoinMatches, FuzzyJoinOptions, FuzzyJoinThreshold, fuzzy_join_materialize,
+};
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "iejoin")]
-pub use iejoin::{IEJoinOptions, InequalityOperator};
+pub use iejoin::{IEJoinOptions, InequalityOperator, iejoin_row_indices};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::POOL;
@@ -247,6 +253,19 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         }
 
+        #[cfg(feature = "fuzzy_join")]
+        if let JoinType::Fuzzy(options) = &args.how {
+            return fuzzy::fuzzy_join(
+                left_df,
+                other,
+                selected_left,
+                selected_right,
+                options,
+                args.suffix,
+                args.slice,
+            );
+        }
+
         // Single keys.
         if selected_left.len() == 1 {
             let s_left = &selected_left[0];
@@ -330,6 +349,10 @@ pub trait DataFrameJoinOps: IntoDf {
                 JoinType::Cross => {
                     unreachable!()
                 },
+                #[cfg(feature = "fuzzy_join")]
+                JoinType::Fuzzy(_) => {
+                    unreachable!()
+                },
             };
         }
         let (lhs_keys, rhs_keys) =
@@ -375,6 +398,10 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Cross => {
                 unreachable!()
             },
+            #[cfg(feature = "fuzzy_join")]
+            JoinType::Fuzzy(_) => {
+                unreachable!()
+            },
             JoinType::Full => {
                 let names_left = selected_left
                     .iter()
@@ -1,7 +1,10 @@
 #[cfg(feature = "dtype-date")]
 use chrono::DateTime;
-use polars_core::prelude::arity::{binary_elementwise_values, try_binary_elementwise};
+use polars_core::prelude::arity::{
+    binary_elementwise_values, broadcast_try_binary_elementwise, try_binary_elementwise,
+};
 use polars_core::prelude::*;
+use polars_core::utils::arrow::temporal_conversions::NANOSECONDS_IN_DAY;
 #[cfg(feature = "dtype-date")]
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
 use polars_utils::binary_search::{find_first_ge_index, find_first_gt_index};
@@ -22,9 +25,11 @@ pub enum Roll {
 
 /// Count the number of business days between `start` and `end`, excluding `end`.
 ///
+/// Datetimes are counted by their (local) date.
+///
 /// # Arguments
-/// - `start`: Series holding start dates.
-/// - `end`: Series holding end dates.
+/// - `start`: Series holding start dates or datetimes.
+/// - `end`: Series holding end dates or datetimes.
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
@@ -40,6 +45,8 @@ pub fn business_day_count(
 
     // Sort now so we can use `binary_search` in the hot for-loop.
     let holidays = normalise_holidays(holidays, &week_mask);
+    let start = to_local_date(start)?;
+    let end = to_local_date(end)?;
     let start_dates = start.date()?;
     let end_dates = end.date()?;
     let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
@@ -389,6 +396,166 @@ pub fn is_business_day(
     Ok(out.into_series())
 }
 
+/// Add a given number of business hours.
+///
+/// A start time outside of business hours first moves to the start of the next business period
+/// if `n` is non-negative, and to the end of the previous business period otherwise. Adding a
+/// whole business day to the start of a business period ends at the end of that period rather
+/// than at the start of the next one.
+///
+/// # Arguments
+/// - `start`: Series holding start datetimes.
+/// - `n`: Number of business hours to add, may be fractional.
+/// - `business_hours`: The start and end of business hours, in nanoseconds since midnight.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn add_business_hours(
+    start: &Series,
+    n: &Series,
+    business_hours: [i64; 2],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+    let [open_ns, close_ns] = business_hours;
+    polars_ensure!(
+        0 <= open_ns && open_ns < close_ns && close_ns <= NANOSECONDS_IN_DAY,
+        ComputeError: "business hours must start before they end, and lie within a single day"
+    );
+
+    let time_unit = match start.dtype() {
+        DataType::Datetime(time_unit, None) => *time_unit,
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(time_zone)) => {
+            let start_naive = replace_time_zone(
+                start.datetime().unwrap(),
+                None,
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            let result_naive = add_business_hours(
+                &start_naive.into_series(),
+                n,
+                business_hours,
+                week_mask,
+                holidays,
+            )?;
+            let result_tz_aware = replace_time_zone(
+                result_naive.datetime().unwrap(),
+                Some(time_zone),
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            return Ok(result_tz_aware.into_series());
+        },
+        _ => polars_bail!(InvalidOperation: "expected datetime, got {}", start.dtype()),
+    };
+    polars_ensure!(
+        n.dtype().is_primitive_numeric(),
+        InvalidOperation: "expected a numeric number of hours, got {}", n.dtype()
+    );
+
+    let ns_per_unit = match time_unit {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    };
+    let params = BusinessHours {
+        open: open_ns / ns_per_unit,
+        close: close_ns / ns_per_unit,
+        units_per_day: NANOSECONDS_IN_DAY / ns_per_unit,
+        week_mask,
+        n_business_days_in_week_mask: week_mask.iter().filter(|&x| *x).count() as i32,
+        // Sort now so we can use `binary_search` in the hot for-loop.
+        holidays: normalise_holidays(holidays, &week_mask),
+    };
+    let units_per_hour = (3_600_000_000_000 / ns_per_unit) as f64;
+
+    let start_datetimes = start.datetime()?;
+    let n = n.cast(&DataType::Float64)?;
+    let n = n.f64()?;
+    polars_ensure!(
+        start_datetimes.len() == n.len() || start_datetimes.len() == 1 || n.len() == 1,
+        length_mismatch = "dt.add_business_hours",
+        start_datetimes.len(),
+        n.len()
+    );
+    let out: Int64Chunked = broadcast_try_binary_elementwise(
+        start_datetimes.physical(),
+        n,
+        |opt_start, opt_n| match (opt_start, opt_n) {
+            (Some(start), Some(n)) => {
+                polars_ensure!(n.is_finite(), ComputeError: "cannot add {} business hours", n);
+                let delta = (n * units_per_hour).round() as i64;
+                params.add(start, delta).map(Some)
+            },
+            _ => Ok(None),
+        },
+    )?;
+    Ok(out.into_datetime(time_unit, None).into_series())
+}
+
+struct BusinessHours {
+    /// Start of business hours since midnight, in the time unit of the data.
+    open: i64,
+    /// End of business hours since midnight, in the time unit of the data.
+    close: i64,
+    units_per_day: i64,
+    week_mask: [bool; 7],
+    n_business_days_in_week_mask: i32,
+    holidays: Vec<i32>,
+}
+
+impl BusinessHours {
+    fn add(&self, timestamp: i64, delta: i64) -> PolarsResult<i64> {
+        let length = self.close - self.open;
+        let date = timestamp.div_euclid(self.units_per_day) as i32;
+        let time = timestamp.rem_euclid(self.units_per_day);
+        // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
+        let is_business_day = unsafe { *self.week_mask.get_unchecked(get_day_of_week(date)) }
+            && self.holidays.binary_search(&date).is_err();
+
+        // Move to a position within a business day, as the offset since the open.
+        let (date, offset) = if delta >= 0 {
+            if !is_business_day || time >= self.close {
+                let (date, _) =
+                    roll_start_date(date + 1, Roll::Forward, &self.week_mask, &self.holidays)?;
+                (date, 0)
+            } else {
+                (date, (time - self.open).max(0))
+            }
+        } else if !is_business_day || time <= self.open {
+            let (date, _) =
+                roll_start_date(date - 1, Roll::Backward, &self.week_mask, &self.holidays)?;
+            (date, length)
+        } else {
+            (date, (time - self.open).min(length))
+        };
+
+        let total = offset + delta;
+        let (mut days, mut remainder) = (total.div_euclid(length), total.rem_euclid(length));
+        if delta > 0 && remainder == 0 {
+            // End at the close rather than at the next open.
+            days -= 1;
+            remainder = length;
+        }
+        let days = i32::try_from(days)
+            .map_err(|_| polars_err!(ComputeError: "business hour offset out of range"))?;
+        let date = add_business_days_impl(
+            date,
+            get_day_of_week(date),
+            days,
+            &self.week_mask,
+            self.n_business_days_in_week_mask,
+            &self.holidays,
+        );
+        Ok(date as i64 * self.units_per_day + self.open + remainder)
+    }
+}
+
 fn roll_start_date(
     mut date: i32,
     roll: Roll,
@@ -432,6 +599,23 @@ fn roll_start_date(
     Ok((date, day_of_week))
 }
 
+/// Convert datetimes to the date they fall on, in local time for time-zone aware datetimes. Other
+/// data types are returned as-is.
+fn to_local_date(s: &Series) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Datetime(_, None) => s.cast(&DataType::Date),
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => replace_time_zone(
+            s.datetime().unwrap(),
+            None,
+            &StringChunked::from_iter(std::iter::once("raise")),
+            NonExistent::Raise,
+        )?
+        .cast(&DataType::Date),
+        _ => Ok(s.clone()),
+    }
+}
+
 /// Sort and deduplicate holidays and remove holidays that are not business days.
 fn normalise_holidays(holidays: &[i32], week_mask: &[bool; 7]) -> Vec<i32> {
     let mut holidays: Vec<i32> = holidays.to_vec();
@@ -7,6 +7,7 @@ pub mod schema;
 pub mod statistics;
 
 use std::io::{Read, Seek};
+use std::sync::Arc;
 
 use arrow::types::{NativeType, i256};
 pub use deserialize::{
@@ -23,13 +24,15 @@ pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_met
 // re-exports of crate::parquet's relevant APIs
 pub use crate::parquet::{
     FallibleStreamingIterator,
+    encryption::KeyRetriever,
     error::ParquetError,
     fallible_streaming_iterator,
     metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
         BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State, decompress,
-        get_column_iterator, read_metadata as _read_metadata,
+        deserialize_footer, get_column_iterator, read_metadata as _read_metadata,
+        read_metadata_with_key_retriever as _read_metadata_with_key_retriever,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -58,6 +61,15 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetadat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, decrypting the metadata of encrypted files with the
+/// keys of `key_retriever`.
+pub fn read_metadata_with_key_retriever<R: Read + Seek>(
+    reader: &mut R,
+    key_retriever: Option<&Arc<dyn KeyRetriever>>,
+) -> PolarsResult<FileMetadata> {
+    Ok(_read_metadata_with_key_retriever(reader, key_retriever)?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
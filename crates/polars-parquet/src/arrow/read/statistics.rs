@@ -12,7 +12,7 @@ use polars_utils::pl_str::PlSmallStr;
 
 use super::{ParquetTimeUnit, RowGroupMetadata};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+use crate::parquet::schema::types::{PhysicalType as ParquetPhysicalType, PrimitiveType};
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
@@ -299,6 +299,41 @@ pub fn deserialize_all(
     field_idx: usize,
 ) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     assert!(!row_groups.is_empty());
+
+    let primitive_type = &row_groups[0].parquet_columns()[field_idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+    let statistics = row_groups
+        .iter()
+        .map(|rg| rg.parquet_columns()[field_idx].statistics().transpose())
+        .collect::<ParquetResult<Vec<_>>>()?;
+
+    deserialize_all_impl(field, primitive_type, statistics)
+}
+
+/// Deserializes the statistics of the pages of a column chunk, as read from its column index,
+/// into arrays with an entry per page.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_all_pages(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    page_statistics: Vec<ParquetStatistics>,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    deserialize_all_impl(
+        field,
+        primitive_type,
+        page_statistics.into_iter().map(Some).collect(),
+    )
+}
+
+fn deserialize_all_impl(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    statistics: Vec<Option<ParquetStatistics>>,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     use ArrowDataType as D;
     match field.dtype() {
         // @TODO: These are all a bit more complex, skip for now.
@@ -308,27 +343,19 @@ pub fn deserialize_all(
         D::Struct(..) => Ok(None),
 
         _ => {
-            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-            let mut distinct_count =
-                MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-
-            let primitive_type = &row_groups[0].parquet_columns()[field_idx]
-                .descriptor()
-                .descriptor
-                .primitive_type;
+            let len = statistics.len();
+            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
+            let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
 
             let logical_type = &primitive_type.logical_type;
             let physical_type = &primitive_type.physical_type;
 
             macro_rules! rmap {
                 ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
-                    let mut min_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-                    let mut max_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-
-                    for rg in row_groups {
-                        let column = &rg.parquet_columns()[field_idx];
-                        let s = column.statistics().transpose()?;
+                    let mut min_arr = <$arr>::with_capacity(len$(, $arg)?);
+                    let mut max_arr = <$arr>::with_capacity(len$(, $arg)?);
 
+                    for s in statistics {
                         let (v_min, v_max, v_null_count, v_distinct_count) = match s {
                             None => (None, None, None, None),
                             Some(s) => {
@@ -401,8 +428,8 @@ pub fn deserialize_all(
             use {ArrowDataType as D, ParquetPhysicalType as PPT};
             let (min_value, max_value) = match (field.dtype(), physical_type) {
                 (D::Null, _) => (
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, len).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, len).to_boxed(),
                 ),
 
                 (D::Boolean, _) => rmap!(
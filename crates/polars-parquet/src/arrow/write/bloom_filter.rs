@@ -0,0 +1,114 @@
+use arrow::array::*;
+use arrow::datatypes::ArrowDataType;
+use arrow::match_integer_type;
+use arrow::types::NativeType;
+use num_traits::AsPrimitive;
+use polars_utils::aliases::PlHashSet;
+
+use super::{BloomFilterOptions, ColumnWriteOptions, to_leaves};
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, num_bytes};
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+/// Builds the split-block bloom filter bitsets of the Parquet columns of `array`.
+///
+/// Returns an entry for every leaf of `array`, which is `None` for leaves that did not request a
+/// bloom filter or whose type is not supported.
+pub fn array_to_bloom_filters(
+    array: &dyn Array,
+    column_options: &ColumnWriteOptions,
+) -> Vec<Option<Vec<u8>>> {
+    let mut field_options = Vec::new();
+    column_options.to_leaves(&mut field_options);
+
+    if field_options.iter().all(|o| o.bloom_filter.is_none()) {
+        return vec![None; field_options.len()];
+    }
+
+    let mut leaves = Vec::new();
+    to_leaves(array, &mut leaves);
+    assert_eq!(leaves.len(), field_options.len());
+
+    leaves
+        .iter()
+        .zip(field_options)
+        .map(|(leaf, field_options)| {
+            leaf_to_bloom_filter(leaf.as_ref(), field_options.bloom_filter.as_ref()?)
+        })
+        .collect()
+}
+
+fn leaf_to_bloom_filter(array: &dyn Array, options: &BloomFilterOptions) -> Option<Vec<u8>> {
+    let mut hashes = PlHashSet::default();
+    hash_values(array, &mut hashes)?;
+
+    let mut bitset = vec![0; num_bytes(hashes.len() as u64, options.fpp)];
+    for hash in hashes {
+        insert(&mut bitset, hash);
+    }
+    Some(bitset)
+}
+
+/// Hashes the non-null values of `array` as they are encoded in Parquet. Returns `None` if the
+/// type is not supported.
+fn hash_values(array: &dyn Array, hashes: &mut PlHashSet<u64>) -> Option<()> {
+    use ArrowDataType as D;
+
+    // Note: the casts below MUST match the casts done when encoding the pages.
+    match array.dtype().to_logical_type() {
+        D::UInt8 => hash_integers::<u8, i32>(array, hashes),
+        D::UInt16 => hash_integers::<u16, i32>(array, hashes),
+        D::UInt32 => hash_integers::<u32, i32>(array, hashes),
+        D::UInt64 => hash_integers::<u64, i64>(array, hashes),
+        D::Int8 => hash_integers::<i8, i32>(array, hashes),
+        D::Int16 => hash_integers::<i16, i32>(array, hashes),
+        D::Int32 | D::Date32 | D::Time32(_) => hash_integers::<i32, i32>(array, hashes),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            hash_integers::<i64, i64>(array, hashes)
+        },
+        D::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            hashes.extend(array.non_null_values_iter().map(hash_byte));
+        },
+        D::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            hashes.extend(array.non_null_values_iter().map(hash_byte));
+        },
+        D::Utf8View => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            hashes.extend(array.non_null_values_iter().map(hash_byte));
+        },
+        D::BinaryView => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            hashes.extend(array.non_null_values_iter().map(hash_byte));
+        },
+        D::Dictionary(key_type, _, _) => {
+            // Only hash the values that are referenced by a key.
+            let values = match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let mut is_used = vec![false; array.values().len()];
+                for k in array.keys_iter().flatten() {
+                    is_used[k] = true;
+                }
+                let mask = BooleanArray::from_slice(is_used);
+                polars_compute::filter::filter(array.values().as_ref(), &mask)
+            });
+            hash_values(values.as_ref(), hashes)?;
+        },
+        _ => return None,
+    }
+
+    Some(())
+}
+
+fn hash_integers<T, P>(array: &dyn Array, hashes: &mut PlHashSet<u64>)
+where
+    T: NativeType + AsPrimitive<P>,
+    P: ParquetNativeType,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    hashes.extend(
+        array
+            .non_null_values_iter()
+            .map(|v| hash_native::<P>(v.as_())),
+    );
+}
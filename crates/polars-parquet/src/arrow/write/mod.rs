@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -36,6 +38,7 @@ pub use utils::write_def_levels;
 
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
+pub use crate::parquet::encryption::{EncryptionAlgorithm, FileEncryptionProperties, KeyRetriever};
 pub use crate::parquet::metadata::{
     Descriptor, FileMetadata, KeyValue, SchemaDescriptor, ThriftFileMetadata,
 };
@@ -125,6 +128,22 @@ impl ColumnWriteOptions {
 #[derive(Clone)]
 pub struct FieldWriteOptions {
     pub encoding: Encoding,
+    /// Write a bloom filter for every column chunk of this field.
+    pub bloom_filter: Option<BloomFilterOptions>,
+}
+
+/// Options to write a split-block bloom filter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The maximum false positive probability of the filter. The filter of a column chunk is
+    /// sized for the number of distinct values in that column chunk.
+    pub fpp: f64,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self { fpp: 0.01 }
+    }
 }
 
 impl ColumnWriteOptions {
@@ -140,7 +159,10 @@ impl ColumnWriteOptions {
 
 impl FieldWriteOptions {
     pub fn default_with_encoding(encoding: Encoding) -> Self {
-        Self { encoding }
+        Self {
+            encoding,
+            bloom_filter: None,
+        }
     }
 
     pub fn into_default_column_write_options(self) -> ColumnWriteOptions {
@@ -160,6 +182,8 @@ pub struct StructFieldWriteOptions {
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::array_to_bloom_filters;
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
 use polars_error::{PolarsResult, polars_bail};
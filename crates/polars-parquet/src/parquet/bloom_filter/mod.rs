@@ -1,11 +1,13 @@
-//! API to read and use bloom filters
+//! API to read, write and use bloom filters
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
+pub use read::{read, read_from_bytes};
 pub use split_block::{insert, is_in_set};
+pub use write::{num_bytes, write};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,29 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn roundtrip() {
+        let mut bitset = vec![0; num_bytes(100, 0.01)];
+        for a in 0..100i32 {
+            insert(&mut bitset, hash_native(a));
+        }
+
+        let mut buffer = vec![];
+        let num_written = write(&mut buffer, &bitset).unwrap();
+        assert_eq!(num_written as usize, buffer.len());
+
+        let mut read_bitset = vec![];
+        read_from_bytes(&buffer, &mut read_bitset).unwrap();
+        assert_eq!(bitset, read_bitset);
+        assert!((0..100i32).all(|a| is_in_set(&read_bitset, hash_native(a))));
+    }
+
+    #[test]
+    fn sizing() {
+        assert_eq!(num_bytes(0, 0.01), 32);
+        // ~9.6 bits per distinct value for a 1% false positive rate.
+        assert_eq!(num_bytes(1000, 0.01), 2048);
+        assert!(num_bytes(1000, 0.0001) > num_bytes(1000, 0.01));
+    }
 }
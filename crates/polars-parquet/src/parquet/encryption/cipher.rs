@@ -0,0 +1,255 @@
+#[cfg(feature = "encryption")]
+use ring::aead::{AES_128_GCM, AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+#[cfg(feature = "encryption")]
+use ring::rand::{SecureRandom, SystemRandom};
+
+#[cfg(not(feature = "encryption"))]
+use crate::parquet::error::Feature;
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// The size of the length prefix of every encrypted module.
+pub(super) const LENGTH_SIZE: usize = 4;
+#[cfg(feature = "encryption")]
+const TAG_SIZE: usize = 16;
+#[cfg(feature = "encryption")]
+const BLOCK_SIZE: usize = 16;
+
+/// An AES key, used in GCM mode for authenticated modules and in CTR mode for the page data of
+/// files encrypted with [`AesGcmCtrV1`](super::EncryptionAlgorithm::AesGcmCtrV1).
+#[cfg(feature = "encryption")]
+pub(crate) struct Cipher {
+    key: LessSafeKey,
+}
+
+// A placeholder type for when encryption is not enabled, which cannot be created.
+#[cfg(not(feature = "encryption"))]
+pub(crate) enum Cipher {}
+
+impl std::fmt::Debug for Cipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cipher").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl Cipher {
+    pub fn try_new(key: &[u8]) -> ParquetResult<Self> {
+        let algorithm = match key.len() {
+            16 => &AES_128_GCM,
+            32 => &AES_256_GCM,
+            24 => {
+                return Err(ParquetError::not_supported(
+                    "AES-192 keys for parquet encryption",
+                ));
+            },
+            n => {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "encryption keys must be 16, 24 or 32 bytes long, got {n} bytes"
+                )));
+            },
+        };
+        let key = UnboundKey::new(algorithm, key)
+            .map_err(|_| ParquetError::InvalidParameter("invalid encryption key".to_string()))?;
+        Ok(Self {
+            key: LessSafeKey::new(key),
+        })
+    }
+
+    /// Decrypts a GCM module `nonce || ciphertext || tag`, without the length prefix.
+    pub fn gcm_decrypt(&self, module: &[u8], aad: &[u8]) -> ParquetResult<Vec<u8>> {
+        if module.len() < NONCE_LEN + TAG_SIZE {
+            return Err(ParquetError::oos("encrypted module is too short"));
+        }
+        let (nonce, ciphertext) = module.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).unwrap();
+
+        let mut buffer = ciphertext.to_vec();
+        let len = self
+            .key
+            .open_in_place(nonce, Aad::from(aad), &mut buffer)
+            .map_err(|_| {
+                ParquetError::oos("failed to decrypt module, the key or the AAD may be wrong")
+            })?
+            .len();
+        buffer.truncate(len);
+        Ok(buffer)
+    }
+
+    /// Encrypts `plaintext` into a length-prefixed GCM module `len || nonce || ciphertext || tag`
+    /// that is appended to `out`.
+    pub fn gcm_encrypt(
+        &self,
+        plaintext: &[u8],
+        aad: &[u8],
+        out: &mut Vec<u8>,
+    ) -> ParquetResult<()> {
+        let nonce = random_nonce()?;
+        let len = module_len(NONCE_LEN + plaintext.len() + TAG_SIZE)?;
+
+        out.reserve(LENGTH_SIZE + NONCE_LEN + plaintext.len() + TAG_SIZE);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&nonce);
+        let start = out.len();
+        out.extend_from_slice(plaintext);
+
+        let tag = self
+            .key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad),
+                &mut out[start..],
+            )
+            .map_err(|_| ParquetError::oos("failed to encrypt module"))?;
+        out.extend_from_slice(tag.as_ref());
+        Ok(())
+    }
+
+    /// Returns whether `signature` is the GCM `nonce || tag` of `plaintext`.
+    pub fn gcm_verify(
+        &self,
+        plaintext: &[u8],
+        aad: &[u8],
+        signature: &[u8],
+    ) -> ParquetResult<bool> {
+        if signature.len() != NONCE_LEN + TAG_SIZE {
+            return Err(ParquetError::oos("invalid length of the footer signature"));
+        }
+        let (nonce, tag) = signature.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).unwrap();
+
+        let mut buffer = plaintext.to_vec();
+        let expected = self
+            .key
+            .seal_in_place_separate_tag(nonce, Aad::from(aad), &mut buffer)
+            .map_err(|_| ParquetError::oos("failed to verify the footer signature"))?;
+        let diff = expected
+            .as_ref()
+            .iter()
+            .zip(tag)
+            .fold(0, |acc, (a, b)| acc | (a ^ b));
+        Ok(diff == 0)
+    }
+
+    /// Decrypts a CTR module `nonce || ciphertext`, without the length prefix.
+    pub fn ctr_decrypt(&self, module: &[u8]) -> ParquetResult<Vec<u8>> {
+        if module.len() < NONCE_LEN {
+            return Err(ParquetError::oos("encrypted module is too short"));
+        }
+        let (nonce, ciphertext) = module.split_at(NONCE_LEN);
+        let mut buffer = ciphertext.to_vec();
+        self.ctr_apply(nonce.try_into().unwrap(), &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Encrypts `plaintext` into a length-prefixed CTR module `len || nonce || ciphertext` that
+    /// is appended to `out`.
+    pub fn ctr_encrypt(&self, plaintext: &[u8], out: &mut Vec<u8>) -> ParquetResult<()> {
+        let nonce = random_nonce()?;
+        let len = module_len(NONCE_LEN + plaintext.len())?;
+
+        out.reserve(LENGTH_SIZE + NONCE_LEN + plaintext.len());
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&nonce);
+        let start = out.len();
+        out.extend_from_slice(plaintext);
+        self.ctr_apply(nonce, &mut out[start..])
+    }
+
+    /// XORs `data` with the AES-CTR keystream of `nonce`, whose 32-bit block counter starts at 1.
+    ///
+    /// GCM encrypts with the same keystream, except that it starts at counter 2 and uses the block
+    /// of counter 1 to mask the tag. As the GHASH of an empty message is zero, the tag of an empty
+    /// message is exactly that first keystream block.
+    fn ctr_apply(&self, nonce: [u8; NONCE_LEN], data: &mut [u8]) -> ParquetResult<()> {
+        let err = || ParquetError::oos("failed to apply AES-CTR keystream");
+
+        let first_block = self
+            .key
+            .seal_in_place_separate_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut [])
+            .map_err(|_| err())?;
+
+        let (head, tail) = data.split_at_mut(data.len().min(BLOCK_SIZE));
+        head.iter_mut()
+            .zip(first_block.as_ref())
+            .for_each(|(d, k)| *d ^= k);
+
+        if !tail.is_empty() {
+            // The tag is meaningless without authentication.
+            let _tag = self
+                .key
+                .seal_in_place_separate_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), tail)
+                .map_err(|_| err())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+impl Cipher {
+    pub fn try_new(_key: &[u8]) -> ParquetResult<Self> {
+        Err(feature_not_active())
+    }
+
+    pub fn gcm_decrypt(&self, _module: &[u8], _aad: &[u8]) -> ParquetResult<Vec<u8>> {
+        match *self {}
+    }
+
+    pub fn gcm_encrypt(
+        &self,
+        _plaintext: &[u8],
+        _aad: &[u8],
+        _out: &mut Vec<u8>,
+    ) -> ParquetResult<()> {
+        match *self {}
+    }
+
+    pub fn gcm_verify(
+        &self,
+        _plaintext: &[u8],
+        _aad: &[u8],
+        _signature: &[u8],
+    ) -> ParquetResult<bool> {
+        match *self {}
+    }
+
+    pub fn ctr_decrypt(&self, _module: &[u8]) -> ParquetResult<Vec<u8>> {
+        match *self {}
+    }
+
+    pub fn ctr_encrypt(&self, _plaintext: &[u8], _out: &mut Vec<u8>) -> ParquetResult<()> {
+        match *self {}
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+fn feature_not_active() -> ParquetError {
+    ParquetError::FeatureNotActive(
+        Feature::Encryption,
+        "read or write encrypted files".to_string(),
+    )
+}
+
+#[cfg(feature = "encryption")]
+pub(super) fn random_bytes<const N: usize>() -> ParquetResult<[u8; N]> {
+    let mut bytes = [0; N];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| ParquetError::oos("failed to generate random bytes"))?;
+    Ok(bytes)
+}
+
+#[cfg(not(feature = "encryption"))]
+pub(super) fn random_bytes<const N: usize>() -> ParquetResult<[u8; N]> {
+    Err(feature_not_active())
+}
+
+#[cfg(feature = "encryption")]
+fn random_nonce() -> ParquetResult<[u8; NONCE_LEN]> {
+    random_bytes()
+}
+
+#[cfg(feature = "encryption")]
+fn module_len(len: usize) -> ParquetResult<u32> {
+    len.try_into()
+        .map_err(|_| ParquetError::oos("encrypted modules must be smaller than 4GiB"))
+}
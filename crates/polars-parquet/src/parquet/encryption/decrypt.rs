@@ -0,0 +1,291 @@
+use std::sync::Arc;
+
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{
+    ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm as TEncryptionAlgorithm, RowGroup,
+};
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::mmap::MemReader;
+
+use super::cipher::{Cipher, LENGTH_SIZE};
+use super::{EncryptionAlgorithm, KeyRetriever, ModuleType, missing_key_retriever, module_aad};
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::page::ParquetPageHeader;
+
+/// Decrypts the footer of a file and resolves the keys of its columns.
+///
+/// Keys that can't be resolved, e.g. because no key retriever is given, only result in an error
+/// once they are needed to decrypt something.
+pub(crate) struct FileDecryptor {
+    algorithm: EncryptionAlgorithm,
+    file_aad: Arc<[u8]>,
+    footer_cipher: Result<Arc<Cipher>, ParquetError>,
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
+    column_ciphers: PlHashMap<Vec<String>, Result<Arc<Cipher>, ParquetError>>,
+}
+
+impl FileDecryptor {
+    pub fn try_new(
+        algorithm: &TEncryptionAlgorithm,
+        footer_key_metadata: Option<&[u8]>,
+        key_retriever: Option<Arc<dyn KeyRetriever>>,
+    ) -> ParquetResult<Self> {
+        let (algorithm, stored_aad_prefix, aad_file_unique, supply_aad_prefix) = match algorithm {
+            TEncryptionAlgorithm::AESGCMV1(a) => (
+                EncryptionAlgorithm::AesGcmV1,
+                &a.aad_prefix,
+                &a.aad_file_unique,
+                a.supply_aad_prefix,
+            ),
+            TEncryptionAlgorithm::AESGCMCTRV1(a) => (
+                EncryptionAlgorithm::AesGcmCtrV1,
+                &a.aad_prefix,
+                &a.aad_file_unique,
+                a.supply_aad_prefix,
+            ),
+        };
+
+        let aad_prefix = match key_retriever.as_ref().and_then(|k| k.aad_prefix()) {
+            Some(aad_prefix) => aad_prefix,
+            None if supply_aad_prefix == Some(true) && key_retriever.is_some() => {
+                return Err(ParquetError::InvalidParameter(
+                    "the file was encrypted with an AAD prefix that must be supplied by the key retriever"
+                        .to_string(),
+                ));
+            },
+            None => stored_aad_prefix.clone().unwrap_or_default(),
+        };
+        let mut file_aad = aad_prefix;
+        file_aad.extend_from_slice(aad_file_unique.as_deref().unwrap_or_default());
+
+        let footer_cipher = match &key_retriever {
+            Some(key_retriever) => Ok(Arc::new(Cipher::try_new(
+                &key_retriever.footer_key(footer_key_metadata)?,
+            )?)),
+            None => Err(missing_key_retriever()),
+        };
+
+        Ok(Self {
+            algorithm,
+            file_aad: file_aad.into(),
+            footer_cipher,
+            key_retriever,
+            column_ciphers: PlHashMap::new(),
+        })
+    }
+
+    /// Decrypts the length-prefixed footer module of a file with an encrypted footer.
+    pub fn decrypt_footer(&self, module: &[u8]) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, 0, 0, None)?;
+        self.footer_cipher()?
+            .gcm_decrypt(strip_length(module, true)?, &aad)
+    }
+
+    /// Verifies the `nonce || tag` signature of the serialized plaintext `footer`.
+    pub fn verify_footer_signature(&self, footer: &[u8], signature: &[u8]) -> ParquetResult<()> {
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, 0, 0, None)?;
+        if self.footer_cipher()?.gcm_verify(footer, &aad, signature)? {
+            Ok(())
+        } else {
+            Err(ParquetError::oos(
+                "the signature of the plaintext footer does not match, the footer key may be wrong",
+            ))
+        }
+    }
+
+    /// Decrypts the metadata of the columns of `row_group` that are encrypted with a column key
+    /// and returns the decryptors of all its columns, which are `None` for plaintext columns.
+    ///
+    /// If the key of a column is not available, its metadata is only readable if the footer is in
+    /// plaintext, which contains a copy of the metadata without statistics. Its decryptor errors
+    /// once its pages are read.
+    pub fn decrypt_row_group(
+        &mut self,
+        ordinal: usize,
+        row_group: &mut RowGroup,
+    ) -> ParquetResult<Vec<Option<Arc<ColumnDecryptor>>>> {
+        row_group
+            .columns
+            .iter_mut()
+            .enumerate()
+            .map(|(i, column)| {
+                let cipher = match &column.crypto_metadata {
+                    None => return Ok(None),
+                    Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => {
+                        self.footer_cipher.clone()
+                    },
+                    Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(key)) => {
+                        self.column_cipher(&key.path_in_schema, key.key_metadata.as_deref())
+                    },
+                };
+
+                if let Some(module) = &column.encrypted_column_metadata {
+                    match &cipher {
+                        Ok(cipher) => {
+                            let aad = module_aad(
+                                &self.file_aad,
+                                ModuleType::ColumnMetaData,
+                                ordinal,
+                                i,
+                                None,
+                            )?;
+                            let bytes = cipher.gcm_decrypt(strip_length(module, false)?, &aad)?;
+                            let max_size = bytes.len() * 2 + 1024;
+                            let mut prot = TCompactInputProtocol::new(bytes.as_slice(), max_size);
+                            column.meta_data =
+                                Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+                        },
+                        // A plaintext footer contains a copy of the metadata without statistics.
+                        Err(_) if column.meta_data.is_some() => {},
+                        Err(err) => return Err(err.clone()),
+                    }
+                }
+
+                Ok(Some(self.column_decryptor(ordinal, i, cipher)))
+            })
+            .collect()
+    }
+
+    fn footer_cipher(&self) -> ParquetResult<&Cipher> {
+        self.footer_cipher.as_deref().map_err(Clone::clone)
+    }
+
+    fn column_decryptor(
+        &self,
+        row_group: usize,
+        column: usize,
+        cipher: Result<Arc<Cipher>, ParquetError>,
+    ) -> Arc<ColumnDecryptor> {
+        Arc::new(ColumnDecryptor {
+            algorithm: self.algorithm,
+            file_aad: self.file_aad.clone(),
+            cipher,
+            row_group,
+            column,
+        })
+    }
+
+    fn column_cipher(
+        &mut self,
+        path_in_schema: &[String],
+        key_metadata: Option<&[u8]>,
+    ) -> Result<Arc<Cipher>, ParquetError> {
+        if let Some(cipher) = self.column_ciphers.get(path_in_schema) {
+            return cipher.clone();
+        }
+        let cipher = match &self.key_retriever {
+            Some(key_retriever) => key_retriever
+                .column_key(path_in_schema, key_metadata)
+                .and_then(|key| Ok(Arc::new(Cipher::try_new(&key)?))),
+            None => Err(missing_key_retriever()),
+        };
+        self.column_ciphers
+            .insert(path_in_schema.to_vec(), cipher.clone());
+        cipher
+    }
+}
+
+/// Decrypts the page headers and pages of an encrypted column chunk.
+#[derive(Debug)]
+pub(crate) struct ColumnDecryptor {
+    algorithm: EncryptionAlgorithm,
+    file_aad: Arc<[u8]>,
+    /// The cipher of the column, or the error of resolving its key.
+    cipher: Result<Arc<Cipher>, ParquetError>,
+    row_group: usize,
+    column: usize,
+}
+
+impl ColumnDecryptor {
+    /// Reads and decrypts the page header at the position of `reader`.
+    ///
+    /// The header of the first page of a column chunk may either belong to a dictionary page or
+    /// to the first data page, which are authenticated differently. If `maybe_dict` is set, both
+    /// are tried.
+    pub fn read_page_header(
+        &self,
+        reader: &mut MemReader,
+        data_page_ordinal: usize,
+        maybe_dict: bool,
+        max_size: usize,
+    ) -> ParquetResult<ParquetPageHeader> {
+        let len = reader.read_slice(LENGTH_SIZE);
+        let len = u32::from_le_bytes(
+            len.as_ref()
+                .try_into()
+                .map_err(|_| ParquetError::oos("encrypted page header is truncated"))?,
+        ) as usize;
+        if len > max_size {
+            return Err(ParquetError::WouldOverAllocate);
+        }
+        let module = reader.read_slice(len);
+        if module.len() != len {
+            return Err(ParquetError::oos("encrypted page header is truncated"));
+        }
+
+        let data_aad = || self.aad(ModuleType::DataPageHeader, Some(data_page_ordinal));
+        let bytes = if maybe_dict {
+            let dict_aad = self.aad(ModuleType::DictionaryPageHeader, None)?;
+            match self.cipher()?.gcm_decrypt(&module, &dict_aad) {
+                Ok(bytes) => bytes,
+                Err(_) => self.cipher()?.gcm_decrypt(&module, &data_aad()?)?,
+            }
+        } else {
+            self.cipher()?.gcm_decrypt(&module, &data_aad()?)?
+        };
+
+        let mut prot = TCompactInputProtocol::new(bytes.as_slice(), max_size);
+        Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+    }
+
+    /// Decrypts the length-prefixed page module `module`.
+    pub fn decrypt_page(
+        &self,
+        module: &[u8],
+        is_dict: bool,
+        data_page_ordinal: usize,
+    ) -> ParquetResult<Vec<u8>> {
+        let module = strip_length(module, true)?;
+        match self.algorithm {
+            EncryptionAlgorithm::AesGcmV1 => {
+                let aad = if is_dict {
+                    self.aad(ModuleType::DictionaryPage, None)?
+                } else {
+                    self.aad(ModuleType::DataPage, Some(data_page_ordinal))?
+                };
+                self.cipher()?.gcm_decrypt(module, &aad)
+            },
+            EncryptionAlgorithm::AesGcmCtrV1 => self.cipher()?.ctr_decrypt(module),
+        }
+    }
+
+    fn cipher(&self) -> ParquetResult<&Cipher> {
+        self.cipher.as_deref().map_err(Clone::clone)
+    }
+
+    fn aad(&self, module_type: ModuleType, page: Option<usize>) -> ParquetResult<Vec<u8>> {
+        module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group,
+            self.column,
+            page,
+        )
+    }
+}
+
+/// Strips the length prefix of `module`. If the prefix is not `required`, a module whose first
+/// bytes do not match its length is assumed to not have one.
+fn strip_length(module: &[u8], required: bool) -> ParquetResult<&[u8]> {
+    let len = module
+        .get(..LENGTH_SIZE)
+        .map(|len| u32::from_le_bytes(len.try_into().unwrap()) as usize);
+    match len {
+        Some(len) if len == module.len() - LENGTH_SIZE => Ok(&module[LENGTH_SIZE..]),
+        Some(len) if required && len < module.len() - LENGTH_SIZE => {
+            Ok(&module[LENGTH_SIZE..LENGTH_SIZE + len])
+        },
+        _ if !required => Ok(module),
+        _ => Err(ParquetError::oos("encrypted module is truncated")),
+    }
+}
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    AesGcmCtrV1, AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm as TEncryptionAlgorithm,
+    EncryptionWithColumnKey, EncryptionWithFooterKey, FileCryptoMetaData, PageType, RowGroup,
+};
+
+use super::cipher::{Cipher, random_bytes};
+use super::{EncryptionAlgorithm, FileEncryptionProperties, ModuleType, module_aad};
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
+use crate::parquet::page::ParquetPageHeader;
+
+/// The size of the unique part of the AAD of every file.
+const AAD_FILE_UNIQUE_SIZE: usize = 8;
+
+/// The key of a column that is encrypted with a key of its own.
+struct ColumnKey {
+    cipher: Arc<Cipher>,
+    path_in_schema: Vec<String>,
+    key_metadata: Option<Vec<u8>>,
+}
+
+/// Encrypts the modules of a file with an encrypted footer. All columns are encrypted, either with
+/// their own key or with the footer key.
+pub(crate) struct FileEncryptor {
+    algorithm: EncryptionAlgorithm,
+    aad_file_unique: Vec<u8>,
+    supply_aad_prefix: bool,
+    file_aad: Arc<[u8]>,
+    footer_cipher: Arc<Cipher>,
+    footer_key_metadata: Option<Vec<u8>>,
+    column_keys: Vec<Option<ColumnKey>>,
+}
+
+impl FileEncryptor {
+    pub fn try_new(
+        properties: &FileEncryptionProperties,
+        schema: &SchemaDescriptor,
+    ) -> ParquetResult<Self> {
+        let key_retriever = &properties.key_retriever;
+
+        if let Some((name, _)) = properties.column_key_metadata.iter().find(|(name, _)| {
+            !schema
+                .columns()
+                .iter()
+                .any(|c| c.path_in_schema[0].as_str() == name)
+        }) {
+            return Err(ParquetError::InvalidParameter(format!(
+                "cannot encrypt column '{name}' with a column key, as it is not in the schema"
+            )));
+        }
+
+        let column_keys = schema
+            .columns()
+            .iter()
+            .map(|column| {
+                let Some((_, key_metadata)) = properties
+                    .column_key_metadata
+                    .iter()
+                    .find(|(name, _)| column.path_in_schema[0].as_str() == name)
+                else {
+                    return Ok(None);
+                };
+                let path_in_schema = column
+                    .path_in_schema
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<_>>();
+                let key = key_retriever.column_key(&path_in_schema, key_metadata.as_deref())?;
+                Ok(Some(ColumnKey {
+                    cipher: Arc::new(Cipher::try_new(&key)?),
+                    path_in_schema,
+                    key_metadata: key_metadata.clone(),
+                }))
+            })
+            .collect::<ParquetResult<Vec<_>>>()?;
+
+        let footer_key = key_retriever.footer_key(properties.footer_key_metadata.as_deref())?;
+        let aad_file_unique = random_bytes::<AAD_FILE_UNIQUE_SIZE>()?.to_vec();
+
+        let mut file_aad = properties.aad_prefix.clone().unwrap_or_default();
+        file_aad.extend_from_slice(&aad_file_unique);
+
+        Ok(Self {
+            algorithm: properties.algorithm,
+            aad_file_unique,
+            supply_aad_prefix: properties.aad_prefix.is_some(),
+            file_aad: file_aad.into(),
+            footer_cipher: Arc::new(Cipher::try_new(&footer_key)?),
+            footer_key_metadata: properties.footer_key_metadata.clone(),
+            column_keys,
+        })
+    }
+
+    /// Returns the encryptor of the column chunk `column` of row group `row_group`.
+    pub fn column(&self, row_group: usize, column: usize) -> ColumnEncryptor {
+        let cipher = self.column_keys[column]
+            .as_ref()
+            .map_or(&self.footer_cipher, |k| &k.cipher);
+        ColumnEncryptor {
+            algorithm: self.algorithm,
+            file_aad: self.file_aad.clone(),
+            cipher: cipher.clone(),
+            row_group,
+            column,
+            data_page_ordinal: 0,
+        }
+    }
+
+    /// Sets the crypto metadata of the columns of `row_group` and moves the metadata of columns
+    /// with a key of their own into their encrypted column metadata.
+    pub fn encrypt_row_group(&self, ordinal: usize, row_group: &mut RowGroup) -> ParquetResult<()> {
+        for (i, column) in row_group.columns.iter_mut().enumerate() {
+            let Some(key) = &self.column_keys[i] else {
+                column.crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                    EncryptionWithFooterKey {},
+                ));
+                continue;
+            };
+
+            column.crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(
+                EncryptionWithColumnKey {
+                    path_in_schema: key.path_in_schema.clone(),
+                    key_metadata: key.key_metadata.clone(),
+                },
+            ));
+
+            let Some(metadata) = column.meta_data.take() else {
+                continue;
+            };
+            let mut bytes = vec![];
+            metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut bytes))?;
+            let aad = module_aad(&self.file_aad, ModuleType::ColumnMetaData, ordinal, i, None)?;
+            let mut module = vec![];
+            key.cipher.gcm_encrypt(&bytes, &aad, &mut module)?;
+            column.encrypted_column_metadata = Some(module);
+        }
+        Ok(())
+    }
+
+    /// Serializes the crypto metadata of the file followed by the encrypted `metadata`.
+    pub fn encrypt_footer(&self, metadata: &ThriftFileMetadata) -> ParquetResult<Vec<u8>> {
+        let aad_prefix = None;
+        let aad_file_unique = Some(self.aad_file_unique.clone());
+        let supply_aad_prefix = Some(self.supply_aad_prefix);
+        let encryption_algorithm = match self.algorithm {
+            EncryptionAlgorithm::AesGcmV1 => TEncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+            EncryptionAlgorithm::AesGcmCtrV1 => TEncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+        };
+        let crypto_metadata = FileCryptoMetaData {
+            encryption_algorithm,
+            key_metadata: self.footer_key_metadata.clone(),
+        };
+
+        let mut out = vec![];
+        crypto_metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut out))?;
+
+        let mut bytes = vec![];
+        metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut bytes))?;
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, 0, 0, None)?;
+        self.footer_cipher.gcm_encrypt(&bytes, &aad, &mut out)?;
+        Ok(out)
+    }
+}
+
+/// Encrypts the modules of a column chunk.
+pub(crate) struct ColumnEncryptor {
+    algorithm: EncryptionAlgorithm,
+    file_aad: Arc<[u8]>,
+    cipher: Arc<Cipher>,
+    row_group: usize,
+    column: usize,
+    data_page_ordinal: usize,
+}
+
+impl ColumnEncryptor {
+    /// Encrypts a page into a length-prefixed header module and page module. The compressed size
+    /// in `header` is updated to the size of the encrypted page.
+    pub fn encrypt_page(
+        &mut self,
+        header: &mut ParquetPageHeader,
+        data: &[u8],
+    ) -> ParquetResult<(Vec<u8>, Vec<u8>)> {
+        let is_dict = header.type_ == PageType::DICTIONARY_PAGE;
+        let page_ordinal = (!is_dict).then_some(self.data_page_ordinal);
+
+        let mut page = vec![];
+        match self.algorithm {
+            EncryptionAlgorithm::AesGcmV1 => {
+                let aad = if is_dict {
+                    self.aad(ModuleType::DictionaryPage, None)?
+                } else {
+                    self.aad(ModuleType::DataPage, page_ordinal)?
+                };
+                self.cipher.gcm_encrypt(data, &aad, &mut page)?;
+            },
+            EncryptionAlgorithm::AesGcmCtrV1 => self.cipher.ctr_encrypt(data, &mut page)?,
+        }
+        header.compressed_page_size = page
+            .len()
+            .try_into()
+            .map_err(|_| ParquetError::oos("An encrypted page can only contain i32::MAX bytes"))?;
+
+        let aad = if is_dict {
+            self.aad(ModuleType::DictionaryPageHeader, None)?
+        } else {
+            self.aad(ModuleType::DataPageHeader, page_ordinal)?
+        };
+        let mut bytes = vec![];
+        header.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut bytes))?;
+        let mut header_module = vec![];
+        self.cipher.gcm_encrypt(&bytes, &aad, &mut header_module)?;
+
+        if !is_dict {
+            self.data_page_ordinal += 1;
+        }
+        Ok((header_module, page))
+    }
+
+    /// Encrypts the serialized column or offset index `bytes` into a length-prefixed module.
+    pub fn encrypt_index(&self, module_type: ModuleType, bytes: &[u8]) -> ParquetResult<Vec<u8>> {
+        let aad = self.aad(module_type, None)?;
+        let mut out = vec![];
+        self.cipher.gcm_encrypt(bytes, &aad, &mut out)?;
+        Ok(out)
+    }
+
+    fn aad(&self, module_type: ModuleType, page: Option<usize>) -> ParquetResult<Vec<u8>> {
+        module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group,
+            self.column,
+            page,
+        )
+    }
+}
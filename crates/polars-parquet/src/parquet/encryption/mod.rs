@@ -0,0 +1,128 @@
+//! Parquet modular encryption, see the
+//! [specification](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Files are encrypted with AES keys that are resolved by a [`KeyRetriever`]. Readers decrypt the
+//! footer and attach a decryptor to the [`ColumnChunkMetadata`] of every encrypted column, which
+//! is used by the [`PageReader`] to transparently decrypt the pages of the column.
+//!
+//! The ciphers are only available with the `encryption` feature. Without it, reading or writing
+//! an encrypted file fails with [`ParquetError::FeatureNotActive`].
+//!
+//! [`ColumnChunkMetadata`]: crate::parquet::metadata::ColumnChunkMetadata
+//! [`PageReader`]: crate::parquet::read::PageReader
+mod cipher;
+mod decrypt;
+mod encrypt;
+
+use std::sync::Arc;
+
+pub(crate) use decrypt::{ColumnDecryptor, FileDecryptor};
+pub(crate) use encrypt::{ColumnEncryptor, FileEncryptor};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// The magic bytes at the start and the end of files with an encrypted footer.
+pub const PARQUET_ENCRYPTED_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'E'];
+
+/// Resolves the keys used to encrypt and decrypt a Parquet file.
+///
+/// Keys must be 16 or 32 bytes long (AES-128 and AES-256). The key metadata is the opaque value
+/// stored in the file to identify a key, e.g. the id of a key in a key management service.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the key of the footer. Columns without a key of their own are encrypted with it.
+    fn footer_key(&self, key_metadata: Option<&[u8]>) -> ParquetResult<Vec<u8>>;
+
+    /// Returns the key of the column at `path_in_schema`.
+    fn column_key(
+        &self,
+        path_in_schema: &[String],
+        key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Vec<u8>>;
+
+    /// Returns the AAD prefix of the file, if it was not stored in the file.
+    fn aad_prefix(&self) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+/// The algorithm used to encrypt a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EncryptionAlgorithm {
+    /// All modules are encrypted with AES-GCM.
+    #[default]
+    AesGcmV1,
+    /// The page data is encrypted with AES-CTR, all other modules with AES-GCM.
+    AesGcmCtrV1,
+}
+
+/// Describes how a file is encrypted when it is written.
+#[derive(Clone)]
+pub struct FileEncryptionProperties {
+    pub algorithm: EncryptionAlgorithm,
+    pub key_retriever: Arc<dyn KeyRetriever>,
+    /// The key metadata of the footer key, stored in the file.
+    pub footer_key_metadata: Option<Vec<u8>>,
+    /// The top-level columns that are encrypted with a key of their own, together with the key
+    /// metadata stored in the file. All other columns are encrypted with the footer key.
+    pub column_key_metadata: Vec<(String, Option<Vec<u8>>)>,
+    /// An AAD prefix that is mixed into the authentication of all modules. It is not stored in
+    /// the file, so readers need to supply it through [`KeyRetriever::aad_prefix`].
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for FileEncryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileEncryptionProperties")
+            .field("algorithm", &self.algorithm)
+            .field("footer_key_metadata", &self.footer_key_metadata)
+            .field("column_key_metadata", &self.column_key_metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) fn missing_key_retriever() -> ParquetError {
+    ParquetError::InvalidParameter(
+        "the file is encrypted, a key retriever is required to read it".to_string(),
+    )
+}
+
+/// The type of an encrypted module, which is part of its AAD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+}
+
+/// Returns the AAD of a module: the file AAD, the module type and the little-endian 16-bit row
+/// group, column and page ordinals.
+fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group: usize,
+    column: usize,
+    page: Option<usize>,
+) -> ParquetResult<Vec<u8>> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    if module_type == ModuleType::Footer {
+        return Ok(aad);
+    }
+
+    let ordinals = [Some(row_group), Some(column), page];
+    for ordinal in ordinals.into_iter().flatten() {
+        let ordinal = i16::try_from(ordinal).map_err(|_| {
+            ParquetError::not_supported(
+                "encrypted files with more than 32767 row groups, columns or pages per column chunk",
+            )
+        })?;
+        aad.extend_from_slice(&ordinal.to_le_bytes());
+    }
+    Ok(aad)
+}
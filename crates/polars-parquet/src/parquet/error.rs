@@ -15,6 +15,8 @@ pub enum Feature {
     Lz4,
     /// Zstd compression and decompression
     Zstd,
+    /// Parquet modular encryption
+    Encryption,
 }
 
 /// Errors generated by this crate
@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -36,6 +39,8 @@ pub struct ColumnChunkMetadata {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 #[cfg(feature = "serde")]
@@ -76,6 +81,7 @@ impl ColumnChunkMetadata {
         Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         }
     }
 
@@ -126,6 +132,18 @@ impl ColumnChunkMetadata {
         self.metadata().num_values
     }
 
+    /// Returns the metadata for reading only some of the pages of this column chunk, stored back to
+    /// back (after the dictionary page, if any), which contain `num_values` values in total.
+    pub fn with_selected_pages(&self, num_values: i64) -> Self {
+        let mut column_chunk = self.column_chunk.clone();
+        column_chunk.meta_data.as_mut().unwrap().num_values = num_values;
+        Self {
+            column_chunk,
+            column_descr: self.column_descr.clone(),
+            decryptor: self.decryptor.clone(),
+        }
+    }
+
     /// [`Compression`] for this column.
     pub fn compression(&self) -> Compression {
         self.metadata().codec.try_into().unwrap()
@@ -172,6 +190,36 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Returns the byte range of the column index of this column chunk, if any.
+    pub fn column_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.column_index_offset,
+            self.column_chunk.column_index_length,
+        )
+    }
+
+    /// Returns the byte range of the offset index of this column chunk, if any.
+    pub fn offset_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.offset_index_offset,
+            self.column_chunk.offset_index_length,
+        )
+    }
+
+    /// Returns `true` if the pages and indexes of this column chunk are encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto_metadata.is_some()
+    }
+
+    /// The decryptor of the pages of this column chunk, if it is encrypted.
+    pub(crate) fn decryptor(&self) -> Option<&Arc<ColumnDecryptor>> {
+        self.decryptor.as_ref()
+    }
+
+    pub(crate) fn set_decryptor(&mut self, decryptor: Option<Arc<ColumnDecryptor>>) {
+        self.decryptor = decryptor;
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
@@ -194,6 +242,7 @@ impl ColumnChunkMetadata {
         Ok(Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         })
     }
 
@@ -214,3 +263,9 @@ pub(super) fn column_metadata_byte_range(
     let len = column_metadata.total_compressed_size as u64;
     offset..offset.checked_add(len).unwrap()
 }
+
+fn index_byte_range(offset: Option<i64>, length: Option<i32>) -> Option<core::ops::Range<u64>> {
+    let offset = u64::try_from(offset?).ok()?;
+    let length = u64::try_from(length?).ok()?;
+    Some(offset..offset.checked_add(length)?)
+}
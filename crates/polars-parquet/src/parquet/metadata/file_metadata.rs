@@ -3,6 +3,7 @@ use polars_parquet_format::ColumnOrder as TColumnOrder;
 use super::RowGroupMetadata;
 use super::column_order::ColumnOrder;
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::ParquetError;
 use crate::parquet::metadata::get_sort_order;
 pub use crate::parquet::thrift_format::KeyValue;
@@ -96,6 +97,26 @@ impl FileMetadata {
             column_orders,
         })
     }
+
+    /// Deserializes the metadata of an encrypted file, decrypting the metadata of its encrypted
+    /// columns with `decryptor`.
+    pub(crate) fn try_from_encrypted_thrift(
+        mut metadata: polars_parquet_format::FileMetaData,
+        decryptor: &mut FileDecryptor,
+    ) -> Result<Self, ParquetError> {
+        let column_decryptors = metadata
+            .row_groups
+            .iter_mut()
+            .enumerate()
+            .map(|(i, rg)| decryptor.decrypt_row_group(i, rg))
+            .collect::<Result<Vec<_>, ParquetError>>()?;
+
+        let mut file_metadata = Self::try_from_thrift(metadata)?;
+        for (rg, decryptors) in file_metadata.row_groups.iter_mut().zip(column_decryptors) {
+            rg.set_column_decryptors(decryptors);
+        }
+        Ok(file_metadata)
+    }
 }
 
 /// Parses [`ColumnOrder`] from Thrift definition.
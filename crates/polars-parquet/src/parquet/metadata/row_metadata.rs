@@ -9,6 +9,7 @@ use polars_utils::unitvec;
 
 use super::column_chunk_metadata::{ColumnChunkMetadata, column_metadata_byte_range};
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 type ColumnLookup = PlHashMap<PlSmallStr, UnitVec<usize>>;
@@ -102,6 +103,17 @@ impl RowGroupMetadata {
         self.sorting_columns.as_deref()
     }
 
+    /// Sets the decryptors of the columns of this row group, in the order of the columns.
+    ///
+    /// # Panics
+    /// Panics if the columns of this row group are shared with a clone.
+    pub(crate) fn set_column_decryptors(&mut self, decryptors: Vec<Option<Arc<ColumnDecryptor>>>) {
+        let columns = Arc::get_mut(&mut self.columns).unwrap();
+        for (column, decryptor) in columns.iter_mut().zip(decryptors) {
+            column.set_decryptor(decryptor);
+        }
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
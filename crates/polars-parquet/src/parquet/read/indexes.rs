@@ -0,0 +1,131 @@
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::schema::types::PrimitiveType;
+use crate::parquet::statistics::{ParquetStatistics, Statistics};
+
+/// Deserializes a [`ColumnIndex`] from `bytes`, which contain exactly the serialized index.
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    let max_size = max_allocation_size(bytes.len());
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes an [`OffsetIndex`] from `bytes`, which contain exactly the serialized index.
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = max_allocation_size(bytes.len());
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// The limit of the memory allocated while deserializing an index of `len` bytes. Every list
+/// element takes at least one byte, but is allocated as a `Vec<u8>` or `PageLocation`, and the
+/// bytes of the min / max values are copied.
+fn max_allocation_size(len: usize) -> usize {
+    let max_element_size = size_of::<Vec<u8>>().max(size_of::<PageLocation>());
+    len.saturating_mul(max_element_size + 1)
+        .saturating_add(1024)
+}
+
+/// Deserializes the min / max values and null counts of every page in `column_index` into
+/// [`Statistics`].
+///
+/// # Error
+/// Errors if the index is inconsistent or the values can't be read as the `primitive_type`.
+pub fn deserialize_page_statistics(
+    column_index: &ColumnIndex,
+    primitive_type: &PrimitiveType,
+) -> ParquetResult<Vec<Statistics>> {
+    let num_pages = column_index.null_pages.len();
+    if column_index.min_values.len() != num_pages
+        || column_index.max_values.len() != num_pages
+        || column_index
+            .null_counts
+            .as_ref()
+            .is_some_and(|c| c.len() != num_pages)
+    {
+        return Err(ParquetError::oos(
+            "the lists of a column index must have the same length",
+        ));
+    }
+
+    (0..num_pages)
+        .map(|i| {
+            // The min / max values of pages that only contain nulls are meaningless.
+            let is_null_page = column_index.null_pages[i];
+            let statistics = ParquetStatistics {
+                max: None,
+                min: None,
+                null_count: column_index.null_counts.as_ref().map(|c| c[i]),
+                distinct_count: None,
+                max_value: (!is_null_page).then(|| column_index.max_values[i].clone()),
+                min_value: (!is_null_page).then(|| column_index.min_values[i].clone()),
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+            Statistics::deserialize(&statistics, primitive_type.clone())
+        })
+        .collect()
+}
+
+/// Returns the ranges of rows of the pages in `offset_index`, given that the column chunk
+/// contains `num_rows` rows.
+///
+/// # Error
+/// Errors if the page locations are not ordered or out of bounds.
+pub fn page_row_ranges(
+    offset_index: &OffsetIndex,
+    num_rows: usize,
+) -> ParquetResult<Vec<std::ops::Range<usize>>> {
+    let starts = offset_index
+        .page_locations
+        .iter()
+        .map(|l| usize::try_from(l.first_row_index))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if starts.first().is_some_and(|&s| s != 0)
+        || starts.windows(2).any(|w| w[0] > w[1])
+        || starts.last().is_some_and(|&s| s > num_rows)
+    {
+        return Err(ParquetError::oos("invalid page locations in offset index"));
+    }
+
+    Ok(starts
+        .iter()
+        .zip(starts.iter().skip(1).chain(std::iter::once(&num_rows)))
+        .map(|(&start, &end)| start..end)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_parquet_format::BoundaryOrder;
+    use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_column_index_many_pages() {
+        let num_pages = 1000;
+        let values = (0..num_pages as i64)
+            .map(|v| v.to_le_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let column_index = ColumnIndex::new(
+            vec![false; num_pages],
+            values.clone(),
+            values,
+            BoundaryOrder::ASCENDING,
+            vec![0; num_pages],
+            None,
+            None,
+        );
+
+        let mut bytes = vec![];
+        column_index
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut bytes))
+            .unwrap();
+
+        assert_eq!(deserialize_column_index(&bytes).unwrap(), column_index);
+    }
+}
@@ -1,11 +1,15 @@
 use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
 
-use polars_parquet_format::FileMetaData as TFileMetadata;
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{FileCryptoMetaData, FileMetaData as TFileMetadata};
 
 use super::super::metadata::FileMetadata;
 use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use crate::parquet::encryption::{
+    FileDecryptor, KeyRetriever, PARQUET_ENCRYPTED_MAGIC, missing_key_retriever,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> u32 {
@@ -28,15 +32,32 @@ fn stream_len(seek: &mut impl Seek) -> std::result::Result<u64, std::io::Error>
 
 /// Reads a [`FileMetadata`] from the reader, located at the end of the file.
 pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> ParquetResult<FileMetadata> {
+    read_metadata_with_key_retriever(reader, None)
+}
+
+/// Reads a [`FileMetadata`] from the reader, located at the end of the file. The metadata of
+/// encrypted files is decrypted with the keys of `key_retriever`.
+pub fn read_metadata_with_key_retriever<R: Read + Seek>(
+    reader: &mut R,
+    key_retriever: Option<&Arc<dyn KeyRetriever>>,
+) -> ParquetResult<FileMetadata> {
     // check file is large enough to hold footer
     let file_size = stream_len(reader)?;
-    read_metadata_with_size(reader, file_size)
+    read_metadata_impl(reader, file_size, key_retriever)
 }
 
 /// Reads a [`FileMetadata`] from the reader, located at the end of the file, with known file size.
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> ParquetResult<FileMetadata> {
+    read_metadata_impl(reader, file_size, None)
+}
+
+fn read_metadata_impl<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    key_retriever: Option<&Arc<dyn KeyRetriever>>,
 ) -> ParquetResult<FileMetadata> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
@@ -55,7 +76,8 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
+    let magic = &buffer[default_end_len - 4..];
+    if magic != PARQUET_MAGIC && magic != PARQUET_ENCRYPTED_MAGIC {
         return Err(ParquetError::oos("The file must end with PAR1"));
     }
 
@@ -69,7 +91,7 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         ));
     }
 
-    let reader: &[u8] = if (footer_len as usize) < buffer.len() {
+    let footer: &[u8] = if (footer_len as usize) < buffer.len() {
         // the whole metadata is in the bytes we already read
         let remaining = buffer.len() - footer_len as usize;
         &buffer[remaining..]
@@ -84,16 +106,232 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         &buffer
     };
 
-    // a highly nested but sparse struct could result in many allocations
-    let max_size = reader.len() * 2 + 1024;
-
-    deserialize_metadata(reader, max_size)
+    deserialize_footer(footer, key_retriever)
 }
 
 /// Parse loaded metadata bytes
+///
+/// The plaintext metadata of a file with encrypted columns can be read, but the encrypted columns
+/// can't, as no keys are given.
 pub fn deserialize_metadata<R: Read>(reader: R, max_size: usize) -> ParquetResult<FileMetadata> {
     let mut prot = TCompactInputProtocol::new(reader, max_size);
     let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
 
-    FileMetadata::try_from_thrift(metadata)
+    let Some(algorithm) = &metadata.encryption_algorithm else {
+        return FileMetadata::try_from_thrift(metadata);
+    };
+    let mut decryptor = FileDecryptor::try_new(
+        algorithm,
+        metadata.footer_signing_key_metadata.as_deref(),
+        None,
+    )?;
+
+    FileMetadata::try_from_encrypted_thrift(metadata, &mut decryptor)
+}
+
+/// Parses the [`FileMetadata`] from the last bytes of a file, `footer`, which must contain the
+/// whole metadata followed by its length and the magic bytes. The metadata of encrypted files is
+/// decrypted with the keys of `key_retriever`.
+pub fn deserialize_footer(
+    footer: &[u8],
+    key_retriever: Option<&Arc<dyn KeyRetriever>>,
+) -> ParquetResult<FileMetadata> {
+    let len = footer.len();
+    if len < FOOTER_SIZE as usize {
+        return Err(ParquetError::oos(
+            "A parquet file must end with a footer of at least 8 bytes",
+        ));
+    }
+
+    let metadata_end = len - FOOTER_SIZE as usize;
+    let metadata = metadata_end
+        .checked_sub(metadata_len(footer, len) as usize)
+        .map(|metadata_start| &footer[metadata_start..metadata_end])
+        .ok_or_else(|| {
+            ParquetError::oos("The footer size must be smaller or equal to the file's size")
+        })?;
+
+    let magic = &footer[len - 4..];
+    if magic == PARQUET_MAGIC {
+        deserialize_plaintext_footer(metadata, key_retriever)
+    } else if magic == PARQUET_ENCRYPTED_MAGIC {
+        deserialize_encrypted_footer(metadata, key_retriever.ok_or_else(missing_key_retriever)?)
+    } else {
+        Err(ParquetError::oos("The file must end with PAR1"))
+    }
+}
+
+/// Deserializes a plaintext footer, whose columns may be encrypted. In that case, the footer is
+/// followed by its signature. Encrypted columns can only be read if `key_retriever` is given.
+fn deserialize_plaintext_footer(
+    bytes: &[u8],
+    key_retriever: Option<&Arc<dyn KeyRetriever>>,
+) -> ParquetResult<FileMetadata> {
+    // a highly nested but sparse struct could result in many allocations
+    let max_size = bytes.len() * 2 + 1024;
+
+    let mut remaining = bytes;
+    let mut prot = TCompactInputProtocol::new(&mut remaining, max_size);
+    let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+
+    let Some(algorithm) = &metadata.encryption_algorithm else {
+        return FileMetadata::try_from_thrift(metadata);
+    };
+
+    let mut decryptor = FileDecryptor::try_new(
+        algorithm,
+        metadata.footer_signing_key_metadata.as_deref(),
+        key_retriever.cloned(),
+    )?;
+    // Without keys the signature can't be verified, but the plaintext columns can still be read.
+    if key_retriever.is_some() {
+        let signed = &bytes[..bytes.len() - remaining.len()];
+        decryptor.verify_footer_signature(signed, remaining)?;
+    }
+
+    FileMetadata::try_from_encrypted_thrift(metadata, &mut decryptor)
+}
+
+/// Deserializes an encrypted footer, which consists of the crypto metadata of the file followed by
+/// the encrypted file metadata.
+fn deserialize_encrypted_footer(
+    bytes: &[u8],
+    key_retriever: &Arc<dyn KeyRetriever>,
+) -> ParquetResult<FileMetadata> {
+    let max_size = bytes.len() * 2 + 1024;
+
+    let mut remaining = bytes;
+    let mut prot = TCompactInputProtocol::new(&mut remaining, max_size);
+    let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+
+    let mut decryptor = FileDecryptor::try_new(
+        &crypto_metadata.encryption_algorithm,
+        crypto_metadata.key_metadata.as_deref(),
+        Some(key_retriever.clone()),
+    )?;
+    let footer = decryptor.decrypt_footer(remaining)?;
+
+    let mut prot = TCompactInputProtocol::new(footer.as_slice(), max_size);
+    let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+
+    FileMetadata::try_from_encrypted_thrift(metadata, &mut decryptor)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Int64Array;
+    use arrow::datatypes::{ArrowSchema, Field};
+    use arrow::record_batch::RecordBatchT;
+    use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+    use polars_parquet_format::{
+        AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    };
+    use polars_utils::mmap::{MemReader, MemSlice};
+
+    use super::*;
+    use crate::arrow::write::{
+        CompressionOptions, Encoding, FieldWriteOptions, FileWriter, RowGroupIterator,
+        StatisticsOptions, Version, WriteOptions,
+    };
+    use crate::parquet::metadata::ColumnChunkMetadata;
+    use crate::parquet::read::PageReader;
+
+    struct TestKeyRetriever;
+
+    impl KeyRetriever for TestKeyRetriever {
+        fn footer_key(&self, _key_metadata: Option<&[u8]>) -> ParquetResult<Vec<u8>> {
+            Ok(vec![1; 16])
+        }
+
+        fn column_key(
+            &self,
+            _path_in_schema: &[String],
+            _key_metadata: Option<&[u8]>,
+        ) -> ParquetResult<Vec<u8>> {
+            Ok(vec![2; 16])
+        }
+    }
+
+    #[test]
+    fn plaintext_footer_with_encrypted_column() {
+        let array = Int64Array::from_vec((0..100).collect()).boxed();
+        let schema = ArrowSchema::from_iter(
+            ["a", "b"].map(|name| Field::new(name.into(), array.dtype().clone(), false)),
+        );
+        let column_options = vec![
+            FieldWriteOptions::default_with_encoding(Encoding::Plain)
+                .into_default_column_write_options();
+            2
+        ];
+        let options = WriteOptions {
+            statistics: StatisticsOptions::full(),
+            compression: CompressionOptions::Uncompressed,
+            version: Version::V1,
+            data_page_size: None,
+        };
+
+        let batch = RecordBatchT::try_new(
+            array.len(),
+            Arc::new(schema.clone()),
+            vec![array.clone(), array],
+        );
+        let row_groups = RowGroupIterator::try_new(
+            [batch].into_iter(),
+            &schema,
+            options,
+            column_options.clone(),
+        )
+        .unwrap();
+        let mut writer = FileWriter::try_new(vec![], schema, options, &column_options).unwrap();
+        for group in row_groups {
+            writer.write(group.unwrap()).unwrap();
+        }
+        writer.end(None, &column_options).unwrap();
+        let (data, mut metadata) = writer.into_inner_and_metadata();
+
+        // Mark `b` as encrypted with a column key and append a signature to the footer.
+        metadata.encryption_algorithm = Some(EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+            aad_prefix: None,
+            aad_file_unique: Some(vec![0; 8]),
+            supply_aad_prefix: None,
+        }));
+        metadata.row_groups[0].columns[1].crypto_metadata = Some(
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                path_in_schema: vec!["b".to_string()],
+                key_metadata: None,
+            }),
+        );
+        let mut footer = vec![];
+        metadata
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut footer))
+            .unwrap();
+        footer.extend_from_slice(&[0; 28]);
+        footer.extend_from_slice(&(footer.len() as u32).to_le_bytes());
+        footer.extend_from_slice(&PARQUET_MAGIC);
+
+        // The signature is only verified if keys are given.
+        let key_retriever: Arc<dyn KeyRetriever> = Arc::new(TestKeyRetriever);
+        assert!(deserialize_footer(&footer, Some(&key_retriever)).is_err());
+
+        let metadata = deserialize_footer(&footer, None).unwrap();
+        let columns = metadata.row_groups[0].parquet_columns();
+        assert!(!columns[0].is_encrypted());
+        assert!(columns[1].is_encrypted());
+
+        // Only reading the encrypted column fails.
+        let read_first_page = |column: &ColumnChunkMetadata| {
+            let range = column.byte_range();
+            let bytes = data[range.start as usize..range.end as usize].to_vec();
+            PageReader::new(
+                MemReader::new(MemSlice::from_vec(bytes)),
+                column,
+                vec![],
+                usize::MAX,
+            )
+            .next()
+            .unwrap()
+        };
+        assert!(read_first_page(&columns[0]).is_ok());
+        assert!(read_first_page(&columns[1]).is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use std::io::Seek;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use polars_utils::mmap::{MemReader, MemSlice};
@@ -7,6 +7,7 @@ use polars_utils::mmap::{MemReader, MemSlice};
 use super::PageIterator;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
 use crate::parquet::page::{
@@ -81,6 +82,12 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Decrypts the pages of encrypted column chunks.
+    decryptor: Option<Arc<ColumnDecryptor>>,
+
+    // The number of data pages we have seen so far, which is part of the AAD of encrypted pages.
+    data_page_ordinal: usize,
 }
 
 impl PageReader {
@@ -94,7 +101,10 @@ impl PageReader {
         scratch: Vec<u8>,
         max_page_size: usize,
     ) -> Self {
-        Self::new_with_page_meta(reader, column.into(), scratch, max_page_size)
+        let mut page_reader =
+            Self::new_with_page_meta(reader, column.into(), scratch, max_page_size);
+        page_reader.decryptor = column.decryptor().cloned();
+        page_reader
     }
 
     /// Create a new [`PageReader`] with [`PageMetaData`].
@@ -114,6 +124,8 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            decryptor: None,
+            data_page_ordinal: 0,
         }
     }
 
@@ -137,7 +149,7 @@ impl PageReader {
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.read_page_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -146,6 +158,34 @@ impl PageReader {
             return Ok(None);
         }
 
+        let buffer = self.read_page_data(&page_header)?;
+
+        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
+            if let CompressedPage::Dict(d) = p {
+                Some(d)
+            } else {
+                unreachable!()
+            }
+        })
+    }
+}
+
+impl PageReader {
+    fn read_page_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        match &self.decryptor {
+            None => read_page_header(&mut self.reader, self.max_page_size),
+            // A dictionary page can only precede the first data page.
+            Some(decryptor) => decryptor.read_page_header(
+                &mut self.reader,
+                self.data_page_ordinal,
+                self.data_page_ordinal == 0,
+                self.max_page_size,
+            ),
+        }
+    }
+
+    /// Reads the data of the page of `page_header`, decrypting it if needed.
+    fn read_page_data(&mut self, page_header: &ParquetPageHeader) -> ParquetResult<MemSlice> {
         let read_size: usize = page_header.compressed_page_size.try_into()?;
 
         if read_size > self.max_page_size {
@@ -160,13 +200,15 @@ impl PageReader {
             ));
         }
 
-        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
-            if let CompressedPage::Dict(d) = p {
-                Some(d)
-            } else {
-                unreachable!()
-            }
-        })
+        let Some(decryptor) = &self.decryptor else {
+            return Ok(buffer);
+        };
+        let is_dict = matches!(page_header.type_.try_into()?, PageType::DictionaryPage);
+        let data = decryptor.decrypt_page(&buffer, is_dict, self.data_page_ordinal)?;
+        if !is_dict {
+            self.data_page_ordinal += 1;
+        }
+        Ok(MemSlice::from_vec(data))
     }
 }
 
@@ -210,23 +252,11 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_page_header()?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
-    let read_size: usize = page_header.compressed_page_size.try_into()?;
-
-    if read_size > reader.max_page_size {
-        return Err(ParquetError::WouldOverAllocate);
-    }
-
-    let buffer = reader.reader.read_slice(read_size);
-
-    if buffer.len() != read_size {
-        return Err(ParquetError::oos(
-            "The page header reported the wrong page size",
-        ));
-    }
+    let buffer = reader.read_page_data(&page_header)?;
 
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
@@ -1,12 +1,15 @@
 use std::io::Write;
 
-use polars_parquet_format::RowGroup;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{ColumnChunk, RowGroup};
 
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::{
+    FileEncryptionProperties, FileEncryptor, ModuleType, PARQUET_ENCRYPTED_MAGIC,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
 use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
@@ -39,6 +42,20 @@ pub(super) fn end_file<W: Write>(
     Ok(metadata_len as u64 + FOOTER_SIZE)
 }
 
+/// Writes the encrypted footer `footer`, which contains the crypto metadata of the file followed
+/// by the encrypted file metadata.
+fn end_encrypted_file<W: Write>(writer: &mut W, footer: &[u8]) -> ParquetResult<u64> {
+    let footer_len: u32 = footer
+        .len()
+        .try_into()
+        .map_err(|_| ParquetError::oos("The footer of a file must be smaller than 4GiB"))?;
+    writer.write_all(footer)?;
+    writer.write_all(&footer_len.to_le_bytes())?;
+    writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+    writer.flush()?;
+    Ok(footer.len() as u64 + FOOTER_SIZE)
+}
+
 fn create_column_orders(schema_desc: &SchemaDescriptor) -> Vec<polars_parquet_format::ColumnOrder> {
     // We only include ColumnOrder for leaf nodes.
     // Currently only supported ColumnOrder is TypeDefinedOrder so we set this
@@ -66,6 +83,10 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// The bloom filter bitsets of each row group, one (optional) per column chunk.
+    bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
+    /// Encrypts the modules of the file, if set.
+    encryptor: Option<FileEncryptor>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
@@ -125,11 +146,32 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            bloom_filters: vec![],
+            encryptor: None,
             state: State::Initialised,
             metadata: None,
         }
     }
 
+    /// Encrypts the file with [Parquet modular encryption]. The footer is encrypted with the
+    /// footer key and every column with either its own key or the footer key.
+    ///
+    /// Bloom filters are not written for encrypted files.
+    ///
+    /// # Errors
+    /// Returns an error if data has been written to the file or if the keys can't be resolved.
+    ///
+    /// [Parquet modular encryption]: https://github.com/apache/parquet-format/blob/master/Encryption.md
+    pub fn with_encryption(mut self, properties: &FileEncryptionProperties) -> ParquetResult<Self> {
+        if self.offset != 0 {
+            return Err(ParquetError::InvalidParameter(
+                "Encryption must be set before writing".to_string(),
+            ));
+        }
+        self.encryptor = Some(FileEncryptor::try_new(properties, &self.schema)?);
+        Ok(self)
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -138,7 +180,12 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = if self.encryptor.is_some() {
+                self.writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+                PARQUET_ENCRYPTED_MAGIC.len() as u64
+            } else {
+                start_file(&mut self.writer)?
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -152,6 +199,38 @@ impl<W: Write> FileWriter<W> {
     ///
     /// This call is IO-bounded
     pub fn write<E>(&mut self, row_group: RowGroupIterColumns<'_, E>) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_impl(row_group, vec![])
+    }
+
+    /// Writes a row group to the file, together with a split-block bloom filter bitset for each
+    /// of its column chunks.
+    ///
+    /// `bloom_filters` is either empty or contains an entry for every column chunk. The bloom
+    /// filters are written at the end of the file, before the footer.
+    ///
+    /// This call is IO-bounded
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_impl(row_group, bloom_filters)
+    }
+
+    fn write_impl<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> ParquetResult<()>
     where
         ParquetError: From<E>,
         E: std::error::Error,
@@ -166,10 +245,12 @@ impl<W: Write> FileWriter<W> {
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        self.bloom_filters.push(bloom_filters);
         Ok(())
     }
 
@@ -188,50 +269,39 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        // write bloom filters
+        #[cfg(feature = "bloom_filter")]
+        if self.encryptor.is_none() {
+            self.write_bloom_filters()?;
+        }
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
-            self.row_groups
-                .iter_mut()
-                .zip(self.page_specs.iter())
-                .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
-                            let offset = self.offset;
-                            column.column_index_offset = Some(offset as i64);
-                            self.offset += write_column_index(&mut self.writer, pages)?;
-                            let length = self.offset - offset;
-                            column.column_index_length = Some(length as i32);
-                            ParquetResult::Ok(())
-                        },
-                    )?;
-                    ParquetResult::Ok(())
-                })?;
+            self.write_indexes(ModuleType::ColumnIndex, |column, offset, length| {
+                column.column_index_offset = Some(offset);
+                column.column_index_length = Some(length);
+            })?;
         };
 
         // write offset index
-        self.row_groups
-            .iter_mut()
-            .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
-                group
-                    .columns
-                    .iter_mut()
-                    .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
-                        let offset = self.offset;
-                        column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
-                        column.offset_index_length = Some((self.offset - offset) as i32);
-                        ParquetResult::Ok(())
-                    })?;
-                ParquetResult::Ok(())
-            })?;
+        self.write_indexes(ModuleType::OffsetIndex, |column, offset, length| {
+            column.offset_index_offset = Some(offset);
+            column.offset_index_length = Some(length);
+        })?;
+
+        let mut row_groups = self.row_groups.clone();
+        if let Some(encryptor) = &self.encryptor {
+            row_groups
+                .iter_mut()
+                .enumerate()
+                .try_for_each(|(i, group)| encryptor.encrypt_row_group(i, group))?;
+        }
 
         let metadata = ThriftFileMetadata::new(
             self.options.version.into(),
             self.schema.clone().into_thrift(),
             num_rows,
-            self.row_groups.clone(),
+            row_groups,
             key_value_metadata,
             self.created_by.clone(),
             Some(create_column_orders(&self.schema)),
@@ -239,12 +309,75 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = if let Some(encryptor) = &self.encryptor {
+            end_encrypted_file(&mut self.writer, &encryptor.encrypt_footer(&metadata)?)?
+        } else {
+            end_file(&mut self.writer, &metadata)?
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
     }
 
+    #[cfg(feature = "bloom_filter")]
+    fn write_bloom_filters(&mut self) -> ParquetResult<()> {
+        self.row_groups
+            .iter_mut()
+            .zip(std::mem::take(&mut self.bloom_filters))
+            .try_for_each(|(group, bloom_filters)| {
+                group
+                    .columns
+                    .iter_mut()
+                    .zip(bloom_filters)
+                    .try_for_each(|(column, bitset)| {
+                        let (Some(bitset), Some(metadata)) = (bitset, column.meta_data.as_mut())
+                        else {
+                            return ParquetResult::Ok(());
+                        };
+                        let length =
+                            crate::parquet::bloom_filter::write(&mut self.writer, &bitset)?;
+                        metadata.bloom_filter_offset = Some(self.offset as i64);
+                        metadata.bloom_filter_length = Some(length as i32);
+                        self.offset += length;
+                        ParquetResult::Ok(())
+                    })
+            })
+    }
+
+    /// Writes the column or offset index of every column chunk, and records their location with
+    /// `set_location`.
+    fn write_indexes(
+        &mut self,
+        module_type: ModuleType,
+        set_location: impl Fn(&mut ColumnChunk, i64, i32),
+    ) -> ParquetResult<()> {
+        self.row_groups
+            .iter_mut()
+            .zip(self.page_specs.iter())
+            .enumerate()
+            .try_for_each(|(rg, (group, pages))| {
+                group
+                    .columns
+                    .iter_mut()
+                    .zip(pages.iter())
+                    .enumerate()
+                    .try_for_each(|(i, (column, pages))| {
+                        let mut bytes = vec![];
+                        match module_type {
+                            ModuleType::ColumnIndex => write_column_index(&mut bytes, pages)?,
+                            _ => write_offset_index(&mut bytes, pages)?,
+                        };
+                        if let Some(encryptor) = &self.encryptor {
+                            bytes = encryptor.column(rg, i).encrypt_index(module_type, &bytes)?;
+                        }
+                        self.writer.write_all(&bytes)?;
+                        set_location(column, self.offset as i64, bytes.len() as i32);
+                        self.offset += bytes.len() as u64;
+                        ParquetResult::Ok(())
+                    })
+            })
+    }
+
     /// Returns the underlying writer.
     pub fn into_inner(self) -> W {
         self.writer
@@ -53,6 +53,37 @@ impl BinaryNameSpace {
             .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
 
+    /// Hash every value with a portable hash function or checksum.
+    ///
+    /// String values are hashed as their UTF-8 bytes.
+    #[cfg(feature = "binary_hash")]
+    pub fn hash(self, algorithm: BinaryHashAlgorithm) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Hash(algorithm)))
+    }
+
+    /// Compress every value into an independent payload of the given format.
+    ///
+    /// String values are compressed as their UTF-8 bytes. A `level` of `None` uses the
+    /// default level of the codec.
+    #[cfg(feature = "binary_compression")]
+    pub fn compress(self, codec: BinaryCodec, level: Option<i32>) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Compress(
+                codec, level,
+            )))
+    }
+
+    /// Decompress every value. If `strict`, invalid payloads raise an error, otherwise they
+    /// become null.
+    #[cfg(feature = "binary_compression")]
+    pub fn decompress(self, codec: BinaryCodec, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Decompress(
+                codec, strict,
+            )))
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn reinterpret(self, to_type: impl Into<DataTypeExpr>, is_little_endian: bool) -> Expr {
         self.0
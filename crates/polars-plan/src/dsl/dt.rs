@@ -11,18 +11,43 @@ impl DateLikeNameSpace {
         n: Expr,
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
         roll: Roll,
     ) -> Expr {
         self.0.map_binary(
             FunctionExpr::Business(BusinessFunction::AddBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
                 roll,
             }),
             n,
         )
     }
 
+    /// Add a given (possibly fractional) number of business hours to datetimes.
+    ///
+    /// `business_hours` are the start and end of business hours, in nanoseconds since midnight.
+    #[cfg(feature = "business")]
+    pub fn add_business_hours(
+        self,
+        n: Expr,
+        business_hours: [i64; 2],
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
+    ) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::Business(BusinessFunction::AddBusinessHours {
+                business_hours,
+                week_mask,
+                holidays,
+                calendar,
+            }),
+            n,
+        )
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
@@ -85,11 +110,17 @@ impl DateLikeNameSpace {
 
     /// Determine whether days are business days.
     #[cfg(feature = "business")]
-    pub fn is_business_day(self, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+    pub fn is_business_day(
+        self,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
+    ) -> Expr {
         self.0
             .map_unary(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
             }))
     }
 
@@ -1,9 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::prelude::PlIndexMap;
-
-// Note, there are a lot of single variant enums here, but the intention is that we'll support
-// Delta deletion vectors as well at some point in the future.
+use polars_utils::pl_str::PlSmallStr;
 
 #[derive(Debug, Clone, Eq, PartialEq, strum_macros::IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -20,6 +18,97 @@ pub enum DeletionFilesList {
     //
     /// Iceberg positional deletes
     IcebergPositionDelete(Arc<PlIndexMap<usize, Arc<[String]>>>),
+
+    /// Iceberg equality deletes, along with the positional deletes of the same data files.
+    IcebergEqualityDelete(Arc<PlIndexMap<usize, IcebergEqualityDeletes>>),
+
+    /// Delta deletion vectors
+    DeltaDeletionVector {
+        /// Root of the table, against which UUID-relative deletion vector paths are resolved.
+        table_root: PlSmallStr,
+        vectors: Arc<PlIndexMap<usize, DeletionVectorDescriptor>>,
+    },
+}
+
+/// The deletion files of a data file of an Iceberg table with equality deletes.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct IcebergEqualityDeletes {
+    /// Data sequence number of the data file.
+    pub data_sequence_number: i64,
+    pub equality_delete_files: Arc<[IcebergEqualityDeleteFile]>,
+    pub position_delete_files: Arc<[String]>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct IcebergEqualityDeleteFile {
+    pub path: String,
+    /// Data sequence number of the delete file. It only deletes rows of data files with a lower
+    /// data sequence number.
+    pub sequence_number: i64,
+    /// Field IDs of the columns whose values identify the deleted rows. These are resolved
+    /// through the `PARQUET:field_id` of the columns in the delete and data files.
+    pub equality_ids: Arc<[u32]>,
+}
+
+impl IcebergEqualityDeleteFile {
+    /// Whether this file deletes rows of a data file with `data_sequence_number`.
+    pub fn applies_to(&self, data_sequence_number: i64) -> bool {
+        self.sequence_number > data_sequence_number
+    }
+}
+
+/// Describes where the deletion vector of a Delta data file is stored.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DeletionVectorDescriptor {
+    pub storage_type: DeletionVectorStorageType,
+    /// Depending on `storage_type`, either:
+    /// * the optional random prefix followed by the Z85-encoded UUID of the file,
+    /// * the Z85-encoded serialized deletion vector, or
+    /// * the absolute path of the file.
+    pub path_or_inline_dv: PlSmallStr,
+    /// Start of the deletion vector in the file. `None` for inline deletion vectors.
+    pub offset: Option<u32>,
+    /// Size of the serialized deletion vector (before Z85 encoding).
+    pub size_in_bytes: u32,
+    /// Number of deleted rows.
+    pub cardinality: u64,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum DeletionVectorStorageType {
+    /// `u`: stored in a file with a path relative to the table root.
+    UuidRelativePath,
+    /// `i`: stored inline in the Delta log.
+    Inline,
+    /// `p`: stored in a file with an absolute path.
+    AbsolutePath,
+}
+
+impl DeletionVectorStorageType {
+    pub fn from_delta_code(code: &str) -> Option<Self> {
+        match code {
+            "u" => Some(Self::UuidRelativePath),
+            "i" => Some(Self::Inline),
+            "p" => Some(Self::AbsolutePath),
+            _ => None,
+        }
+    }
+
+    pub fn delta_code(&self) -> &'static str {
+        match self {
+            Self::UuidRelativePath => "u",
+            Self::Inline => "i",
+            Self::AbsolutePath => "p",
+        }
+    }
 }
 
 impl DeletionFilesList {
@@ -31,6 +120,16 @@ impl DeletionFilesList {
             Some(IcebergPositionDelete(paths)) => {
                 (!paths.is_empty()).then_some(IcebergPositionDelete(paths))
             },
+            Some(IcebergEqualityDelete(deletes)) => {
+                (!deletes.is_empty()).then_some(IcebergEqualityDelete(deletes))
+            },
+            Some(DeltaDeletionVector {
+                table_root,
+                vectors,
+            }) => (!vectors.is_empty()).then_some(DeltaDeletionVector {
+                table_root,
+                vectors,
+            }),
             None => None,
         }
     }
@@ -40,6 +139,8 @@ impl DeletionFilesList {
 
         match self {
             IcebergPositionDelete(paths) => paths.len(),
+            IcebergEqualityDelete(deletes) => deletes.len(),
+            DeltaDeletionVector { vectors, .. } => vectors.len(),
         }
     }
 }
@@ -58,6 +159,16 @@ impl std::hash::Hash for DeletionFilesList {
 
                 addr.hash(state)
             },
+            IcebergEqualityDelete(deletes) => {
+                (Arc::as_ptr(deletes) as *const () as usize).hash(state)
+            },
+            DeltaDeletionVector {
+                table_root,
+                vectors,
+            } => {
+                table_root.hash(state);
+                (Arc::as_ptr(vectors) as *const () as usize).hash(state)
+            },
         }
     }
 }
@@ -71,6 +182,14 @@ impl std::fmt::Display for DeletionFilesList {
                 let s = if paths.len() == 1 { "" } else { "s" };
                 write!(f, "iceberg-position-delete: {} source{s}", paths.len())?;
             },
+            IcebergEqualityDelete(deletes) => {
+                let s = if deletes.len() == 1 { "" } else { "s" };
+                write!(f, "iceberg-equality-delete: {} source{s}", deletes.len())?;
+            },
+            DeltaDeletionVector { vectors, .. } => {
+                let s = if vectors.len() == 1 { "" } else { "s" };
+                write!(f, "delta-deletion-vector: {} source{s}", vectors.len())?;
+            },
         }
 
         Ok(())
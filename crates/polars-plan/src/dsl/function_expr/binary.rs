@@ -23,6 +23,14 @@ pub enum BinaryFunction {
     /// The parameters are destination type, and whether to use little endian
     /// encoding.
     Reinterpret(DataTypeExpr, bool),
+    #[cfg(feature = "binary_hash")]
+    Hash(BinaryHashAlgorithm),
+    #[cfg(feature = "binary_compression")]
+    /// The parameters are the codec and the compression level.
+    Compress(BinaryCodec, Option<i32>),
+    #[cfg(feature = "binary_compression")]
+    /// The parameters are the codec and whether invalid payloads raise an error.
+    Decompress(BinaryCodec, bool),
 }
 
 impl Display for BinaryFunction {
@@ -43,6 +51,12 @@ impl Display for BinaryFunction {
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
+            #[cfg(feature = "binary_hash")]
+            Hash(algorithm) => algorithm.name(),
+            #[cfg(feature = "binary_compression")]
+            Compress(_, _) => "compress",
+            #[cfg(feature = "binary_compression")]
+            Decompress(_, _) => "decompress",
         };
         write!(f, "bin.{s}")
     }
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use polars_ops::prelude::Roll;
+use polars_time::HolidayCalendar;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -11,15 +12,25 @@ pub enum BusinessFunction {
     BusinessDayCount {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
     },
     AddBusinessDay {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
         roll: Roll,
     },
     IsBusinessDay {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
+    },
+    AddBusinessHours {
+        /// The start and end of business hours, in nanoseconds since midnight.
+        business_hours: [i64; 2],
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
     },
 }
 
@@ -30,6 +41,7 @@ impl fmt::Display for BusinessFunction {
             BusinessDayCount { .. } => "business_day_count",
             AddBusinessDay { .. } => "add_business_days",
             IsBusinessDay { .. } => "is_business_day",
+            AddBusinessHours { .. } => "add_business_hours",
         };
         write!(f, "{s}")
     }
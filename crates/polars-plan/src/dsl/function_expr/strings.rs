@@ -65,6 +65,14 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein,
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshtein,
+    #[cfg(feature = "string_similarity")]
+    Hamming,
+    #[cfg(feature = "string_similarity")]
+    Similarity(StringSimilarityMetric),
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -171,6 +179,14 @@ impl Display for StringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein => "damerau_levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming => "hamming",
+            #[cfg(feature = "string_similarity")]
+            Similarity(_) => "similarity",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
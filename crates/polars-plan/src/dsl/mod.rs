@@ -953,6 +953,13 @@ impl Expr {
         self.map_unary(FunctionExpr::ApproxNUnique)
     }
 
+    /// Get an approximation of the quantile, computed with a t-digest sketch.
+    ///
+    /// Unlike [`Expr::quantile`], this can be computed in a single streaming pass.
+    pub fn approx_quantile(self, quantile: f64) -> Self {
+        self.map_unary(FunctionExpr::ApproxQuantile { quantile })
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
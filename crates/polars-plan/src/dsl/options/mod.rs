@@ -8,6 +8,8 @@ mod sink;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -16,6 +18,8 @@ use polars_io::ipc::IpcWriterOptions;
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars_io::utils::compression::FileCompression;
 #[cfg(feature = "iejoin")]
 use polars_ops::frame::IEJoinOptions;
 use polars_ops::frame::{CrossJoinFilter, CrossJoinOptions, JoinTypeOptions};
@@ -119,6 +123,16 @@ pub struct JoinOptionsIR {
     pub force_parallel: bool,
     pub args: JoinArgs,
     pub options: Option<JoinTypeOptionsIR>,
+    /// Which input should be used to build the hash table, as chosen by the
+    /// optimizer from the estimated input cardinalities.
+    pub build_side: Option<JoinBuildSide>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ir_serde", derive(Serialize, Deserialize))]
+pub enum JoinBuildSide {
+    Left,
+    Right,
 }
 
 impl From<JoinOptions> for JoinOptionsIR {
@@ -128,6 +142,7 @@ impl From<JoinOptions> for JoinOptionsIR {
             force_parallel: opts.force_parallel,
             args: opts.args,
             options: Default::default(),
+            build_side: None,
         }
     }
 }
@@ -340,6 +355,8 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 impl FileType {
@@ -350,9 +367,19 @@ impl FileType {
             #[cfg(feature = "ipc")]
             Self::Ipc(_) => "ipc",
             #[cfg(feature = "csv")]
-            Self::Csv(_) => "csv",
+            Self::Csv(options) => match options.compression {
+                FileCompression::Uncompressed => "csv",
+                FileCompression::Gzip(_) => "csv.gz",
+                FileCompression::Zstd(_) => "csv.zst",
+            },
             #[cfg(feature = "json")]
-            Self::Json(_) => "jsonl",
+            Self::Json(options) => match options.compression {
+                FileCompression::Uncompressed => "jsonl",
+                FileCompression::Gzip(_) => "jsonl.gz",
+                FileCompression::Zstd(_) => "jsonl.zst",
+            },
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -389,6 +389,36 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "string_similarity")]
+    /// Levenshtein distance to `other`, counted in characters.
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::Levenshtein, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Damerau-Levenshtein distance to `other`, counted in characters.
+    pub fn damerau_levenshtein(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::DamerauLevenshtein, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Hamming distance to `other`, null if the lengths differ.
+    pub fn hamming(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::Hamming, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Jaro-Winkler similarity to `other`, between 0 and 1.
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.similarity(other, StringSimilarityMetric::JaroWinkler)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Normalized similarity to `other`, between 0 and 1, where 1 means equal.
+    pub fn similarity(self, other: Expr, metric: StringSimilarityMetric) -> Expr {
+        self.0.map_binary(StringFunction::Similarity(metric), other)
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
@@ -37,6 +37,8 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 15;
+        /// Reorder chains of inner joins so that the most selective joins run first.
+        const JOIN_REORDER = 1 << 16;
     }
 }
 
@@ -17,6 +17,12 @@ pub enum IRBinaryFunction {
     Size,
     #[cfg(feature = "binary_encoding")]
     Reinterpret(DataType, bool),
+    #[cfg(feature = "binary_hash")]
+    Hash(BinaryHashAlgorithm),
+    #[cfg(feature = "binary_compression")]
+    Compress(BinaryCodec, Option<i32>),
+    #[cfg(feature = "binary_compression")]
+    Decompress(BinaryCodec, bool),
 }
 
 impl IRBinaryFunction {
@@ -32,6 +38,10 @@ impl IRBinaryFunction {
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             Reinterpret(dtype, _) => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "binary_hash")]
+            Hash(algorithm) => mapper.with_dtype(algorithm.output_dtype()),
+            #[cfg(feature = "binary_compression")]
+            Compress(_, _) | Decompress(_, _) => mapper.with_dtype(DataType::Binary),
         }
     }
 
@@ -48,6 +58,10 @@ impl IRBinaryFunction {
             | B::Base64Decode(_)
             | B::Base64Encode
             | B::Reinterpret(_, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "binary_hash")]
+            B::Hash(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "binary_compression")]
+            B::Compress(_, _) | B::Decompress(_, _) => FunctionOptions::elementwise(),
         }
     }
 }
@@ -70,6 +84,12 @@ impl Display for IRBinaryFunction {
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
+            #[cfg(feature = "binary_hash")]
+            Hash(algorithm) => algorithm.name(),
+            #[cfg(feature = "binary_compression")]
+            Compress(_, _) => "compress",
+            #[cfg(feature = "binary_compression")]
+            Decompress(_, _) => "decompress",
         };
         write!(f, "bin.{s}")
     }
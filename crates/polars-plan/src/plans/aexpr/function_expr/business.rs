@@ -2,6 +2,7 @@ use std::fmt::{Display, Formatter};
 
 use polars_core::prelude::*;
 use polars_ops::prelude::Roll;
+use polars_time::HolidayCalendar;
 
 use super::FunctionOptions;
 use crate::plans::aexpr::function_expr::FieldsMapper;
@@ -13,15 +14,25 @@ pub enum IRBusinessFunction {
     BusinessDayCount {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
     },
     AddBusinessDay {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
         roll: Roll,
     },
     IsBusinessDay {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
+    },
+    AddBusinessHours {
+        /// The start and end of business hours, in nanoseconds since midnight.
+        business_hours: [i64; 2],
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
     },
 }
 
@@ -29,7 +40,7 @@ impl IRBusinessFunction {
     pub fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
         match self {
             Self::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
-            Self::AddBusinessDay { .. } => mapper.with_same_dtype(),
+            Self::AddBusinessDay { .. } | Self::AddBusinessHours { .. } => mapper.with_same_dtype(),
             Self::IsBusinessDay { .. } => mapper.with_dtype(DataType::Boolean),
         }
     }
@@ -39,7 +50,9 @@ impl IRBusinessFunction {
             B::BusinessDayCount { .. } => {
                 FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
-            B::AddBusinessDay { .. } | B::IsBusinessDay { .. } => FunctionOptions::elementwise(),
+            B::AddBusinessDay { .. } | B::IsBusinessDay { .. } | B::AddBusinessHours { .. } => {
+                FunctionOptions::elementwise()
+            },
         }
     }
 }
@@ -51,6 +64,7 @@ impl Display for IRBusinessFunction {
             BusinessDayCount { .. } => "business_day_count",
             AddBusinessDay { .. } => "add_business_days",
             IsBusinessDay { .. } => "is_business_day",
+            AddBusinessHours { .. } => "add_business_hours",
         };
         write!(f, "{s}")
     }
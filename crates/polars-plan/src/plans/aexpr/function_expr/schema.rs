@@ -206,6 +206,7 @@ impl IRFunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            ApproxQuantile { .. } => mapper.map_numeric_to_float_dtype(true),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
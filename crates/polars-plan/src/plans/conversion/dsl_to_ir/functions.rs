@@ -125,6 +125,15 @@ pub(super) fn convert_functions(
                     );
                     IB::Reinterpret(dtype, v)
                 },
+                #[cfg(feature = "binary_hash")]
+                B::Hash(algorithm) => IB::Hash(algorithm),
+                #[cfg(feature = "binary_compression")]
+                B::Compress(codec, level) => {
+                    codec.validate_level(level)?;
+                    IB::Compress(codec, level)
+                },
+                #[cfg(feature = "binary_compression")]
+                B::Decompress(codec, strict) => IB::Decompress(codec, strict),
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -248,6 +257,14 @@ pub(super) fn convert_functions(
                 S::Normalize { form } => IS::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
+                #[cfg(feature = "string_similarity")]
+                S::Levenshtein => IS::Levenshtein,
+                #[cfg(feature = "string_similarity")]
+                S::DamerauLevenshtein => IS::DamerauLevenshtein,
+                #[cfg(feature = "string_similarity")]
+                S::Hamming => IS::Hamming,
+                #[cfg(feature = "string_similarity")]
+                S::Similarity(metric) => IS::Similarity(metric),
                 #[cfg(feature = "string_pad")]
                 S::PadStart { fill_char } => IS::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -529,25 +546,62 @@ pub(super) fn convert_functions(
             BusinessFunction::BusinessDayCount {
                 week_mask,
                 holidays,
-            } => IRBusinessFunction::BusinessDayCount {
-                week_mask,
-                holidays,
+                calendar,
+            } => {
+                if let Some(calendar) = &calendar {
+                    calendar.validate()?;
+                }
+                IRBusinessFunction::BusinessDayCount {
+                    week_mask,
+                    holidays,
+                    calendar,
+                }
             },
             BusinessFunction::AddBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
                 roll,
-            } => IRBusinessFunction::AddBusinessDay {
-                week_mask,
-                holidays,
-                roll,
+            } => {
+                if let Some(calendar) = &calendar {
+                    calendar.validate()?;
+                }
+                IRBusinessFunction::AddBusinessDay {
+                    week_mask,
+                    holidays,
+                    calendar,
+                    roll,
+                }
             },
             BusinessFunction::IsBusinessDay {
                 week_mask,
                 holidays,
-            } => IRBusinessFunction::IsBusinessDay {
+                calendar,
+            } => {
+                if let Some(calendar) = &calendar {
+                    calendar.validate()?;
+                }
+                IRBusinessFunction::IsBusinessDay {
+                    week_mask,
+                    holidays,
+                    calendar,
+                }
+            },
+            BusinessFunction::AddBusinessHours {
+                business_hours,
                 week_mask,
                 holidays,
+                calendar,
+            } => {
+                if let Some(calendar) = &calendar {
+                    calendar.validate()?;
+                }
+                IRBusinessFunction::AddBusinessHours {
+                    business_hours,
+                    week_mask,
+                    holidays,
+                    calendar,
+                }
             },
         }),
         #[cfg(feature = "abs")]
@@ -810,6 +864,13 @@ pub(super) fn convert_functions(
         F::UniqueCounts => I::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => I::ApproxNUnique,
+        F::ApproxQuantile { quantile } => {
+            polars_ensure!(
+                quantile.is_finite() && (0.0..=1.0).contains(&quantile),
+                InvalidOperation: "`approx_quantile` quantile should be between 0.0 and 1.0, got {quantile}"
+            );
+            I::ApproxQuantile { quantile }
+        },
         F::Coalesce => I::Coalesce,
         #[cfg(feature = "diff")]
         F::Diff(n) => {
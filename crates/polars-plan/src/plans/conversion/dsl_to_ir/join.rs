@@ -96,6 +96,15 @@ pub fn resolve_join(
             }
         }
 
+        #[cfg(feature = "fuzzy_join")]
+        if let JoinType::Fuzzy(options) = &options.args.how {
+            options.validate()?;
+            polars_ensure!(
+                left_on.len() == 1 && right_on.len() == 1,
+                InvalidOperation: "fuzzy join requires exactly one join key on each side"
+            );
+        }
+
         polars_ensure!(
             left_on.len() == right_on.len(),
             InvalidOperation:
@@ -318,6 +327,19 @@ pub fn resolve_join(
         }
     }
 
+    #[cfg(feature = "fuzzy_join")]
+    if options.args.how.is_fuzzy() {
+        for (l, r) in left_on.iter().zip(right_on.iter()) {
+            let ltype = get_dtype!(l, &schema_left)?;
+            let rtype = get_dtype!(r, &schema_right)?;
+            polars_ensure!(
+                ltype == DataType::String && rtype == DataType::String,
+                SchemaMismatch: "fuzzy join keys must be of type String, got `{}`: {} on left and `{}`: {} on right",
+                l.output_name(), ltype, r.output_name(), rtype
+            );
+        }
+    }
+
     // Every expression must be elementwise so that we are
     // guaranteed the keys for a join are all the same length.
 
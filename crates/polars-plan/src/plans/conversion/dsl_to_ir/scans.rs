@@ -48,6 +48,8 @@ pub(super) fn dsl_to_ir(
             },
             #[cfg(feature = "ipc")]
             FileScanDsl::Ipc { .. } => sources.expand_paths_with_hive_update(unified_scan_args)?,
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { .. } => sources.expand_paths_with_hive_update(unified_scan_args)?,
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args)?,
             #[cfg(feature = "json")]
@@ -190,7 +192,7 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -221,6 +223,7 @@ pub(super) fn parquet_file_info(
     first_scan_source: ScanSourceRef<'_>,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    key_retriever: Option<&polars_io::parquet::encryption::ParquetKeyRetriever>,
     n_sources: usize,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
@@ -230,8 +233,9 @@ pub(super) fn parquet_file_info(
             let first_path = first_scan_source.as_path().unwrap();
             feature_gated!("cloud", {
                 get_runtime().block_in_place_on(async {
-                    let mut reader =
-                        ParquetObjectStore::from_uri(first_path, cloud_options, None).await?;
+                    let mut reader = ParquetObjectStore::from_uri(first_path, cloud_options, None)
+                        .await?
+                        .with_key_retriever(key_retriever.cloned());
 
                     PolarsResult::Ok((
                         reader.schema().await?,
@@ -242,7 +246,8 @@ pub(super) fn parquet_file_info(
             })
         } else {
             let memslice = first_scan_source.to_memslice()?;
-            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                .with_key_retriever(key_retriever.cloned());
             (
                 reader.schema()?,
                 reader.num_rows()?,
@@ -325,6 +330,52 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    sources: &ScanSources,
+    first_scan_source: ScanSourceRef<'_>,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+    use polars_io::SerReader;
+    use polars_io::avro::AvroReader;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .take(1)
+                        .map(|path| Arc::from(path.to_str())),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice =
+        first_scan_source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let reader_schema =
+        Arc::new(AvroReader::new(std::io::Cursor::new(memslice.as_ref())).arrow_schema()?);
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(reader_schema.as_ref()), row_index)?,
+        Some(Either::Left(reader_schema)),
+        (None, usize::MAX),
+    );
+
+    Ok(file_info)
+}
+
 #[cfg(feature = "csv")]
 pub fn csv_file_info(
     sources: &ScanSources,
@@ -590,6 +641,7 @@ this scan to succeed with an empty DataFrame.",
                             first_scan_source,
                             unified_scan_args.row_index.as_ref(),
                             cloud_options,
+                            options.key_retriever.as_ref(),
                             n_sources,
                         )?;
 
@@ -634,6 +686,29 @@ this scan to succeed with an empty DataFrame.",
                 ))
             })()
             .map_err(|e| e.context(failed_here!(ipc scan)))?,
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options } => (|| {
+                let first_scan_source =
+                    require_first_source("failed to retrieve first file schema (avro)", "")?;
+
+                if verbose() {
+                    eprintln!(
+                        "sourcing avro scan file schema from: '{}'",
+                        first_scan_source.to_include_path_name()
+                    )
+                }
+
+                PolarsResult::Ok((
+                    scans::avro_file_info(
+                        sources,
+                        first_scan_source,
+                        unified_scan_args.row_index.as_ref(),
+                        cloud_options,
+                    )?,
+                    FileScanIR::Avro { options },
+                ))
+            })()
+            .map_err(|e| e.context(failed_here!(avro scan)))?,
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
                 (|| {
@@ -330,6 +330,12 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Size => B::Size,
                 #[cfg(feature = "binary_encoding")]
                 IB::Reinterpret(data_type, v) => B::Reinterpret(data_type.into(), v),
+                #[cfg(feature = "binary_hash")]
+                IB::Hash(algorithm) => B::Hash(algorithm),
+                #[cfg(feature = "binary_compression")]
+                IB::Compress(codec, level) => B::Compress(codec, level),
+                #[cfg(feature = "binary_compression")]
+                IB::Decompress(codec, strict) => B::Decompress(codec, strict),
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -453,6 +459,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Normalize { form } => B::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
+                #[cfg(feature = "string_similarity")]
+                IB::Levenshtein => B::Levenshtein,
+                #[cfg(feature = "string_similarity")]
+                IB::DamerauLevenshtein => B::DamerauLevenshtein,
+                #[cfg(feature = "string_similarity")]
+                IB::Hamming => B::Hamming,
+                #[cfg(feature = "string_similarity")]
+                IB::Similarity(metric) => B::Similarity(metric),
                 #[cfg(feature = "string_pad")]
                 IB::PadStart { fill_char } => B::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -670,25 +684,42 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::BusinessDayCount {
                     week_mask,
                     holidays,
+                    calendar,
                 } => B::BusinessDayCount {
                     week_mask,
                     holidays,
+                    calendar,
                 },
                 IB::AddBusinessDay {
                     week_mask,
                     holidays,
+                    calendar,
                     roll,
                 } => B::AddBusinessDay {
                     week_mask,
                     holidays,
+                    calendar,
                     roll,
                 },
                 IB::IsBusinessDay {
                     week_mask,
                     holidays,
+                    calendar,
                 } => B::IsBusinessDay {
                     week_mask,
                     holidays,
+                    calendar,
+                },
+                IB::AddBusinessHours {
+                    business_hours,
+                    week_mask,
+                    holidays,
+                    calendar,
+                } => B::AddBusinessHours {
+                    business_hours,
+                    week_mask,
+                    holidays,
+                    calendar,
                 },
             })
         },
@@ -917,6 +948,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::UniqueCounts => F::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         IF::ApproxNUnique => F::ApproxNUnique,
+        IF::ApproxQuantile { quantile } => F::ApproxQuantile { quantile },
         IF::Coalesce => F::Coalesce,
         #[cfg(feature = "diff")]
         IF::Diff(nb) => F::Diff(nb),
@@ -4,14 +4,17 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "avro"
 ))]
 use polars_core::error::feature_gated;
 #[cfg(any(feature = "json", feature = "parquet"))]
 use polars_io::SerReader;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(feature = "parquet", feature = "json", feature = "avro"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
+use polars_io::parquet::encryption::ParquetKeyRetriever;
+#[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetReader;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
@@ -30,7 +33,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     )))]
     {
         unreachable!()
@@ -40,14 +44,17 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
             #[cfg(feature = "csv")]
             FileScanIR::Csv { options } => count_all_rows_csv(sources, options),
             #[cfg(feature = "parquet")]
-            FileScanIR::Parquet { .. } => count_rows_parquet(sources, cloud_options),
+            FileScanIR::Parquet { options, .. } => {
+                count_rows_parquet(sources, cloud_options, options.key_retriever.as_ref())
+            },
             #[cfg(feature = "ipc")]
             FileScanIR::Ipc { options, metadata } => count_rows_ipc(
                 sources,
@@ -55,6 +62,8 @@ pub fn count_rows(
                 cloud_options,
                 metadata.as_deref(),
             ),
+            #[cfg(feature = "avro")]
+            FileScanIR::Avro { options: _ } => count_rows_avro(sources, cloud_options),
             #[cfg(feature = "json")]
             FileScanIR::NDJson { options } => count_rows_ndjson(sources, cloud_options),
             #[cfg(feature = "scan_lines")]
@@ -116,6 +125,7 @@ fn count_all_rows_csv(
 pub(super) fn count_rows_parquet(
     sources: &ScanSources,
     #[allow(unused)] cloud_options: Option<&CloudOptions>,
+    key_retriever: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<usize> {
     if sources.is_empty() {
         return Ok(0);
@@ -126,13 +136,16 @@ pub(super) fn count_rows_parquet(
             get_runtime().block_on(count_rows_cloud_parquet(
                 sources.as_paths().unwrap(),
                 cloud_options,
+                key_retriever,
             ))
         })
     } else {
         sources
             .iter()
             .map(|source| {
-                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?)).num_rows()
+                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?))
+                    .with_key_retriever(key_retriever.cloned())
+                    .num_rows()
             })
             .sum::<PolarsResult<usize>>()
     }
@@ -142,13 +155,15 @@ pub(super) fn count_rows_parquet(
 async fn count_rows_cloud_parquet(
     paths: &[PlPath],
     cloud_options: Option<&CloudOptions>,
+    key_retriever: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<usize> {
     use polars_io::prelude::ParquetObjectStore;
 
     let collection = paths.iter().map(|path| {
         with_concurrency_budget(1, || async {
-            let mut reader =
-                ParquetObjectStore::from_uri(path.as_ref(), cloud_options, None).await?;
+            let mut reader = ParquetObjectStore::from_uri(path.as_ref(), cloud_options, None)
+                .await?
+                .with_key_retriever(key_retriever.cloned());
             reader.num_rows().await
         })
     });
@@ -206,6 +221,47 @@ async fn count_rows_cloud_ipc(
         .map(|rows| rows.iter().map(|v| *v as usize).sum())
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn count_rows_avro(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str())),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            polars_io::avro::count_rows(&memslice)
+        })
+        .sum()
+}
+
 #[cfg(feature = "json")]
 pub(super) fn count_rows_ndjson(
     sources: &ScanSources,
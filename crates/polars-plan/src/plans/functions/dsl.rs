@@ -128,7 +128,10 @@ impl DslFunction {
                 }
                 FunctionIR::Unnest { columns, separator }
             },
-            DslFunction::Hint(h) => FunctionIR::Hint(h),
+            DslFunction::Hint(h) => {
+                validate_columns_in_input(h.columns(), input_schema, "hint")?;
+                FunctionIR::Hint(h)
+            },
             #[cfg(feature = "python")]
             DslFunction::OpaquePython(inner) => FunctionIR::OpaquePython(inner),
             DslFunction::Stats(_)
@@ -12,12 +12,30 @@ pub struct Sorted {
     pub nulls_last: bool,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Hash)]
+pub struct DistinctCount {
+    pub column: PlSmallStr,
+    /// Approximate number of distinct values, including null.
+    pub count: usize,
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Hash, strum_macros::IntoStaticStr)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum HintIR {
     Sorted(Arc<[Sorted]>),
+    /// No two rows have the same values in these columns.
+    Unique(Arc<[PlSmallStr]>),
+    /// Approximate number of rows.
+    RowCount(usize),
+    /// Approximate number of distinct values per column.
+    DistinctCount(Arc<[DistinctCount]>),
+    /// Rows with the same values in these columns are adjacent, i.e. the data is already
+    /// partitioned (clustered) by these columns.
+    Partitioned(Arc<[PlSmallStr]>),
 }
 
 impl fmt::Display for Sorted {
@@ -30,6 +48,36 @@ impl fmt::Display for Sorted {
     }
 }
 
+impl fmt::Display for DistinctCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}': {}", self.column, self.count)
+    }
+}
+
+impl HintIR {
+    /// The columns this hint refers to.
+    pub fn columns(&self) -> Vec<&PlSmallStr> {
+        match self {
+            HintIR::Sorted(s) => s.iter().map(|s| &s.column).collect(),
+            HintIR::Unique(columns) | HintIR::Partitioned(columns) => columns.iter().collect(),
+            HintIR::RowCount(_) => vec![],
+            HintIR::DistinctCount(d) => d.iter().map(|d| &d.column).collect(),
+        }
+    }
+}
+
+fn write_list<T: fmt::Display>(f: &mut fmt::Formatter<'_>, name: &str, items: &[T]) -> fmt::Result {
+    write!(f, "{name}(")?;
+    if let Some(fst) = items.first() {
+        fst.fmt(f)?;
+        for item in &items[1..] {
+            f.write_str(", ")?;
+            item.fmt(f)?;
+        }
+    }
+    write!(f, ")")
+}
+
 impl fmt::Debug for HintIR {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{self}")
@@ -39,17 +87,11 @@ impl fmt::Debug for HintIR {
 impl fmt::Display for HintIR {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            HintIR::Sorted(s) => {
-                write!(f, "sorted(")?;
-                if let Some(fst) = s.first() {
-                    fst.fmt(f)?;
-                    for si in &s[1..] {
-                        f.write_str(", ")?;
-                        si.fmt(f)?;
-                    }
-                }
-                write!(f, ")")
-            },
+            HintIR::Sorted(s) => write_list(f, "sorted", s),
+            HintIR::Unique(columns) => write_list(f, "unique", columns),
+            HintIR::RowCount(count) => write!(f, "row_count({count})"),
+            HintIR::DistinctCount(d) => write_list(f, "distinct_count", d),
+            HintIR::Partitioned(columns) => write_list(f, "partitioned", columns),
         }
     }
 }
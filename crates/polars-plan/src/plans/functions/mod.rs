@@ -240,7 +240,7 @@ impl FunctionIR {
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.clone(), *offset),
             Hint(hint) => {
-                #[expect(irrefutable_let_patterns)]
+                // The other hints only inform the optimizer.
                 if let HintIR::Sorted(s) = &hint
                     && let Some(s) = s.first()
                 {
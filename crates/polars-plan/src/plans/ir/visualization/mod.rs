@@ -328,6 +328,7 @@ impl IRVisualizationDataGenerator<'_> {
                             maintain_order,
                         },
                     options,
+                    build_side: _,
                 } = options.as_ref();
 
                 let properties = match how {
@@ -395,6 +396,24 @@ impl IRVisualizationDataGenerator<'_> {
                             slice: convert_opt_slice(slice),
                         }
                     },
+                    #[cfg(feature = "fuzzy_join")]
+                    JoinType::Fuzzy(fuzzy_options) => {
+                        assert_eq!(left_on.len(), 1);
+                        assert_eq!(right_on.len(), 1);
+
+                        IRNodeProperties::FuzzyJoin {
+                            left_on: format_pl_smallstr!("{}", left_on[0].display(self.expr_arena)),
+                            right_on: format_pl_smallstr!(
+                                "{}",
+                                right_on[0].display(self.expr_arena)
+                            ),
+                            metric: format_pl_smallstr!("{:?}", fuzzy_options.metric),
+                            threshold: format_pl_smallstr!("{:?}", fuzzy_options.threshold),
+                            score_name: fuzzy_options.score_name.clone(),
+                            suffix: suffix.clone(),
+                            slice: convert_opt_slice(slice),
+                        }
+                    },
                     JoinType::Cross => {
                         let predicate: Option<PlSmallStr> = options.as_ref().map(|x| {
                             let JoinTypeOptionsIR::CrossAndFilter { predicate } = x else {
@@ -198,6 +198,16 @@ pub enum IRNodeProperties {
         suffix: Option<PlSmallStr>,
         slice: Option<(i64, u64)>,
     },
+    #[cfg(feature = "fuzzy_join")]
+    FuzzyJoin {
+        left_on: PlSmallStr,
+        right_on: PlSmallStr,
+        metric: PlSmallStr,
+        threshold: PlSmallStr,
+        score_name: PlSmallStr,
+        suffix: Option<PlSmallStr>,
+        slice: Option<(i64, u64)>,
+    },
     #[cfg(feature = "dynamic_group_by")]
     DynamicGroupBy {
         index_column: PlSmallStr,
@@ -0,0 +1,607 @@
+//! Cardinality estimation of (sub)plans, used to reorder joins and to pick their build side.
+//!
+//! Row counts come from the scan metadata (parquet row counts, in-memory heights) or from row count
+//! hints, and are scaled by the estimated selectivity of the filters on top. Where possible,
+//! selectivities of range and equality predicates are derived from min/max statistics, otherwise
+//! fixed defaults are used. The number of distinct keys of joins and group-bys follows from
+//! distinct count hints and from columns known to be unique, e.g. the keys of a group-by.
+#[cfg(feature = "parquet")]
+use either::Either;
+use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_utils::itertools::Itertools;
+
+use super::hints::{column_names, is_unique, passthrough_input};
+use crate::prelude::*;
+
+/// Selectivity of a predicate we know nothing about.
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+/// Selectivity of an equality predicate without statistics.
+const DEFAULT_EQ_SELECTIVITY: f64 = 0.1;
+/// Selectivity of a range predicate without statistics.
+const DEFAULT_RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+const DEFAULT_NULL_SELECTIVITY: f64 = 0.1;
+/// Semi and anti joins are assumed to keep this fraction of their left input.
+const DEFAULT_SEMI_ANTI_SELECTIVITY: f64 = 0.5;
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct RowEstimate {
+    /// Estimated number of output rows.
+    pub rows: f64,
+    /// Estimated number of rows before any filters were applied. This bounds the number of
+    /// distinct values of the columns and thereby the selectivity of joins on them.
+    pub base_rows: f64,
+}
+
+impl RowEstimate {
+    fn new(rows: f64) -> Self {
+        Self {
+            rows,
+            base_rows: rows,
+        }
+    }
+
+    fn filtered(self, selectivity: f64) -> Self {
+        Self {
+            rows: self.rows * selectivity.clamp(0.0, 1.0),
+            base_rows: self.base_rows,
+        }
+    }
+
+    fn limited(self, len: f64) -> Self {
+        Self {
+            rows: self.rows.min(len),
+            base_rows: self.base_rows,
+        }
+    }
+}
+
+/// Min/max statistics of a column, with one bucket per chunk of rows (e.g. a parquet row group).
+struct ColumnStats {
+    buckets: Vec<StatsBucket>,
+    /// Whether the values are integers, so that a range `[min, max]` holds at most
+    /// `max - min + 1` distinct values.
+    is_integral: bool,
+}
+
+struct StatsBucket {
+    rows: f64,
+    /// `None` if the statistics are missing.
+    range: Option<(f64, f64)>,
+}
+
+#[derive(Default)]
+pub(super) struct CardinalityEstimator {
+    cache: PlHashMap<Node, Option<RowEstimate>>,
+}
+
+impl CardinalityEstimator {
+    /// Estimates the number of rows produced by `node`, `None` if unknown.
+    pub(super) fn estimate(
+        &mut self,
+        node: Node,
+        ir_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<RowEstimate> {
+        if let Some(estimate) = self.cache.get(&node) {
+            return *estimate;
+        }
+        let estimate = self.estimate_impl(node, ir_arena, expr_arena);
+        self.cache.insert(node, estimate);
+        estimate
+    }
+
+    /// Drops the cached estimate of `node`, to be called when it is replaced.
+    pub(super) fn invalidate(&mut self, node: Node) {
+        self.cache.remove(&node);
+    }
+
+    /// Estimates the number of distinct combinations of values of `columns` in the output of
+    /// `node`, before any filters, from the hints. `None` if unknown.
+    pub(super) fn distinct_count(
+        &mut self,
+        columns: &[PlSmallStr],
+        node: Node,
+        ir_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<f64> {
+        if is_unique(columns, node, ir_arena, expr_arena) {
+            return Some(self.estimate(node, ir_arena, expr_arena)?.base_rows);
+        }
+
+        let mut counts = vec![None; columns.len()];
+        let mut current = node;
+        while let Some(input) = passthrough_input(current, columns, ir_arena, expr_arena) {
+            if let IR::MapFunction {
+                function: FunctionIR::Hint(HintIR::DistinctCount(hints)),
+                ..
+            } = ir_arena.get(current)
+            {
+                for hint in hints.iter() {
+                    if let Some(i) = columns.iter().position(|c| c == &hint.column) {
+                        counts[i].get_or_insert(hint.count as f64);
+                    }
+                }
+            }
+            current = input;
+        }
+        // Assume the columns are independent, the number of rows bounds the result.
+        let product = counts.into_iter().product::<Option<f64>>()?;
+        match self.estimate(node, ir_arena, expr_arena) {
+            Some(estimate) => Some(product.min(estimate.base_rows)),
+            None => Some(product),
+        }
+    }
+
+    fn estimate_impl(
+        &mut self,
+        node: Node,
+        ir_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<RowEstimate> {
+        use IR::*;
+        let estimate = match ir_arena.get(node) {
+            DataFrameScan { df, .. } => RowEstimate::new(df.height() as f64),
+            Scan {
+                file_info,
+                predicate,
+                unified_scan_args,
+                ..
+            } => {
+                let rows = match file_info.row_estimation {
+                    (Some(known), _) => known,
+                    (None, usize::MAX) => return None,
+                    (None, estimate) => estimate,
+                };
+                let mut estimate = RowEstimate::new(rows as f64);
+                if let Some(predicate) = predicate {
+                    let selectivity =
+                        predicate_selectivity(predicate.node(), node, ir_arena, expr_arena);
+                    estimate = estimate.filtered(selectivity);
+                }
+                if let Some(pre_slice) = &unified_scan_args.pre_slice {
+                    estimate = estimate.limited(pre_slice.len() as f64);
+                }
+                estimate
+            },
+            Filter { input, predicate } => {
+                let selectivity =
+                    predicate_selectivity(predicate.node(), *input, ir_arena, expr_arena);
+                self.estimate(*input, ir_arena, expr_arena)?
+                    .filtered(selectivity)
+            },
+            Slice { input, offset, len } => {
+                let estimate = self.estimate(*input, ir_arena, expr_arena)?;
+                let available = if *offset >= 0 {
+                    (estimate.rows - *offset as f64).max(0.0)
+                } else {
+                    (-*offset) as f64
+                };
+                estimate.limited(available.min(*len as f64))
+            },
+            Sort { input, slice, .. } => {
+                let estimate = self.estimate(*input, ir_arena, expr_arena)?;
+                match slice {
+                    Some((_, len)) => estimate.limited(*len as f64),
+                    None => estimate,
+                }
+            },
+            Select { input, .. }
+            | HStack { input, .. }
+            | SimpleProjection { input, .. }
+            | Cache { input, .. } => self.estimate(*input, ir_arena, expr_arena)?,
+            MapFunction {
+                input,
+                function: FunctionIR::Hint(HintIR::RowCount(rows)),
+            } => {
+                let rows = *rows as f64;
+                // Filters may have been pushed down below the hint.
+                match self.estimate(*input, ir_arena, expr_arena) {
+                    Some(input) if input.base_rows > 0.0 => RowEstimate {
+                        rows: rows * (input.rows / input.base_rows),
+                        base_rows: rows,
+                    },
+                    _ => RowEstimate::new(rows),
+                }
+            },
+            MapFunction {
+                input,
+                function: FunctionIR::RowIndex { .. } | FunctionIR::Rechunk | FunctionIR::Hint(_),
+            } => self.estimate(*input, ir_arena, expr_arena)?,
+            GroupBy {
+                input,
+                keys,
+                options,
+                apply: None,
+                ..
+            } if !options.is_rolling() && !options.is_dynamic() => {
+                let input_estimate = self.estimate(*input, ir_arena, expr_arena)?;
+                let keys = column_names(keys, expr_arena)?;
+                let groups = self.distinct_count(&keys, *input, ir_arena, expr_arena)?;
+                let estimate = RowEstimate::new(input_estimate.rows.min(groups));
+                match &options.slice {
+                    Some((_, len)) => estimate.limited(*len as f64),
+                    None => estimate,
+                }
+            },
+            Distinct { input, options } => {
+                let mut estimate = self.estimate(*input, ir_arena, expr_arena)?;
+                // Without a known number of distinct values, the input is an upper bound.
+                if let Some(subset) = &options.subset
+                    && let Some(distinct) =
+                        self.distinct_count(subset, *input, ir_arena, expr_arena)
+                {
+                    estimate = estimate.limited(distinct);
+                }
+                match &options.slice {
+                    Some((_, len)) => estimate.limited(*len as f64),
+                    None => estimate,
+                }
+            },
+            Union { inputs, .. } => {
+                let mut estimate = RowEstimate::new(0.0);
+                for input in inputs {
+                    let input = self.estimate(*input, ir_arena, expr_arena)?;
+                    estimate.rows += input.rows;
+                    estimate.base_rows += input.base_rows;
+                }
+                estimate
+            },
+            HConcat { inputs, .. } => {
+                let mut rows: f64 = 0.0;
+                for input in inputs {
+                    rows = rows.max(self.estimate(*input, ir_arena, expr_arena)?.rows);
+                }
+                RowEstimate::new(rows)
+            },
+            Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } => {
+                let left = self.estimate(*input_left, ir_arena, expr_arena)?;
+                let right = self.estimate(*input_right, ir_arena, expr_arena)?;
+                let mut key_distinct_count = |keys: &[ExprIR], input: Node| {
+                    let keys = column_names(keys, expr_arena)?;
+                    self.distinct_count(&keys, input, ir_arena, expr_arena)
+                };
+                let distinct = [
+                    key_distinct_count(left_on, *input_left),
+                    key_distinct_count(right_on, *input_right),
+                ];
+                let inner_rows = equi_join_rows(left, right, left.base_rows, distinct);
+                let rows = match &options.args.how {
+                    JoinType::Inner => inner_rows,
+                    JoinType::Left => inner_rows.max(left.rows),
+                    JoinType::Right => inner_rows.max(right.rows),
+                    JoinType::Full => left.rows + right.rows,
+                    // A cross join fused with a filter.
+                    JoinType::Cross if options.options.is_some() => {
+                        left.rows * right.rows * DEFAULT_RANGE_SELECTIVITY
+                    },
+                    JoinType::Cross => left.rows * right.rows,
+                    #[cfg(feature = "semi_anti_join")]
+                    JoinType::Semi | JoinType::Anti => left.rows * DEFAULT_SEMI_ANTI_SELECTIVITY,
+                    #[cfg(feature = "asof_join")]
+                    JoinType::AsOf(_) => left.rows,
+                    #[allow(unreachable_patterns)]
+                    _ => return None,
+                };
+                let estimate = RowEstimate::new(rows);
+                match &options.args.slice {
+                    Some((_, len)) => estimate.limited(*len as f64),
+                    None => estimate,
+                }
+            },
+            _ => return None,
+        };
+        Some(estimate)
+    }
+}
+
+/// Estimates the size of an inner equi-join, assuming the keys of both sides are drawn from a
+/// domain of `left_domain` values. `distinct` holds the number of distinct keys of the left and
+/// right input, if known from hints.
+///
+/// Filters reduce the number of distinct keys of a side, so a side can match at most as many keys
+/// as it has rows. The side with the most distinct keys determines how many pairs match.
+pub(super) fn equi_join_rows(
+    left: RowEstimate,
+    right: RowEstimate,
+    left_domain: f64,
+    distinct: [Option<f64>; 2],
+) -> f64 {
+    let domain = left_domain.min(right.base_rows).max(1.0);
+    let left_distinct = left.rows.min(distinct[0].unwrap_or(domain));
+    let right_distinct = right.rows.min(distinct[1].unwrap_or(domain));
+    left.rows * right.rows / left_distinct.max(right_distinct).max(1.0)
+}
+
+/// Estimates the fraction of rows of `input` for which `predicate` holds.
+fn predicate_selectivity(
+    predicate: Node,
+    input: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> f64 {
+    let selectivity = |predicate| predicate_selectivity(predicate, input, ir_arena, expr_arena);
+
+    match expr_arena.get(predicate) {
+        AExpr::BinaryExpr { left, op, right } => match op {
+            Operator::And | Operator::LogicalAnd => selectivity(*left) * selectivity(*right),
+            Operator::Or | Operator::LogicalOr => {
+                let (left, right) = (selectivity(*left), selectivity(*right));
+                left + right - left * right
+            },
+            op if op.is_comparison() => match (expr_arena.get(*left), expr_arena.get(*right)) {
+                (AExpr::Column(name), AExpr::Literal(value)) => {
+                    comparison_selectivity(name, *op, value, input, ir_arena, expr_arena)
+                },
+                (AExpr::Literal(value), AExpr::Column(name)) => comparison_selectivity(
+                    name,
+                    op.swap_operands(),
+                    value,
+                    input,
+                    ir_arena,
+                    expr_arena,
+                ),
+                _ => default_comparison_selectivity(*op),
+            },
+            _ => DEFAULT_SELECTIVITY,
+        },
+        AExpr::Function {
+            input: args,
+            function: IRFunctionExpr::Boolean(function),
+            ..
+        } => match function {
+            IRBooleanFunction::Not => 1.0 - selectivity(args[0].node()),
+            IRBooleanFunction::IsNull => DEFAULT_NULL_SELECTIVITY,
+            IRBooleanFunction::IsNotNull => 1.0 - DEFAULT_NULL_SELECTIVITY,
+            #[cfg(feature = "is_in")]
+            IRBooleanFunction::IsIn { .. } => {
+                let num_values = match expr_arena.get(args[1].node()) {
+                    AExpr::Literal(LiteralValue::Series(s)) => s.len(),
+                    AExpr::Literal(value) => match value.to_any_value() {
+                        Some(AnyValue::List(s)) => s.len(),
+                        _ => return DEFAULT_SELECTIVITY,
+                    },
+                    _ => return DEFAULT_SELECTIVITY,
+                };
+                let eq = match expr_arena.get(args[0].node()) {
+                    AExpr::Column(name) => column_stats(name, input, ir_arena, expr_arena)
+                        .map_or(DEFAULT_EQ_SELECTIVITY, |stats| stats.eq_selectivity()),
+                    _ => DEFAULT_EQ_SELECTIVITY,
+                };
+                (num_values as f64 * eq).min(1.0)
+            },
+            #[cfg(feature = "is_between")]
+            IRBooleanFunction::IsBetween { closed } => {
+                let lower = match closed {
+                    ClosedInterval::Both | ClosedInterval::Left => Operator::GtEq,
+                    ClosedInterval::Right | ClosedInterval::None => Operator::Gt,
+                };
+                let upper = match closed {
+                    ClosedInterval::Both | ClosedInterval::Right => Operator::LtEq,
+                    ClosedInterval::Left | ClosedInterval::None => Operator::Lt,
+                };
+                let bound = |op, bound: &ExprIR| match (
+                    expr_arena.get(args[0].node()),
+                    expr_arena.get(bound.node()),
+                ) {
+                    (AExpr::Column(name), AExpr::Literal(value)) => {
+                        comparison_selectivity(name, op, value, input, ir_arena, expr_arena)
+                    },
+                    _ => default_comparison_selectivity(op),
+                };
+                // The rows below the lower bound and those above the upper bound are disjoint.
+                (bound(lower, &args[1]) + bound(upper, &args[2]) - 1.0).max(0.0)
+            },
+            _ => DEFAULT_SELECTIVITY,
+        },
+        AExpr::Literal(value) => match value.to_any_value() {
+            Some(AnyValue::Boolean(true)) => 1.0,
+            Some(AnyValue::Boolean(false) | AnyValue::Null) => 0.0,
+            _ => DEFAULT_SELECTIVITY,
+        },
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
+
+fn default_comparison_selectivity(op: Operator) -> f64 {
+    match op {
+        Operator::Eq | Operator::EqValidity => DEFAULT_EQ_SELECTIVITY,
+        Operator::NotEq | Operator::NotEqValidity => 1.0 - DEFAULT_EQ_SELECTIVITY,
+        _ => DEFAULT_RANGE_SELECTIVITY,
+    }
+}
+
+/// Estimates the selectivity of `name <op> value` from the statistics of the column.
+fn comparison_selectivity(
+    name: &PlSmallStr,
+    op: Operator,
+    value: &LiteralValue,
+    input: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> f64 {
+    let value = value
+        .to_any_value()
+        .and_then(|av| av.extract::<f64>())
+        .filter(|v| !v.is_nan());
+    match (value, column_stats(name, input, ir_arena, expr_arena)) {
+        (Some(value), Some(stats)) => stats.comparison_selectivity(op, value),
+        _ => default_comparison_selectivity(op),
+    }
+}
+
+impl ColumnStats {
+    fn from_series(s: &Series) -> Option<Self> {
+        let dtype = s.dtype();
+        if !(dtype.is_primitive_numeric() || dtype.is_temporal()) {
+            return None;
+        }
+        let physical = s.to_physical_repr();
+        let min = physical.min::<f64>().ok()?;
+        let max = physical.max::<f64>().ok()?;
+        Some(Self {
+            buckets: vec![StatsBucket {
+                rows: (s.len() - s.null_count()) as f64,
+                range: min.zip(max),
+            }],
+            is_integral: dtype.to_physical().is_integer(),
+        })
+    }
+
+    /// Weighs `f`, the selectivity within a bucket given its range, by the rows of each bucket.
+    fn weighted(&self, default: f64, f: impl Fn(f64, f64, f64) -> f64) -> f64 {
+        let total_rows: f64 = self.buckets.iter().map(|b| b.rows).sum();
+        if total_rows == 0.0 {
+            return default;
+        }
+        let matching: f64 = self
+            .buckets
+            .iter()
+            .map(|b| match b.range {
+                Some((min, max)) => b.rows * f(min, max, b.rows),
+                None => b.rows * default,
+            })
+            .sum();
+        matching / total_rows
+    }
+
+    fn eq_selectivity_in(&self, min: f64, max: f64, rows: f64) -> f64 {
+        if self.is_integral {
+            1.0 / rows.min(max - min + 1.0).max(1.0)
+        } else {
+            DEFAULT_EQ_SELECTIVITY
+        }
+    }
+
+    #[cfg(feature = "is_in")]
+    fn eq_selectivity(&self) -> f64 {
+        self.weighted(DEFAULT_EQ_SELECTIVITY, |min, max, rows| {
+            self.eq_selectivity_in(min, max, rows)
+        })
+    }
+
+    fn comparison_selectivity(&self, op: Operator, value: f64) -> f64 {
+        // Fraction of the range `[min, max]` below `value`.
+        let below = |min: f64, max: f64| {
+            if value <= min {
+                0.0
+            } else if value > max {
+                1.0
+            } else if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.5
+            }
+        };
+        let eq = |min: f64, max: f64, rows: f64| {
+            if value < min || value > max {
+                0.0
+            } else {
+                self.eq_selectivity_in(min, max, rows)
+            }
+        };
+
+        match op {
+            Operator::Eq | Operator::EqValidity => self.weighted(DEFAULT_EQ_SELECTIVITY, eq),
+            Operator::NotEq | Operator::NotEqValidity => self
+                .weighted(1.0 - DEFAULT_EQ_SELECTIVITY, |min, max, rows| {
+                    1.0 - eq(min, max, rows)
+                }),
+            Operator::Lt => self.weighted(DEFAULT_RANGE_SELECTIVITY, |min, max, _| below(min, max)),
+            Operator::LtEq => self.weighted(DEFAULT_RANGE_SELECTIVITY, |min, max, rows| {
+                (below(min, max) + eq(min, max, rows)).min(1.0)
+            }),
+            Operator::Gt => self.weighted(DEFAULT_RANGE_SELECTIVITY, |min, max, rows| {
+                (1.0 - below(min, max) - eq(min, max, rows)).max(0.0)
+            }),
+            Operator::GtEq => self.weighted(DEFAULT_RANGE_SELECTIVITY, |min, max, _| {
+                1.0 - below(min, max)
+            }),
+            _ => DEFAULT_SELECTIVITY,
+        }
+    }
+}
+
+/// Finds the statistics of column `name` in the output of `node`, by following the column down to
+/// the scan that produces it.
+fn column_stats(
+    name: &PlSmallStr,
+    mut node: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<ColumnStats> {
+    loop {
+        match ir_arena.get(node) {
+            IR::DataFrameScan { df, .. } => {
+                return ColumnStats::from_series(df.column(name).ok()?.as_materialized_series());
+            },
+            #[cfg(feature = "parquet")]
+            IR::Scan {
+                file_info,
+                scan_type,
+                ..
+            } => {
+                let FileScanIR::Parquet {
+                    metadata: Some(metadata),
+                    ..
+                } = scan_type.as_ref()
+                else {
+                    return None;
+                };
+                let Some(Either::Left(reader_schema)) = &file_info.reader_schema else {
+                    return None;
+                };
+                return parquet_column_stats(reader_schema.get(name)?, metadata);
+            },
+            _ => node = passthrough_input(node, std::slice::from_ref(name), ir_arena, expr_arena)?,
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_column_stats(
+    field: &ArrowField,
+    metadata: &polars_io::parquet::metadata::FileMetadataRef,
+) -> Option<ColumnStats> {
+    use polars_parquet::read::statistics::deserialize_all;
+
+    let row_groups = &metadata.row_groups;
+    let &[idx] = row_groups
+        .first()?
+        .columns_idxs_under_root_iter(&field.name)?
+    else {
+        return None;
+    };
+    let statistics = deserialize_all(field, row_groups, idx).ok()??;
+    let dtype = DataType::from_arrow_field(field);
+    if !(dtype.is_primitive_numeric() || dtype.is_temporal()) {
+        return None;
+    }
+    let to_f64 = |array| -> Option<Series> {
+        let s = Series::from_arrow(field.name.clone(), array).ok()?;
+        s.to_physical_repr().cast(&DataType::Float64).ok()
+    };
+    let min = to_f64(statistics.min_value)?;
+    let max = to_f64(statistics.max_value)?;
+    let (min, max) = (min.f64().ok()?, max.f64().ok()?);
+
+    let buckets = row_groups
+        .iter()
+        .zip(min.iter().zip(max.iter()))
+        .map(|(rg, (min, max))| StatsBucket {
+            rows: rg.num_rows() as f64,
+            range: min.zip(max),
+        })
+        .collect_vec();
+    Some(ColumnStats {
+        buckets,
+        is_integral: dtype.to_physical().is_integer(),
+    })
+}
@@ -282,6 +282,9 @@ pub(super) fn expand_datasets(
                                 metadata: None,
                             },
 
+                            #[cfg(feature = "avro")]
+                            FileScanDsl::Avro { options } => FileScanIR::Avro { options },
+
                             #[cfg(feature = "parquet")]
                             FileScanDsl::Parquet { options } => FileScanIR::Parquet {
                                 options,
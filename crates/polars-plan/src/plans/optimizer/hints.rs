@@ -0,0 +1,212 @@
+//! Optimizations using the properties of the data that were declared with [`HintIR`]s, or that
+//! follow from the plan itself.
+//!
+//! - A `unique` on columns that are already unique, e.g. the keys of a group-by or columns hinted
+//!   to be unique, is removed.
+//! - A partition sink that partitions by key, on input that is already partitioned by these keys,
+//!   writes every partition as soon as the key changes instead of buffering all of them.
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use crate::prelude::*;
+
+pub(super) fn optimize(root: Node, ir_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let mut visited = PlHashSet::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+
+        match ir_arena.get(node) {
+            IR::Distinct { input, options } => {
+                let input = *input;
+                let is_unique = match &options.subset {
+                    Some(subset) => is_unique(subset, input, ir_arena, expr_arena),
+                    None => {
+                        let schema = ir_arena.get(input).schema(ir_arena);
+                        let columns = schema.iter_names().cloned().collect::<Vec<_>>();
+                        is_unique(&columns, input, ir_arena, expr_arena)
+                    },
+                };
+                if is_unique {
+                    let ir = match options.slice {
+                        Some((offset, len)) => IR::Slice {
+                            input,
+                            offset,
+                            len: len as IdxSize,
+                        },
+                        None => ir_arena.get(input).clone(),
+                    };
+                    ir_arena.replace(node, ir);
+                    // Visit the replacement.
+                    visited.remove(&node);
+                    stack.push(node);
+                    continue;
+                }
+            },
+            IR::Sink {
+                input,
+                payload: SinkTypeIR::Partition(sink),
+            } => {
+                // Without maintaining the order, the rows of a partition can be interleaved with
+                // those of other partitions.
+                if sink.sink_options.maintain_order
+                    && let PartitionVariantIR::ByKey {
+                        key_exprs,
+                        include_key,
+                    } = &sink.variant
+                    && let Some(keys) = column_names(key_exprs, expr_arena)
+                    && is_partitioned_by(&keys, *input, ir_arena, expr_arena)
+                {
+                    let variant = PartitionVariantIR::Parted {
+                        key_exprs: key_exprs.clone(),
+                        include_key: *include_key,
+                    };
+                    if let IR::Sink {
+                        payload: SinkTypeIR::Partition(sink),
+                        ..
+                    } = ir_arena.get_mut(node)
+                    {
+                        sink.variant = variant;
+                    }
+                }
+            },
+            _ => {},
+        }
+        ir_arena.get(node).copy_inputs(&mut stack);
+    }
+}
+
+/// The names of the columns referred to by `exprs`, `None` if they are not all plain columns.
+pub(super) fn column_names(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> Option<Vec<PlSmallStr>> {
+    exprs
+        .iter()
+        .map(|e| match expr_arena.get(e.node()) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the input of `node` if `node` only filters or reorders the rows of its input and passes
+/// `columns` through unchanged.
+pub(super) fn passthrough_input(
+    node: Node,
+    columns: &[PlSmallStr],
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Node> {
+    match ir_arena.get(node) {
+        IR::Filter { input, .. }
+        | IR::Sort { input, .. }
+        | IR::Slice { input, .. }
+        | IR::Cache { input, .. }
+        | IR::SimpleProjection { input, .. } => Some(*input),
+        IR::MapFunction {
+            input,
+            function: FunctionIR::Rechunk | FunctionIR::Hint(_),
+        } => Some(*input),
+        IR::MapFunction {
+            input,
+            function: FunctionIR::RowIndex { name, .. },
+        } => (!columns.contains(name)).then_some(*input),
+        IR::Select { input, expr, .. } => {
+            let is_passed_through = |name: &PlSmallStr| {
+                expr.iter().any(|e| {
+                    e.output_name() == name
+                        && matches!(expr_arena.get(e.node()), AExpr::Column(c) if c == name)
+                })
+            };
+            columns.iter().all(is_passed_through).then_some(*input)
+        },
+        IR::HStack { input, exprs, .. } => {
+            let is_overwritten = exprs.iter().any(|e| columns.contains(e.output_name()));
+            (!is_overwritten).then_some(*input)
+        },
+        _ => None,
+    }
+}
+
+/// Whether no two rows in the output of `node` have the same values in `columns`.
+pub(super) fn is_unique(
+    columns: &[PlSmallStr],
+    mut node: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    // Any superset of a set of unique columns is unique.
+    let contains_all = |names: Vec<&PlSmallStr>| {
+        !names.is_empty() && names.iter().all(|name| columns.contains(name))
+    };
+
+    loop {
+        match ir_arena.get(node) {
+            IR::MapFunction {
+                function: FunctionIR::Hint(HintIR::Unique(unique)),
+                ..
+            } if contains_all(unique.iter().collect()) => return true,
+            IR::MapFunction {
+                function: FunctionIR::RowIndex { name, .. },
+                ..
+            } if columns.contains(name) => return true,
+            IR::GroupBy {
+                keys,
+                options,
+                apply: None,
+                ..
+            } if !options.is_rolling() && !options.is_dynamic() => {
+                return contains_all(keys.iter().map(|k| k.output_name()).collect());
+            },
+            IR::Distinct { input, options } => {
+                return match &options.subset {
+                    Some(subset) => contains_all(subset.iter().collect()),
+                    None => {
+                        contains_all(ir_arena.get(*input).schema(ir_arena).iter_names().collect())
+                    },
+                };
+            },
+            _ => match passthrough_input(node, columns, ir_arena, expr_arena) {
+                Some(input) => node = input,
+                None => return false,
+            },
+        }
+    }
+}
+
+/// Whether the rows with the same values in `columns` are adjacent in the output of `node`.
+fn is_partitioned_by(
+    columns: &[PlSmallStr],
+    mut node: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let is_same_set = |names: Vec<&PlSmallStr>| {
+        names.len() == columns.len() && names.iter().all(|name| columns.contains(name))
+    };
+
+    loop {
+        match ir_arena.get(node) {
+            IR::MapFunction {
+                function: FunctionIR::Hint(HintIR::Partitioned(partitioned)),
+                ..
+            } if is_same_set(partitioned.iter().collect()) => return true,
+            // Sorting by columns partitions by every prefix of them.
+            IR::MapFunction {
+                function: FunctionIR::Hint(HintIR::Sorted(sorted)),
+                ..
+            } if sorted.len() >= columns.len()
+                && is_same_set(sorted[..columns.len()].iter().map(|s| &s.column).collect()) =>
+            {
+                return true;
+            },
+            // A sort on other columns breaks up the partitions.
+            IR::Sort { .. } => return false,
+            _ => match passthrough_input(node, columns, ir_arena, expr_arena) {
+                Some(input) => node = input,
+                None => return false,
+            },
+        }
+    }
+}
@@ -0,0 +1,360 @@
+//! Cost-based optimization of joins.
+//!
+//! Chains of inner joins are reordered so that the joins estimated to be most selective run first,
+//! which keeps the intermediate results small. Queries on a star schema are typically written
+//! fact-first, joining the dimensions in an arbitrary order, e.g.
+//!
+//! ```text
+//! ((sales ⋈ stores) ⋈ products) ⋈ σ(dates)
+//! ```
+//!
+//! where joining the filtered `dates` first shrinks all the following joins. The first input of
+//! the chain stays in place and the other inputs are ordered greedily, respecting the join keys
+//! that refer to columns of earlier inputs.
+//!
+//! Afterwards every equi-join is annotated with the input that is estimated to be smaller, so the
+//! streaming engine can use it to build the hash table.
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use super::cardinality::{CardinalityEstimator, RowEstimate, equi_join_rows};
+use crate::plans::schema::det_join_schema;
+use crate::prelude::*;
+
+/// A reordered chain must have an estimated cost below this fraction of the original cost.
+const MAX_COST_RATIO: f64 = 0.8;
+/// The estimated intermediate results must shrink by at least this many rows. For smaller inputs
+/// the order of the joins hardly matters and we keep the order the query was written in.
+const MIN_ROWS_SAVED: f64 = 100_000.0;
+/// An input must be estimated to be at least this many times smaller than the other to be picked
+/// as build side. Otherwise the streaming engine samples both inputs to decide.
+const MIN_BUILD_SIDE_RATIO: f64 = 2.0;
+
+/// A left-deep chain of inner joins `((base ⋈ r_0) ⋈ r_1) ⋈ ... ⋈ r_n`.
+struct JoinChain {
+    base: Node,
+    /// The joins from the bottom up.
+    joins: Vec<ChainJoin>,
+}
+
+struct ChainJoin {
+    node: Node,
+    input_right: Node,
+    left_on: Vec<ExprIR>,
+    right_on: Vec<ExprIR>,
+    options: Arc<JoinOptionsIR>,
+}
+
+pub(super) fn optimize(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    reorder_joins: bool,
+    set_build_sides: bool,
+) -> PolarsResult<()> {
+    let mut estimator = CardinalityEstimator::default();
+    let mut visited = PlHashSet::new();
+    // Joins that are part of an already optimized chain.
+    let mut in_chain = PlHashSet::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+
+        if reorder_joins
+            && !in_chain.contains(&node)
+            && let Some(chain) = JoinChain::collect(node, ir_arena, expr_arena)
+        {
+            match reorder_chain(node, &chain, ir_arena, expr_arena, &mut estimator)? {
+                Some(new_joins) => in_chain.extend(new_joins),
+                None => in_chain.extend(chain.joins.iter().map(|j| j.node)),
+            }
+        }
+
+        if set_build_sides {
+            set_build_side(node, ir_arena, expr_arena, &mut estimator);
+        }
+        ir_arena.get(node).copy_inputs(&mut stack);
+    }
+    Ok(())
+}
+
+impl ChainJoin {
+    fn new(node: Node, ir_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        let IR::Join {
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } = ir_arena.get(node)
+        else {
+            return None;
+        };
+        let args = &options.args;
+        let is_plain_inner_join = matches!(args.how, JoinType::Inner)
+            && options.options.is_none()
+            && args.slice.is_none()
+            && args.maintain_order == MaintainOrderJoin::None
+            && args.validation == JoinValidation::ManyToMany;
+        let keys_are_columns = left_on
+            .iter()
+            .chain(right_on)
+            .all(|e| matches!(expr_arena.get(e.node()), AExpr::Column(_)));
+        if !(is_plain_inner_join && keys_are_columns) {
+            return None;
+        }
+
+        Some(Self {
+            node,
+            input_right: *input_right,
+            left_on: left_on.clone(),
+            right_on: right_on.clone(),
+            options: options.clone(),
+        })
+    }
+
+    /// Name of the column referred to by key `e`.
+    fn key_name<'a>(e: &ExprIR, expr_arena: &'a Arena<AExpr>) -> &'a PlSmallStr {
+        let AExpr::Column(name) = expr_arena.get(e.node()) else {
+            unreachable!()
+        };
+        name
+    }
+}
+
+impl JoinChain {
+    fn collect(top: Node, ir_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        let mut joins = vec![];
+        let mut node = top;
+        while let Some(join) = ChainJoin::new(node, ir_arena, expr_arena) {
+            let IR::Join { input_left, .. } = ir_arena.get(node) else {
+                unreachable!()
+            };
+            node = *input_left;
+            joins.push(join);
+        }
+        if joins.len() < 2 {
+            return None;
+        }
+        joins.reverse();
+        Some(Self { base: node, joins })
+    }
+}
+
+/// Reorders the joins of `chain`, which has `top` as its top-most join. Returns the new join
+/// nodes if the chain was reordered.
+fn reorder_chain(
+    top: Node,
+    chain: &JoinChain,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    estimator: &mut CardinalityEstimator,
+) -> PolarsResult<Option<Vec<Node>>> {
+    // Input 0 is the base of the chain, input `i + 1` the right input of join `i`.
+    let inputs = std::iter::once(chain.base)
+        .chain(chain.joins.iter().map(|j| j.input_right))
+        .collect::<Vec<_>>();
+
+    // Map every output column to the input it comes from. If the inputs share column names,
+    // joining them in a different order would change which columns get suffixed, so we bail.
+    let mut origins: PlHashMap<PlSmallStr, usize> = PlHashMap::new();
+    for (i, input) in inputs.iter().enumerate() {
+        let coalesced: PlHashSet<&PlSmallStr> = match i.checked_sub(1) {
+            Some(j) if chain.joins[j].options.args.should_coalesce() => chain.joins[j]
+                .right_on
+                .iter()
+                .map(|e| ChainJoin::key_name(e, expr_arena))
+                .collect(),
+            _ => PlHashSet::new(),
+        };
+        let schema = ir_arena.get(*input).schema(ir_arena);
+        for name in schema.iter_names() {
+            if !coalesced.contains(name) && origins.insert(name.clone(), i).is_some() {
+                return Ok(None);
+            }
+        }
+    }
+
+    // The inputs whose columns are used as left keys of each join.
+    let mut dependencies = Vec::with_capacity(chain.joins.len());
+    for join in &chain.joins {
+        let mut deps = Vec::with_capacity(join.left_on.len());
+        for e in &join.left_on {
+            let Some(&origin) = origins.get(ChainJoin::key_name(e, expr_arena)) else {
+                return Ok(None);
+            };
+            deps.push(origin);
+        }
+        dependencies.push(deps);
+    }
+
+    let mut estimates = Vec::with_capacity(inputs.len());
+    for input in &inputs {
+        let Some(estimate) = estimator.estimate(*input, ir_arena, expr_arena) else {
+            return Ok(None);
+        };
+        estimates.push(estimate);
+    }
+
+    // The number of distinct keys of both sides of each join, if known from hints.
+    let mut distinct = Vec::with_capacity(chain.joins.len());
+    for (join, deps) in chain.joins.iter().zip(&dependencies) {
+        let key_names = |keys: &[ExprIR]| {
+            keys.iter()
+                .map(|e| ChainJoin::key_name(e, expr_arena).clone())
+                .collect::<Vec<_>>()
+        };
+        let left = match deps.as_slice() {
+            [first, rest @ ..] if rest.iter().all(|i| i == first) => estimator.distinct_count(
+                &key_names(&join.left_on),
+                inputs[*first],
+                ir_arena,
+                expr_arena,
+            ),
+            _ => None,
+        };
+        let right = estimator.distinct_count(
+            &key_names(&join.right_on),
+            join.input_right,
+            ir_arena,
+            expr_arena,
+        );
+        distinct.push([left, right]);
+    }
+
+    let join_rows = |left_rows: f64, j: usize| {
+        let left = RowEstimate {
+            rows: left_rows,
+            base_rows: left_rows,
+        };
+        // The keys can't have more distinct values than the inputs they originate from.
+        let domain = dependencies[j]
+            .iter()
+            .map(|&i| estimates[i].base_rows)
+            .fold(f64::INFINITY, f64::min);
+        equi_join_rows(left, estimates[j + 1], domain, distinct[j])
+    };
+    // The sum of the sizes of all intermediate results.
+    let cost = |order: &[usize]| {
+        let mut rows = estimates[0].rows;
+        let mut cost = 0.0;
+        for &j in order {
+            rows = join_rows(rows, j);
+            cost += rows;
+        }
+        cost
+    };
+
+    // Greedily pick the join with the smallest result out of those whose left keys are available.
+    let num_joins = chain.joins.len();
+    let mut available = vec![false; inputs.len()];
+    available[0] = true;
+    let mut order = Vec::with_capacity(num_joins);
+    let mut rows = estimates[0].rows;
+    while order.len() < num_joins {
+        let (next_rows, next) = (0..num_joins)
+            .filter(|&j| !available[j + 1] && dependencies[j].iter().all(|&i| available[i]))
+            .map(|j| (join_rows(rows, j), j))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap();
+        available[next + 1] = true;
+        order.push(next);
+        rows = next_rows;
+    }
+
+    let original_order = (0..num_joins).collect::<Vec<_>>();
+    let (original_cost, new_cost) = (cost(&original_order), cost(&order));
+    if order == original_order
+        || new_cost > original_cost * MAX_COST_RATIO
+        || original_cost - new_cost < MIN_ROWS_SAVED
+    {
+        return Ok(None);
+    }
+
+    if verbose() {
+        eprintln!(
+            "reordered a chain of {num_joins} inner joins; estimated intermediate rows: {original_cost:.0} -> {new_cost:.0}"
+        );
+    }
+
+    let mut new_joins = Vec::with_capacity(num_joins);
+    let mut input_left = chain.base;
+    for j in order {
+        let join = &chain.joins[j];
+        let schema = det_join_schema(
+            &ir_arena.get(input_left).schema(ir_arena),
+            &ir_arena.get(join.input_right).schema(ir_arena),
+            &join.left_on,
+            &join.right_on,
+            &join.options,
+            expr_arena,
+        )?;
+        input_left = ir_arena.add(IR::Join {
+            input_left,
+            input_right: join.input_right,
+            schema,
+            left_on: join.left_on.clone(),
+            right_on: join.right_on.clone(),
+            options: join.options.clone(),
+        });
+        new_joins.push(input_left);
+    }
+
+    // Restore the original column order.
+    let columns = ir_arena.get(top).schema(ir_arena).into_owned();
+    ir_arena.replace(
+        top,
+        IR::SimpleProjection {
+            input: input_left,
+            columns,
+        },
+    );
+    estimator.invalidate(top);
+    Ok(Some(new_joins))
+}
+
+/// Annotates an equi-join with the input that is estimated to be smaller.
+fn set_build_side(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    estimator: &mut CardinalityEstimator,
+) {
+    let IR::Join {
+        input_left,
+        input_right,
+        options,
+        ..
+    } = ir_arena.get(node)
+    else {
+        return;
+    };
+    if !options.args.how.is_equi()
+        || options.args.maintain_order != MaintainOrderJoin::None
+        || options.build_side.is_some()
+    {
+        return;
+    }
+    let (input_left, input_right) = (*input_left, *input_right);
+    let (Some(left), Some(right)) = (
+        estimator.estimate(input_left, ir_arena, expr_arena),
+        estimator.estimate(input_right, ir_arena, expr_arena),
+    ) else {
+        return;
+    };
+
+    let build_side = if left.rows * MIN_BUILD_SIDE_RATIO <= right.rows {
+        JoinBuildSide::Left
+    } else if right.rows * MIN_BUILD_SIDE_RATIO <= left.rows {
+        JoinBuildSide::Right
+    } else {
+        return;
+    };
+    if let IR::Join { options, .. } = ir_arena.get_mut(node) {
+        Arc::make_mut(options).build_side = Some(build_side);
+    }
+}
@@ -5,6 +5,7 @@ use crate::prelude::*;
 
 mod delay_rechunk;
 
+mod cardinality;
 mod cluster_with_columns;
 mod collapse_and_project;
 mod collect_members;
@@ -14,6 +15,8 @@ mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod hints;
+mod join_reorder;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
@@ -206,6 +209,24 @@ pub fn optimize(
         )?;
     }
 
+    if !opt_flags.eager() {
+        let members = get_or_init_members!();
+        if members.has_hint || members.has_distinct {
+            hints::optimize(root, ir_arena, expr_arena);
+        }
+    }
+
+    // Run after the pushdowns, so that the filters have reached the scans. Only the streaming
+    // engine uses the build side of a join.
+    let reorder_joins = opt_flags.contains(OptFlags::JOIN_REORDER);
+    let set_build_sides = opt_flags.contains(OptFlags::ROW_ESTIMATE) && opt_flags.new_streaming();
+    if (reorder_joins || set_build_sides)
+        && !opt_flags.eager()
+        && get_or_init_members!().has_joins_or_unions
+    {
+        join_reorder::optimize(root, ir_arena, expr_arena, reorder_joins, set_build_sides)?;
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
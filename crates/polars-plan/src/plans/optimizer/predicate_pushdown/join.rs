@@ -195,6 +195,8 @@ pub(super) fn process_join(
 
             #[cfg(feature = "iejoin")]
             IEJoin => false,
+            #[cfg(feature = "fuzzy_join")]
+            Fuzzy(_) => false,
 
             Cross => unreachable!(), // Cross left/right_on should be empty
         } {
@@ -235,6 +237,16 @@ pub(super) fn process_join(
         let mut push_right = true;
 
         for col_name in aexpr_to_leaf_names_iter(predicate.node(), expr_arena) {
+            // The score is produced by the join itself.
+            #[cfg(feature = "fuzzy_join")]
+            if let JoinType::Fuzzy(fuzzy_options) = &options.args.how
+                && col_name == fuzzy_options.score_name
+            {
+                push_left = false;
+                push_right = false;
+                continue;
+            }
+
             let origin: ExprOrigin = ExprOrigin::get_column_origin(
                 col_name.as_str(),
                 &schema_left,
@@ -314,6 +326,10 @@ pub(super) fn process_join(
             // Same as inner-join.
             #[cfg(feature = "iejoin")]
             JoinType::IEJoin => !(push_left || push_right),
+
+            // Same as inner-join.
+            #[cfg(feature = "fuzzy_join")]
+            JoinType::Fuzzy(_) => !(push_left || push_right),
         };
 
         if has_residual {
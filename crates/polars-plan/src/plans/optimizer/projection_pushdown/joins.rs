@@ -77,6 +77,14 @@ pub(super) fn process_join(
             continue;
         }
 
+        // The score is produced by the join itself.
+        #[cfg(feature = "fuzzy_join")]
+        if let JoinType::Fuzzy(fuzzy_options) = &options.args.how
+            && output_name == &fuzzy_options.score_name
+        {
+            continue;
+        }
+
         match ExprOrigin::get_column_origin(
             output_name,
             &input_schema_left,
@@ -104,7 +104,6 @@ fn is_sorted_rec(
         IR::MapFunction { input, function } => match function {
             FunctionIR::Hint(hint) => match hint {
                 HintIR::Sorted(v) => Some(v.clone()),
-                #[expect(unreachable_patterns)]
                 _ => rec!(*input),
             },
             _ => None,
@@ -244,6 +244,14 @@ pub(crate) fn det_join_schema(
                 })?;
             }
 
+            #[cfg(feature = "fuzzy_join")]
+            if let JoinType::Fuzzy(fuzzy_options) = how {
+                new_schema.try_insert(
+                    fuzzy_options.score_name.clone(),
+                    fuzzy_options.score_dtype(),
+                )?;
+            }
+
             Ok(Arc::new(new_schema))
         },
     }
@@ -16,11 +16,16 @@ use polars::frame::row::Row;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars::io::utils::compression::FileCompression;
 use polars::prelude::ColumnMapping;
 use polars::prelude::default_values::{
     DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
 };
-use polars::prelude::deletion::DeletionFilesList;
+use polars::prelude::deletion::{
+    DeletionFilesList, DeletionVectorDescriptor, DeletionVectorStorageType,
+    IcebergEqualityDeleteFile, IcebergEqualityDeletes,
+};
 use polars::series::ops::NullBehavior;
 use polars_compute::decimal::dec128_verify_prec_scale;
 use polars_core::schema::iceberg::IcebergSchema;
@@ -1148,6 +1153,84 @@ impl<'py> FromPyObject<'py> for Wrap<Roll> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<HolidayCalendar> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let Ok(name) = ob.extract::<PyBackedStr>() else {
+            let rules = ob
+                .try_iter()?
+                .map(|rule| Ok(rule?.extract::<Wrap<HolidayRule>>()?.0))
+                .collect::<PyResult<Vec<_>>>()?;
+            return Ok(Wrap(HolidayCalendar::Custom(rules)));
+        };
+        let parsed = match &*name {
+            "nyse" => HolidayCalendar::Nyse,
+            "uk" => HolidayCalendar::Uk,
+            "target2" => HolidayCalendar::Target2,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`calendar` must be one of {{'nyse', 'uk', 'target2'}} or a sequence of holiday rules, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+/// Gets a field of a holiday rule, which is either a dict or an object with attributes.
+fn holiday_rule_field<'py, T: FromPyObject<'py>>(
+    ob: &Bound<'py, PyAny>,
+    key: &str,
+) -> PyResult<Option<T>> {
+    let value = match ob.downcast::<PyDict>() {
+        Ok(dict) => dict.get_item(key)?,
+        Err(_) => ob.getattr(key).ok(),
+    };
+    value
+        .filter(|v| !v.is_none())
+        .map(|v| v.extract())
+        .transpose()
+}
+
+impl<'py> FromPyObject<'py> for Wrap<HolidayRule> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let date = match (
+            holiday_rule_field(ob, "month")?,
+            holiday_rule_field(ob, "day")?,
+            holiday_rule_field(ob, "weekday")?,
+            holiday_rule_field(ob, "n")?,
+            holiday_rule_field(ob, "easter_offset")?,
+        ) {
+            (Some(month), Some(day), None, None, None) => HolidayDate::Fixed { month, day },
+            (Some(month), None, Some(weekday), Some(n), None) => {
+                HolidayDate::NthWeekday { month, weekday, n }
+            },
+            (None, None, None, None, Some(offset)) => HolidayDate::Easter { offset },
+            _ => {
+                return Err(PyValueError::new_err(
+                    "a holiday rule must have either `month` and `day`, `month`, `weekday` and `n`, or `easter_offset`",
+                ));
+            },
+        };
+        let observance = match holiday_rule_field::<PyBackedStr>(ob, "observance")?.as_deref() {
+            None | Some("actual") => Observance::Actual,
+            Some("nearest_weekday") => Observance::NearestWeekday,
+            Some("sunday_to_monday") => Observance::SundayToMonday,
+            Some("next_free_weekday") => Observance::NextFreeWeekday,
+            Some(v) => {
+                return Err(PyValueError::new_err(format!(
+                    "`observance` must be one of {{'actual', 'nearest_weekday', 'sunday_to_monday', 'next_free_weekday'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(HolidayRule {
+            date,
+            observance,
+            start_year: holiday_rule_field(ob, "start_year")?,
+            end_year: holiday_rule_field(ob, "end_year")?,
+        }))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<TimeUnit> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1531,6 +1614,39 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+pub(crate) fn parse_file_compression(
+    compression: &str,
+    compression_level: Option<i32>,
+) -> PyResult<FileCompression> {
+    use polars_utils::compression::{GzipLevel, ZstdLevel};
+
+    let parsed = match compression {
+        "uncompressed" => FileCompression::Uncompressed,
+        "gzip" => FileCompression::Gzip(
+            compression_level
+                .map(|lvl| {
+                    GzipLevel::try_new(lvl as u8)
+                        .map_err(|e| PyValueError::new_err(format!("{e:?}")))
+                })
+                .transpose()?,
+        ),
+        "zstd" => FileCompression::Zstd(
+            compression_level
+                .map(|lvl| {
+                    ZstdLevel::try_new(lvl).map_err(|e| PyValueError::new_err(format!("{e:?}")))
+                })
+                .transpose()?,
+        ),
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`compression` must be one of {{'uncompressed', 'gzip', 'zstd'}}, got {e}",
+            )));
+        },
+    };
+    Ok(parsed)
+}
+
 pub(crate) fn strings_to_pl_smallstr<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
@@ -1567,6 +1683,24 @@ impl<'py> FromPyObject<'py> for PyCompatLevel {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<StringSimilarityMetric> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "levenshtein" => StringSimilarityMetric::Levenshtein,
+            "damerau_levenshtein" => StringSimilarityMetric::DamerauLevenshtein,
+            "hamming" => StringSimilarityMetric::Hamming,
+            "jaro" => StringSimilarityMetric::Jaro,
+            "jaro_winkler" => StringSimilarityMetric::JaroWinkler,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`metric` must be one of {{'levenshtein', 'damerau_levenshtein', 'hamming', 'jaro', 'jaro_winkler'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "string_normalize")]
 impl<'py> FromPyObject<'py> for Wrap<UnicodeForm> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -1733,6 +1867,92 @@ impl<'py> FromPyObject<'py> for Wrap<DeletionFilesList> {
                 DeletionFilesList::IcebergPositionDelete(Arc::new(out))
             },
 
+            "iceberg-equality-delete" => {
+                let dict: Bound<'_, PyDict> = ob.extract()?;
+
+                let mut out = PlIndexMap::new();
+
+                for (k, v) in dict
+                    .try_iter()?
+                    .zip(dict.call_method0("values")?.try_iter()?)
+                {
+                    let k: usize = k?.extract()?;
+                    let (data_sequence_number, equality_delete_files, position_delete_files): (
+                        i64,
+                        Vec<(String, i64, Vec<u32>)>,
+                        Vec<String>,
+                    ) = v?.extract()?;
+
+                    let equality_delete_files = equality_delete_files
+                        .into_iter()
+                        .map(
+                            |(path, sequence_number, equality_ids)| IcebergEqualityDeleteFile {
+                                path,
+                                sequence_number,
+                                equality_ids: equality_ids.into(),
+                            },
+                        )
+                        .collect::<Arc<[_]>>();
+
+                    if !equality_delete_files.is_empty() || !position_delete_files.is_empty() {
+                        out.insert(
+                            k,
+                            IcebergEqualityDeletes {
+                                data_sequence_number,
+                                equality_delete_files,
+                                position_delete_files: position_delete_files.into(),
+                            },
+                        );
+                    }
+                }
+
+                DeletionFilesList::IcebergEqualityDelete(Arc::new(out))
+            },
+
+            "delta-deletion-vector" => {
+                let (table_root, dict): (String, Bound<'_, PyDict>) = ob.extract()?;
+
+                let mut out = PlIndexMap::new();
+
+                for (k, v) in dict
+                    .try_iter()?
+                    .zip(dict.call_method0("values")?.try_iter()?)
+                {
+                    let k: usize = k?.extract()?;
+                    let (storage_type, path_or_inline_dv, offset, size_in_bytes, cardinality): (
+                        PyBackedStr,
+                        String,
+                        Option<u32>,
+                        u32,
+                        u64,
+                    ) = v?.extract()?;
+
+                    let storage_type = DeletionVectorStorageType::from_delta_code(&storage_type)
+                        .ok_or_else(|| {
+                            PyValueError::new_err(format!(
+                                "unknown deletion vector storage type: {}",
+                                &*storage_type
+                            ))
+                        })?;
+
+                    out.insert(
+                        k,
+                        DeletionVectorDescriptor {
+                            storage_type,
+                            path_or_inline_dv: path_or_inline_dv.into(),
+                            offset,
+                            size_in_bytes,
+                            cardinality,
+                        },
+                    );
+                }
+
+                DeletionFilesList::DeltaDeletionVector {
+                    table_root: table_root.into(),
+                    vectors: Arc::new(out),
+                }
+            },
+
             v => {
                 return Err(PyValueError::new_err(format!(
                     "unknown deletion file type: {v}"
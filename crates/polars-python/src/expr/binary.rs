@@ -62,7 +62,64 @@ impl PyExpr {
             .into())
     }
 
+    #[cfg(feature = "binary_hash")]
+    fn bin_hash(&self, algorithm: &str, seed: u64) -> PyResult<Self> {
+        use polars::prelude::BinaryHashAlgorithm as H;
+        use pyo3::exceptions::PyValueError;
+
+        let seed_u32 = || {
+            u32::try_from(seed).map_err(|_| {
+                PyValueError::new_err(format!("`seed` must fit in 32 bits, got {seed}"))
+            })
+        };
+        let algorithm = match algorithm {
+            "sha256" => H::Sha256,
+            "sha1" => H::Sha1,
+            "md5" => H::Md5,
+            "xxh64" => H::Xxh64 { seed },
+            "crc32" => H::Crc32,
+            "murmur3" => H::Murmur3 { seed: seed_u32()? },
+            "spark_murmur3" => H::SparkMurmur3 { seed: seed_u32()? },
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`algorithm` must be one of {{'sha256', 'sha1', 'md5', 'xxh64', 'crc32', 'murmur3', 'spark_murmur3'}}, got {v}",
+                )));
+            },
+        };
+        Ok(self.inner.clone().binary().hash(algorithm).into())
+    }
+
+    #[cfg(feature = "binary_compression")]
+    fn bin_compress(&self, codec: &str, level: Option<i32>) -> PyResult<Self> {
+        let codec = parse_binary_codec(codec)?;
+        Ok(self.inner.clone().binary().compress(codec, level).into())
+    }
+
+    #[cfg(feature = "binary_compression")]
+    fn bin_decompress(&self, codec: &str, strict: bool) -> PyResult<Self> {
+        let codec = parse_binary_codec(codec)?;
+        Ok(self.inner.clone().binary().decompress(codec, strict).into())
+    }
+
     fn bin_size_bytes(&self) -> Self {
         self.inner.clone().binary().size_bytes().into()
     }
 }
+
+#[cfg(feature = "binary_compression")]
+fn parse_binary_codec(codec: &str) -> PyResult<polars::prelude::BinaryCodec> {
+    use polars::prelude::BinaryCodec as C;
+    use pyo3::exceptions::PyValueError;
+
+    Ok(match codec {
+        "gzip" => C::Gzip,
+        "zstd" => C::Zstd,
+        "lz4" => C::Lz4,
+        "snappy" => C::Snappy,
+        v => {
+            return Err(PyValueError::new_err(format!(
+                "`codec` must be one of {{'gzip', 'zstd', 'lz4', 'snappy'}}, got {v}",
+            )));
+        },
+    })
+}
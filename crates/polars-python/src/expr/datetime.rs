@@ -11,12 +11,30 @@ impl PyExpr {
         n: PyExpr,
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<Wrap<HolidayCalendar>>,
         roll: Wrap<Roll>,
     ) -> Self {
+        let calendar = calendar.map(|c| c.0);
         self.inner
             .clone()
             .dt()
-            .add_business_days(n.inner, week_mask, holidays, roll.0)
+            .add_business_days(n.inner, week_mask, holidays, calendar, roll.0)
+            .into()
+    }
+
+    fn dt_add_business_hours(
+        &self,
+        n: PyExpr,
+        business_hours: [i64; 2],
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<Wrap<HolidayCalendar>>,
+    ) -> Self {
+        let calendar = calendar.map(|c| c.0);
+        self.inner
+            .clone()
+            .dt()
+            .add_business_hours(n.inner, business_hours, week_mask, holidays, calendar)
             .into()
     }
 
@@ -142,11 +160,17 @@ impl PyExpr {
     fn dt_year(&self) -> Self {
         self.inner.clone().dt().year().into()
     }
-    fn dt_is_business_day(&self, week_mask: [bool; 7], holidays: Vec<i32>) -> Self {
+    fn dt_is_business_day(
+        &self,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<Wrap<HolidayCalendar>>,
+    ) -> Self {
+        let calendar = calendar.map(|c| c.0);
         self.inner
             .clone()
             .dt()
-            .is_business_day(week_mask, holidays)
+            .is_business_day(week_mask, holidays, calendar)
             .into()
     }
     fn dt_is_leap_year(&self) -> Self {
@@ -431,6 +431,10 @@ impl PyExpr {
         self.inner.clone().approx_n_unique().into()
     }
 
+    fn approx_quantile(&self, quantile: f64) -> Self {
+        self.inner.clone().approx_quantile(quantile).into()
+    }
+
     fn is_first_distinct(&self) -> Self {
         self.inner.clone().is_first_distinct().into()
     }
@@ -165,6 +165,30 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_levenshtein(&self, other: Self) -> Self {
+        self.inner.clone().str().levenshtein(other.inner).into()
+    }
+
+    fn str_damerau_levenshtein(&self, other: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .damerau_levenshtein(other.inner)
+            .into()
+    }
+
+    fn str_hamming(&self, other: Self) -> Self {
+        self.inner.clone().str().hamming(other.inner).into()
+    }
+
+    fn str_similarity(&self, other: Self, metric: Wrap<StringSimilarityMetric>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .similarity(other.inner, metric.0)
+            .into()
+    }
+
     fn str_pad_start(&self, length: PyExpr, fill_char: char) -> Self {
         self.inner
             .clone()
@@ -1,7 +1,9 @@
 use polars::lazy::dsl;
+use polars::prelude::HolidayCalendar;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
+use crate::conversion::Wrap;
 
 #[pyfunction]
 pub fn business_day_count(
@@ -9,8 +11,10 @@ pub fn business_day_count(
     end: PyExpr,
     week_mask: [bool; 7],
     holidays: Vec<i32>,
+    calendar: Option<Wrap<HolidayCalendar>>,
 ) -> PyExpr {
     let start = start.inner;
     let end = end.inner;
-    dsl::business_day_count(start, end, week_mask, holidays).into()
+    let calendar = calendar.map(|c| c.0);
+    dsl::business_day_count(start, end, week_mask, holidays, calendar).into()
 }
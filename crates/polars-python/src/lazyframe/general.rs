@@ -3,12 +3,14 @@ use std::num::NonZeroUsize;
 
 use either::Either;
 use polars::io::RowIndex;
+#[cfg(feature = "avro")]
+use polars::io::avro::AvroCompression;
 use polars::time::*;
 use polars_core::prelude::*;
 #[cfg(feature = "parquet")]
 use polars_parquet::arrow::write::StatisticsOptions;
 use polars_plan::dsl::ScanSources;
-use polars_plan::plans::{AExpr, HintIR, IR, Sorted};
+use polars_plan::plans::{AExpr, DistinctCount, HintIR, IR, Sorted};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::python_function::PythonObject;
 use pyo3::exceptions::{PyTypeError, PyValueError};
@@ -323,6 +325,7 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            key_retriever: None,
         };
 
         let sources = sources.0;
@@ -367,6 +370,36 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "avro")]
+    #[staticmethod]
+    #[pyo3(signature = (sources, scan_options, file_cache_ttl))]
+    fn new_from_avro(
+        sources: Wrap<ScanSources>,
+        scan_options: PyScanOptions,
+        file_cache_ttl: Option<u64>,
+    ) -> PyResult<Self> {
+        use polars::io::avro::AvroScanOptions;
+
+        let options = AvroScanOptions;
+
+        let sources = sources.0;
+        let first_path = sources.first_path().map(|p| p.into_owned());
+
+        let mut unified_scan_args =
+            scan_options.extract_unified_scan_args(first_path.as_ref().map(|p| p.as_ref()))?;
+
+        if let Some(file_cache_ttl) = file_cache_ttl {
+            unified_scan_args
+                .cloud_options
+                .get_or_insert_default()
+                .file_cache_ttl = file_cache_ttl;
+        }
+
+        let lf = LazyFrame::scan_avro_sources(sources, options, unified_scan_args)
+            .map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
     #[cfg(feature = "scan_lines")]
     #[staticmethod]
     #[pyo3(signature = (sources, scan_options, name, file_cache_ttl))]
@@ -671,6 +704,7 @@ impl PyLazyFrame {
             data_page_size,
             key_value_metadata: metadata.0,
             field_overwrites: field_overwrites.into_iter().map(|f| f.0).collect(),
+            encryption: None,
         };
 
         let cloud_options = match target.base_path() {
@@ -778,7 +812,8 @@ impl PyLazyFrame {
     #[pyo3(signature = (
         target, include_bom, include_header, separator, line_terminator, quote_char, batch_size,
         datetime_format, date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style, cloud_options, credential_provider, retries, sink_options
+        quote_style, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
     ))]
     fn sink_csv(
         &self,
@@ -798,6 +833,8 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<Py<PyAny>>,
         retries: usize,
@@ -825,6 +862,7 @@ impl PyLazyFrame {
             include_header,
             batch_size,
             serialize_options,
+            compression: parse_file_compression(compression, compression_level)?,
         };
 
         #[cfg(feature = "cloud")]
@@ -870,17 +908,24 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "json")]
-    #[pyo3(signature = (target, cloud_options, credential_provider, retries, sink_options))]
+    #[pyo3(signature = (
+        target, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
+    ))]
     fn sink_json(
         &self,
         py: Python<'_>,
         target: SinkTarget,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<Py<PyAny>>,
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions {
+            compression: parse_file_compression(compression, compression_level)?,
+        };
 
         let cloud_options = match target.base_path() {
             None => None,
@@ -919,6 +964,62 @@ impl PyLazyFrame {
         .map_err(Into::into)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[cfg(feature = "avro")]
+    #[pyo3(signature = (target, compression, name, cloud_options, credential_provider, retries, sink_options))]
+    fn sink_avro(
+        &self,
+        py: Python<'_>,
+        target: SinkTarget,
+        compression: Wrap<Option<AvroCompression>>,
+        name: String,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<Py<PyAny>>,
+        retries: usize,
+        sink_options: Wrap<SinkOptions>,
+    ) -> PyResult<PyLazyFrame> {
+        let options = AvroWriterOptions {
+            compression: compression.0.map(Into::into),
+            name: name.into(),
+        };
+
+        let cloud_options = match target.base_path() {
+            None => None,
+            Some(base_path) => {
+                let cloud_options =
+                    parse_cloud_options(base_path.to_str(), cloud_options.unwrap_or_default())?;
+                Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(polars::prelude::cloud::credential_provider::PlCredentialProvider::from_python_builder),
+                    ),
+            )
+            },
+        };
+
+        py.enter_polars(|| {
+            let ldf = self.ldf.read().clone();
+            match target {
+                SinkTarget::File(path) => {
+                    ldf.sink_avro(path, options, cloud_options, sink_options.0)
+                },
+                SinkTarget::Partition(partition) => ldf.sink_avro_partitioned(
+                    Arc::new(partition.base_path.0),
+                    partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.variant,
+                    options,
+                    cloud_options,
+                    sink_options.0,
+                    partition.per_partition_sort_by,
+                    partition.finish_callback,
+                ),
+            }
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
     #[pyo3(signature = (function, maintain_order, chunk_size))]
     pub fn sink_batches(
         &self,
@@ -1098,6 +1199,45 @@ impl PyLazyFrame {
             .into())
     }
 
+    #[pyo3(signature = (other, left_on, right_on, metric, threshold, max_distance, score_name, suffix, allow_parallel, force_parallel))]
+    fn join_fuzzy(
+        &self,
+        other: Self,
+        left_on: PyExpr,
+        right_on: PyExpr,
+        metric: Wrap<StringSimilarityMetric>,
+        threshold: Option<f64>,
+        max_distance: Option<u32>,
+        score_name: String,
+        suffix: String,
+        allow_parallel: bool,
+        force_parallel: bool,
+    ) -> PyResult<Self> {
+        let threshold = match (threshold, max_distance) {
+            (Some(t), None) => FuzzyJoinThreshold::Similarity(t),
+            (None, Some(k)) => FuzzyJoinThreshold::Distance(k),
+            _ => {
+                return Err(PyValueError::new_err(
+                    "exactly one of `threshold` and `max_distance` must be set",
+                ));
+            },
+        };
+        let options = FuzzyJoinOptions::new(metric.0, threshold).with_score_name(score_name.into());
+        let ldf = self.ldf.read().clone();
+        let other = other.ldf.into_inner();
+        Ok(ldf
+            .join_builder()
+            .with(other)
+            .left_on([left_on.inner])
+            .right_on([right_on.inner])
+            .allow_parallel(allow_parallel)
+            .force_parallel(force_parallel)
+            .how(JoinType::Fuzzy(Box::new(options)))
+            .suffix(suffix)
+            .finish()
+            .into())
+    }
+
     #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None))]
     fn join(
         &self,
@@ -1586,6 +1726,46 @@ impl PyLazyFrame {
             .map_err(PyPolarsErr::from)?;
         Ok(out.into())
     }
+
+    #[pyo3(signature = (unique, row_count, distinct_count, partitioned_by))]
+    fn hint(
+        &self,
+        unique: Option<Vec<String>>,
+        row_count: Option<usize>,
+        distinct_count: Option<Vec<(String, usize)>>,
+        partitioned_by: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        let to_columns = |columns: Vec<String>| -> Arc<[PlSmallStr]> {
+            columns.into_iter().map(PlSmallStr::from_string).collect()
+        };
+
+        let mut hints = Vec::new();
+        if let Some(columns) = unique {
+            hints.push(HintIR::Unique(to_columns(columns)));
+        }
+        if let Some(count) = row_count {
+            hints.push(HintIR::RowCount(count));
+        }
+        if let Some(counts) = distinct_count {
+            let counts = counts
+                .into_iter()
+                .map(|(column, count)| DistinctCount {
+                    column: PlSmallStr::from_string(column),
+                    count,
+                })
+                .collect();
+            hints.push(HintIR::DistinctCount(counts));
+        }
+        if let Some(columns) = partitioned_by {
+            hints.push(HintIR::Partitioned(to_columns(columns)));
+        }
+
+        let mut ldf = self.ldf.read().clone();
+        for hint in hints {
+            ldf = ldf.hint(hint).map_err(PyPolarsErr::from)?;
+        }
+        Ok(ldf.into())
+    }
 }
 
 #[cfg(feature = "parquet")]
@@ -1633,12 +1813,18 @@ impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOver
             .map(|v| v.extract::<bool>())
             .transpose()?;
 
+        let bloom_filter = PyDictMethods::get_item(&parsed, "bloom_filter")?
+            .map(|v| v.extract::<bool>())
+            .transpose()?
+            .unwrap_or(false);
+
         Ok(Wrap(ParquetFieldOverwrites {
             name,
             children,
             field_id,
             metadata,
             required,
+            bloom_filter,
         }))
     }
 }
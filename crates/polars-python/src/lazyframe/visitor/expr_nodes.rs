@@ -3,6 +3,7 @@ use polars::prelude::InequalityOperator;
 use polars::series::ops::NullBehavior;
 use polars_core::chunked_array::ops::FillNullStrategy;
 use polars_core::series::IsSorted;
+use polars_ops::chunked_array::StringSimilarityMetric;
 #[cfg(feature = "string_normalize")]
 use polars_ops::chunked_array::UnicodeForm;
 use polars_ops::prelude::RankMethod;
@@ -176,6 +177,10 @@ pub enum PyStringFunction {
     ReplaceMany,
     EscapeRegex,
     Normalize,
+    Levenshtein,
+    DamerauLevenshtein,
+    Hamming,
+    Similarity,
 }
 
 #[pymethods]
@@ -853,6 +858,24 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     )
                         .into_py_any(py),
                     IRStringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
+                    IRStringFunction::Levenshtein => {
+                        (PyStringFunction::Levenshtein,).into_py_any(py)
+                    },
+                    IRStringFunction::DamerauLevenshtein => {
+                        (PyStringFunction::DamerauLevenshtein,).into_py_any(py)
+                    },
+                    IRStringFunction::Hamming => (PyStringFunction::Hamming,).into_py_any(py),
+                    IRStringFunction::Similarity(metric) => (
+                        PyStringFunction::Similarity,
+                        match metric {
+                            StringSimilarityMetric::Levenshtein => "levenshtein",
+                            StringSimilarityMetric::DamerauLevenshtein => "damerau_levenshtein",
+                            StringSimilarityMetric::Hamming => "hamming",
+                            StringSimilarityMetric::Jaro => "jaro",
+                            StringSimilarityMetric::JaroWinkler => "jaro_winkler",
+                        },
+                    )
+                        .into_py_any(py),
                     IRStringFunction::PadStart { fill_char } => {
                         (PyStringFunction::PadStart, fill_char).into_py_any(py)
                     },
@@ -1255,6 +1278,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 IRFunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
                 IRFunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                IRFunctionExpr::ApproxQuantile { quantile } => {
+                    ("approx_quantile", quantile).into_py_any(py)
+                },
                 IRFunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
                 IRFunctionExpr::Diff(null_behaviour) => (
                     "diff",
@@ -41,6 +41,8 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro { .. } => Err(PyNotImplementedError::new_err("avro scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
@@ -135,6 +137,8 @@ impl PyFileOptions {
     /// One of:
     /// * None
     /// * ("iceberg-position-delete", dict[int, list[str]])
+    /// * ("iceberg-equality-delete", dict[int, (int, list[(str, int, list[str])], list[str])])
+    /// * ("delta-deletion-vector", (str, dict[int, (str, str, int | None, int, int)]))
     #[getter]
     fn deletion_files(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         Ok(match &self.inner.deletion_files {
@@ -152,6 +156,63 @@ impl PyFileOptions {
                     .into_any()
                     .unbind()
             },
+
+            Some(DeletionFilesList::IcebergEqualityDelete(deletes)) => {
+                let out = PyDict::new(py);
+
+                for (k, v) in deletes.iter() {
+                    let equality_delete_files = v
+                        .equality_delete_files
+                        .iter()
+                        .map(|file| {
+                            (
+                                file.path.as_str(),
+                                file.sequence_number,
+                                file.equality_ids.as_ref(),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+
+                    out.set_item(
+                        *k,
+                        (
+                            v.data_sequence_number,
+                            equality_delete_files,
+                            v.position_delete_files.as_ref(),
+                        ),
+                    )?;
+                }
+
+                ("iceberg-equality-delete", out)
+                    .into_pyobject(py)?
+                    .into_any()
+                    .unbind()
+            },
+
+            Some(DeletionFilesList::DeltaDeletionVector {
+                table_root,
+                vectors,
+            }) => {
+                let out = PyDict::new(py);
+
+                for (k, v) in vectors.iter() {
+                    out.set_item(
+                        *k,
+                        (
+                            v.storage_type.delta_code(),
+                            v.path_or_inline_dv.as_str(),
+                            v.offset,
+                            v.size_in_bytes,
+                            v.cardinality,
+                        ),
+                    )?;
+                }
+
+                ("delta-deletion-vector", (table_root.as_str(), out))
+                    .into_pyobject(py)?
+                    .into_any()
+                    .unbind()
+            },
         })
     }
 
@@ -534,6 +595,9 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<Py<PyAny>> {
                         JoinType::AsOf(_) => {
                             return Err(PyNotImplementedError::new_err("asof join"));
                         },
+                        JoinType::Fuzzy(_) => {
+                            return Err(PyNotImplementedError::new_err("fuzzy join"));
+                        },
                         #[cfg(feature = "iejoin")]
                         JoinType::IEJoin => {
                             let Some(JoinTypeOptionsIR::IEJoin(ie_options)) = &options.options
@@ -8,11 +8,13 @@ use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    Visit, Visitor, WildcardAdditionalOptions,
+    Assignment, AssignmentTarget, BinaryOperator, CreateTable, Cte, Delete, Distinct,
+    ExcludeSelectItem, Expr as SQLExpr, FromTable, FunctionArg, GroupByExpr, Ident, Insert,
+    JoinConstraint, JoinOperator, MergeAction, MergeClauseKind, MergeInsertExpr, MergeInsertKind,
+    NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectType, Offset, OrderBy, Query,
+    RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement,
+    TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values, Visit,
+    Visitor, WildcardAdditionalOptions, WindowSpec, WindowType, visit_relations,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
@@ -23,6 +25,9 @@ use crate::sql_expr::{
 };
 use crate::table_functions::PolarsTableFunctions;
 
+/// Default maximum number of iterations of a recursive CTE.
+const DEFAULT_RECURSIVE_CTE_LIMIT: usize = 1000;
+
 #[derive(Clone)]
 pub struct TableInfo {
     pub(crate) frame: LazyFrame,
@@ -62,6 +67,8 @@ pub struct SQLContext {
     cte_map: PlHashMap<String, LazyFrame>,
     table_aliases: PlHashMap<String, String>,
     joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
+    named_windows: PlHashMap<String, WindowSpec>,
+    recursive_cte_limit: usize,
 }
 
 impl Default for SQLContext {
@@ -72,8 +79,10 @@ impl Default for SQLContext {
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            named_windows: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            recursive_cte_limit: DEFAULT_RECURSIVE_CTE_LIMIT,
         }
     }
 }
@@ -165,6 +174,7 @@ impl SQLContext {
         self.cte_map.clear();
         self.table_aliases.clear();
         self.joined_aliases.clear();
+        self.named_windows.clear();
 
         Ok(res)
     }
@@ -176,6 +186,12 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations of a recursive CTE, after which the query fails.
+    pub fn with_recursive_cte_limit(mut self, max_iterations: usize) -> Self {
+        self.recursive_cte_limit = max_iterations;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -201,6 +217,9 @@ impl SQLContext {
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert(_) => self.execute_insert_into_table(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update_table(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge_into_table(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -626,25 +645,471 @@ impl SQLContext {
         }
     }
 
+    // INSERT INTO <tbl> [(<cols>)] { VALUES ... | SELECT ... }
+    fn execute_insert_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            or,
+            ignore,
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            after_columns,
+            on,
+            returning,
+            replace_into,
+            ..
+        }) = stmt
+        {
+            let error_message = match () {
+                _ if or.is_some() || *ignore || *replace_into || on.is_some() => {
+                    Some("INSERT does not support conflict resolution clauses")
+                },
+                _ if *overwrite || partitioned.is_some() || !after_columns.is_empty() => {
+                    Some("INSERT does not support OVERWRITE or PARTITION clauses")
+                },
+                _ if returning.is_some() => Some("INSERT does not support the RETURNING clause"),
+                _ => None,
+            };
+            if let Some(error_message) = error_message {
+                polars_bail!(SQLInterface: error_message);
+            }
+            let Some(source) = source else {
+                polars_bail!(SQLInterface: "INSERT expects a VALUES clause or a query")
+            };
+            let tbl_name = table_name.0.first().unwrap().value.clone();
+            let Some(mut lf) = self.table_map.get(&tbl_name).cloned() else {
+                polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+            };
+            let schema = self.get_frame_schema(&mut lf)?;
+
+            // the inserted columns (all of the table's columns if not given)
+            let target_cols = if columns.is_empty() {
+                schema.iter_names().map(|name| name.to_string()).collect()
+            } else {
+                columns
+                    .iter()
+                    .map(|c| {
+                        polars_ensure!(
+                            schema.contains(&c.value),
+                            ColumnNotFound: "column '{}' does not exist in table '{}'", c.value, tbl_name
+                        );
+                        Ok(c.value.clone())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?
+            };
+            let (rows, rows_schema) = self.execute_isolated(|ctx| ctx.execute_query(source))?;
+            polars_ensure!(
+                rows_schema.len() == target_cols.len(),
+                SQLInterface: "INSERT expects {} value(s) per row; found {}", target_cols.len(), rows_schema.len()
+            );
+
+            // match the inserted values to the table's columns by position, casting them
+            // to the table's types and filling the columns that are not given with nulls
+            let rows = rows.select(
+                schema
+                    .iter()
+                    .map(|(name, dtype)| {
+                        match target_cols.iter().position(|c| c == name.as_str()) {
+                            Some(idx) => {
+                                let (src_name, _) = rows_schema.get_at_index(idx).unwrap();
+                                col(src_name.clone()).strict_cast(dtype.clone())
+                            },
+                            None => lit(LiteralValue::untyped_null()).cast(dtype.clone()),
+                        }
+                        .alias(name.clone())
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            let lf = concat(vec![lf, rows], UnionArgs::default())?;
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        }
+    }
+
+    // UPDATE <tbl> SET <col> = <expr> [, ...] [WHERE ...]
+    fn execute_update_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+            or,
+        } = stmt
+        {
+            let error_message = match () {
+                _ if !table.joins.is_empty() => Some("UPDATE does not support table JOINs"),
+                _ if from.is_some() => Some("UPDATE does not support the FROM clause"),
+                _ if returning.is_some() => Some("UPDATE does not support the RETURNING clause"),
+                _ if or.is_some() => Some("UPDATE does not support conflict resolution clauses"),
+                _ => None,
+            };
+            if let Some(error_message) = error_message {
+                polars_bail!(SQLInterface: error_message);
+            }
+            let tbl_name = self.get_registered_table_name(&table.relation, "UPDATE")?;
+            let (_, mut lf) = self.get_table(&table.relation)?;
+            let schema = self.get_frame_schema(&mut lf)?;
+
+            let condition = selection
+                .as_ref()
+                .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
+                .transpose()?;
+            let mut exprs = Vec::with_capacity(assignments.len());
+            for assignment in assignments {
+                let (name, dtype) = assignment_target(assignment, &schema, &tbl_name)?;
+                let value = parse_sql_expr(&assignment.value, self, Some(&schema))?
+                    .strict_cast(dtype.clone());
+                let value = match &condition {
+                    Some(cond) => when(cond.clone()).then(value).otherwise(col(name.clone())),
+                    None => value,
+                };
+                exprs.push(value.alias(name.clone()));
+            }
+            let lf = lf.with_columns(exprs);
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected UPDATE")
+        }
+    }
+
+    // MERGE INTO <tbl> USING <src> ON <cond>
+    //   WHEN [NOT] MATCHED [BY {SOURCE|TARGET}] [AND <cond>] THEN {UPDATE ...|DELETE|INSERT ...}
+    //
+    // The target and source are full-joined on the ON condition, after which the first
+    // WHEN clause that applies to each joined row decides what happens to that row. Each
+    // row of the target may match at most one row of the source.
+    fn execute_merge_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        const TARGET_INDEX: &str = "__POLARS_MERGE_TARGET_IDX";
+        const SOURCE_INDEX: &str = "__POLARS_MERGE_SOURCE_IDX";
+        const ACTION: &str = "__POLARS_MERGE_ACTION";
+
+        if let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        {
+            let tbl_name = self.get_registered_table_name(table, "MERGE")?;
+            let (t_name, tgt) = self.get_table(table)?;
+            let (s_name, src) = self.get_table(source)?;
+            if s_name.is_empty() {
+                polars_bail!(
+                    SQLInterface:
+                    "cannot merge from unnamed relation; please provide an alias"
+                )
+            }
+            let mut tgt = tgt.with_row_index(TARGET_INDEX, None);
+            let mut src = src.with_row_index(SOURCE_INDEX, None);
+            let left_schema = self.get_frame_schema(&mut tgt)?;
+            let right_schema = self.get_frame_schema(&mut src)?;
+            let mut lf = self.process_join(
+                &TableInfo {
+                    frame: tgt,
+                    name: (&t_name).into(),
+                    schema: left_schema.clone(),
+                },
+                &TableInfo {
+                    frame: src,
+                    name: (&s_name).into(),
+                    schema: right_schema.clone(),
+                },
+                &JoinConstraint::On(on.as_ref().clone()),
+                JoinType::Full,
+            )?;
+            let joined_schema = self.get_frame_schema(&mut lf)?;
+            self.register_joined_aliases(&s_name, &left_schema, &right_schema, &joined_schema);
+
+            // the join is materialized once so that ambiguous matches can be rejected
+            let joined = lf.collect()?;
+            let is_matched = joined.column(TARGET_INDEX)?.is_not_null()
+                & joined.column(SOURCE_INDEX)?.is_not_null();
+            let matched_targets = joined.column(TARGET_INDEX)?.filter(&is_matched)?;
+            polars_ensure!(
+                matched_targets.n_unique()? == matched_targets.len(),
+                SQLInterface: "MERGE failed: target row matched multiple source rows"
+            );
+            let lf = joined.lazy();
+
+            let in_target = col(TARGET_INDEX).is_not_null();
+            let in_source = col(SOURCE_INDEX).is_not_null();
+            let mut conditions = Vec::with_capacity(clauses.len());
+            let mut updates = vec![];
+            let mut inserts = vec![];
+            let mut deletes = vec![];
+            for (idx, clause) in clauses.iter().enumerate() {
+                let condition = match clause.clause_kind {
+                    MergeClauseKind::Matched => in_target.clone().and(in_source.clone()),
+                    MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                        in_target.clone().not()
+                    },
+                    MergeClauseKind::NotMatchedBySource => in_source.clone().not(),
+                };
+                conditions.push(match &clause.predicate {
+                    Some(predicate) => {
+                        condition.and(parse_sql_expr(predicate, self, Some(&joined_schema))?)
+                    },
+                    None => condition,
+                });
+                let idx = idx as i32;
+                match (&clause.clause_kind, &clause.action) {
+                    (
+                        MergeClauseKind::Matched | MergeClauseKind::NotMatchedBySource,
+                        MergeAction::Update { assignments },
+                    ) => {
+                        let mut values = PlHashMap::with_capacity(assignments.len());
+                        for assignment in assignments {
+                            let (name, _) = assignment_target(assignment, &left_schema, &tbl_name)?;
+                            let value =
+                                parse_sql_expr(&assignment.value, self, Some(&joined_schema))?;
+                            values.insert(name.clone(), value);
+                        }
+                        updates.push((idx, values));
+                    },
+                    (
+                        MergeClauseKind::Matched | MergeClauseKind::NotMatchedBySource,
+                        MergeAction::Delete,
+                    ) => deletes.push(idx),
+                    (
+                        MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget,
+                        MergeAction::Insert(MergeInsertExpr { columns, kind }),
+                    ) => {
+                        let MergeInsertKind::Values(Values { rows, .. }) = kind else {
+                            polars_bail!(SQLInterface: "MERGE does not support INSERT ROW")
+                        };
+                        let [row] = rows.as_slice() else {
+                            polars_bail!(SQLInterface: "MERGE expects exactly one row of values to INSERT; found {}", rows.len())
+                        };
+                        let names = if columns.is_empty() {
+                            left_schema
+                                .iter_names()
+                                .filter(|name| name.as_str() != TARGET_INDEX)
+                                .cloned()
+                                .collect()
+                        } else {
+                            columns
+                                .iter()
+                                .map(|c| {
+                                    left_schema
+                                        .get_full(&c.value)
+                                        .map(|(_, name, _)| name.clone())
+                                        .ok_or_else(|| polars_err!(ColumnNotFound: "column '{}' does not exist in table '{}'", c.value, tbl_name))
+                                })
+                                .collect::<PolarsResult<Vec<_>>>()?
+                        };
+                        polars_ensure!(
+                            names.len() == row.len(),
+                            SQLInterface: "MERGE INSERT expects {} value(s); found {}", names.len(), row.len()
+                        );
+                        let mut values = PlHashMap::with_capacity(row.len());
+                        for (name, value) in names.into_iter().zip(row) {
+                            values.insert(name, parse_sql_expr(value, self, Some(&joined_schema))?);
+                        }
+                        inserts.push((idx, values));
+                    },
+                    (kind, action) => {
+                        let action = match action {
+                            MergeAction::Insert(_) => "INSERT",
+                            MergeAction::Update { .. } => "UPDATE",
+                            MergeAction::Delete => "DELETE",
+                        };
+                        polars_bail!(SQLInterface: "MERGE does not allow {} in a 'WHEN {}' clause", action, kind)
+                    },
+                }
+            }
+
+            // index of the first clause that applies to each row (or -1 if none apply)
+            let action = conditions
+                .into_iter()
+                .enumerate()
+                .rev()
+                .fold(lit(-1i32), |acc, (idx, cond)| {
+                    when(cond).then(lit(idx as i32)).otherwise(acc)
+                });
+            let action_is = |indices: &mut dyn Iterator<Item = i32>| {
+                indices.fold(lit(false), |acc, idx| acc.or(col(ACTION).eq(lit(idx))))
+            };
+            let is_deleted = action_is(&mut deletes.iter().copied());
+            let is_inserted = action_is(&mut inserts.iter().map(|(idx, _)| *idx));
+
+            let exprs = left_schema
+                .iter()
+                .filter(|(name, _)| name.as_str() != TARGET_INDEX)
+                .map(|(name, dtype)| {
+                    let mut expr = col(name.clone());
+                    for (idx, values) in &updates {
+                        if let Some(value) = values.get(name) {
+                            expr = when(col(ACTION).eq(lit(*idx)))
+                                .then(value.clone().strict_cast(dtype.clone()))
+                                .otherwise(expr);
+                        }
+                    }
+                    for (idx, values) in &inserts {
+                        let value = values.get(name).cloned().map_or_else(
+                            || lit(LiteralValue::untyped_null()).cast(dtype.clone()),
+                            |value| value.strict_cast(dtype.clone()),
+                        );
+                        expr = when(col(ACTION).eq(lit(*idx))).then(value).otherwise(expr);
+                    }
+                    expr.strict_cast(dtype.clone()).alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+
+            // keep the (possibly updated) target rows in their original order, followed
+            // by the inserted rows in the order of the source
+            let lf = lf
+                .with_column(action.alias(ACTION))
+                .filter(in_target.and(is_deleted.not()).or(is_inserted))
+                .sort_by_exprs(
+                    [col(TARGET_INDEX), col(SOURCE_INDEX)],
+                    SortMultipleOptions::default()
+                        .with_nulls_last(true)
+                        .with_maintain_order(true),
+                )
+                .select(exprs);
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected MERGE")
+        }
+    }
+
+    /// Get the name of the registered table that is modified by a DML statement.
+    fn get_registered_table_name(
+        &self,
+        relation: &TableFactor,
+        statement: &str,
+    ) -> PolarsResult<String> {
+        let TableFactor::Table {
+            name, args: None, ..
+        } = relation
+        else {
+            polars_bail!(SQLInterface: "{} expects a table name", statement)
+        };
+        let tbl_name = name.0.first().unwrap().value.clone();
+        if !self.table_map.contains_key(&tbl_name) {
+            polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+        }
+        Ok(tbl_name)
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = if with.recursive && refers_to_table(cte.query.as_ref(), &cte_name) {
+                    self.execute_recursive_cte(cte)?
+                } else {
+                    let lf = self.execute_query(&cte.query)?;
+                    self.rename_columns_from_table_alias(lf, &cte.alias)?
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE of the form `<anchor> UNION [ALL] <recursive term>`.
+    ///
+    /// The recursive term is evaluated repeatedly against the rows produced by the previous
+    /// iteration until it produces no new rows. With `UNION`, rows that were already produced
+    /// are discarded, which guarantees termination for cyclic data.
+    fn execute_recursive_cte(&mut self, cte: &Cte) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } = cte.query.body.as_ref()
+        else {
+            polars_bail!(
+                SQLInterface:
+                "recursive CTE '{}' must be of the form '<anchor> UNION [ALL] <recursive term>'",
+                cte_name
+            )
+        };
+        let distinct = match set_quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => {
+                polars_bail!(SQLInterface: "'UNION {}' is not supported in recursive CTEs", set_quantifier)
+            },
+        };
+        polars_ensure!(
+            !refers_to_table(left.as_ref(), cte_name),
+            SQLInterface: "the anchor of recursive CTE '{}' cannot refer to itself", cte_name
+        );
+        self.register_ctes(&cte.query)?;
+
+        let (anchor, schema) = self.execute_isolated(|ctx| {
+            let lf = ctx.process_query(left, &cte.query)?;
+            ctx.rename_columns_from_table_alias(lf, &cte.alias)
+        })?;
+        let dtypes: PlHashMap<&str, DataType> = schema
+            .iter()
+            .map(|(name, dtype)| (name.as_str(), dtype.clone()))
+            .collect();
+
+        let mut working = if distinct {
+            anchor.unique_stable(None, UniqueKeepStrategy::First)
+        } else {
+            anchor
+        }
+        .collect()?;
+        // Only the rows produced by each iteration are materialized, the result is their union.
+        let mut parts = vec![working.clone()];
+
+        let mut iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                iterations < self.recursive_cte_limit,
+                SQLInterface: "recursive CTE '{}' did not terminate within {} iterations",
+                cte_name, self.recursive_cte_limit
+            );
+            iterations += 1;
+
+            // The recursive term only sees the rows of the previous iteration.
+            self.register_cte(cte_name, working.lazy());
+            let (mut step, step_schema) =
+                self.execute_isolated(|ctx| ctx.process_query(right, &cte.query))?;
+            if step_schema.len() != schema.len() {
+                polars_bail!(
+                    SQLInterface:
+                    "the recursive term of CTE '{}' returns {} columns; expected {}",
+                    cte_name, step_schema.len(), schema.len()
+                )
+            }
+            if step_schema.iter_names().ne(schema.iter_names()) {
+                step = step.rename(step_schema.iter_names(), schema.iter_names(), true);
+            }
+            step = step.cast(dtypes.clone(), true);
+
+            if distinct {
+                step = new_distinct_rows(step, &parts)?;
+            }
+            working = step.collect()?;
+            parts.push(working.clone());
+        }
+
+        let result = concat(
+            parts.into_iter().map(IntoLazy::lazy).collect::<Vec<_>>(),
+            UnionArgs::default(),
+        )?;
+        self.process_limit_offset(result, &cte.query.limit, &cte.query.offset)
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
@@ -719,29 +1184,94 @@ impl SQLContext {
 
                 // track join-aliased columns so we can resolve them later
                 let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{name}:{r_name}");
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
             }
         };
         Ok(lf)
     }
 
+    fn register_joined_aliases(
+        &mut self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        joined_schema: &Schema,
+    ) {
+        self.joined_aliases.insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{name}:{r_name}");
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+    }
+
+    fn register_named_windows(
+        &mut self,
+        named_windows: &[NamedWindowDefinition],
+    ) -> PolarsResult<()> {
+        for NamedWindowDefinition(name, window) in named_windows {
+            let spec = match window {
+                NamedWindowExpr::NamedWindow(base) => {
+                    self.resolve_window(&WindowType::NamedWindow(base.clone()))?
+                },
+                NamedWindowExpr::WindowSpec(spec) => self.resolve_window_spec(spec)?,
+            };
+            self.named_windows.insert(name.value.clone(), spec);
+        }
+        Ok(())
+    }
+
+    /// Resolve a window that (optionally) refers to a named window from the `WINDOW`
+    /// clause, eg: `OVER w` or `OVER (w ORDER BY x)`.
+    pub(crate) fn resolve_window(&self, window_type: &WindowType) -> PolarsResult<WindowSpec> {
+        match window_type {
+            WindowType::WindowSpec(spec) => self.resolve_window_spec(spec),
+            WindowType::NamedWindow(name) => {
+                self.named_windows.get(&name.value).cloned().ok_or_else(
+                    || polars_err!(SQLInterface: "window '{}' is not defined", name.value),
+                )
+            },
+        }
+    }
+
+    fn resolve_window_spec(&self, spec: &WindowSpec) -> PolarsResult<WindowSpec> {
+        let Some(name) = &spec.window_name else {
+            return Ok(spec.clone());
+        };
+        let Some(base) = self.named_windows.get(&name.value) else {
+            polars_bail!(SQLInterface: "window '{}' is not defined", name.value)
+        };
+        // a window can only add an ORDER BY and/or frame to the window it refers to
+        if !spec.partition_by.is_empty() {
+            polars_bail!(SQLSyntax: "cannot override PARTITION BY clause of window '{}'", name.value)
+        }
+        if !spec.order_by.is_empty() && !base.order_by.is_empty() {
+            polars_bail!(SQLSyntax: "cannot override ORDER BY clause of window '{}'", name.value)
+        }
+        if base.window_frame.is_some() {
+            polars_bail!(SQLSyntax: "cannot copy window '{}' as it has a frame clause", name.value)
+        }
+        Ok(WindowSpec {
+            window_name: None,
+            partition_by: base.partition_by.clone(),
+            order_by: if spec.order_by.is_empty() {
+                base.order_by.clone()
+            } else {
+                spec.order_by.clone()
+            },
+            window_frame: spec.window_frame.clone(),
+        })
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
         let mut lf = if select_stmt.from.is_empty() {
@@ -756,6 +1286,9 @@ impl SQLContext {
             self.execute_from_statement(from.first().unwrap())?
         };
 
+        // Named windows (WINDOW clause)
+        self.register_named_windows(&select_stmt.named_window)?;
+
         // Filter expression (WHERE clause)
         let schema = self.get_frame_schema(&mut lf)?;
         lf = self.process_where(lf, &select_stmt.selection, false, Some(schema.clone()))?;
@@ -1661,6 +2194,68 @@ impl<'a> Visitor for FindTableIdentifier<'a> {
     }
 }
 
+/// Get the column (and its type) that is assigned to by an `UPDATE` or `MERGE` statement.
+fn assignment_target<'a>(
+    assignment: &Assignment,
+    schema: &'a Schema,
+    tbl_name: &str,
+) -> PolarsResult<(&'a PlSmallStr, &'a DataType)> {
+    let AssignmentTarget::ColumnName(name) = &assignment.target else {
+        polars_bail!(SQLInterface: "tuple assignments are not supported")
+    };
+    let name = &name.0.last().unwrap().value;
+    schema
+        .get_full(name)
+        .map(|(_, name, dtype)| (name, dtype))
+        .ok_or_else(|| {
+            polars_err!(ColumnNotFound: "column '{}' does not exist in table '{}'", name, tbl_name)
+        })
+}
+
+/// Check if a query (or part of it) reads from a specific table.
+fn refers_to_table<V: Visit>(v: &V, table_name: &str) -> bool {
+    visit_relations(v, |relation| match relation.0.first() {
+        Some(ident) if ident.value == table_name => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_break()
+}
+
+/// Returns the distinct rows of `step` that are not in any of the frames in `seen`, which are
+/// already distinct among each other.
+fn new_distinct_rows(step: LazyFrame, seen: &[DataFrame]) -> PolarsResult<LazyFrame> {
+    let step = step.unique_stable(None, UniqueKeepStrategy::First);
+    let seen_lf = concat(
+        seen.iter().cloned().map(IntoLazy::lazy).collect::<Vec<_>>(),
+        UnionArgs::default(),
+    )?;
+
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let on: Vec<_> = seen[0]
+            .get_column_names_owned()
+            .into_iter()
+            .map(col)
+            .collect();
+        Ok(step
+            .join_builder()
+            .with(seen_lf)
+            .how(JoinType::Anti)
+            .join_nulls(true)
+            .maintain_order(polars_ops::frame::MaintainOrderJoin::Left)
+            .on(on)
+            .finish())
+    }
+    #[cfg(not(feature = "semi_anti_join"))]
+    {
+        // The rows that remain after the seen rows are exactly the new ones.
+        let n_seen = seen.iter().map(|df| df.height()).sum::<usize>();
+        Ok(concat([seen_lf, step], UnionArgs::default())?
+            .unique_stable(None, UniqueKeepStrategy::First)
+            .slice(n_seen as i64, IdxSize::MAX))
+    }
+}
+
 /// Check if all columns referred to in a Polars expression exist in the given Schema.
 fn expr_cols_all_in_schema(expr: &Expr, schema: &Schema) -> bool {
     let mut found_cols = false;
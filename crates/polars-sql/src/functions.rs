@@ -2,25 +2,33 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, FillNullStrategy, IDX_DTYPE, IdxSize, PolarsResult, QuantileMethod,
+    RollingOptionsFixedWindow, Schema, TimeUnit, polars_bail, polars_ensure, polars_err,
 };
 use polars_lazy::dsl::Expr;
 use polars_ops::chunked_array::UnicodeForm;
 use polars_ops::series::RoundMode;
-use polars_plan::dsl::{coalesce, concat_str, element, len, max_horizontal, min_horizontal, when};
+use polars_plan::dsl::{
+    WindowMapping, coalesce, concat_str, element, len, max_horizontal, min_horizontal, repeat, when,
+};
 use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
 use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
+use polars_time::chunkedarray::RollingOptionsDynamicWindow;
+use polars_time::{ClosedWindow, Duration};
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -991,6 +999,16 @@ impl SQLFunctionVisitor<'_> {
             polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
         }
 
+        // aggregates over an explicit window frame (eg: `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW`)
+        if let Some(WindowType::WindowSpec(spec)) = &function.over {
+            if let (Some(frame), Some(agg)) = (
+                &spec.window_frame,
+                FrameAggregate::from_function(&function_name),
+            ) {
+                return self.visit_window_frame(agg, spec, frame);
+            }
+        }
+
         let log_with_base =
             |e: Expr, base: f64| e.log(LiteralValue::Dyn(DynLiteralValue::Float(base)).lit());
 
@@ -1637,6 +1655,152 @@ impl SQLFunctionVisitor<'_> {
         }
     }
 
+    /// Aggregates over an explicit window frame are mapped onto the rolling (or cumulative)
+    /// kernels, evaluated over each partition in the order of the window
+    /// e.g. SUM(a) OVER (ORDER BY b ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) -> ROLLING_SUM(a, 3)
+    fn visit_window_frame(
+        &mut self,
+        agg: FrameAggregate,
+        spec: &WindowSpec,
+        frame: &WindowFrame,
+    ) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let expr = match (agg, args.as_slice()) {
+            (_, [FunctionArgExpr::Expr(sql_expr)]) => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            // COUNT(*) counts all the rows in the frame
+            (FrameAggregate::Count, [FunctionArgExpr::Wildcard] | []) => repeat(lit(true), len()),
+            _ => return self.not_supported_error(),
+        };
+
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        let mut sort_options = Vec::with_capacity(spec.order_by.len());
+        for ob in &spec.order_by {
+            let desc_order = !ob.asc.unwrap_or(true);
+            order_by.push(parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?);
+            sort_options.push(
+                SortOptions::default()
+                    .with_order_descending(desc_order)
+                    .with_nulls_last(!ob.nulls_first.unwrap_or(desc_order)),
+            );
+        }
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let start_bound = &frame.start_bound;
+        let end_bound = frame
+            .end_bound
+            .as_ref()
+            .unwrap_or(&WindowFrameBound::CurrentRow);
+        let frame_str = format!("{} BETWEEN {} AND {}", frame.units, start_bound, end_bound);
+        if matches!(start_bound, WindowFrameBound::Following(None))
+            || matches!(end_bound, WindowFrameBound::Preceding(None))
+        {
+            polars_bail!(SQLSyntax: "invalid window frame ({})", frame_str)
+        }
+        let unbounded_err = || {
+            polars_err!(
+                SQLInterface: "unbounded window frames are not supported for {}",
+                self.func.name
+            )
+        };
+
+        let framed = match frame.units {
+            WindowFrameUnits::Rows => {
+                match (
+                    rows_frame_offset(start_bound)?,
+                    rows_frame_offset(end_bound)?,
+                ) {
+                    (None, None) => agg.aggregate(expr),
+                    (Some(start), Some(end)) => {
+                        polars_ensure!(
+                            start <= end,
+                            SQLSyntax: "window frame cannot start after it ends ({})", frame_str
+                        );
+                        let window_size = (end - start + 1) as usize;
+                        align_frame_end(expr, end, |e| Some(agg.rolling(e, window_size))).unwrap()
+                    },
+                    (None, Some(end)) => align_frame_end(expr, end, |e| {
+                        agg.cumulative(e, false)
+                            .map(|e| e.fill_null_with_strategy(FillNullStrategy::Forward(None)))
+                    })
+                    .ok_or_else(unbounded_err)?,
+                    // in reverse order, the frame starts at the first row
+                    (Some(start), None) => align_frame_end(expr.reverse(), -start, |e| {
+                        agg.cumulative(e, false)
+                            .map(|e| e.fill_null_with_strategy(FillNullStrategy::Forward(None)))
+                    })
+                    .ok_or_else(unbounded_err)?
+                    .reverse(),
+                }
+            },
+            WindowFrameUnits::Range => match (start_bound, end_bound) {
+                (WindowFrameBound::Preceding(None), WindowFrameBound::Following(None)) => {
+                    agg.aggregate(expr)
+                },
+                // without ORDER BY, all the rows of the partition are peers
+                (
+                    WindowFrameBound::Preceding(None) | WindowFrameBound::CurrentRow,
+                    WindowFrameBound::CurrentRow | WindowFrameBound::Following(None),
+                ) if order_by.is_empty() => agg.aggregate(expr),
+                (WindowFrameBound::Preceding(None), WindowFrameBound::CurrentRow) => {
+                    let cumulative = agg
+                        .cumulative(expr, false)
+                        .ok_or_else(unbounded_err)?
+                        .fill_null_with_strategy(FillNullStrategy::Forward(None));
+                    peer_value(cumulative, &order_by, true)
+                },
+                (WindowFrameBound::CurrentRow, WindowFrameBound::Following(None)) => {
+                    let cumulative = agg
+                        .cumulative(expr, true)
+                        .ok_or_else(unbounded_err)?
+                        .fill_null_with_strategy(FillNullStrategy::Backward(None));
+                    peer_value(cumulative, &order_by, false)
+                },
+                (WindowFrameBound::Preceding(Some(offset)), WindowFrameBound::CurrentRow) => {
+                    let ([by], [options]) = (order_by.as_slice(), sort_options.as_slice()) else {
+                        polars_bail!(SQLSyntax: "RANGE frame with an offset requires exactly one ORDER BY expression")
+                    };
+                    polars_ensure!(
+                        !options.descending,
+                        SQLInterface: "RANGE frame with an offset requires an ascending ORDER BY"
+                    );
+                    agg.rolling_by(expr, by.clone(), range_frame_offset(offset)?)
+                },
+                _ => polars_bail!(
+                    SQLInterface: "RANGE frames must end at the current row (or be unbounded); found {}",
+                    frame_str
+                ),
+            },
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS window frames are not supported")
+            },
+        };
+
+        if partition_by.is_empty() && order_by.is_empty() {
+            return Ok(framed);
+        }
+        let order_by = match sort_options.first() {
+            Some(options) => {
+                polars_ensure!(
+                    sort_options.iter().all(|o| o.descending == options.descending),
+                    SQLInterface: "window frames require all ORDER BY expressions to have the same sort direction"
+                );
+                Some((order_by, *options))
+            },
+            None => None,
+        };
+        framed.over_with_options(
+            (!partition_by.is_empty()).then_some(partition_by),
+            order_by,
+            WindowMapping::default(),
+        )
+    }
+
     fn visit_unary(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         self.try_visit_unary(|e| Ok(f(e)))
     }
@@ -1672,7 +1836,7 @@ impl SQLFunctionVisitor<'_> {
                 self.apply_cumulative_window(f, cumulative_f, spec)
             },
             Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
+                SQLInterface: "window '{}' is not defined",
                 named_window
             ),
             _ => self.visit_unary(f),
@@ -1906,7 +2070,7 @@ impl SQLFunctionVisitor<'_> {
                 }
             },
             Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
+                SQLInterface: "window '{}' is not defined",
                 named_window
             ),
             None => expr,
@@ -1922,6 +2086,183 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Aggregate functions that can be evaluated over an explicit window frame.
+#[derive(Clone, Copy)]
+enum FrameAggregate {
+    Avg,
+    Count,
+    Max,
+    Median,
+    Min,
+    StdDev,
+    Sum,
+    Variance,
+}
+
+impl FrameAggregate {
+    fn from_function(function: &PolarsSQLFunctions) -> Option<Self> {
+        Some(match function {
+            PolarsSQLFunctions::Avg => Self::Avg,
+            PolarsSQLFunctions::Count => Self::Count,
+            PolarsSQLFunctions::Max => Self::Max,
+            PolarsSQLFunctions::Median => Self::Median,
+            PolarsSQLFunctions::Min => Self::Min,
+            PolarsSQLFunctions::StdDev => Self::StdDev,
+            PolarsSQLFunctions::Sum => Self::Sum,
+            PolarsSQLFunctions::Variance => Self::Variance,
+            _ => return None,
+        })
+    }
+
+    /// Aggregate over the whole partition.
+    fn aggregate(self, e: Expr) -> Expr {
+        match self {
+            Self::Avg => e.mean(),
+            Self::Count => e.count(),
+            Self::Max => e.max(),
+            Self::Median => e.median(),
+            Self::Min => e.min(),
+            Self::StdDev => e.std(1),
+            Self::Sum => e.sum(),
+            Self::Variance => e.var(1),
+        }
+    }
+
+    /// Aggregate from the start (or, if `reverse`, to the end) of the partition.
+    ///
+    /// Rows with null values yield null, rather than the aggregate of the rows before them.
+    fn cumulative(self, e: Expr, reverse: bool) -> Option<Expr> {
+        Some(match self {
+            Self::Avg => e.clone().cum_sum(reverse).cast(DataType::Float64) / e.cum_count(reverse),
+            Self::Count => e.cum_count(reverse),
+            Self::Max => e.cum_max(reverse),
+            Self::Min => e.cum_min(reverse),
+            Self::Sum => e.cum_sum(reverse),
+            Self::Median | Self::StdDev | Self::Variance => return None,
+        })
+    }
+
+    /// Aggregate over a window of `window_size` rows that ends at the current row.
+    fn rolling(self, e: Expr, window_size: usize) -> Expr {
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods: 1,
+            ..Default::default()
+        };
+        match self {
+            Self::Avg => e.rolling_mean(options),
+            Self::Count => e.is_not_null().cast(IDX_DTYPE).rolling_sum(options),
+            Self::Max => e.rolling_max(options),
+            Self::Median => e.rolling_median(options),
+            Self::Min => e.rolling_min(options),
+            Self::StdDev => e.rolling_std(options),
+            Self::Sum => e.rolling_sum(options),
+            Self::Variance => e.rolling_var(options),
+        }
+    }
+
+    /// Aggregate over the rows whose value of `by` is at most `window_size` before that
+    /// of the current row.
+    fn rolling_by(self, e: Expr, by: Expr, window_size: Duration) -> Expr {
+        let options = RollingOptionsDynamicWindow {
+            window_size,
+            min_periods: 1,
+            closed_window: ClosedWindow::Both,
+            fn_params: None,
+        };
+        match self {
+            Self::Avg => e.rolling_mean_by(by, options),
+            Self::Count => e.is_not_null().cast(IDX_DTYPE).rolling_sum_by(by, options),
+            Self::Max => e.rolling_max_by(by, options),
+            Self::Median => e.rolling_median_by(by, options),
+            Self::Min => e.rolling_min_by(by, options),
+            Self::StdDev => e.rolling_std_by(by, options),
+            Self::Sum => e.rolling_sum_by(by, options),
+            Self::Variance => e.rolling_var_by(by, options),
+        }
+    }
+}
+
+/// Get the offset (in rows) of a `ROWS` frame bound from the current row; `None` if unbounded.
+fn rows_frame_offset(bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+    let (offset, sign) = match bound {
+        WindowFrameBound::CurrentRow => return Ok(Some(0)),
+        WindowFrameBound::Preceding(offset) => (offset, -1),
+        WindowFrameBound::Following(offset) => (offset, 1),
+    };
+    match offset.as_deref() {
+        None => Ok(None),
+        Some(SQLExpr::Value(SQLValue::Number(n, _))) => match n.parse::<i64>() {
+            Ok(n) if n >= 0 => Ok(Some(sign * n)),
+            _ => {
+                polars_bail!(SQLSyntax: "ROWS frame offset must be a non-negative integer; found {}", n)
+            },
+        },
+        Some(e) => {
+            polars_bail!(SQLSyntax: "ROWS frame offset must be a non-negative integer; found {}", e)
+        },
+    }
+}
+
+/// Get the size of a `RANGE` frame from its (numeric or interval) offset.
+fn range_frame_offset(offset: &SQLExpr) -> PolarsResult<Duration> {
+    match offset {
+        SQLExpr::Value(SQLValue::Number(n, _)) => match n.parse::<i64>() {
+            Ok(n) if n >= 0 => Ok(Duration::parse(&format!("{n}i"))),
+            _ => {
+                polars_bail!(SQLSyntax: "RANGE frame offset must be a non-negative integer or interval; found {}", n)
+            },
+        },
+        SQLExpr::Interval(interval) => interval_to_duration(interval, false),
+        _ => {
+            polars_bail!(SQLSyntax: "RANGE frame offset must be a non-negative integer or interval; found {}", offset)
+        },
+    }
+}
+
+/// Apply `f`, which aggregates frames that end at the current row, to the frames that end
+/// `end` rows after (or, if negative, before) the current row.
+fn align_frame_end(e: Expr, end: i64, f: impl FnOnce(Expr) -> Option<Expr>) -> Option<Expr> {
+    match end {
+        0 => f(e),
+        // frames that end before the current row only need to be shifted onto it
+        ..0 => f(e.shift(lit(-end))),
+        // frames that end after the current row also need the values to be padded, so
+        // that the frames of the last rows can end past the last value
+        _ => Some(
+            f(e.extend_constant(lit(LiteralValue::untyped_null()), lit(end as IdxSize)))?
+                .slice(lit(end as IdxSize), lit(IdxSize::MAX)),
+        ),
+    }
+}
+
+/// Take the value of `e` at the last (or, if not `last`, the first) of the peers of each row, the
+/// rows with the same values of the (sorted) `order_by` expressions.
+///
+/// A `RANGE` frame that ends (or starts) at the current row includes all of its peers, so the
+/// cumulative aggregate of such a frame is the same for all the peers.
+fn peer_value(e: Expr, order_by: &[Expr], last: bool) -> Expr {
+    let offset = if last { -1 } else { 1 };
+    let is_boundary = order_by
+        .iter()
+        .map(|by| by.clone().neq_missing(by.clone().shift(lit(offset))))
+        .reduce(Expr::or)
+        .unwrap();
+    let idx = repeat(lit(true), len()).cum_count(false) - lit(1 as IdxSize);
+    let peer_idx = if last {
+        when(is_boundary)
+            .then(idx)
+            .otherwise(len() - lit(1 as IdxSize))
+            .cum_min(true)
+    } else {
+        when(is_boundary)
+            .then(idx)
+            .otherwise(lit(0 as IdxSize))
+            .cum_max(false)
+    };
+    e.gather(peer_idx)
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
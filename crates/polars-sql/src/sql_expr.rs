@@ -22,6 +22,7 @@ use sqlparser::ast::{
     BinaryOperator as SQLBinaryOperator, CastFormat, CastKind, DataType as SQLDataType,
     DateTimeField, Expr as SQLExpr, Function as SQLFunction, Ident, Interval, Query as Subquery,
     SelectItem, Subscript, TimezoneInfo, TrimWhereField, UnaryOperator, Value as SQLValue,
+    WindowSpec, WindowType,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
@@ -694,6 +695,23 @@ impl SQLExprVisitor<'_> {
     ///
     /// See [SQLFunctionVisitor] for more details
     fn visit_function(&mut self, function: &SQLFunction) -> PolarsResult<Expr> {
+        // resolve references to named windows (eg: `OVER w`) into the window they name
+        let resolved;
+        let function = match &function.over {
+            Some(WindowType::NamedWindow(_))
+            | Some(WindowType::WindowSpec(WindowSpec {
+                window_name: Some(_),
+                ..
+            })) => {
+                let spec = self.ctx.resolve_window(function.over.as_ref().unwrap())?;
+                resolved = SQLFunction {
+                    over: Some(WindowType::WindowSpec(spec)),
+                    ..function.clone()
+                };
+                &resolved
+            },
+            _ => function,
+        };
         let mut visitor = SQLFunctionVisitor {
             func: function,
             ctx: self.ctx,
@@ -0,0 +1,160 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "id" => [3i64, 1, 6, 2, 5, 4],
+      "grp" => ["a", "a", "b", "a", "b", "b"],
+      "value" => [30i64, 10, 60, 20, 50, 40],
+      "day" => [5i32, 1, 9, 2, 8, 6],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("day").cast(DataType::Date));
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+fn execute(ctx: &mut SQLContext, sql: &str) -> DataFrame {
+    ctx.execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_window_frame_rows() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT
+        id,
+        SUM(value) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS s,
+        AVG(value) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS m,
+        MIN(value) OVER (PARTITION BY grp ORDER BY id DESC ROWS 2 PRECEDING) AS mn,
+        COUNT(*) OVER (ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND 2 PRECEDING) AS c,
+        MAX(value) OVER (ORDER BY id ROWS BETWEEN 1 FOLLOWING AND UNBOUNDED FOLLOWING) AS mx
+      FROM df
+      ORDER BY id
+    "#;
+    let actual = execute(&mut ctx, sql);
+    let expected = df! {
+      "id" => [1i64, 2, 3, 4, 5, 6],
+      "s" => [10i64, 30, 50, 40, 90, 110],
+      "m" => [15.0, 20.0, 25.0, 45.0, 50.0, 55.0],
+      "mn" => [10i64, 20, 30, 40, 50, 60],
+      "c" => [0 as IdxSize, 0, 1, 2, 3, 4],
+      "mx" => [Some(60i64), Some(60), Some(60), Some(60), Some(60), None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_window_frame_range() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT
+        id,
+        SUM(value) OVER (ORDER BY day RANGE BETWEEN INTERVAL '3 days' PRECEDING AND CURRENT ROW) AS s,
+        COUNT(value) OVER (ORDER BY id RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS c,
+        SUM(value) OVER (PARTITION BY grp RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS t
+      FROM df
+      ORDER BY id
+    "#;
+    let actual = execute(&mut ctx, sql);
+    let expected = df! {
+      "id" => [1i64, 2, 3, 4, 5, 6],
+      "s" => [10i64, 30, 50, 70, 120, 150],
+      "c" => [1 as IdxSize, 2, 2, 2, 2, 2],
+      "t" => [60i64, 60, 60, 150, 150, 150],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_window_frame_range_peers() {
+    // rows with the same ORDER BY value are peers, and are in each other's frames
+    let df = df! {
+      "id" => [1i64, 2, 3, 4, 5, 6],
+      "grp" => ["a", "a", "a", "b", "b", "b"],
+      "k" => [2i32, 1, 2, 1, 1, 2],
+      "value" => [10i64, 20, 30, 40, 50, 60],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+
+    let sql = r#"
+      SELECT
+        id,
+        SUM(value) OVER (ORDER BY k RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS s,
+        SUM(value) OVER (PARTITION BY grp ORDER BY k DESC RANGE UNBOUNDED PRECEDING) AS sd,
+        COUNT(*) OVER (ORDER BY k RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS c,
+        SUM(value) OVER (ORDER BY k DESC RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS sf,
+        SUM(value) OVER (PARTITION BY grp RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS t
+      FROM df
+      ORDER BY id
+    "#;
+    let actual = execute(&mut ctx, sql);
+    let expected = df! {
+      "id" => [1i64, 2, 3, 4, 5, 6],
+      "s" => [210i64, 110, 210, 110, 110, 210],
+      "sd" => [40i64, 60, 40, 150, 150, 60],
+      "c" => [3 as IdxSize, 6, 3, 6, 6, 3],
+      "sf" => [210i64, 110, 210, 110, 110, 210],
+      "t" => [60i64, 60, 60, 150, 150, 150],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_named_windows() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT
+        id,
+        SUM(value) OVER w AS total,
+        AVG(value) OVER (w_ordered ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS m
+      FROM df
+      WINDOW w AS (PARTITION BY grp), w_ordered AS (w ORDER BY id)
+      ORDER BY id
+    "#;
+    let actual = execute(&mut ctx, sql);
+    let expected = df! {
+      "id" => [1i64, 2, 3, 4, 5, 6],
+      "total" => [60i64, 60, 60, 150, 150, 150],
+      "m" => [10.0, 15.0, 25.0, 40.0, 45.0, 55.0],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_window_frame_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT SUM(value) OVER undefined FROM df",
+        "SELECT SUM(value) OVER (ORDER BY id GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT SUM(value) OVER (ORDER BY id ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) FROM df",
+        "SELECT SUM(value) OVER (ORDER BY id RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM df",
+        "SELECT SUM(value) OVER (ORDER BY id DESC RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT MEDIAN(value) OVER (ORDER BY id ROWS UNBOUNDED PRECEDING) FROM df",
+        "SELECT SUM(value) OVER (w PARTITION BY id) FROM df WINDOW w AS (PARTITION BY grp)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "expected error for: {sql}");
+    }
+}
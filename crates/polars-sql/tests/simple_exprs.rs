@@ -581,6 +581,95 @@ fn test_cte_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_recursive_cte() -> PolarsResult<()> {
+    let sql = r#"
+        WITH RECURSIVE t(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM t WHERE n < 5
+        )
+        SELECT n FROM t
+    "#;
+    let mut context = SQLContext::new();
+    let df = context.execute(sql)?.collect()?;
+    let values: Vec<_> = df
+        .column("n")?
+        .cast(&DataType::Int64)?
+        .i64()?
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(values, [1, 2, 3, 4, 5]);
+
+    Ok(())
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() -> PolarsResult<()> {
+    // Contains the cycle 4 -> 5 -> 4, which terminates because of the `UNION` dedup.
+    let edges = df!(
+        "parent" => [1, 1, 2, 4, 5],
+        "child" => [2, 3, 4, 5, 4],
+    )?;
+    let mut context = SQLContext::new();
+    context.register("edges", edges.lazy());
+
+    let sql = r#"
+        WITH RECURSIVE reachable(node) AS (
+            SELECT 1
+            UNION
+            SELECT e.child FROM edges e JOIN reachable r ON e.parent = r.node
+        )
+        SELECT node FROM reachable ORDER BY node
+    "#;
+    let df = context.execute(sql)?.collect()?;
+    let nodes: Vec<_> = df
+        .column("node")?
+        .cast(&DataType::Int64)?
+        .i64()?
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(nodes, [1, 2, 3, 4, 5]);
+
+    // Without the dedup, the cycle never terminates.
+    let sql = sql.replace("UNION\n", "UNION ALL\n");
+    let mut context = context.with_recursive_cte_limit(20);
+    let err = context
+        .execute(&sql)
+        .and_then(|lf| lf.collect())
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("did not terminate within 20 iterations")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_recursive_cte_union_nulls() -> PolarsResult<()> {
+    // Rows with nulls are duplicates of each other in a `UNION`, so this terminates.
+    let sql = r#"
+        WITH RECURSIVE t(n, m) AS (
+            SELECT 1 AS n, CAST(NULL AS INT) AS m
+            UNION
+            SELECT CASE WHEN n < 3 THEN n + 1 ELSE n END, m FROM t
+        )
+        SELECT n FROM t
+    "#;
+    let mut context = SQLContext::new().with_recursive_cte_limit(10);
+    let df = context.execute(sql)?.collect()?;
+    let values: Vec<_> = df
+        .column("n")?
+        .cast(&DataType::Int64)?
+        .i64()?
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(values, [1, 2, 3]);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "ipc")]
 fn test_group_by_2() -> PolarsResult<()> {
@@ -593,3 +593,146 @@ fn test_compound_invalid_2() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+fn prepare_dml_context() -> SQLContext {
+    let mut ctx = SQLContext::new();
+    let df = df! {
+        "id" => [1i64, 2, 3],
+        "name" => ["a", "b", "c"],
+        "value" => [10.0, 20.0, 30.0],
+    }
+    .unwrap();
+    ctx.register("tbl", df.lazy());
+    ctx
+}
+
+#[test]
+fn test_insert_into() {
+    let mut ctx = prepare_dml_context();
+    let _ = ctx
+        .execute("INSERT INTO tbl VALUES (4, 'd', 40.5), (5, 'e', 50)")
+        .unwrap();
+    let _ = ctx
+        .execute("INSERT INTO tbl (name, id) SELECT name, id + 10 FROM tbl WHERE id < 2")
+        .unwrap();
+
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1i64, 2, 3, 4, 5, 11],
+        "name" => ["a", "b", "c", "d", "e", "a"],
+        "value" => [Some(10.0), Some(20.0), Some(30.0), Some(40.5), Some(50.0), None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    assert!(ctx.execute("INSERT INTO tbl VALUES (6, 'f')").is_err());
+    assert!(ctx.execute("INSERT INTO tbl (xyz) VALUES (6)").is_err());
+    assert!(ctx.execute("INSERT INTO nope VALUES (6, 'f', 60)").is_err());
+
+    // values that cannot be cast to the table's types fail on collect
+    let lf = ctx.execute("INSERT INTO tbl VALUES ('six', 'f', 60)");
+    assert!(lf.unwrap().collect().is_err());
+}
+
+#[test]
+fn test_update() {
+    let mut ctx = prepare_dml_context();
+    let _ = ctx
+        .execute("UPDATE tbl SET value = value * 2, name = 'x' WHERE id >= 2")
+        .unwrap();
+    let _ = ctx
+        .execute("UPDATE tbl AS t SET t.id = t.id + 100")
+        .unwrap();
+
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [101i64, 102, 103],
+        "name" => ["a", "x", "x"],
+        "value" => [10.0, 40.0, 60.0],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    assert!(ctx.execute("UPDATE tbl SET xyz = 1").is_err());
+    assert!(ctx.execute("UPDATE nope SET id = 1").is_err());
+}
+
+#[test]
+fn test_merge_into() {
+    let mut ctx = prepare_dml_context();
+    let src = df! {
+        "id" => [2i64, 3, 4, 5],
+        "name" => ["B", "C", "D", "E"],
+        "value" => [200.0, -1.0, 400.0, 500.0],
+    }
+    .unwrap();
+    ctx.register("src", src.lazy());
+
+    let _ = ctx
+        .execute(
+            r#"
+        MERGE INTO tbl AS t
+        USING src AS s
+        ON t.id = s.id
+        WHEN MATCHED AND s.value < 0 THEN DELETE
+        WHEN MATCHED THEN UPDATE SET name = s.name, value = t.value + s.value
+        WHEN NOT MATCHED AND s.id < 5 THEN INSERT (id, name) VALUES (s.id, s.name)
+        "#,
+        )
+        .unwrap();
+
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1i64, 2, 4],
+        "name" => ["a", "B", "D"],
+        "value" => [Some(10.0), Some(220.0), None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // INSERT is not valid for matched rows
+    assert!(
+        ctx.execute(
+            "MERGE INTO tbl USING src AS s ON tbl.id = s.id WHEN MATCHED THEN INSERT VALUES (1, 'a', 1.0)"
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_merge_into_multiple_matches() {
+    let mut ctx = prepare_dml_context();
+    let src = df! {
+        "id" => [1i64, 1, 4],
+        "name" => ["A", "AA", "D"],
+    }
+    .unwrap();
+    ctx.register("src", src.lazy());
+
+    let Err(err) = ctx.execute(
+        "MERGE INTO tbl USING src AS s ON tbl.id = s.id WHEN MATCHED THEN UPDATE SET name = s.name",
+    ) else {
+        panic!("expected MERGE to fail")
+    };
+    assert!(
+        err.to_string()
+            .contains("target row matched multiple source rows"),
+        "{err}"
+    );
+
+    // the target is left untouched
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    assert_eq!(
+        actual.column("name").unwrap().str().unwrap().get(0),
+        Some("a")
+    );
+}
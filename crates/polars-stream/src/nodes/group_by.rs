@@ -1,19 +1,20 @@
 use std::sync::Arc;
 
 use polars_core::POOL;
-use polars_core::prelude::{IntoColumn, PlHashSet, PlRandomState};
+use polars_core::prelude::{Column, IntoColumn, PlHashSet, PlRandomState};
 use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::polars_err;
 use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::sparse_init_vec::SparseInitVec;
+use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
 use super::compute_node_prelude::*;
@@ -21,12 +22,15 @@ use crate::async_executor;
 use crate::expression::StreamExpr;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{PartitionedSpill, SpillFile};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
 #[cfg(not(debug_assertions))]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4096;
 
+const SPILL_SEQ_NAME: &str = "__POLARS_GB_SPILL_SEQ";
+
 struct LocalGroupBySinkState {
     hot_grouper: Box<dyn HotGrouper>,
     hot_grouped_reductions: Vec<Box<dyn GroupedReduction>>,
@@ -42,6 +46,22 @@ struct LocalGroupBySinkState {
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
 
+    // If spilling is enabled the cold rows are not stored in cold_morsels, instead
+    // cold_spill_dfs_per_p[p] contains the cold rows of partition p in the layout
+    // they are spilled in: the keys, the values and the sequence id of each row.
+    cold_spill_dfs_per_p: Vec<Vec<DataFrame>>,
+    cold_idxs_per_p: Vec<Vec<IdxSize>>,
+    cold_num_bytes: usize,
+
+    // If spilling is enabled, the estimated size of the pre-aggregates, based on
+    // the estimated size of an input row.
+    pre_agg_num_bytes: usize,
+    row_num_bytes: usize,
+
+    // Set once the hot table is flushed to the pre-aggregates, after which all
+    // rows are cold.
+    hot_table_flushed: bool,
+
     // Similar to the above, but for (evicted) pre-aggregates.
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
@@ -66,6 +86,15 @@ impl LocalGroupBySinkState {
             morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
             morsel_idxs_offsets_per_p: vec![0; num_partitions],
 
+            cold_spill_dfs_per_p: vec![Vec::new(); num_partitions],
+            cold_idxs_per_p: vec![Vec::new(); num_partitions],
+            cold_num_bytes: 0,
+
+            pre_agg_num_bytes: 0,
+            row_num_bytes: 0,
+
+            hot_table_flushed: false,
+
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
@@ -96,8 +125,37 @@ impl LocalGroupBySinkState {
         );
         self.pre_agg_idxs_offsets_per_p
             .extend(self.pre_agg_idxs_values_per_p.iter().map(|vp| vp.len()));
+        self.pre_agg_num_bytes += hash_keys.len() * self.row_num_bytes;
         self.pre_aggs.push((hash_keys, reductions));
     }
+
+    /// Moves all groups in the hot table to the pre-aggregates, after which the hot table is no
+    /// longer used.
+    fn flush_hot_table(&mut self, partitioner: &HashPartitioner) {
+        if self.hot_grouper.num_evictions() > 0 {
+            self.flush_evictions(partitioner);
+        }
+        let hot_keys = self.hot_grouper.keys();
+        let hot_reductions = core::mem::take(&mut self.hot_grouped_reductions);
+        self.add_pre_agg(hot_keys, hot_reductions, partitioner);
+        self.hot_table_flushed = true;
+    }
+
+    /// Moves all cold rows to their partition's spill file.
+    fn spill_cold_rows(&mut self, spill: &PartitionedSpill) -> PolarsResult<()> {
+        for (p, dfs) in self.cold_spill_dfs_per_p.iter_mut().enumerate() {
+            for df in dfs.drain(..) {
+                spill.write(p, &df)?;
+            }
+        }
+        self.cold_num_bytes = 0;
+        Ok(())
+    }
+}
+
+struct GroupBySpill {
+    budget_per_local: usize,
+    partitions: PartitionedSpill,
 }
 
 struct GroupBySinkState {
@@ -110,6 +168,7 @@ struct GroupBySinkState {
     random_state: PlRandomState,
     partitioner: HashPartitioner,
     has_order_sensitive_agg: bool,
+    spill: Option<GroupBySpill>,
 }
 
 impl GroupBySinkState {
@@ -127,6 +186,7 @@ impl GroupBySinkState {
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
             let has_order_sensitive_agg = self.has_order_sensitive_agg;
+            let spill = self.spill.as_ref();
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -143,37 +203,41 @@ impl GroupBySinkState {
                     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
                     let hash_keys = HashKeys::from_df(&keys, random_state.clone(), true, false);
 
-                    hot_idxs.clear();
-                    hot_group_idxs.clear();
-                    cold_idxs.clear();
-                    local.hot_grouper.insert_keys(
-                        &hash_keys,
-                        &mut hot_idxs,
-                        &mut hot_group_idxs,
-                        &mut cold_idxs,
-                        has_order_sensitive_agg,
-                    );
-
                     // Drop columns not used for reductions (key-only columns).
                     if uniq_grouped_reduction_cols.len() < grouped_reduction_cols.len() {
                         df = df._select_impl(uniq_grouped_reduction_cols).unwrap();
                     }
                     df.rechunk_mut(); // For gathers.
 
-                    // Update hot reductions.
-                    for (col, reduction) in grouped_reduction_cols
-                        .iter()
-                        .zip(&mut local.hot_grouped_reductions)
-                    {
-                        unsafe {
-                            // SAFETY: we resize the reduction to the number of groups beforehand.
-                            reduction.resize(local.hot_grouper.num_groups());
-                            reduction.update_groups_while_evicting(
-                                df.column(col).unwrap(),
-                                &hot_idxs,
-                                &hot_group_idxs,
-                                seq,
-                            )?;
+                    hot_idxs.clear();
+                    hot_group_idxs.clear();
+                    cold_idxs.clear();
+                    if local.hot_table_flushed {
+                        cold_idxs.extend(0..df.height() as IdxSize);
+                    } else {
+                        local.hot_grouper.insert_keys(
+                            &hash_keys,
+                            &mut hot_idxs,
+                            &mut hot_group_idxs,
+                            &mut cold_idxs,
+                            has_order_sensitive_agg,
+                        );
+
+                        // Update hot reductions.
+                        for (col, reduction) in grouped_reduction_cols
+                            .iter()
+                            .zip(&mut local.hot_grouped_reductions)
+                        {
+                            unsafe {
+                                // SAFETY: we resize the reduction to the number of groups beforehand.
+                                reduction.resize(local.hot_grouper.num_groups());
+                                reduction.update_groups_while_evicting(
+                                    df.column(col).unwrap(),
+                                    &hot_idxs,
+                                    &hot_group_idxs,
+                                    seq,
+                                )?;
+                            }
                         }
                     }
 
@@ -184,16 +248,63 @@ impl GroupBySinkState {
                             let cold_keys = hash_keys.gather_unchecked(&cold_idxs);
                             let cold_df = df.take_slice_unchecked_impl(&cold_idxs, false);
 
-                            cold_keys.gen_idxs_per_partition(
-                                &partitioner,
-                                &mut local.morsel_idxs_values_per_p,
-                                &mut local.sketch_per_p,
-                                true,
-                            );
-                            local
-                                .morsel_idxs_offsets_per_p
-                                .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
-                            local.cold_morsels.push((seq, cold_keys, cold_df));
+                            if spill.is_some() {
+                                // Only the spill layout is kept, the hash keys are rebuilt
+                                // from it when the rows are read back.
+                                for idxs in &mut local.cold_idxs_per_p {
+                                    idxs.clear();
+                                }
+                                cold_keys.gen_idxs_per_partition(
+                                    &partitioner,
+                                    &mut local.cold_idxs_per_p,
+                                    &mut local.sketch_per_p,
+                                    true,
+                                );
+                                drop(cold_keys);
+
+                                let mut spill_df =
+                                    keys.take_slice_unchecked_impl(&cold_idxs, false);
+                                for (i, c) in spill_df.get_columns_mut().iter_mut().enumerate() {
+                                    c.rename(format_pl_smallstr!("__POLARS_GB_SPILL_KEY_{i}"));
+                                }
+                                spill_df.hstack_mut_unchecked(cold_df.get_columns());
+                                spill_df.with_column_unchecked(Column::new_scalar(
+                                    PlSmallStr::from_static(SPILL_SEQ_NAME),
+                                    seq.into(),
+                                    cold_idxs.len(),
+                                ));
+                                local.cold_num_bytes += spill_df.estimated_size();
+                                local.row_num_bytes = spill_df.estimated_size() / spill_df.height();
+                                for (p, idxs) in local.cold_idxs_per_p.iter().enumerate() {
+                                    if !idxs.is_empty() {
+                                        local.cold_spill_dfs_per_p[p]
+                                            .push(spill_df.take_slice_unchecked_impl(idxs, false));
+                                    }
+                                }
+                            } else {
+                                cold_keys.gen_idxs_per_partition(
+                                    &partitioner,
+                                    &mut local.morsel_idxs_values_per_p,
+                                    &mut local.sketch_per_p,
+                                    true,
+                                );
+                                local.morsel_idxs_offsets_per_p.extend(
+                                    local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()),
+                                );
+                                local.cold_morsels.push((seq, cold_keys, cold_df));
+                            }
+                        }
+                    }
+
+                    if let Some(spill) = spill {
+                        // The pre-aggregates can't be spilled, so once we exceed the budget we
+                        // flush the hot table and stop pre-aggregating, all rows are spilled
+                        // from then on.
+                        if local.cold_num_bytes + local.pre_agg_num_bytes > spill.budget_per_local {
+                            if !local.hot_table_flushed {
+                                local.flush_hot_table(&partitioner);
+                            }
+                            local.spill_cold_rows(&spill.partitions)?;
                         }
                     }
 
@@ -207,22 +318,40 @@ impl GroupBySinkState {
         }
     }
 
-    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
-        // Finalize pre-aggregations.
+    /// Finalize pre-aggregations.
+    fn flush_hot_tables(&mut self) {
         POOL.install(|| {
             self.locals
                 .as_mut_slice()
                 .into_par_iter()
                 .with_max_len(1)
                 .for_each(|l| {
-                    if l.hot_grouper.num_evictions() > 0 {
-                        l.flush_evictions(&self.partitioner);
+                    if !l.hot_table_flushed {
+                        l.flush_hot_table(&self.partitioner);
                     }
-                    let hot_keys = l.hot_grouper.keys();
-                    let hot_reductions = core::mem::take(&mut l.hot_grouped_reductions);
-                    l.add_pre_agg(hot_keys, hot_reductions, &self.partitioner);
                 });
         });
+    }
+
+    /// Prepares the finalization of the partitions one at a time, after spilling.
+    fn into_spilled_source(mut self, spill: GroupBySpill) -> PolarsResult<SpilledGroupBySource> {
+        self.flush_hot_tables();
+        Ok(SpilledGroupBySource {
+            spill_files: spill.partitions.finish()?,
+            locals: self.locals,
+            grouper: self.grouper,
+            grouped_reductions: self.grouped_reductions,
+            grouped_reduction_cols: self.grouped_reduction_cols,
+            num_keys: self.key_selectors.len(),
+            random_state: self.random_state,
+            next_partition: 0,
+            source: None,
+            seq_offset: MorselSeq::new(0),
+        })
+    }
+
+    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
+        self.flush_hot_tables();
 
         // To reduce maximum memory usage we want to drop the morsels
         // as soon as they're processed, so we move into Arcs. The drops might
@@ -445,9 +574,185 @@ impl GroupByPartition {
     }
 }
 
+/// A group-by which spilled to disk. To bound the memory usage its partitions are finalized one
+/// at a time, each partition is loaded, aggregated and sent out before the next one is loaded.
+struct SpilledGroupBySource {
+    spill_files: Vec<Option<SpillFile>>,
+    locals: Vec<LocalGroupBySinkState>,
+    grouper: Box<dyn Grouper>,
+    grouped_reductions: Vec<Box<dyn GroupedReduction>>,
+    grouped_reduction_cols: Vec<PlSmallStr>,
+    num_keys: usize,
+    random_state: PlRandomState,
+    next_partition: usize,
+    source: Option<InMemorySourceNode>,
+    seq_offset: MorselSeq,
+}
+
+impl SpilledGroupBySource {
+    /// Updates the state of the current partition's source, moving on to the next partition once
+    /// it is exhausted. Returns false once all partitions are sent.
+    fn update_state(
+        &mut self,
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+        key_schema: &Schema,
+        output_schema: &Schema,
+    ) -> PolarsResult<bool> {
+        loop {
+            if let Some(source) = &mut self.source {
+                source.update_state(&mut [], send, state)?;
+                if send[0] != PortState::Done {
+                    return Ok(true);
+                }
+                self.source = None;
+            }
+
+            if self.next_partition == self.spill_files.len() {
+                return Ok(false);
+            }
+            let df = self
+                .finalize_partition(self.next_partition)?
+                .into_df(key_schema, output_schema)?;
+            self.next_partition += 1;
+
+            let height = df.height() as u64;
+            self.source = Some(InMemorySourceNode::new(Arc::new(df), self.seq_offset));
+            self.seq_offset = self.seq_offset.offset_by_u64(height + 1);
+            send[0] = PortState::Ready;
+        }
+    }
+
+    fn finalize_partition(&mut self, p: usize) -> PolarsResult<GroupByPartition> {
+        let num_partitions = self.spill_files.len();
+
+        let mut sketch = CardinalitySketch::new();
+        for l in &self.locals {
+            sketch.combine(&l.sketch_per_p[p]);
+        }
+        let est_num_groups = sketch.estimate() * 5 / 4;
+        let mut p_grouper = self.grouper.new_empty();
+        let mut p_reductions = self
+            .grouped_reductions
+            .iter()
+            .map(|gr| gr.new_empty())
+            .collect_vec();
+        p_grouper.reserve(est_num_groups);
+        for r in &mut p_reductions {
+            r.reserve(est_num_groups);
+        }
+
+        // Insert pre-aggregates.
+        let mut group_idxs = Vec::new();
+        for l in &self.locals {
+            for (i, (keys, pre_aggs)) in l.pre_aggs.iter().enumerate() {
+                unsafe {
+                    let p_pre_agg_idxs_start = l.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
+                    let p_pre_agg_idxs_stop =
+                        l.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                    let p_pre_agg_idxs =
+                        &l.pre_agg_idxs_values_per_p[p][p_pre_agg_idxs_start..p_pre_agg_idxs_stop];
+
+                    group_idxs.clear();
+                    p_grouper.insert_keys_subset(keys, p_pre_agg_idxs, Some(&mut group_idxs));
+                    for (pre_agg, r) in pre_aggs.iter().zip(&mut p_reductions) {
+                        r.resize(p_grouper.num_groups());
+                        r.combine_subset(&**pre_agg, p_pre_agg_idxs, &group_idxs)?;
+                    }
+                }
+            }
+        }
+
+        // Insert the cold rows which were not spilled, followed by the spilled rows.
+        let mut insert = |df: DataFrame| {
+            insert_spilled_rows(
+                &df,
+                self.num_keys,
+                &self.random_state,
+                &self.grouped_reduction_cols,
+                &mut *p_grouper,
+                &mut p_reductions,
+            )
+        };
+        for l in &mut self.locals {
+            for df in core::mem::take(&mut l.cold_spill_dfs_per_p[p]) {
+                insert(df)?;
+            }
+        }
+        // Dropping the spill file removes it.
+        if let Some(spill_file) = self.spill_files[p].take() {
+            let mut reader = spill_file.reader()?;
+            while let Some(df) = reader.next_df()? {
+                insert(df)?;
+            }
+        }
+
+        Ok(GroupByPartition {
+            grouper: p_grouper,
+            grouped_reductions: p_reductions,
+        })
+    }
+}
+
+/// Inserts rows in the spill layout, the keys followed by the values and the sequence id of each
+/// row, into the grouper and reductions of a partition.
+fn insert_spilled_rows(
+    df: &DataFrame,
+    num_keys: usize,
+    random_state: &PlRandomState,
+    grouped_reduction_cols: &[PlSmallStr],
+    grouper: &mut dyn Grouper,
+    reductions: &mut [Box<dyn GroupedReduction>],
+) -> PolarsResult<()> {
+    let keys = df.select_by_range(..num_keys)?;
+    let hash_keys = HashKeys::from_df(&keys, random_state.clone(), true, false);
+    let seqs: Vec<u64> = df
+        .column(SPILL_SEQ_NAME)?
+        .u64()?
+        .iter()
+        .map(|seq| {
+            seq.ok_or_else(
+                || polars_err!(ComputeError: "missing sequence id in spilled group-by rows"),
+            )
+        })
+        .try_collect()?;
+
+    let subset = (0..df.height() as IdxSize).collect_vec();
+    let mut group_idxs = Vec::with_capacity(subset.len());
+    unsafe {
+        grouper.insert_keys_subset(&hash_keys, &subset, Some(&mut group_idxs));
+    }
+    for r in reductions.iter_mut() {
+        r.resize(grouper.num_groups());
+    }
+
+    // The reductions take a single sequence id per update, so we update them per run of rows
+    // with the same sequence id.
+    let mut start = 0;
+    while start < seqs.len() {
+        let seq = seqs[start];
+        let len = seqs[start..].iter().take_while(|s| **s == seq).count();
+        let run = start..start + len;
+        for (c, r) in grouped_reduction_cols.iter().zip(reductions.iter_mut()) {
+            let values = df.column(c.as_str())?;
+            unsafe {
+                r.update_groups_subset(
+                    values,
+                    &subset[run.clone()],
+                    &group_idxs[run.clone()],
+                    seq,
+                )?;
+            }
+        }
+        start = run.end;
+    }
+    Ok(())
+}
+
 enum GroupByState {
     Sink(GroupBySinkState),
     Source(InMemorySourceNode),
+    SpilledSource(SpilledGroupBySource),
     Done,
 }
 
@@ -469,6 +774,7 @@ impl GroupByNode {
         random_state: PlRandomState,
         num_pipelines: usize,
         has_order_sensitive_agg: bool,
+        memory_budget: Option<usize>,
     ) -> Self {
         let hot_table_size = std::env::var("POLARS_HOT_TABLE_SIZE")
             .map(|sz| sz.parse::<usize>().unwrap())
@@ -492,6 +798,10 @@ impl GroupByNode {
             })
             .collect();
         let partitioner = HashPartitioner::new(num_partitions, 0);
+        let spill = memory_budget.map(|budget| GroupBySpill {
+            budget_per_local: budget / num_pipelines,
+            partitions: PartitionedSpill::new(num_partitions),
+        });
         Self {
             state: GroupByState::Sink(GroupBySinkState {
                 key_selectors,
@@ -503,6 +813,7 @@ impl GroupByNode {
                 locals,
                 partitioner,
                 has_order_sensitive_agg,
+                spill,
             }),
             key_schema,
             output_schema,
@@ -536,17 +847,22 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
-                let partitions = sink.combine_locals()?;
-                let dfs = POOL.install(|| {
-                    partitions
-                        .into_par_iter()
-                        .map(|p| p.into_df(&self.key_schema, &self.output_schema))
-                        .collect::<Result<Vec<_>, _>>()
-                })?;
-
-                let df = accumulate_dataframes_vertical_unchecked(dfs);
-                let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
-                self.state = GroupByState::Source(source);
+                if let Some(spill) = sink.spill.take() {
+                    let source = sink.into_spilled_source(spill)?;
+                    self.state = GroupByState::SpilledSource(source);
+                } else {
+                    let partitions = sink.combine_locals()?;
+                    let dfs = POOL.install(|| {
+                        partitions
+                            .into_par_iter()
+                            .map(|p| p.into_df(&self.key_schema, &self.output_schema))
+                            .collect::<Result<Vec<_>, _>>()
+                    })?;
+
+                    let df = accumulate_dataframes_vertical_unchecked(dfs);
+                    let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
+                    self.state = GroupByState::Source(source);
+                }
             },
             // Defer to source node implementation.
             GroupByState::Source(src) => {
@@ -556,7 +872,14 @@ impl ComputeNode for GroupByNode {
                 }
             },
             // Nothing to change.
-            GroupByState::Done | GroupByState::Sink(_) => {},
+            GroupByState::Done | GroupByState::Sink(_) | GroupByState::SpilledSource(_) => {},
+        }
+
+        // Move on to the next spilled partition once the current one is sent.
+        if let GroupByState::SpilledSource(src) = &mut self.state {
+            if !src.update_state(send, state, &self.key_schema, &self.output_schema)? {
+                self.state = GroupByState::Done;
+            }
         }
 
         // Communicate our state.
@@ -565,7 +888,7 @@ impl ComputeNode for GroupByNode {
                 send[0] = PortState::Blocked;
                 recv[0] = PortState::Ready;
             },
-            GroupByState::Source(..) => {
+            GroupByState::Source(..) | GroupByState::SpilledSource(..) => {
                 recv[0] = PortState::Done;
                 send[0] = PortState::Ready;
             },
@@ -600,6 +923,11 @@ impl ComputeNode for GroupByNode {
                 assert!(recv_ports[0].is_none());
                 source.spawn(scope, &mut [], send_ports, state, join_handles);
             },
+            GroupByState::SpilledSource(src) => {
+                assert!(recv_ports[0].is_none());
+                let source = src.source.as_mut().unwrap();
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
             GroupByState::Done => unreachable!(),
         }
     }
@@ -0,0 +1,187 @@
+use std::cmp::Reverse;
+use std::pin::Pin;
+
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_io::avro::{AvroBlockEncoder, AvroWriterOptions};
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{SinkOptions, SinkTarget};
+use polars_utils::priority::Priority;
+
+use super::{SinkInputPort, SinkNode};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::{Receiver, Sender, connector};
+use crate::async_primitives::linearizer::Linearizer;
+use crate::execute::StreamingExecutionState;
+use crate::morsel::MorselSeq;
+use crate::nodes::io_sinks::parallelize_receive_task;
+use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::{JoinHandle, TaskPriority};
+use crate::utils::task_handles_ext::AbortOnDropHandle;
+
+type IOSend = Linearizer<Priority<Reverse<MorselSeq>, Vec<u8>>>;
+
+pub struct AvroSinkNode {
+    target: SinkTarget,
+
+    input_schema: SchemaRef,
+    write_options: AvroWriterOptions,
+    sink_options: SinkOptions,
+    cloud_options: Option<CloudOptions>,
+
+    encoder: Option<AvroBlockEncoder>,
+    io_tx: Option<Sender<IOSend>>,
+    io_task: Option<AbortOnDropHandle<PolarsResult<()>>>,
+}
+impl AvroSinkNode {
+    pub fn new(
+        input_schema: SchemaRef,
+        target: SinkTarget,
+        sink_options: SinkOptions,
+        write_options: AvroWriterOptions,
+        cloud_options: Option<CloudOptions>,
+    ) -> Self {
+        Self {
+            target,
+
+            input_schema,
+            write_options,
+            sink_options,
+            cloud_options,
+
+            encoder: None,
+            io_tx: None,
+            io_task: None,
+        }
+    }
+}
+
+impl SinkNode for AvroSinkNode {
+    fn name(&self) -> &str {
+        "avro-sink"
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        true
+    }
+    fn do_maintain_order(&self) -> bool {
+        self.sink_options.maintain_order
+    }
+
+    fn initialize(&mut self, _state: &StreamingExecutionState) -> PolarsResult<()> {
+        let encoder = AvroBlockEncoder::try_new(
+            &self.input_schema,
+            self.write_options.name.to_string(),
+            self.write_options.compression.map(Into::into),
+        )?;
+
+        // The header is the same for every block, so it is written once before all the blocks.
+        let mut header = Vec::new();
+        encoder.write_header(&mut header)?;
+
+        let (io_tx, mut io_rx) = connector::<IOSend>();
+
+        // IO task.
+        //
+        // Task that will actually do write to the target file.
+        let sink_options = self.sink_options.clone();
+        let cloud_options = self.cloud_options.clone();
+        let target = self.target.clone();
+        let io_task = polars_io::pl_async::get_runtime().spawn(async move {
+            use tokio::io::AsyncWriteExt;
+
+            let mut file = target
+                .open_into_writeable_async(&sink_options, cloud_options.as_ref())
+                .await?
+                .try_into_async_writeable()?;
+
+            file.write_all(&header).await?;
+
+            while let Ok(mut lin_rx) = io_rx.recv().await {
+                while let Some(Priority(_, buffer)) = lin_rx.get().await {
+                    file.write_all(&buffer).await?;
+                }
+            }
+
+            file.sync_on_close(sink_options.sync_on_close).await?;
+            file.close().await?;
+
+            PolarsResult::Ok(())
+        });
+
+        self.encoder = Some(encoder);
+        self.io_tx = Some(io_tx);
+        self.io_task = Some(AbortOnDropHandle(io_task));
+
+        Ok(())
+    }
+
+    fn spawn_sink(
+        &mut self,
+        recv_port_rx: Receiver<(PhaseOutcome, SinkInputPort)>,
+        state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let io_tx = self
+            .io_tx
+            .take()
+            .expect("not initialized / spawn called more than once");
+        let encoder = self.encoder.take().unwrap();
+        let pass_rxs = parallelize_receive_task(
+            join_handles,
+            recv_port_rx,
+            state.num_pipelines,
+            self.sink_options.maintain_order,
+            io_tx,
+        );
+
+        // Encode task.
+        //
+        // Task encodes the morsels into Avro blocks, each followed by the sync marker.
+        join_handles.extend(pass_rxs.into_iter().map(|mut pass_rx| {
+            let encoder = encoder.clone();
+            spawn(TaskPriority::High, async move {
+                while let Ok((mut rx, mut lin_tx)) = pass_rx.recv().await {
+                    while let Ok(morsel) = rx.recv().await {
+                        let (mut df, seq, _, consume_token) = morsel.into_inner();
+
+                        let mut buffer = Vec::new();
+                        if df.height() > 0 {
+                            df.align_chunks_par();
+                            encoder.write_blocks(&mut buffer, &df)?;
+                        }
+
+                        if lin_tx.insert(Priority(Reverse(seq), buffer)).await.is_err() {
+                            return Ok(());
+                        }
+                        drop(consume_token); // Keep the consume_token until here to increase the
+                        // backpressure.
+                    }
+                }
+
+                PolarsResult::Ok(())
+            })
+        }));
+    }
+
+    fn finalize(
+        &mut self,
+        _state: &StreamingExecutionState,
+    ) -> Option<Pin<Box<dyn Future<Output = PolarsResult<()>> + Send>>> {
+        // If we were never spawned, we need to make sure that the `tx` is taken. This signals to
+        // the IO task that it is done and prevents deadlocks.
+        drop(self.io_tx.take());
+
+        let io_task = self
+            .io_task
+            .take()
+            .expect("not initialized / finish called more than once");
+
+        // Wait for the IO task to complete.
+        Some(Box::pin(async move {
+            io_task
+                .await
+                .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))
+        }))
+    }
+}
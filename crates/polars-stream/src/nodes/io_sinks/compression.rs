@@ -0,0 +1,49 @@
+use std::io::Write;
+
+use polars_error::PolarsResult;
+use polars_io::pl_async::get_runtime;
+use polars_io::utils::compression::{CompressedWriter, FileCompression};
+
+/// Compresses the output of a sink as a single stream.
+///
+/// The compression runs on a blocking thread, so that it doesn't hold up the async runtime on
+/// which the IO task of the sink runs.
+pub struct BlockingCompressor {
+    writer: Option<CompressedWriter<Vec<u8>>>,
+}
+
+impl BlockingCompressor {
+    /// Returns `None` if the output isn't compressed.
+    pub fn new(compression: FileCompression) -> PolarsResult<Option<Self>> {
+        Ok(match compression {
+            FileCompression::Uncompressed => None,
+            compression => Some(Self {
+                writer: Some(CompressedWriter::new(Vec::new(), compression)?),
+            }),
+        })
+    }
+
+    /// Compresses `buffer` and returns the compressed output produced so far.
+    pub async fn compress(&mut self, buffer: Vec<u8>) -> PolarsResult<Vec<u8>> {
+        let mut writer = self.writer.take().unwrap();
+        let (writer, out) = get_runtime()
+            .spawn_blocking(move || {
+                writer.write_all(&buffer)?;
+                let out = std::mem::take(writer.get_mut());
+                PolarsResult::Ok((writer, out))
+            })
+            .await
+            .unwrap()?;
+        self.writer = Some(writer);
+        Ok(out)
+    }
+
+    /// Finishes the compressed stream and returns the remaining output.
+    pub async fn finish(mut self) -> PolarsResult<Vec<u8>> {
+        let writer = self.writer.take().unwrap();
+        get_runtime()
+            .spawn_blocking(move || writer.finish())
+            .await
+            .unwrap()
+    }
+}
@@ -10,6 +10,7 @@ use polars_io::prelude::{CsvWriter, CsvWriterOptions};
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
+use super::compression::BlockingCompressor;
 use super::{SinkInputPort, SinkNode};
 use crate::async_executor::spawn;
 use crate::async_primitives::connector::{Receiver, Sender, connector};
@@ -77,13 +78,18 @@ impl SinkNode for CsvSinkNode {
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
             use tokio::io::AsyncWriteExt;
 
-            let mut file = target
+            let file = target
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?;
 
+            // The whole file is compressed as a single stream, rather than every morsel on its
+            // own, which would give a worse ratio and a file of concatenated gzip members.
+            let mut compressor = BlockingCompressor::new(options.compression)?;
+
             // Write the header
+            let mut header = Vec::new();
             if options.include_header || options.include_bom {
-                let mut writer = CsvWriter::new(&mut *file)
+                let mut writer = CsvWriter::new(&mut header)
                     .include_bom(options.include_bom)
                     .include_header(options.include_header)
                     .with_separator(options.serialize_options.separator)
@@ -103,13 +109,26 @@ impl SinkNode for CsvSinkNode {
             }
 
             let mut file = file.try_into_async_writeable()?;
+            match &mut compressor {
+                None => file.write_all(&header).await?,
+                Some(compressor) => file.write_all(&compressor.compress(header).await?).await?,
+            }
 
             while let Ok(mut lin_rx) = io_rx.recv().await {
                 while let Some(Priority(_, buffer)) = lin_rx.get().await {
-                    file.write_all(&buffer).await?;
+                    match &mut compressor {
+                        None => file.write_all(&buffer).await?,
+                        Some(compressor) => {
+                            file.write_all(&compressor.compress(buffer).await?).await?
+                        },
+                    }
                 }
             }
 
+            if let Some(compressor) = compressor {
+                file.write_all(&compressor.finish().await?).await?;
+            }
+
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
 
@@ -20,10 +20,14 @@ use crate::execute::StreamingExecutionState;
 use crate::nodes::TaskPriority;
 use crate::pipe::PortReceiver;
 
+#[cfg(any(feature = "csv", feature = "json"))]
+mod compression;
 mod metrics;
 mod phase;
 use phase::PhaseOutcome;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 #[cfg(feature = "csv")]
 pub mod csv;
 #[cfg(feature = "ipc")]
@@ -14,7 +14,7 @@ use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, Version,
-    WriteOptions, array_to_columns, to_parquet_schema,
+    WriteOptions, array_to_bloom_filters, array_to_columns, to_parquet_schema,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
@@ -50,7 +50,7 @@ pub struct ParquetSinkNode {
     file_size: Arc<RelaxedCell<u64>>,
     metrics: Arc<Mutex<Option<WriteMetrics>>>,
 
-    io_tx: Option<crate::async_primitives::connector::Sender<Vec<Vec<CompressedPage>>>>,
+    io_tx: Option<crate::async_primitives::connector::Sender<EncodedRowGroup>>,
     io_task: Option<AbortOnDropHandle<PolarsResult<()>>>,
 }
 
@@ -97,6 +97,9 @@ impl ParquetSinkNode {
 // 512 ^ 2
 const DEFAULT_ROW_GROUP_SIZE: usize = 1 << 18;
 
+/// The compressed pages and the bloom filter of each Parquet column of a row group.
+type EncodedRowGroup = (Vec<Vec<CompressedPage>>, Vec<Option<Vec<u8>>>);
+
 impl SinkNode for ParquetSinkNode {
     fn name(&self) -> &str {
         "parquet-sink"
@@ -111,7 +114,7 @@ impl SinkNode for ParquetSinkNode {
 
     fn initialize(&mut self, _state: &StreamingExecutionState) -> PolarsResult<()> {
         // Collect task -> IO task
-        let (io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (io_tx, mut io_rx) = connector::<EncodedRowGroup>();
 
         // IO task.
         //
@@ -132,18 +135,23 @@ impl SinkNode for ParquetSinkNode {
 
             let writer = BufWriter::new(&mut *file);
             let key_value_metadata = write_options.key_value_metadata;
+            let encryption = write_options.encryption;
             let write_options = WriteOptions {
                 statistics: write_options.statistics,
                 compression: write_options.compression.into(),
                 version: Version::V1,
                 data_page_size: write_options.data_page_size,
             };
-            let file_writer = Mutex::new(FileWriter::new_with_parquet_schema(
+            let mut file_writer = FileWriter::new_with_parquet_schema(
                 writer,
                 arrow_schema,
                 parquet_schema,
                 write_options,
-            ));
+            );
+            if let Some(encryption) = &encryption {
+                file_writer = file_writer.with_encryption(&encryption.to_properties())?;
+            }
+            let file_writer = Mutex::new(file_writer);
             let mut writer = BatchedWriter::new(
                 file_writer,
                 column_options,
@@ -153,11 +161,11 @@ impl SinkNode for ParquetSinkNode {
             );
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
-            while let Ok(current_row_group) = io_rx.recv().await {
+            while let Ok((current_row_group, bloom_filters)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 assert_eq!(current_row_group.len(), num_parquet_columns);
-                writer.write_row_group(&current_row_group)?;
+                writer.write_row_group(&current_row_group, bloom_filters)?;
             }
 
             let file_size = writer.finish()?;
@@ -234,6 +242,9 @@ impl SinkNode for ParquetSinkNode {
                             // insert here
                             let array = array.to_arrow(0, CompatLevel::newest());
 
+                            let bloom_filters =
+                                array_to_bloom_filters(array.as_ref(), column_options);
+
                             // @TODO: This causes all structs fields to be handled on a single thread. It
                             // would be preferable to split the encoding among multiple threads.
 
@@ -264,7 +275,10 @@ impl SinkNode for ParquetSinkNode {
                                 .collect::<ParquetResult<Vec<_>>>()?;
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, (compressed_pages, bloom_filters)),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -286,7 +300,7 @@ impl SinkNode for ParquetSinkNode {
             struct Current {
                 seq: usize,
                 num_columns_seen: usize,
-                columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                columns: Vec<Option<EncodedRowGroup>>,
             }
 
             let mut current = Current {
@@ -296,14 +310,14 @@ impl SinkNode for ParquetSinkNode {
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, encoded_column))) = lin_rx.get().await {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
 
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
-                current.columns[i] = Some(compressed_pages);
+                current.columns[i] = Some(encoded_column);
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -311,11 +325,18 @@ impl SinkNode for ParquetSinkNode {
                     // them.
                     let mut current_row_group: Vec<Vec<CompressedPage>> =
                         Vec::with_capacity(num_parquet_columns);
+                    let mut bloom_filters = Vec::with_capacity(num_parquet_columns);
                     for column in current.columns.iter_mut() {
-                        current_row_group.extend(column.take().unwrap());
+                        let (compressed_pages, column_bloom_filters) = column.take().unwrap();
+                        current_row_group.extend(compressed_pages);
+                        bloom_filters.extend(column_bloom_filters);
                     }
 
-                    if io_tx.send(current_row_group).await.is_err() {
+                    if io_tx
+                        .send((current_row_group, bloom_filters))
+                        .await
+                        .is_err()
+                    {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
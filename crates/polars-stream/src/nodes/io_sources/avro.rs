@@ -0,0 +1,532 @@
+use std::cmp::Reverse;
+use std::io::Cursor;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::array::TryExtend;
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::io::avro::avro_schema::file::FileMetadata;
+use arrow::io::avro::avro_schema::read::read_metadata;
+use arrow::io::avro::read::{BlockLocation, Reader, infer_schema, read_block_locations};
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::DataType;
+use polars_core::schema::{Schema, SchemaExt, SchemaRef};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_scan::components::column_selector::ColumnSelector;
+use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_scan::reader_interface::{
+    FileReader, FileReaderCallbacks, Projection,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::AvroFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct AvroReaderBuilder;
+
+    impl FileReaderBuilder for AvroReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "avro"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::NEEDS_FILE_CACHE_INIT
+                | RC::ROW_INDEX
+                | RC::PRE_SLICE
+                | RC::NEGATIVE_PRE_SLICE
+                | RC::PARTIAL_FILTER
+                | RC::FULL_FILTER
+                | RC::MAPPED_COLUMN_PROJECTION
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            #[expect(unused)] scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = AvroFileReader {
+                scan_source: source,
+                cloud_options,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: Arc<FileMetadata>,
+    arrow_schema: ArrowSchemaRef,
+    // The block headers are read eagerly, this is cheap as the blocks are not decoded.
+    blocks: Arc<[BlockLocation]>,
+    n_rows_in_file: IdxSize,
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // check_latest: IR resolution does not download Avro.
+        if let ScanSourceRef::Path(addr) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                [Arc::from(addr.to_str())].into_iter(),
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let mut reader = Cursor::new(memslice.as_ref());
+        let metadata = read_metadata(&mut reader)?;
+        let arrow_schema = Arc::new(infer_schema(&metadata.record)?);
+        let blocks = read_block_locations(
+            memslice.as_ref(),
+            usize::try_from(reader.position()).unwrap(),
+            metadata.marker,
+        )?;
+
+        let n_rows: usize = blocks.iter().map(|block| block.num_rows).sum();
+        let n_rows_in_file = IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = n_rows))?;
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            metadata: Arc::new(metadata),
+            arrow_schema,
+            blocks: blocks.into(),
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            memslice,
+            metadata,
+            arrow_schema,
+            blocks,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate,
+            cast_columns_policy,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args;
+
+        let normalized_pre_slice = pre_slice_arg.clone().map(|pre_slice| {
+            pre_slice.restrict_to_bounds(usize::try_from(n_rows_in_file).unwrap())
+        });
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        let file_schema = self._file_schema();
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(file_schema.clone());
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[AvroFileReader]: early return: \
+                    n_rows_in_file: {n_rows_in_file}, \
+                    pre_slice: {pre_slice_arg:?}, \
+                    resolved_pre_slice: {normalized_pre_slice:?} \
+                    "
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        // Prepare parameters for tasks
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        // Resolve the renames and casts of the projected columns, which must be applied before the
+        // predicate is evaluated. Missing columns are inserted after the reader.
+        let projection = match projection {
+            Projection::Plain(projected_schema) => {
+                ProjectionBuilder::new(projected_schema, None, None).build_projection(
+                    Some(file_schema.as_ref()),
+                    None,
+                    cast_columns_policy,
+                    usize::MAX,
+                )?
+            },
+            Projection::Mapped { .. } => projection,
+        };
+        let output_columns: Arc<[(PlSmallStr, Option<ColumnSelector>)]> = projection
+            .iter_non_missing_columns()
+            .map(|projection| {
+                let transform = projection
+                    .resolved_transform
+                    .map(|x| x.attach_transforms(ColumnSelector::Position(0)));
+                (projection.source_name.clone(), transform)
+            })
+            .collect();
+
+        // The Avro reader decodes the projected columns in the order of the file.
+        let projection: Vec<bool> = arrow_schema
+            .iter_names()
+            .map(|name| {
+                output_columns
+                    .iter()
+                    .any(|(source_name, _)| source_name == name)
+            })
+            .collect();
+        let decoded_schema = arrow_schema
+            .iter()
+            .zip(&projection)
+            .filter(|(_, is_projected)| **is_projected)
+            .map(|((name, field), _)| (name.clone(), DataType::from_arrow_field(field)))
+            .collect::<Schema>();
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                blocks: {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?}, \
+                predicate: {} \
+                ",
+                decoded_schema.len(),
+                arrow_schema.len(),
+                blocks.len(),
+                pre_slice_arg,
+                normalized_pre_slice,
+                predicate.is_some(),
+            )
+        }
+
+        // Split size for morsels.
+        let max_morsel_size = get_ideal_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct BatchMessage {
+            row_idx_offset: IdxSize,
+            slice: Range<usize>,
+            block_range: Range<usize>,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut batch_tx, batch_rxs) =
+            distributor_channel::<BatchMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Explicitly linearize here to redistribute morsels from large blocks.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Takes a range of blocks and decompresses and decodes them into a single DataFrame, which
+        // is filtered by the predicate and split into morsels if it is too large.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(batch_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let metadata = metadata.clone();
+                let arrow_schema = arrow_schema.clone();
+                let blocks = blocks.clone();
+                let projection = projection.clone();
+                let decoded_schema = decoded_schema.clone();
+                let output_columns = output_columns.clone();
+                let row_index = row_index.clone();
+                let predicate = predicate.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(m) = rx.recv().await {
+                        let BatchMessage {
+                            row_idx_offset,
+                            slice,
+                            block_range,
+                            morsel_seq_base,
+                        } = m;
+
+                        // If we don't project any columns there is nothing to decode, so we just
+                        // create an empty frame with the proper height.
+                        let mut df = if decoded_schema.is_empty() {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let mut df = DataFrame::empty_with_schema(&decoded_schema);
+
+                            // The block iterator stops at the first empty block, so we decode
+                            // consecutive runs of non-empty blocks.
+                            for run in blocks[block_range]
+                                .split(|block| block.num_rows == 0)
+                                .filter(|run| !run.is_empty())
+                            {
+                                let last = run.last().unwrap();
+                                let bytes = &memslice[run[0].offset..last.offset + last.length];
+
+                                let reader = Reader::new(
+                                    Cursor::new(bytes),
+                                    metadata.as_ref().clone(),
+                                    arrow_schema.as_ref().clone(),
+                                    Some(projection.clone()),
+                                );
+                                df.try_extend(reader.take(run.len()))?;
+                            }
+
+                            df.slice(slice.start as i64, slice.len())
+                        };
+
+                        let height = df.height();
+                        let columns = output_columns
+                            .iter()
+                            .map(|(source_name, transform)| {
+                                let column = df.column(source_name)?.clone();
+                                match transform {
+                                    Some(transform) => {
+                                        transform.select_from_columns(&[column], height)
+                                    },
+                                    None => Ok(column),
+                                }
+                            })
+                            .collect::<PolarsResult<Vec<_>>>()?;
+                        df = unsafe { DataFrame::new_no_checks(height, columns) };
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            df = df.with_row_index(name.clone(), Some(row_idx_offset))?;
+                        }
+
+                        if let Some(predicate) = &predicate {
+                            let mask = predicate.predicate.evaluate_io(&df)?;
+                            df = df._filter_seq(mask.bool().expect("predicate not boolean"))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Groups the blocks that overlap the slice into batches and sends them to the decoder
+        // tasks.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let row_idx_base = row_index.as_ref().map_or(0, |ri| ri.offset);
+            let mut morsel_seq: u64 = 0;
+
+            // Batch completion parameters
+            let batch_size_limit = get_ideal_morsel_size();
+            let sliced_batch_size_limit = slice.len().div_ceil(num_pipelines);
+            let batch_block_limit = blocks.len().div_ceil(num_pipelines);
+
+            let mut block_idx = 0;
+            // Row position of the start of the block at `block_idx`.
+            let mut row_position = 0;
+
+            // Skip over all blocks that the slice would skip anyway.
+            while block_idx < blocks.len()
+                && row_position + blocks[block_idx].num_rows <= slice.start
+            {
+                row_position += blocks[block_idx].num_rows;
+                block_idx += 1;
+            }
+
+            while block_idx < blocks.len() && row_position < slice.end {
+                let batch_block_start = block_idx;
+                let batch_row_position = row_position;
+
+                // Batch blocks such that we send appropriately sized morsels. We guarantee a
+                // lower bound here, but not an upper bound.
+                while block_idx < blocks.len() && row_position < slice.end {
+                    row_position += blocks[block_idx].num_rows;
+                    block_idx += 1;
+
+                    let batch_num_rows = row_position - batch_row_position;
+                    if batch_num_rows >= batch_size_limit
+                        || batch_num_rows >= sliced_batch_size_limit
+                        || block_idx - batch_block_start >= batch_block_limit
+                    {
+                        break;
+                    }
+                }
+
+                let batch_slice = slice.start.saturating_sub(batch_row_position)
+                    ..slice.end.min(row_position) - batch_row_position;
+                let batch_slice_len = batch_slice.len();
+
+                let message = BatchMessage {
+                    row_idx_offset: row_idx_base
+                        + (batch_row_position + batch_slice.start) as IdxSize,
+                    slice: batch_slice,
+                    block_range: batch_block_start..block_idx,
+                    morsel_seq_base: morsel_seq,
+                };
+
+                if batch_tx.send(message).await.is_err() {
+                    // This should only happen if the receiver of the decoder
+                    // has broken off, meaning no further input will be needed.
+                    break;
+                }
+
+                morsel_seq += batch_slice_len.div_ceil(max_morsel_size) as u64;
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self._file_schema())
+    }
+
+    async fn file_arrow_schema(&mut self) -> PolarsResult<Option<ArrowSchemaRef>> {
+        Ok(Some(self.init_data.as_ref().unwrap().arrow_schema.clone()))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
+
+impl AvroFileReader {
+    fn _file_schema(&self) -> SchemaRef {
+        let arrow_schema = &self.init_data.as_ref().unwrap().arrow_schema;
+        Arc::new(Schema::from_arrow_schema(arrow_schema.as_ref()))
+    }
+}
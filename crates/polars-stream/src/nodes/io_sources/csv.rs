@@ -13,13 +13,14 @@ use polars_io::prelude::_csv_read_internal::{
 };
 use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{CsvEncoding, CsvParseOptions, CsvReadOptions};
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::StreamingDecompressor;
 use polars_io::utils::slice::SplitSlicePosition;
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
 use polars_utils::mmap::MemSlice;
 use polars_utils::slice_enum::Slice;
 
+use super::decompressed_chunk_size;
 use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
 use super::multi_scan::reader_interface::{BeginReadArgs, FileReader, FileReaderCallbacks};
 use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
@@ -90,8 +91,7 @@ const NO_SLICE: (usize, usize) = (0, usize::MAX);
 const SLICE_ENDED: (usize, usize) = (usize::MAX, 0);
 
 struct LineBatch {
-    // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-    bytes: &'static [u8],
+    bytes: MemSlice,
     n_lines: usize,
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
@@ -129,8 +129,6 @@ impl FileReader for CsvFileReader {
     ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
         let verbose = self.verbose;
 
-        let memslice = self.get_bytes_maybe_decompress()?;
-
         let BeginReadArgs {
             projection: Projection::Plain(projected_schema),
             // Because we currently only support PRE_SLICE we don't need to handle row index here.
@@ -174,6 +172,10 @@ impl FileReader for CsvFileReader {
             self.options.infer_schema_length
         };
 
+        // Note: For compressed files this only holds the start of the file, the rest is
+        // decompressed incrementally by the `LineBatchSource`.
+        let (memslice, decompressor) = self.get_bytes_maybe_decompress(infer_schema_length)?;
+
         let (mut inferred_schema, ..) = polars_io::csv::read::infer_file_schema(
             &polars_io::mmap::ReaderBytes::Owned(memslice.clone()),
             &self.options.parse_options,
@@ -241,11 +243,13 @@ impl FileReader for CsvFileReader {
 
         if verbose {
             eprintln!(
-                "[CsvFileReader]: project: {} / {}, slice: {:?}, row_index: {:?}",
+                "[CsvFileReader]: project: {} / {}, slice: {:?}, row_index: {:?}, \
+                streaming_decompression: {}",
                 projection.len(),
                 inferred_schema.len(),
                 &pre_slice,
                 row_index,
+                decompressor.is_some(),
             )
         }
 
@@ -264,7 +268,8 @@ impl FileReader for CsvFileReader {
         let line_batch_source_handle = AbortOnDropHandle::new(spawn(
             TaskPriority::Low,
             LineBatchSource {
-                memslice: memslice.clone(),
+                memslice,
+                decompressor,
                 line_counter: CountLines::new(
                     self.options.parse_options.quote_char,
                     self.options.parse_options.eol_char,
@@ -290,8 +295,6 @@ impl FileReader for CsvFileReader {
             .zip(morsel_senders)
             .enumerate()
             .map(|(worker_idx, (mut line_batch_rx, mut morsel_tx))| {
-                // Hold a ref as we are receiving `&'static [u8]`s pointing to this.
-                let global_memslice = memslice.clone();
                 // Only verbose log from the last worker to avoid flooding output.
                 let verbose = verbose && worker_idx == n_workers - 1;
                 let mut n_rows_processed: usize = 0;
@@ -308,19 +311,13 @@ impl FileReader for CsvFileReader {
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
-                        debug_assert!(bytes.as_ptr() as usize >= global_memslice.as_ptr() as usize);
-                        debug_assert!(
-                            bytes.as_ptr() as usize + bytes.len()
-                                <= global_memslice.as_ptr() as usize + global_memslice.len()
-                        );
-
                         let (offset, len) = match slice {
                             SLICE_ENDED => (0, 1),
                             v => v,
                         };
 
                         let (df, n_rows_in_chunk) =
-                            chunk_reader.read_chunk(bytes, n_lines, (offset, len), row_offset)?;
+                            chunk_reader.read_chunk(&bytes, n_lines, (offset, len), row_offset)?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
 
@@ -397,22 +394,88 @@ impl FileReader for CsvFileReader {
 }
 
 impl CsvFileReader {
+    /// Returns the bytes of the file. If the file is compressed, only the start of the file is
+    /// decompressed - enough to infer the schema and to skip to the first row. The decompressor
+    /// for the remainder is returned alongside if the file is not yet fully decompressed.
+    ///
     /// # Panics
     /// Panics if `self.cached_bytes` is None.
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+    fn get_bytes_maybe_decompress(
+        &mut self,
+        infer_schema_length: Option<usize>,
+    ) -> PolarsResult<(MemSlice, Option<StreamingDecompressor>)> {
+        let memslice = self.cached_bytes.clone().unwrap();
+
+        let Some(mut decompressor) = StreamingDecompressor::try_new(memslice.clone())? else {
+            return Ok((memslice, None));
+        };
+
+        let options = self.options.as_ref();
+        let parse_options = options.parse_options.as_ref();
+
+        // Lines needed before the data starts, plus the lines used for schema inference.
+        let n_lines_needed = infer_schema_length.map(|n| {
+            [
+                options.skip_lines,
+                options.skip_rows,
+                options.has_header as usize,
+                options.skip_rows_after_header,
+                n,
+                1,
+            ]
+            .into_iter()
+            .fold(0usize, usize::saturating_add)
+        });
+
+        let line_counter = CountLines::new(
+            parse_options.quote_char,
+            parse_options.eol_char,
+            parse_options.comment_prefix.clone(),
+        );
+
         let mut out = vec![];
-        maybe_decompress_bytes(self.cached_bytes.as_deref().unwrap(), &mut out)?;
 
-        if !out.is_empty() {
+        loop {
+            decompressor.read_chunk(&mut out, decompressed_chunk_size())?;
+
+            if decompressor.is_finished() {
+                break;
+            }
+
+            if let Some(n_lines_needed) = n_lines_needed {
+                let mut chunk_size = usize::MAX;
+                let (n_lines, _) = line_counter.find_next(&out, &mut chunk_size);
+
+                if n_lines >= n_lines_needed {
+                    break;
+                }
+            }
+        }
+
+        if self.verbose {
+            eprintln!(
+                "[CsvFileReader]: decompressed {} bytes, finished: {}",
+                out.len(),
+                decompressor.is_finished()
+            )
+        }
+
+        let decompressor = (!decompressor.is_finished()).then_some(decompressor);
+
+        // Cache the decompressed file so that it is only decompressed once if we are called again.
+        if decompressor.is_none() {
             self.cached_bytes = Some(MemSlice::from_vec(out));
+            return Ok((self.cached_bytes.clone().unwrap(), None));
         }
 
-        Ok(self.cached_bytes.clone().unwrap())
+        Ok((MemSlice::from_vec(out), decompressor))
     }
 }
 
 struct LineBatchSource {
     memslice: MemSlice,
+    /// Present if the file is compressed and `memslice` only holds the start of the file.
+    decompressor: Option<StreamingDecompressor>,
     line_counter: CountLines,
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     options: Arc<CsvReadOptions>,
@@ -427,7 +490,8 @@ impl LineBatchSource {
     /// Returns the number of rows skipped from the start of the file according to CountLines.
     async fn run(self) -> PolarsResult<usize> {
         let LineBatchSource {
-            memslice,
+            mut memslice,
+            mut decompressor,
             line_counter,
             mut line_batch_tx,
             options,
@@ -458,8 +522,6 @@ impl LineBatchSource {
             eprintln!("[CsvSource]: Start line splitting",);
         }
 
-        let global_bytes: &[u8] = memslice.as_ref();
-        let global_bytes: &'static [u8] = unsafe { std::mem::transmute(global_bytes) };
         let comment_prefix = options.parse_options.comment_prefix.as_ref();
 
         let parse_options = options.parse_options.as_ref();
@@ -475,7 +537,7 @@ impl LineBatchSource {
             let has_header = options.has_header;
 
             find_starting_point(
-                global_bytes,
+                &memslice,
                 quote_char,
                 eol_char,
                 file_schema_len,
@@ -487,14 +549,18 @@ impl LineBatchSource {
             )?
         };
 
-        let mut bytes = &global_bytes[i..];
+        // Start of the bytes in `memslice` that have not been split into line batches yet.
+        let mut offset = i;
 
         let mut chunk_size = {
             let max_chunk_size = 16 * 1024 * 1024;
             let chunk_size = if global_slice.is_some() {
                 max_chunk_size
+            } else if decompressor.is_some() {
+                // The file size is unknown, split each decompressed chunk across the pipelines.
+                std::cmp::min(decompressed_chunk_size() / num_pipelines, max_chunk_size)
             } else {
-                std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
+                std::cmp::min((memslice.len() - i) / (16 * num_pipelines), max_chunk_size)
             };
 
             // Use a small min chunk size to catch failures in tests.
@@ -505,12 +571,40 @@ impl LineBatchSource {
             std::cmp::max(chunk_size, min_chunk_size)
         };
 
+        // Set when the remaining bytes do not contain a complete line.
+        let mut needs_more_bytes = false;
+
         loop {
+            if let Some(decompressor_ref) = decompressor.as_mut() {
+                if needs_more_bytes || memslice.len() - offset < chunk_size {
+                    // Carry over the incomplete trailing line to the next buffer.
+                    let mut buf = memslice[offset..].to_vec();
+                    decompressor_ref
+                        .read_chunk(&mut buf, decompressed_chunk_size().max(chunk_size))?;
+
+                    if decompressor_ref.is_finished() {
+                        decompressor = None;
+                    }
+
+                    memslice = MemSlice::from_vec(buf);
+                    offset = 0;
+                    needs_more_bytes = false;
+                }
+            }
+
+            let bytes = &memslice[offset..];
+
             if bytes.is_empty() {
                 break;
             }
 
             let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
+
+            if count == 0 && decompressor.is_some() {
+                needs_more_bytes = true;
+                continue;
+            }
+
             let (count, position) = if count == 0 {
                 let c = if *bytes.last().unwrap() != eol_char
                     && !is_comment_line(
@@ -527,9 +621,9 @@ impl LineBatchSource {
                 (count, pos)
             };
 
-            let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
+            let bytes_this_chunk = memslice.slice(offset..offset + position);
 
-            bytes = &bytes[position..];
+            offset += position;
 
             let current_row_offset = *current_row_offset_ref;
             *current_row_offset_ref += count;
@@ -561,8 +655,6 @@ impl LineBatchSource {
                 NO_SLICE
             };
 
-            let bytes_this_chunk = &global_bytes[slice_start..slice_start + position];
-
             let morsel_seq = *morsel_seq_ref;
             *morsel_seq_ref = morsel_seq.successor();
 
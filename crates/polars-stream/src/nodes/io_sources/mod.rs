@@ -1,5 +1,7 @@
 pub mod multi_scan;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
@@ -9,3 +11,12 @@ pub mod ipc;
 pub mod ndjson;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+
+/// Size of the buffers that compressed CSV and NDJSON files are incrementally decompressed into.
+#[cfg(any(feature = "csv", feature = "json"))]
+fn decompressed_chunk_size() -> usize {
+    std::env::var("POLARS_FORCE_DECOMPRESSED_CHUNK_SIZE").map_or(16 * 1024 * 1024, |x| {
+        x.parse::<usize>()
+            .expect("expected `POLARS_FORCE_DECOMPRESSED_CHUNK_SIZE` to be an integer")
+    })
+}
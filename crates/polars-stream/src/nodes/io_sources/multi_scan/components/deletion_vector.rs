@@ -0,0 +1,431 @@
+//! Decoding of Delta Lake deletion vectors.
+//!
+//! A deletion vector is a `RoaringBitmapArray` of the (64-bit) positions of the deleted rows of a
+//! data file. It is either stored inline in the Delta log (Z85-encoded), or in a file at a given
+//! offset.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vector-format>.
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_plan::dsl::deletion::{DeletionVectorDescriptor, DeletionVectorStorageType};
+use polars_utils::plpath::PlPath;
+
+/// Magic number at the start of a serialized `RoaringBitmapArray`.
+const MAGIC_NUMBER: u32 = 1681511377;
+
+/// Number of characters of the Z85-encoded UUID of a deletion vector file.
+const ENCODED_UUID_LEN: usize = 20;
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Decodes a Z85-encoded string. The length of `encoded` must be a multiple of 5.
+pub fn z85_decode(encoded: &[u8]) -> PolarsResult<Vec<u8>> {
+    const INVALID: u8 = u8::MAX;
+    const DECODER: [u8; 256] = {
+        let mut decoder = [INVALID; 256];
+        let mut i = 0;
+        while i < Z85_ALPHABET.len() {
+            decoder[Z85_ALPHABET[i] as usize] = i as u8;
+            i += 1;
+        }
+        decoder
+    };
+
+    polars_ensure!(
+        encoded.len().is_multiple_of(5),
+        ComputeError: "invalid Z85 string: length {} is not a multiple of 5", encoded.len()
+    );
+
+    let mut out = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks_exact(5) {
+        let mut value: u64 = 0;
+        for &c in chunk {
+            let digit = DECODER[c as usize];
+            polars_ensure!(
+                digit != INVALID,
+                ComputeError: "invalid Z85 string: unexpected character {:?}", c as char
+            );
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value)
+            .map_err(|_| polars_err!(ComputeError: "invalid Z85 string: value out of range"))?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+
+    Ok(out)
+}
+
+/// Returns the path of the file of a deletion vector that is not stored inline.
+pub fn deletion_vector_path(
+    descriptor: &DeletionVectorDescriptor,
+    table_root: &str,
+) -> PolarsResult<PlPath> {
+    let path = descriptor.path_or_inline_dv.as_str();
+
+    match descriptor.storage_type {
+        DeletionVectorStorageType::AbsolutePath => Ok(PlPath::new(path)),
+        DeletionVectorStorageType::UuidRelativePath => {
+            let Some(prefix_len) = path.len().checked_sub(ENCODED_UUID_LEN) else {
+                polars_bail!(ComputeError: "invalid deletion vector path: {}", path)
+            };
+            let (prefix, encoded_uuid) = path.split_at(prefix_len);
+            let uuid = z85_decode(encoded_uuid.as_bytes())?;
+
+            let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+            let file_name = format!(
+                "deletion_vector_{}-{}-{}-{}-{}.bin",
+                hex(&uuid[0..4]),
+                hex(&uuid[4..6]),
+                hex(&uuid[6..8]),
+                hex(&uuid[8..10]),
+                hex(&uuid[10..16]),
+            );
+
+            let root = PlPath::new(table_root);
+            Ok(if prefix.is_empty() {
+                root.as_ref().join(file_name)
+            } else {
+                root.as_ref().join(prefix).as_ref().join(file_name)
+            })
+        },
+        DeletionVectorStorageType::Inline => {
+            polars_bail!(ComputeError: "inline deletion vectors are not stored in a file")
+        },
+    }
+}
+
+/// Returns the serialized deletion vector of an inline deletion vector.
+pub fn decode_inline_deletion_vector(
+    descriptor: &DeletionVectorDescriptor,
+) -> PolarsResult<Vec<u8>> {
+    let mut bytes = z85_decode(descriptor.path_or_inline_dv.as_bytes())?;
+    let size = descriptor.size_in_bytes as usize;
+    polars_ensure!(
+        size <= bytes.len(),
+        ComputeError: "inline deletion vector is truncated: expected {} bytes, found {}",
+        size, bytes.len()
+    );
+    bytes.truncate(size);
+    Ok(bytes)
+}
+
+/// Returns the serialized deletion vector from the bytes of the file it is stored in, starting at
+/// its offset.
+///
+/// In a file, each deletion vector is prefixed by its size and followed by its checksum.
+pub fn strip_deletion_vector_size_prefix<'a>(
+    descriptor: &DeletionVectorDescriptor,
+    bytes: &'a [u8],
+) -> PolarsResult<&'a [u8]> {
+    let size = descriptor.size_in_bytes as usize;
+    let stored_size = bytes
+        .get(..4)
+        .map(|b| u32::from_be_bytes(b.try_into().unwrap()) as usize);
+    polars_ensure!(
+        stored_size == Some(size) && bytes.len() >= 4 + size,
+        ComputeError: "deletion vector in '{}' does not match its size of {} bytes",
+        descriptor.path_or_inline_dv, size
+    );
+    Ok(&bytes[4..4 + size])
+}
+
+/// Decodes a serialized deletion vector into a filter mask, in which the deleted rows are unset.
+///
+/// The mask ends at the last deleted row. The vector is validated against the `cardinality` of
+/// its descriptor and, if known, the number of rows in the data file.
+pub fn deletion_vector_to_mask(
+    bytes: &[u8],
+    cardinality: u64,
+    n_rows_in_file: Option<usize>,
+) -> PolarsResult<Bitmap> {
+    let mut reader = Reader(bytes);
+
+    polars_ensure!(
+        reader.u32()? == MAGIC_NUMBER,
+        ComputeError: "invalid deletion vector: unexpected magic number"
+    );
+
+    let num_bitmaps = reader.u64()?;
+    let mut positions = Vec::new();
+    let mut prev_key = None;
+
+    for _ in 0..num_bitmaps {
+        let key = reader.u32()?;
+        polars_ensure!(
+            prev_key.is_none_or(|prev| prev < key),
+            ComputeError: "invalid deletion vector: bitmaps are not sorted"
+        );
+        prev_key = Some(key);
+        read_roaring_bitmap(&mut reader, (key as u64) << 32, &mut positions)?;
+    }
+
+    polars_ensure!(
+        positions.len() as u64 == cardinality,
+        ComputeError: "invalid deletion vector: contains {} rows, expected {}",
+        positions.len(), cardinality
+    );
+
+    let len = positions.iter().max().map_or(0, |&p| p + 1);
+    if let Some(n_rows) = n_rows_in_file {
+        polars_ensure!(
+            len <= n_rows as u64,
+            ComputeError: "invalid deletion vector: deleted row {} is out of bounds for a file of {} rows",
+            len - 1, n_rows
+        );
+    }
+    let len = usize::try_from(len)
+        .map_err(|_| polars_err!(ComputeError: "deletion vector position out of range"))?;
+
+    let mut mask = MutableBitmap::from_len_set(len);
+    for p in positions {
+        mask.set(p as usize, false);
+    }
+
+    Ok(mask.freeze())
+}
+
+/// Reads a 32-bit roaring bitmap in the portable serialization format, pushing its values (offset
+/// by `high_bits`) to `out` in ascending order.
+///
+/// See <https://github.com/RoaringBitmap/RoaringFormatSpec>.
+fn read_roaring_bitmap(
+    reader: &mut Reader,
+    high_bits: u64,
+    out: &mut Vec<u64>,
+) -> PolarsResult<()> {
+    const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+    const SERIAL_COOKIE: u32 = 12347;
+    const NO_OFFSET_THRESHOLD: usize = 4;
+    const MAX_ARRAY_CARDINALITY: usize = 4096;
+
+    let cookie = reader.u32()?;
+    let (num_containers, run_flags, has_offsets) = if cookie & 0xFFFF == SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        let run_flags = reader.bytes(num_containers.div_ceil(8))?;
+        (
+            num_containers,
+            Some(run_flags),
+            num_containers >= NO_OFFSET_THRESHOLD,
+        )
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None, true)
+    } else {
+        polars_bail!(ComputeError: "invalid deletion vector: unexpected roaring bitmap cookie")
+    };
+
+    // Each description takes 4 bytes, which bounds the number of containers by the data size.
+    let mut descriptions = Vec::with_capacity(num_containers.min(reader.0.len() / 4));
+    for _ in 0..num_containers {
+        descriptions.push((reader.u16()?, reader.u16()? as usize + 1));
+    }
+
+    if has_offsets {
+        reader.bytes(4 * num_containers)?;
+    }
+
+    for (i, (key, cardinality)) in descriptions.into_iter().enumerate() {
+        let base = high_bits | ((key as u64) << 16);
+        let is_run = run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+
+        if is_run {
+            let num_runs = reader.u16()?;
+            for _ in 0..num_runs {
+                let start = reader.u16()? as u64;
+                let len = reader.u16()? as u64;
+                out.extend((start..=start + len).map(|v| base | v));
+            }
+        } else if cardinality <= MAX_ARRAY_CARDINALITY {
+            for _ in 0..cardinality {
+                out.push(base | reader.u16()? as u64);
+            }
+        } else {
+            for word_idx in 0..1024u64 {
+                let mut word = reader.u64()?;
+                while word != 0 {
+                    let bit = word.trailing_zeros() as u64;
+                    out.push(base | (word_idx * 64 + bit));
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            n <= self.0.len(),
+            ComputeError: "invalid deletion vector: unexpected end of data"
+        );
+        let (bytes, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn z85_encode(bytes: &[u8]) -> String {
+        bytes
+            .chunks(4)
+            .flat_map(|chunk| {
+                let mut value = u32::from_be_bytes(chunk.try_into().unwrap()) as u64;
+                let mut out = [0u8; 5];
+                for c in out.iter_mut().rev() {
+                    *c = Z85_ALPHABET[(value % 85) as usize];
+                    value /= 85;
+                }
+                out
+            })
+            .map(char::from)
+            .collect()
+    }
+
+    /// Serializes a `RoaringBitmapArray` with a single bitmap of array containers.
+    fn serialize(key: u32, values: &[u32]) -> Vec<u8> {
+        let mut containers = Vec::<(u16, Vec<u16>)>::new();
+        for &v in values {
+            let (high, low) = ((v >> 16) as u16, v as u16);
+            match containers.last_mut() {
+                Some((k, c)) if *k == high => c.push(low),
+                _ => containers.push((high, vec![low])),
+            }
+        }
+
+        let mut out = vec![];
+        out.extend_from_slice(&MAGIC_NUMBER.to_le_bytes());
+        out.extend_from_slice(&1u64.to_le_bytes());
+        out.extend_from_slice(&key.to_le_bytes());
+        out.extend_from_slice(&12346u32.to_le_bytes());
+        out.extend_from_slice(&(containers.len() as u32).to_le_bytes());
+        for (k, c) in &containers {
+            out.extend_from_slice(&k.to_le_bytes());
+            out.extend_from_slice(&(c.len() as u16 - 1).to_le_bytes());
+        }
+        // Offsets are not read.
+        out.extend(std::iter::repeat_n(0, 4 * containers.len()));
+        for (_, c) in &containers {
+            for v in c {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_z85_decode() {
+        // Example from the Z85 specification.
+        let decoded = z85_decode(b"HelloWorld").unwrap();
+        assert_eq!(decoded, [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]);
+        assert_eq!(z85_encode(&decoded), "HelloWorld");
+
+        assert!(z85_decode(b"Hello").is_ok());
+        assert!(z85_decode(b"Hell").is_err());
+        assert!(z85_decode(b"Hell\"").is_err());
+    }
+
+    #[test]
+    fn test_deletion_vector_to_mask() {
+        let mask = deletion_vector_to_mask(&serialize(0, &[1, 3, 4]), 3, Some(5)).unwrap();
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            [true, false, true, false, false]
+        );
+
+        let mask = deletion_vector_to_mask(&serialize(0, &[0, 70_000]), 2, None).unwrap();
+        assert_eq!(mask.len(), 70_001);
+        assert_eq!(mask.unset_bits(), 2);
+        assert!(!mask.get_bit(0) && !mask.get_bit(70_000));
+
+        let mask = deletion_vector_to_mask(&serialize(0, &[]), 0, Some(0)).unwrap();
+        assert!(mask.is_empty());
+
+        assert!(deletion_vector_to_mask(&[0; 12], 0, None).is_err());
+        assert!(deletion_vector_to_mask(&serialize(0, &[1, 2])[..20], 2, None).is_err());
+
+        // The number of rows must match the cardinality of the descriptor.
+        assert!(deletion_vector_to_mask(&serialize(0, &[1, 2]), 3, None).is_err());
+        assert!(deletion_vector_to_mask(&serialize(0, &[1, 2]), 1, None).is_err());
+
+        // The positions must be within the data file.
+        assert!(deletion_vector_to_mask(&serialize(0, &[1, 2]), 2, Some(3)).is_ok());
+        assert!(deletion_vector_to_mask(&serialize(0, &[1, 2]), 2, Some(2)).is_err());
+        assert!(deletion_vector_to_mask(&serialize(1, &[0]), 1, Some(1 << 20)).is_err());
+    }
+
+    #[test]
+    fn test_decode_inline_deletion_vector() {
+        let bytes = serialize(0, &[2]);
+        let mut padded = bytes.clone();
+        padded.resize(bytes.len().next_multiple_of(4), 0);
+
+        let descriptor = DeletionVectorDescriptor {
+            storage_type: DeletionVectorStorageType::Inline,
+            path_or_inline_dv: z85_encode(&padded).into(),
+            offset: None,
+            size_in_bytes: bytes.len() as u32,
+            cardinality: 1,
+        };
+
+        let decoded = decode_inline_deletion_vector(&descriptor).unwrap();
+        assert_eq!(decoded, bytes);
+        assert_eq!(
+            deletion_vector_to_mask(&decoded, descriptor.cardinality, None)
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            [true, true, false]
+        );
+    }
+
+    #[test]
+    fn test_deletion_vector_path() {
+        let uuid = [
+            0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc,
+            0xde, 0xf0,
+        ];
+        let descriptor = |path: String| DeletionVectorDescriptor {
+            storage_type: DeletionVectorStorageType::UuidRelativePath,
+            path_or_inline_dv: path.into(),
+            offset: Some(1),
+            size_in_bytes: 0,
+            cardinality: 0,
+        };
+
+        let path =
+            deletion_vector_path(&descriptor(z85_encode(&uuid)), "s3://bucket/table").unwrap();
+        assert_eq!(
+            path.to_str(),
+            "s3://bucket/table/deletion_vector_12345678-9abc-def0-1234-56789abcdef0.bin"
+        );
+
+        let path = deletion_vector_path(
+            &descriptor(format!("ab{}", z85_encode(&uuid))),
+            "/data/table",
+        )
+        .unwrap();
+        assert_eq!(
+            path.to_str(),
+            "/data/table/ab/deletion_vector_12345678-9abc-def0-1234-56789abcdef0.bin"
+        );
+    }
+}
@@ -3,6 +3,8 @@ pub mod apply_extra_ops;
 pub mod bridge;
 pub mod column_selector;
 pub mod default_field_values;
+#[cfg(feature = "parquet")]
+pub mod deletion_vector;
 pub mod errors;
 pub mod forbid_extra_columns;
 pub mod physical_slice;
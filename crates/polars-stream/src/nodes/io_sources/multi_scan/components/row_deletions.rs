@@ -9,6 +9,8 @@ use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_error::{PolarsResult, feature_gated};
 use polars_io::cloud::CloudOptions;
 use polars_plan::dsl::deletion::DeletionFilesList;
+#[cfg(feature = "parquet")]
+use polars_plan::dsl::deletion::{DeletionVectorDescriptor, IcebergEqualityDeletes};
 use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
@@ -32,6 +34,19 @@ pub enum DeletionFilesProvider {
         reader_builder: ParquetReaderBuilder,
         projected_schema: SchemaRef,
     },
+
+    #[cfg(feature = "parquet")]
+    IcebergEqualityDelete {
+        deletes: Arc<PlIndexMap<usize, IcebergEqualityDeletes>>,
+        // Amortized allocations
+        reader_builder: ParquetReaderBuilder,
+    },
+
+    #[cfg(feature = "parquet")]
+    DeltaDeletionVector {
+        table_root: PlSmallStr,
+        vectors: Arc<PlIndexMap<usize, DeletionVectorDescriptor>>,
+    },
 }
 
 impl DeletionFilesProvider {
@@ -56,6 +71,7 @@ impl DeletionFilesProvider {
                             parallel: polars_io::prelude::ParallelStrategy::Auto,
                             low_memory: false,
                             use_statistics: false,
+                            key_retriever: None,
                         }),
                     },
                     projected_schema: Arc::new(Schema::from_iter([
@@ -64,12 +80,42 @@ impl DeletionFilesProvider {
                     ])),
                 }
             ),
+            DeletionFilesList::IcebergEqualityDelete(deletes) => feature_gated!(
+                "parquet",
+                Self::IcebergEqualityDelete {
+                    deletes,
+                    reader_builder: ParquetReaderBuilder {
+                        first_metadata: None,
+                        options: Arc::new(polars_io::prelude::ParquetOptions {
+                            schema: None,
+                            parallel: polars_io::prelude::ParallelStrategy::Auto,
+                            low_memory: false,
+                            use_statistics: false,
+                            key_retriever: None,
+                        }),
+                    },
+                }
+            ),
+            DeletionFilesList::DeltaDeletionVector {
+                table_root,
+                vectors,
+            } => feature_gated!(
+                "parquet",
+                Self::DeltaDeletionVector {
+                    table_root,
+                    vectors
+                }
+            ),
         }
     }
 
+    /// `n_rows_in_file` is the number of physical rows in the data file, if known. It is used to
+    /// validate the deletion files.
     pub fn spawn_row_deletions_init(
         &self,
         scan_source_idx: usize,
+        scan_source: &ScanSource,
+        n_rows_in_file: Option<usize>,
         cloud_options: Option<Arc<CloudOptions>>,
         num_pipelines: usize,
         verbose: bool,
@@ -244,10 +290,361 @@ impl DeletionFilesProvider {
 
                 Some(RowDeletionsInit::Initializing(handle))
             },
+
+            #[cfg(feature = "parquet")]
+            Self::IcebergEqualityDelete {
+                deletes,
+                reader_builder,
+            } => {
+                use polars_core::chunked_array::ops::row_encode::encode_rows_unordered;
+                use polars_utils::aliases::{PlHashSet, PlIndexSet};
+
+                let deletes = deletes.get(&scan_source_idx)?;
+
+                // Positional deletes are loaded separately and combined at the end.
+                let position_deletes = (!deletes.position_delete_files.is_empty()).then(|| {
+                    Self::new(Some(DeletionFilesList::IcebergPositionDelete(Arc::new(
+                        PlIndexMap::from_iter([(
+                            scan_source_idx,
+                            deletes.position_delete_files.clone(),
+                        )]),
+                    ))))
+                    .spawn_row_deletions_init(
+                        scan_source_idx,
+                        scan_source,
+                        n_rows_in_file,
+                        cloud_options.clone(),
+                        num_pipelines,
+                        verbose,
+                    )
+                    .unwrap()
+                });
+
+                // Equality deletes only apply to data files that were written before them.
+                let equality_delete_files = deletes
+                    .equality_delete_files
+                    .iter()
+                    .filter(|file| file.applies_to(deletes.data_sequence_number))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if verbose {
+                    eprintln!(
+                        "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {}, \
+                        data_sequence_number: {}, {} equality delete files \
+                        ({} skipped by sequence number), {} position delete files",
+                        scan_source_idx,
+                        deletes.data_sequence_number,
+                        equality_delete_files.len(),
+                        deletes.equality_delete_files.len() - equality_delete_files.len(),
+                        deletes.position_delete_files.len(),
+                    )
+                }
+
+                if equality_delete_files.is_empty() {
+                    return position_deletes;
+                }
+
+                // The rows to delete are identified by the values of their key columns, so these
+                // are read from both the delete files and the data file.
+                let delete_dfs = equality_delete_files
+                    .iter()
+                    .enumerate()
+                    .map(|(deletion_file_idx, file)| {
+                        let reader = reader_builder.build_file_reader(
+                            ScanSource::Path(PlPath::new(&file.path)),
+                            cloud_options.clone(),
+                            deletion_file_idx,
+                        );
+
+                        if verbose {
+                            eprintln!(
+                                "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {scan_source_idx}, \
+                                deletion_file_idx: {deletion_file_idx}, \
+                                deletion_file_path: {}, equality_ids: {:?}",
+                                file.path, file.equality_ids,
+                            )
+                        }
+
+                        let field_ids = file.equality_ids.clone();
+                        AbortOnDropHandle::new(async_executor::spawn(
+                            TaskPriority::Low,
+                            async move { read_file_columns(reader, &field_ids, num_pipelines).await },
+                        ))
+                    })
+                    .collect::<Vec<_>>();
+
+                let key_ids = equality_delete_files
+                    .iter()
+                    .flat_map(|file| file.equality_ids.iter().copied())
+                    .collect::<PlIndexSet<_>>();
+                let data_reader =
+                    reader_builder.build_file_reader(scan_source.clone(), cloud_options, 0);
+                let data_keys = {
+                    let key_ids = key_ids.iter().copied().collect::<Vec<_>>();
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        read_file_columns(data_reader, &key_ids, num_pipelines).await
+                    }))
+                };
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let data_keys = data_keys.await?;
+                        let mut filter_mask = MutableBitmap::from_len_set(data_keys.height());
+
+                        // Anti-join the data file with each of the delete files. Nulls are equal
+                        // to each other in equality deletes, as they are in the row encoding.
+                        for (file, handle) in equality_delete_files.iter().zip(delete_dfs) {
+                            let delete_df = handle.await?;
+
+                            let mut data_columns = Vec::with_capacity(file.equality_ids.len());
+                            let mut delete_columns = Vec::with_capacity(file.equality_ids.len());
+                            for (field_id, delete_column) in
+                                file.equality_ids.iter().zip(delete_df.get_columns())
+                            {
+                                let data_column = &data_keys.get_columns()
+                                    [key_ids.get_index_of(field_id).unwrap()];
+                                delete_columns.push(delete_column.cast(data_column.dtype())?);
+                                data_columns.push(data_column.clone());
+                            }
+
+                            let deleted_rows = encode_rows_unordered(&delete_columns)?;
+                            let deleted_rows = deleted_rows
+                                .downcast_iter()
+                                .flat_map(|arr| arr.values_iter())
+                                .collect::<PlHashSet<_>>();
+                            if deleted_rows.is_empty() {
+                                continue;
+                            }
+
+                            let data_rows = encode_rows_unordered(&data_columns)?;
+                            let data_rows =
+                                data_rows.downcast_iter().flat_map(|arr| arr.values_iter());
+                            for (idx, row) in data_rows.enumerate() {
+                                if deleted_rows.contains(row) {
+                                    filter_mask.set(idx, false);
+                                }
+                            }
+                        }
+
+                        if let Some(position_deletes) = position_deletes {
+                            let position_mask = position_deletes
+                                .into_external_filter_mask()
+                                .await?
+                                .get_mask();
+
+                            for (idx, keep) in position_mask.iter().enumerate() {
+                                if !keep && idx < filter_mask.len() {
+                                    filter_mask.set(idx, false);
+                                }
+                            }
+                        }
+
+                        let bitmap = filter_mask.freeze();
+
+                        // Also trigger the bitcount to reduce blocking later down.
+                        bitmap.unset_bits();
+                        debug_assert!(bitmap.lazy_unset_bits().is_some());
+
+                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+                        let mask = ExternalFilterMask::IcebergEqualityDelete { mask };
+
+                        if verbose {
+                            let num_deleted_rows = mask.num_deleted_rows();
+
+                            eprintln!(
+                                "[DeletionFilesProvider[Iceberg]]: \
+                                scan_source_idx: {scan_source_idx}, \
+                                num_deleted_rows: {num_deleted_rows}",
+                            )
+                        }
+
+                        Ok(mask)
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
+
+            #[cfg(feature = "parquet")]
+            Self::DeltaDeletionVector {
+                table_root,
+                vectors,
+            } => {
+                use polars_error::polars_ensure;
+                use polars_io::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+                use polars_plan::dsl::deletion::DeletionVectorStorageType;
+
+                use crate::nodes::io_sources::multi_scan::components::deletion_vector::{
+                    decode_inline_deletion_vector, deletion_vector_path, deletion_vector_to_mask,
+                    strip_deletion_vector_size_prefix,
+                };
+
+                let descriptor = vectors.get(&scan_source_idx)?.clone();
+                let table_root = table_root.clone();
+
+                if verbose {
+                    eprintln!(
+                        "[DeletionFilesProvider[Delta]]: scan_source_idx: {}, \
+                        storage_type: {}, cardinality: {}",
+                        scan_source_idx,
+                        descriptor.storage_type.delta_code(),
+                        descriptor.cardinality,
+                    )
+                }
+
+                let handle = AbortOnDropHandle::new(async_executor::spawn(
+                    TaskPriority::Low,
+                    async move {
+                        let bitmap = if descriptor.storage_type == DeletionVectorStorageType::Inline
+                        {
+                            deletion_vector_to_mask(
+                                &decode_inline_deletion_vector(&descriptor)?,
+                                descriptor.cardinality,
+                                n_rows_in_file,
+                            )?
+                        } else {
+                            let path = deletion_vector_path(&descriptor, &table_root)?;
+
+                            if verbose {
+                                eprintln!(
+                                    "[DeletionFilesProvider[Delta]]: scan_source_idx: {scan_source_idx}, \
+                                    deletion_vector_path: {}",
+                                    path.to_str()
+                                )
+                            }
+
+                            let byte_source_builder =
+                                if path.is_cloud_url() || polars_core::config::force_async() {
+                                    DynByteSourceBuilder::ObjectStore
+                                } else {
+                                    DynByteSourceBuilder::Mmap
+                                };
+                            let byte_source = byte_source_builder
+                                .try_build_from_path(path.as_ref(), cloud_options.as_deref())
+                                .await?;
+
+                            // The file starts with a format version byte, which is skipped when
+                            // there is no offset.
+                            let offset = descriptor.offset.map_or(1, |x| x as usize);
+                            let end = offset + 4 + descriptor.size_in_bytes as usize;
+                            let file_size = byte_source.get_size().await?;
+                            polars_ensure!(
+                                end <= file_size,
+                                ComputeError: "deletion vector file '{}' is truncated", path.to_str()
+                            );
+
+                            let bytes = byte_source.get_range(offset..end).await?;
+                            deletion_vector_to_mask(
+                                strip_deletion_vector_size_prefix(&descriptor, &bytes)?,
+                                descriptor.cardinality,
+                                n_rows_in_file,
+                            )?
+                        };
+
+                        polars_ensure!(
+                            bitmap.unset_bits() as u64 == descriptor.cardinality,
+                            ComputeError: "deletion vector of scan source {} has {} deleted rows, \
+                            expected {}",
+                            scan_source_idx, bitmap.unset_bits(), descriptor.cardinality
+                        );
+
+                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+                        let mask = ExternalFilterMask::DeltaDeletionVector { mask };
+
+                        if verbose {
+                            let num_deleted_rows = mask.num_deleted_rows();
+                            let max_index = mask.len().checked_sub(1);
+
+                            eprintln!(
+                                "[DeletionFilesProvider[Delta]]: \
+                                scan_source_idx: {scan_source_idx}, \
+                                num_deleted_rows: {num_deleted_rows}, \
+                                max_index: {max_index:?}",
+                            )
+                        }
+
+                        Ok(mask)
+                    },
+                ));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
         }
     }
 }
 
+/// Reads the columns with the Iceberg `field_ids` of the file of `reader` into a single
+/// `DataFrame`, in the order of `field_ids`.
+#[cfg(feature = "parquet")]
+async fn read_file_columns(
+    mut reader: Box<dyn crate::nodes::io_sources::multi_scan::reader_interface::FileReader>,
+    field_ids: &[u32],
+    num_pipelines: usize,
+) -> PolarsResult<DataFrame> {
+    use polars_core::schema::iceberg::IcebergSchema;
+    use polars_core::utils::accumulate_dataframes_vertical_unchecked_optional;
+    use polars_error::polars_err;
+
+    use crate::nodes::io_sources::multi_scan::components::projection::Projection;
+
+    reader.initialize().await?;
+
+    // Columns may have been renamed since the file was written, so they are looked up by the
+    // field ID stored in the file.
+    let file_iceberg_schema = reader
+        .file_arrow_schema()
+        .await?
+        .map(|x| IcebergSchema::from_arrow_schema(x.as_ref()))
+        .transpose()?
+        .ok_or_else(
+            || polars_err!(ComputeError: "equality deletes require a file with field IDs"),
+        )?;
+    let file_schema = reader.file_schema().await?;
+    let projected_schema = field_ids
+        .iter()
+        .map(|field_id| {
+            let name = &file_iceberg_schema
+                .get(field_id)
+                .ok_or_else(|| {
+                    polars_err!(
+                        ColumnNotFound:
+                        "equality delete field ID {} not found in file",
+                        field_id,
+                    )
+                })?
+                .name;
+            Ok((name.clone(), file_schema.get(name).unwrap().clone()))
+        })
+        .collect::<PolarsResult<Schema>>()?;
+    let projected_schema = Arc::new(projected_schema);
+
+    let (mut rx, handle) = reader.begin_read(BeginReadArgs {
+        projection: Projection::Plain(projected_schema.clone()),
+        row_index: None,
+        pre_slice: None,
+        predicate: None,
+        cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+        num_pipelines,
+        callbacks: FileReaderCallbacks {
+            file_schema_tx: None,
+            n_rows_in_file_tx: None,
+            row_position_on_end_tx: None,
+        },
+    })?;
+
+    let mut dfs = vec![];
+
+    while let Ok(morsel) = rx.recv().await {
+        dfs.push(morsel.into_df());
+    }
+
+    handle.await?;
+
+    accumulate_dataframes_vertical_unchecked_optional(dfs)
+        .unwrap_or_else(|| DataFrame::empty_with_schema(&projected_schema))
+        .select(projected_schema.iter_names_cloned())
+}
+
 pub enum RowDeletionsInit {
     Initializing(AbortOnDropHandle<PolarsResult<ExternalFilterMask>>),
 
@@ -270,7 +667,19 @@ impl RowDeletionsInit {
 #[derive(Debug, Clone)]
 pub enum ExternalFilterMask {
     /// Note: Iceberg positional deletes can have a mask length shorter than the actual data.
-    IcebergPositionDelete { mask: BooleanChunked },
+    IcebergPositionDelete {
+        mask: BooleanChunked,
+    },
+
+    IcebergEqualityDelete {
+        mask: BooleanChunked,
+    },
+
+    /// Note: The mask of a Delta deletion vector ends at the last deleted row, so it can also be
+    /// shorter than the actual data.
+    DeltaDeletionVector {
+        mask: BooleanChunked,
+    },
 }
 
 impl ExternalFilterMask {
@@ -278,6 +687,8 @@ impl ExternalFilterMask {
         use ExternalFilterMask::*;
         match self {
             IcebergPositionDelete { .. } => "IcebergPositionDelete",
+            IcebergEqualityDelete { .. } => "IcebergEqualityDelete",
+            DeltaDeletionVector { .. } => "DeltaDeletionVector",
         }
     }
 
@@ -296,7 +707,9 @@ impl ExternalFilterMask {
 
     pub fn filter_df(&self, df: &mut DataFrame) -> PolarsResult<()> {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergEqualityDelete { mask }
+            | Self::DeltaDeletionVector { mask } => {
                 if !mask.is_empty() {
                     *df = if mask.len() < df.height() {
                         accumulate_dataframes_vertical_unchecked([
@@ -314,23 +727,33 @@ impl ExternalFilterMask {
     }
 
     pub fn slice(&self, offset: usize, len: usize) -> Self {
-        match self {
-            Self::IcebergPositionDelete { mask } => {
-                // This is not a valid offset, it's also a sentinel value from `RowCounter::MAX`.
-                assert_ne!(offset, usize::MAX);
-                let offset = offset.min(mask.len());
-                let len = len.min(mask.len() - offset);
+        let slice_mask = |mask: &BooleanChunked| {
+            // This is not a valid offset, it's also a sentinel value from `RowCounter::MAX`.
+            assert_ne!(offset, usize::MAX);
+            let offset = offset.min(mask.len());
+            let len = len.min(mask.len() - offset);
 
-                let mask = mask.slice(i64::try_from(offset).unwrap(), len);
+            mask.slice(i64::try_from(offset).unwrap(), len)
+        };
 
-                Self::IcebergPositionDelete { mask }
+        match self {
+            Self::IcebergPositionDelete { mask } => Self::IcebergPositionDelete {
+                mask: slice_mask(mask),
+            },
+            Self::IcebergEqualityDelete { mask } => Self::IcebergEqualityDelete {
+                mask: slice_mask(mask),
+            },
+            Self::DeltaDeletionVector { mask } => Self::DeltaDeletionVector {
+                mask: slice_mask(mask),
             },
         }
     }
 
     pub fn num_deleted_rows(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergEqualityDelete { mask }
+            | Self::DeltaDeletionVector { mask } => mask
                 .rechunk()
                 .downcast_get(0)
                 .unwrap()
@@ -387,7 +810,9 @@ impl ExternalFilterMask {
 
     fn get_mask(&self) -> Bitmap {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergEqualityDelete { mask }
+            | Self::DeltaDeletionVector { mask } => {
                 mask.rechunk().downcast_get(0).unwrap().values().clone()
             },
         }
@@ -395,7 +820,9 @@ impl ExternalFilterMask {
 
     pub fn len(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask.len(),
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergEqualityDelete { mask }
+            | Self::DeltaDeletionVector { mask } => mask.len(),
         }
     }
 }
@@ -89,32 +89,30 @@ async fn resolve_negative_slice(config: &MultiScanConfig) -> PolarsResult<Resolv
             let deletion_files_provider = deletion_files_provider.clone();
 
             AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
-                let mut reader =
-                    sources
-                        .get(scan_source_idx)
-                        .unwrap()
-                        .into_owned()
-                        .map(|source| {
-                            file_reader_builder.build_file_reader(
-                                source,
-                                cloud_options.clone(),
-                                scan_source_idx,
-                            )
-                        })?;
+                let source = sources.get(scan_source_idx).unwrap().into_owned()?;
+                let mut reader = file_reader_builder.build_file_reader(
+                    source.clone(),
+                    cloud_options.clone(),
+                    scan_source_idx,
+                );
 
                 if verbose {
                     eprintln!("resolve_negative_slice(): init scan source {scan_source_idx}");
                 }
 
+                reader.initialize().await?;
+                let n_rows = reader.n_rows_in_file().await?;
+
                 let row_deletions = deletion_files_provider.spawn_row_deletions_init(
                     scan_source_idx,
+                    &source,
+                    Some(n_rows as usize),
                     cloud_options,
                     num_pipelines,
                     verbose,
                 );
 
-                reader.initialize().await?;
-                PolarsResult::Ok((scan_source_idx, reader, row_deletions))
+                PolarsResult::Ok((scan_source_idx, reader, n_rows, row_deletions))
             }))
         })
         .buffered(config.n_readers_pre_init());
@@ -125,11 +123,9 @@ async fn resolve_negative_slice(config: &MultiScanConfig) -> PolarsResult<Resolv
     let mut n_rows_trimmed: RowCounter = RowCounter::default();
     let mut n_files_from_end: usize = 0;
 
-    while let Some((scan_source_idx, mut file_reader, row_deletions)) =
+    while let Some((scan_source_idx, file_reader, n_rows, row_deletions)) =
         readers_init_iter.next().await.transpose()?
     {
-        let n_rows = file_reader.n_rows_in_file().await?;
-
         let n_rows_deleted = if let Some(row_deletions) = row_deletions {
             let mask = row_deletions.into_external_filter_mask().await?;
             let n_rows_deleted = mask.num_deleted_rows();
@@ -203,11 +199,9 @@ async fn resolve_negative_slice(config: &MultiScanConfig) -> PolarsResult<Resolv
         let mut n_rows_skipped_from_start = RowCounter::default();
 
         // Fully traverse to the beginning to update the row index offset.
-        while let Some((_scan_source_idx, mut reader, row_deletions)) =
+        while let Some((_scan_source_idx, _reader, n_rows, row_deletions)) =
             readers_init_iter.next().await.transpose()?
         {
-            let n_rows = reader.n_rows_in_file().await?;
-
             let row_deletions = if let Some(row_deletions) = row_deletions {
                 Some(row_deletions.into_external_filter_mask().await?)
             } else {
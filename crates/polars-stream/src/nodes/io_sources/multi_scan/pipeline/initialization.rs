@@ -330,6 +330,8 @@ async fn finish_initialize_multi_scan_pipeline(
                         .or_else(|| {
                             deletion_files_provider.spawn_row_deletions_init(
                                 scan_source_idx,
+                                &scan_source,
+                                n_rows_in_file.map(|x| x.num_physical_rows()),
                                 cloud_options,
                                 num_pipelines,
                                 verbose,
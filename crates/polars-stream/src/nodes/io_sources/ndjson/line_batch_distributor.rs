@@ -1,6 +1,7 @@
 use polars_core::config;
 use polars_error::PolarsResult;
 use polars_io::prelude::json_lines;
+use polars_io::utils::compression::StreamingDecompressor;
 use polars_utils::idx_mapper::IdxMapper;
 use polars_utils::mmap::MemSlice;
 
@@ -9,6 +10,8 @@ use crate::async_primitives::distributor_channel;
 
 pub(super) struct LineBatchDistributor {
     pub(super) global_bytes: MemSlice,
+    /// If set, lines are read from this instead of from `global_bytes`.
+    pub(super) decompressor: Option<StreamingDecompressor>,
     pub(super) chunk_size: usize,
     pub(super) n_rows_to_skip: usize,
     pub(super) reverse: bool,
@@ -20,15 +23,26 @@ impl LineBatchDistributor {
     pub(super) async fn run(self) -> PolarsResult<usize> {
         let LineBatchDistributor {
             global_bytes: global_bytes_mem_slice,
+            decompressor,
             chunk_size,
             n_rows_to_skip,
             reverse,
             mut line_batch_distribute_tx,
         } = self;
 
-        // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-        let global_bytes: &'static [u8] =
-            unsafe { std::mem::transmute(global_bytes_mem_slice.as_ref()) };
+        if let Some(decompressor) = decompressor {
+            assert!(!reverse);
+
+            return run_decompress(
+                decompressor,
+                chunk_size,
+                n_rows_to_skip,
+                line_batch_distribute_tx,
+            )
+            .await;
+        }
+
+        let global_bytes: &[u8] = global_bytes_mem_slice.as_ref();
         let n_chunks = global_bytes.len().div_ceil(chunk_size);
         let verbose = config::verbose();
 
@@ -53,7 +67,7 @@ impl LineBatchDistributor {
         // The logic below processes in fixed chunks with remainder handling so that in the future
         // we can handle receiving data in a batched manner.
 
-        let mut prev_remainder: &'static [u8] = &[];
+        let mut prev_remainder: &[u8] = &[];
 
         let global_idx_map = IdxMapper::new(global_bytes.len(), reverse);
 
@@ -106,7 +120,7 @@ impl LineBatchDistributor {
                 if !full_chunk.is_empty()
                     && line_batch_distribute_tx
                         .send(LineBatch {
-                            bytes: full_chunk,
+                            bytes: subslice(&global_bytes_mem_slice, full_chunk),
                             chunk_idx,
                         })
                         .await
@@ -138,6 +152,85 @@ impl LineBatchDistributor {
     }
 }
 
+/// Reads line batches from a decompressor. The chunks are decompressed one at a time, so that the
+/// full decompressed file is never held in memory.
+async fn run_decompress(
+    mut decompressor: StreamingDecompressor,
+    chunk_size: usize,
+    n_rows_to_skip: usize,
+    mut line_batch_distribute_tx: distributor_channel::Sender<LineBatch>,
+) -> PolarsResult<usize> {
+    let verbose = config::verbose();
+
+    if verbose {
+        eprintln!(
+            "\
+            [NDJSON LineBatchDistributor]: \
+            streaming decompression, \
+            chunk_size: {chunk_size}, \
+            n_rows_to_skip: {n_rows_to_skip}\
+            "
+        )
+    }
+
+    let mut row_skipper = RowSkipper {
+        remaining_rows_to_skip: n_rows_to_skip,
+        reverse: false,
+    };
+
+    // Bytes after the last newline char of the previous chunk.
+    let mut prev_remainder: Vec<u8> = vec![];
+    let mut chunk_idx: usize = 0;
+
+    while !decompressor.is_finished() {
+        let mut chunk = std::mem::take(&mut prev_remainder);
+        decompressor.read_chunk(&mut chunk, chunk_size)?;
+
+        if !decompressor.is_finished() {
+            // chunk:     ---------\n---
+            // remainder:            ---
+            let Some(i) = chunk.iter().rposition(|&c| c == b'\n') else {
+                // The entire chunk does not contain a newline.
+                prev_remainder = chunk;
+                continue;
+            };
+
+            prev_remainder = chunk[i + 1..].to_vec();
+            chunk.truncate(i + 1);
+        }
+
+        let chunk = MemSlice::from_vec(chunk);
+        let mut full_chunk: &[u8] = chunk.as_ref();
+        row_skipper.skip_rows(&mut full_chunk);
+
+        if !full_chunk.is_empty()
+            && line_batch_distribute_tx
+                .send(LineBatch {
+                    bytes: subslice(&chunk, full_chunk),
+                    chunk_idx,
+                })
+                .await
+                .is_err()
+        {
+            break;
+        }
+
+        chunk_idx += 1;
+    }
+
+    if verbose {
+        eprintln!("[NDJSON LineBatchDistributor]: returning");
+    }
+
+    Ok(n_rows_to_skip - row_skipper.remaining_rows_to_skip)
+}
+
+/// Slices `mem_slice` to the range covered by `bytes`, which must point into it.
+fn subslice(mem_slice: &MemSlice, bytes: &[u8]) -> MemSlice {
+    let offset = bytes.as_ptr() as usize - mem_slice.as_ptr() as usize;
+    mem_slice.slice(offset..offset + bytes.len())
+}
+
 struct RowSkipper {
     remaining_rows_to_skip: usize,
     reverse: bool,
@@ -12,7 +12,7 @@ use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::estimate_n_lines_in_file;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{StreamingDecompressor, maybe_decompress_bytes};
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -21,6 +21,7 @@ use polars_utils::priority::Priority;
 use polars_utils::slice_enum::Slice;
 use row_index_limit_pass::ApplyRowIndexOrLimit;
 
+use super::decompressed_chunk_size;
 use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
 use super::multi_scan::reader_interface::{BeginReadArgs, FileReader, FileReaderCallbacks};
 use crate::async_executor::{AbortOnDropHandle, spawn};
@@ -80,9 +81,11 @@ impl FileReader for NDJsonFileReader {
             panic!("unsupported args: {:?}", &args)
         };
 
-        // TODO: This currently downloads and decompresses everything upfront in a blocking manner.
-        // Ideally we have a streaming download/decompression.
-        let global_bytes = self.get_bytes_maybe_decompress()?;
+        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
+
+        // TODO: This currently downloads everything upfront in a blocking manner.
+        // Ideally we have a streaming download.
+        let (global_bytes, decompressor) = self.get_bytes_maybe_decompress(!is_negative_slice)?;
 
         // NDJSON: We just use the projected schema - the parser will automatically append NULL if
         // the field is not found.
@@ -97,8 +100,6 @@ impl FileReader for NDJsonFileReader {
             _ = tx.try_send(schema.clone())
         }
 
-        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
-
         // Convert (offset, len) to Range
         // Note: This is converted to right-to-left for negative slice (i.e. range.start is position
         // from end).
@@ -133,7 +134,11 @@ impl FileReader for NDJsonFileReader {
                 && matches!(pre_slice, Some(Slice::Negative { .. })));
 
         let chunk_size: usize = {
-            let n_bytes_to_split = if let Some(x) = global_slice.as_ref() {
+            let n_bytes_to_split = if decompressor.is_some() {
+                // The decompressed size is unknown, split each decompressed chunk across the
+                // pipelines.
+                decompressed_chunk_size().saturating_mul(16)
+            } else if let Some(x) = global_slice.as_ref() {
                 if needs_total_row_count {
                     global_bytes.len()
                 } else {
@@ -181,13 +186,15 @@ impl FileReader for NDJsonFileReader {
                 row_index: {:?}, \
                 chunk_size: {}, \
                 n_chunks: {}, \
-                is_negative_slice: {}",
+                is_negative_slice: {}, \
+                streaming_decompression: {}",
                 schema.len(),
                 &global_slice,
                 &row_index,
                 chunk_size,
                 global_bytes.len().div_ceil(chunk_size),
                 is_negative_slice,
+                decompressor.is_some(),
             );
         }
 
@@ -308,7 +315,6 @@ impl FileReader for NDJsonFileReader {
             .enumerate()
             .rev()
             .map(|(worker_idx, line_batch_rx)| {
-                let global_bytes = global_bytes.clone();
                 let chunk_reader = chunk_reader.clone();
                 // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
                 let source_token = SourceToken::new();
@@ -318,7 +324,6 @@ impl FileReader for NDJsonFileReader {
                     LineBatchProcessor {
                         worker_idx,
 
-                        global_bytes,
                         chunk_reader,
 
                         line_batch_rx,
@@ -346,6 +351,7 @@ impl FileReader for NDJsonFileReader {
             TaskPriority::Low,
             line_batch_distributor::LineBatchDistributor {
                 global_bytes,
+                decompressor,
                 chunk_size,
                 n_rows_to_skip,
                 reverse: is_negative_slice,
@@ -439,7 +445,12 @@ impl NDJsonFileReader {
         ChunkReader::try_new(&self.options, schema)
     }
 
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+    /// Returns the bytes of the file. If the file is compressed and `streaming` is set, an empty
+    /// `MemSlice` is returned along with a decompressor to incrementally read the file from.
+    fn get_bytes_maybe_decompress(
+        &mut self,
+        streaming: bool,
+    ) -> PolarsResult<(MemSlice, Option<StreamingDecompressor>)> {
         if self.cached_bytes.is_none() {
             let run_async = self.scan_source.run_async();
             let source = self
@@ -447,20 +458,29 @@ impl NDJsonFileReader {
                 .as_scan_source_ref()
                 .to_memslice_async_assume_latest(run_async)?;
 
-            let memslice = {
-                let mut out = vec![];
-                maybe_decompress_bytes(&source, &mut out)?;
+            self.cached_bytes = Some(source);
+        }
 
-                if out.is_empty() {
-                    source
-                } else {
-                    MemSlice::from_vec(out)
-                }
-            };
+        let source = self.cached_bytes.clone().unwrap();
+
+        if streaming {
+            let decompressor = StreamingDecompressor::try_new(source.clone())?;
+
+            return Ok(if decompressor.is_some() {
+                (MemSlice::EMPTY, decompressor)
+            } else {
+                (source, None)
+            });
+        }
+
+        // Reading from the end of the file (negative slice) needs the fully decompressed file.
+        let mut out = vec![];
+        maybe_decompress_bytes(&source, &mut out)?;
 
-            self.cached_bytes = Some(memslice);
+        if !out.is_empty() {
+            self.cached_bytes = Some(MemSlice::from_vec(out));
         }
 
-        Ok(self.cached_bytes.clone().unwrap())
+        Ok((self.cached_bytes.clone().unwrap(), None))
     }
 }
@@ -13,7 +13,10 @@ use crate::async_executor;
 use crate::morsel::{Morsel, SourceToken, get_ideal_morsel_size};
 use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
-use crate::nodes::io_sources::parquet::statistics::calculate_row_group_pred_pushdown_skip_mask;
+use crate::nodes::io_sources::parquet::statistics::{
+    calculate_row_group_bloom_filter_skip_mask, calculate_row_group_pred_pushdown_skip_mask,
+    page_index_field_indices,
+};
 use crate::nodes::{MorselSeq, TaskPriority};
 use crate::utils::task_handles_ext::{self, AbortOnDropHandle};
 
@@ -42,8 +45,9 @@ impl ParquetReadImpl {
         let predicate = self.predicate.clone();
         let memory_prefetch_func = self.memory_prefetch_func;
 
-        let row_group_decoder = self.init_row_group_decoder();
-        let row_group_decoder = Arc::new(row_group_decoder);
+        let page_index_field_indices =
+            page_index_field_indices(use_statistics, predicate.as_ref(), &projected_arrow_fields);
+        let row_group_decoder = Arc::new(self.init_row_group_decoder());
 
         let ideal_morsel_size = get_ideal_morsel_size();
 
@@ -122,7 +126,19 @@ impl ParquetReadImpl {
                 predicate.as_ref(),
                 &metadata,
                 projected_arrow_fields.clone(),
-                row_index,
+                row_index.clone(),
+                verbose,
+            )
+            .await?;
+
+            let row_group_mask = calculate_row_group_bloom_filter_skip_mask(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &projected_arrow_fields,
+                &byte_source,
+                row_group_mask,
                 verbose,
             )
             .await?;
@@ -130,7 +146,9 @@ impl ParquetReadImpl {
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
+                page_index_field_indices,
                 predicate,
+                row_index,
                 slice_range,
                 memory_prefetch_func,
                 metadata,
@@ -138,6 +156,7 @@ impl ParquetReadImpl {
                 row_group_slice,
                 row_group_mask,
                 row_offset,
+                verbose,
             };
 
             while let Some(prefetch) = row_group_data_fetcher.next().await {
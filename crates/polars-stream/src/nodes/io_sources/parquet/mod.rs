@@ -100,9 +100,9 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::MemSlice(MemSliceByteSource(full_bytes)));
             }
 
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
+            Arc::new(polars_parquet::parquet::read::deserialize_footer(
                 metadata_bytes.as_ref(),
-                metadata_bytes.len() * 2 + 1024,
+                self.config.key_retriever.as_ref().map(|k| &k.0),
             )?)
         };
 
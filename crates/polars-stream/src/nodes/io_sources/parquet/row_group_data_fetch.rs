@@ -1,18 +1,23 @@
 use std::ops::Range;
 use std::sync::Arc;
 
-use polars_core::prelude::PlHashMap;
+use polars_core::prelude::{PlHashMap, PlHashSet};
 use polars_core::series::IsSorted;
-use polars_core::utils::arrow::bitmap::Bitmap;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
+use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
-use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::parquet::read::indexes::{deserialize_offset_index, page_row_ranges};
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+use crate::nodes::io_sources::parquet::statistics::{
+    calculate_page_index_row_mask, offset_index_byte_range, row_group_page_index_byte_ranges,
+};
 use crate::utils::task_handles_ext;
 
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
@@ -22,13 +27,63 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: Vec<(usize, IsSorted)>,
+    /// Mask of the rows in pages that may match the predicate, it is only set if the row group is
+    /// not sliced.
+    pub(super) page_mask: Option<Bitmap>,
+    /// Column chunks of which only the pages containing rows of `page_mask` were fetched, keyed by
+    /// the start of their byte range.
+    pub(super) selected_pages: PlHashMap<usize, SelectedPages>,
+}
+
+/// The pages of a column chunk that contain rows of the page mask.
+pub(super) struct SelectedPages {
+    /// The rows of the row group that are in the selected pages.
+    pub(super) rows: Bitmap,
+    /// Metadata with the number of values in the selected pages.
+    pub(super) metadata: ColumnChunkMetadata,
+    /// The dictionary page, if any, followed by the selected pages.
+    pub(super) bytes: MemSlice,
+}
+
+impl RowGroupData {
+    /// Returns the column chunks under `root_name` together with their bytes, and the rows of the
+    /// row group that these contain if only some of their pages were fetched. Returns `None` if
+    /// the column does not exist in the row group.
+    #[allow(clippy::type_complexity)]
+    pub(super) fn column_chunks(
+        &self,
+        root_name: &str,
+    ) -> Option<(Vec<(&ColumnChunkMetadata, MemSlice)>, Option<&Bitmap>)> {
+        let mut rows = None;
+
+        let columns = self
+            .row_group_metadata
+            .columns_under_root_iter(root_name)?
+            .map(|col_md| {
+                let byte_range = col_md.byte_range();
+                let byte_range = byte_range.start as usize..byte_range.end as usize;
+
+                match self.selected_pages.get(&byte_range.start) {
+                    Some(selected_pages) => {
+                        rows = Some(&selected_pages.rows);
+                        (&selected_pages.metadata, selected_pages.bytes.clone())
+                    },
+                    None => (col_md, self.fetched_bytes.get_range(byte_range)),
+                }
+            })
+            .collect();
+
+        Some((columns, rows))
+    }
 }
 
 pub(super) struct RowGroupDataFetcher {
     pub(super) projection: Arc<[ArrowFieldProjection]>,
     pub(super) is_full_projection: bool,
-    #[allow(unused)] // TODO: Fix!
+    /// Indices into `projection` of the fields whose page indexes are used to skip pages.
+    pub(super) page_index_field_indices: Arc<[usize]>,
     pub(super) predicate: Option<ScanIOPredicate>,
+    pub(super) row_index: Option<RowIndex>,
     pub(super) slice_range: Option<Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) metadata: Arc<FileMetadata>,
@@ -38,6 +93,7 @@ pub(super) struct RowGroupDataFetcher {
     pub(super) row_group_mask: Option<Bitmap>,
 
     pub(super) row_offset: usize,
+    pub(super) verbose: bool,
 }
 
 impl RowGroupDataFetcher {
@@ -56,7 +112,7 @@ impl RowGroupDataFetcher {
 
             self.row_offset = current_row_offset.saturating_add(num_rows);
 
-            let slice = if let Some(slice_range) = self.slice_range.as_mut() {
+            let mut slice = if let Some(slice_range) = self.slice_range.as_mut() {
                 let rg_row_start = slice_range.start;
                 let rg_row_end = slice_range.end.min(num_rows);
 
@@ -68,6 +124,10 @@ impl RowGroupDataFetcher {
                 None
             };
 
+            // If the slice consumes the entire row-group. Don't slice. This allows for prefiltering
+            // to happen more often until we properly support prefiltering with pre-slices.
+            slice.take_if(|slice| slice.0 == 0 && slice.1 >= num_rows);
+
             if let Some(row_group_mask) = self.row_group_mask.as_mut() {
                 let do_skip = row_group_mask.get_bit(0);
                 row_group_mask.slice(1, self.row_group_slice.len());
@@ -81,12 +141,38 @@ impl RowGroupDataFetcher {
             let current_byte_source = self.byte_source.clone();
             let projection = self.projection.clone();
             let is_full_projection = self.is_full_projection;
+            let page_index_field_indices = self.page_index_field_indices.clone();
+            // Pages can only be skipped if the entire row group is read.
+            let page_index_predicate = self
+                .predicate
+                .clone()
+                .filter(|_| slice.is_none() && !page_index_field_indices.is_empty());
+            let row_index = self.row_index.clone();
+            let verbose = self.verbose;
             let memory_prefetch_func = self.memory_prefetch_func;
             let io_runtime = polars_io::pl_async::get_runtime();
 
             let handle = io_runtime.spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
-                let fetched_bytes =
+
+                let calculate_page_mask = |fetched_bytes: &FetchedBytes| {
+                    let Some(predicate) = page_index_predicate.as_ref() else {
+                        return Ok(None);
+                    };
+
+                    calculate_page_index_row_mask(
+                        row_group_metadata,
+                        current_row_offset,
+                        fetched_bytes,
+                        predicate,
+                        &projection,
+                        &page_index_field_indices,
+                        row_index.as_ref(),
+                        verbose,
+                    )
+                };
+
+                let (fetched_bytes, page_mask, selected_pages) =
                     if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
                         // Skip byte range calculation for `no_prefetch`.
                         if memory_prefetch_func as usize
@@ -113,42 +199,128 @@ impl RowGroupDataFetcher {
                         // file that can be sliced directly, so we can skip the byte-range
                         // calculations and HashMap allocation.
                         let mem_slice = mem_slice.0.clone();
-                        FetchedBytes::MemSlice {
+                        let fetched_bytes = FetchedBytes::MemSlice {
                             offset: 0,
                             mem_slice,
-                        }
-                    } else if !is_full_projection {
-                        let mut ranges = get_row_group_byte_ranges_for_projection(
-                            row_group_metadata,
-                            &mut projection.iter().map(|x| &x.arrow_field().name),
-                        )
-                        .collect::<Vec<_>>();
+                        };
+                        let page_mask = calculate_page_mask(&fetched_bytes)?;
 
-                        let n_ranges = ranges.len();
+                        (fetched_bytes, page_mask, PlHashMap::default())
+                    } else {
+                        let mut ranges = if !is_full_projection {
+                            get_row_group_byte_ranges_for_projection(
+                                row_group_metadata,
+                                &mut projection.iter().map(|x| &x.arrow_field().name),
+                            )
+                            .collect::<Vec<_>>()
+                        } else {
+                            // We still prefer `get_ranges()` over a single `get_range()` for downloading
+                            // the entire row group, as it can have less memory-copying. A single `get_range()`
+                            // would naively concatenate the memory blocks of the entire row group, while
+                            // `get_ranges()` can skip concatenation since the downloaded blocks are
+                            // aligned to the columns.
+                            row_group_metadata
+                                .byte_ranges_iter()
+                                .map(|x| x.start as usize..x.end as usize)
+                                .collect::<Vec<_>>()
+                        };
+
+                        // The page indexes are fetched first, so that only the pages containing
+                        // rows of the page mask have to be fetched.
+                        let mut page_mask = None;
+                        let mut page_selections = Vec::new();
+
+                        if page_index_predicate.is_some() {
+                            let mut index_ranges = row_group_page_index_byte_ranges(
+                                row_group_metadata,
+                                &projection,
+                                &page_index_field_indices,
+                            )
+                            .chain(projection.iter().filter_map(|projection| {
+                                offset_index_byte_range(row_group_metadata, projection)
+                                    .map(|(_, range)| range)
+                            }))
+                            .collect::<Vec<_>>();
+                            index_ranges.sort_unstable_by_key(|range| (range.start, range.end));
+                            index_ranges.dedup();
 
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+                            let index_bytes = FetchedBytes::BytesMap(
+                                current_byte_source.get_ranges(&mut index_ranges).await?,
+                            );
 
-                        assert_eq!(bytes_map.len(), n_ranges);
+                            page_mask = calculate_page_mask(&index_bytes)?;
 
-                        FetchedBytes::BytesMap(bytes_map)
-                    } else {
-                        // We still prefer `get_ranges()` over a single `get_range()` for downloading
-                        // the entire row group, as it can have less memory-copying. A single `get_range()`
-                        // would naively concatenate the memory blocks of the entire row group, while
-                        // `get_ranges()` can skip concatenation since the downloaded blocks are
-                        // aligned to the columns.
-                        let mut ranges = row_group_metadata
-                            .byte_ranges_iter()
-                            .map(|x| x.start as usize..x.end as usize)
-                            .collect::<Vec<_>>();
+                            if let Some(page_mask) = &page_mask {
+                                page_selections = select_pages(
+                                    row_group_metadata,
+                                    &projection,
+                                    &index_bytes,
+                                    page_mask,
+                                )?;
+                            }
+                        }
+
+                        let selected_column_starts = page_selections
+                            .iter()
+                            .map(|selection| selection.column.byte_range().start as usize)
+                            .collect::<PlHashSet<_>>();
+                        ranges.retain(|range| !selected_column_starts.contains(&range.start));
+                        ranges.extend(
+                            page_selections
+                                .iter()
+                                .flat_map(|selection| selection.byte_ranges.iter().cloned()),
+                        );
 
                         let n_ranges = ranges.len();
 
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+                        let mut bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
 
                         assert_eq!(bytes_map.len(), n_ranges);
 
-                        FetchedBytes::BytesMap(bytes_map)
+                        if verbose && !page_selections.is_empty() {
+                            eprintln!(
+                                "[ParquetFileReader]: Page index pushdown: \
+                                fetching {} / {} pages of {} columns",
+                                page_selections
+                                    .iter()
+                                    .map(|s| s.num_selected_pages)
+                                    .sum::<usize>(),
+                                page_selections.iter().map(|s| s.num_pages).sum::<usize>(),
+                                page_selections.len(),
+                            );
+                        }
+
+                        let selected_pages = page_selections
+                            .into_iter()
+                            .map(|selection| {
+                                let mut pages = selection
+                                    .byte_ranges
+                                    .iter()
+                                    .map(|range| bytes_map.remove(&range.start).unwrap());
+
+                                let bytes = if selection.byte_ranges.len() == 1 {
+                                    pages.next().unwrap()
+                                } else {
+                                    let mut bytes = Vec::with_capacity(
+                                        selection.byte_ranges.iter().map(|r| r.len()).sum(),
+                                    );
+                                    pages.for_each(|page| bytes.extend_from_slice(&page));
+                                    MemSlice::from_vec(bytes)
+                                };
+
+                                let selected_pages = SelectedPages {
+                                    metadata: selection
+                                        .column
+                                        .with_selected_pages(selection.rows.set_bits() as i64),
+                                    rows: selection.rows,
+                                    bytes,
+                                };
+
+                                (selection.column.byte_range().start as usize, selected_pages)
+                            })
+                            .collect();
+
+                        (FetchedBytes::BytesMap(bytes_map), page_mask, selected_pages)
                     };
 
                 PolarsResult::Ok(RowGroupData {
@@ -158,6 +330,8 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_mask,
+                    selected_pages,
                 })
             });
 
@@ -191,6 +365,107 @@ impl FetchedBytes {
     }
 }
 
+/// The pages of a column chunk that have to be fetched for the rows of the page mask.
+struct PageSelection<'a> {
+    column: &'a ColumnChunkMetadata,
+    /// The rows of the row group that are in the selected pages.
+    rows: Bitmap,
+    /// The byte ranges of the dictionary page, if any, and of the selected pages.
+    byte_ranges: Vec<Range<usize>>,
+    num_pages: usize,
+    num_selected_pages: usize,
+}
+
+/// Selects the pages containing rows of `page_mask` of the projected column chunks that have an
+/// offset index. Column chunks of which all pages are needed are not included.
+fn select_pages<'a>(
+    row_group_metadata: &'a RowGroupMetadata,
+    projection: &[ArrowFieldProjection],
+    index_bytes: &FetchedBytes,
+    page_mask: &Bitmap,
+) -> PolarsResult<Vec<PageSelection<'a>>> {
+    let num_rows = row_group_metadata.num_rows();
+    let mut selections = Vec::new();
+
+    for projection in projection {
+        let Some((column, offset_index)) = offset_index_byte_range(row_group_metadata, projection)
+        else {
+            continue;
+        };
+
+        let offset_index = deserialize_offset_index(&index_bytes.get_range(offset_index))?;
+        let page_rows = page_row_ranges(&offset_index, num_rows)?;
+
+        let column_range = column.byte_range();
+        let column_range = column_range.start as usize..column_range.end as usize;
+
+        // Fall back to fetching the entire column chunk if the page locations don't lie in order
+        // within it.
+        let Some(page_ranges) = offset_index
+            .page_locations
+            .iter()
+            .map(|location| {
+                let start = usize::try_from(location.offset).ok()?;
+                let end =
+                    start.checked_add(usize::try_from(location.compressed_page_size).ok()?)?;
+                Some(start..end)
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        if page_ranges.is_empty()
+            || page_ranges[0].start < column_range.start
+            || page_ranges.last().unwrap().end > column_range.end
+            || page_ranges.windows(2).any(|w| w[0].end > w[1].start)
+        {
+            continue;
+        }
+
+        let mut is_selected = page_rows
+            .iter()
+            .map(|rows| page_mask.null_count_range(rows.start, rows.len()) < rows.len())
+            .collect::<Vec<_>>();
+
+        if !is_selected.contains(&false) {
+            continue;
+        }
+        // At least one page is decoded, even if no rows match.
+        if !is_selected.contains(&true) {
+            is_selected[0] = true;
+        }
+
+        let mut rows = MutableBitmap::with_capacity(num_rows);
+        // The dictionary page, if any, precedes the first data page.
+        let mut byte_ranges = Vec::with_capacity(page_ranges.len() + 1);
+        byte_ranges.push(column_range.start..page_ranges[0].start);
+
+        for ((page_rows, page_range), &is_selected) in
+            page_rows.iter().zip(&page_ranges).zip(&is_selected)
+        {
+            rows.extend_constant(page_rows.len(), is_selected);
+
+            if is_selected {
+                match byte_ranges.last_mut() {
+                    Some(last) if last.end == page_range.start => last.end = page_range.end,
+                    _ => byte_ranges.push(page_range.clone()),
+                }
+            }
+        }
+        byte_ranges.retain(|range| !range.is_empty());
+
+        selections.push(PageSelection {
+            column,
+            rows: rows.freeze(),
+            byte_ranges,
+            num_pages: page_ranges.len(),
+            num_selected_pages: is_selected.iter().filter(|x| **x).count(),
+        });
+    }
+
+    Ok(selections)
+}
+
 fn get_row_group_byte_ranges_for_projection<'a>(
     row_group_metadata: &'a RowGroupMetadata,
     columns: &'a mut dyn Iterator<Item = &PlSmallStr>,
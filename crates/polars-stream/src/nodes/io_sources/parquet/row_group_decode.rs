@@ -40,25 +40,26 @@ impl RowGroupDecoder {
         &self,
         mut row_group_data: RowGroupData,
     ) -> PolarsResult<DataFrame> {
-        // If the slice consumes the entire row-group. Don't slice. This allows for prefiltering to
-        // happen more often until we properly support prefiltering with pre-slices.
-        row_group_data.slice.take_if(|slice| {
-            slice.0 == 0 && slice.1 >= row_group_data.row_group_metadata.num_rows()
-        });
+        let page_mask = row_group_data.page_mask.take();
 
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
             && !self.predicate_field_indices.is_empty()
         {
-            self.row_group_data_to_df_prefiltered(row_group_data).await
+            self.row_group_data_to_df_prefiltered(row_group_data, page_mask)
+                .await
         } else {
-            self.row_group_data_to_df_impl(row_group_data).await
+            self.row_group_data_to_df_impl(row_group_data, page_mask)
+                .await
         }
     }
 
+    /// `page_mask` is a mask of the rows in pages that may match the predicate, it is only set if
+    /// the row group is not sliced.
     async fn row_group_data_to_df_impl(
         &self,
         row_group_data: RowGroupData,
+        page_mask: Option<Bitmap>,
     ) -> PolarsResult<DataFrame> {
         let row_group_data = Arc::new(row_group_data);
 
@@ -74,18 +75,23 @@ impl RowGroupDecoder {
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
         if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
-            out_columns.push(s);
+            out_columns.push(match &page_mask {
+                Some(page_mask) => filter_by_bitmap(&s, page_mask)?,
+                None => s,
+            });
         }
 
-        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
+        let (filter, projection_height) = match page_mask {
+            Some(page_mask) => {
+                let height = page_mask.set_bits();
+                (Filter::Mask(page_mask), height)
+            },
+            None => (Filter::Range(slice_range.clone()), slice_range.len()),
+        };
 
-        let projection_height = slice_range.len();
+        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, Some(filter))
+            .await?;
 
         out_columns.extend(decoded_cols);
 
@@ -234,9 +240,8 @@ fn decode_column(
     filter: Option<polars_parquet::read::Filter>,
     expected_num_rows: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
-    let Some(iter) = row_group_data
-        .row_group_metadata
-        .columns_under_root_iter(&arrow_field.name)
+    let Some((columns_to_deserialize, selected_rows)) =
+        row_group_data.column_chunks(&arrow_field.name)
     else {
         return Ok((
             Column::full_null(
@@ -248,33 +253,39 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
-
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
+    // Only the pages with rows of the page mask were fetched, so the mask is restricted to the
+    // rows in these pages.
+    let filter = match (filter, selected_rows) {
+        (Some(Filter::Mask(mask)), Some(rows)) => Some(Filter::Mask(restrict_mask(&mask, rows))),
+        (filter, rows) => {
+            assert!(rows.is_none());
+            filter
+        },
+    };
 
     let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
+    // Nested columns can't be decoded with a mask, so they are filtered after decoding.
+    let (filter, post_filter_mask) = match filter {
+        Some(Filter::Mask(mask)) if arrow_field.dtype.is_nested() => (None, Some(mask)),
+        filter => (filter, None),
+    };
+
     let (arrays, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
         columns_to_deserialize,
         arrow_field.clone(),
         filter,
     )?;
 
-    if !skip_num_rows_check {
-        let num_rows = arrays.iter().map(|array| array.len()).sum::<usize>();
-        assert_eq!(num_rows, expected_num_rows);
+    let mut series = Series::try_from((arrow_field, arrays))?;
+
+    if let Some(mask) = post_filter_mask {
+        series = series.filter(&BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask))?;
     }
 
-    let mut series = Series::try_from((arrow_field, arrays))?;
+    if !skip_num_rows_check {
+        assert_eq!(series.len(), expected_num_rows);
+    }
 
     if let Some(col_idxs) = row_group_data
         .row_group_metadata
@@ -327,6 +338,32 @@ async fn filter_cols(
     Ok(out_vec)
 }
 
+fn filter_by_bitmap(column: &Column, mask: &Bitmap) -> PolarsResult<Column> {
+    column.filter(&BooleanChunked::from_bitmap(
+        PlSmallStr::EMPTY,
+        mask.clone(),
+    ))
+}
+
+/// Returns the bits of `mask` at the positions of the set bits of `rows`.
+fn restrict_mask(mask: &Bitmap, rows: &Bitmap) -> Bitmap {
+    assert_eq!(mask.len(), rows.len());
+    mask.iter()
+        .zip(rows.iter())
+        .filter_map(|(is_set, is_row)| is_row.then_some(is_set))
+        .collect()
+}
+
+/// Expands `inner`, which has a bit for every set bit of `outer`, to the length of `outer`.
+fn expand_mask(outer: &Bitmap, inner: &Bitmap) -> Bitmap {
+    assert_eq!(outer.set_bits(), inner.len());
+    let mut inner = inner.iter();
+    outer
+        .iter()
+        .map(|is_set| is_set && inner.next().unwrap())
+        .collect()
+}
+
 fn calc_cols_per_thread(n_rows_per_col: usize, target_n_rows_per_thread: usize) -> usize {
     if n_rows_per_col == 0 {
         return usize::MAX;
@@ -350,9 +387,10 @@ fn decode_column_in_filter(
     use_column_predicates: bool,
     column_predicates: &ColumnPredicates,
     row_group_data: &RowGroupData,
+    page_mask: Option<&Bitmap>,
     projection_height: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
-    let mut filter = None;
+    let mut filter = page_mask.map(|m| Filter::Mask(m.clone()));
     let mut constant = None;
     if use_column_predicates {
         if let Some((column_predicate, specialized)) =
@@ -388,12 +426,14 @@ impl RowGroupDecoder {
     async fn row_group_data_to_df_prefiltered(
         &self,
         row_group_data: RowGroupData,
+        page_mask: Option<Bitmap>,
     ) -> PolarsResult<DataFrame> {
         debug_assert!(row_group_data.slice.is_none()); // Invariant of the optimizer.
         assert!(self.predicate_field_indices.len() <= self.projected_arrow_fields.len());
 
         let row_group_data = Arc::new(row_group_data);
-        let projection_height = row_group_data.row_group_metadata.num_rows();
+        let num_rows = row_group_data.row_group_metadata.num_rows();
+        let projection_height = page_mask.as_ref().map_or(num_rows, |m| m.set_bits());
 
         let mut live_columns = Vec::with_capacity(
             self.row_index.is_some() as usize
@@ -404,16 +444,19 @@ impl RowGroupDecoder {
             self.row_index.is_some() as usize + self.predicate_field_indices.len(),
         );
 
-        if let Some(s) = self.materialize_row_index(
-            row_group_data.as_ref(),
-            0..row_group_data.row_group_metadata.num_rows(),
-        )? {
-            live_columns.push(s);
+        if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), 0..num_rows)? {
+            live_columns.push(match &page_mask {
+                Some(page_mask) => filter_by_bitmap(&s, page_mask)?,
+                None => s,
+            });
         }
 
         let scan_predicate = self.predicate.as_ref().unwrap();
 
+        // Column predicates are evaluated during decoding, which can't be combined with the
+        // page mask.
         let use_column_predicates = self.allow_column_predicates
+            && page_mask.is_none()
             && !row_group_data
                 .row_group_metadata
                 .parquet_columns()
@@ -434,6 +477,7 @@ impl RowGroupDecoder {
             let predicate_field_indices = self.predicate_field_indices.clone();
             let projected_arrow_fields = self.projected_arrow_fields.clone();
             let row_group_data = row_group_data.clone();
+            let page_mask = page_mask.clone();
 
             parallelize_first_to_local(
                 (0..self.predicate_field_indices.len())
@@ -443,6 +487,7 @@ impl RowGroupDecoder {
                         let predicate_field_indices = predicate_field_indices.clone();
                         let projected_arrow_fields = projected_arrow_fields.clone();
                         let column_predicates = scan_predicate.column_predicates.clone();
+                        let page_mask = page_mask.clone();
 
                         async move {
                             (offset
@@ -465,6 +510,7 @@ impl RowGroupDecoder {
                                         use_column_predicates,
                                         column_predicates.as_ref(),
                                         row_group_data.as_ref(),
+                                        page_mask.as_ref(),
                                         projection_height,
                                     )?;
 
@@ -515,9 +561,7 @@ impl RowGroupDecoder {
                 (DataFrame::new(live_columns).unwrap(), mask)
             }
         } else {
-            let mut live_df = unsafe {
-                DataFrame::new_no_checks(row_group_data.row_group_metadata.num_rows(), live_columns)
-            };
+            let mut live_df = unsafe { DataFrame::new_no_checks(projection_height, live_columns) };
 
             let mask = scan_predicate.predicate.evaluate_io(&live_df)?;
             let mask = mask.bool().unwrap();
@@ -550,13 +594,19 @@ impl RowGroupDecoder {
 
         mask.rechunk_mut();
         let mask_bitmap = mask.downcast_as_array();
-        let mask_bitmap = match mask_bitmap.validity() {
+        let mut mask_bitmap = match mask_bitmap.validity() {
             None => mask_bitmap.values().clone(),
             Some(v) => mask_bitmap.values() & v,
         };
 
         assert_eq!(mask_bitmap.len(), projection_height);
 
+        // The non-predicate columns are decoded with a mask over all rows of the row group.
+        if let Some(page_mask) = &page_mask {
+            mask_bitmap = expand_mask(page_mask, &mask_bitmap);
+            mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask_bitmap.clone());
+        }
+
         let expected_num_rows = mask_bitmap.set_bits();
 
         let cols_per_thread = (self
@@ -625,9 +675,8 @@ fn decode_column_prefiltered(
     mask_bitmap: &Bitmap,
     expected_num_rows: usize,
 ) -> PolarsResult<Column> {
-    let Some(iter) = row_group_data
-        .row_group_metadata
-        .columns_under_root_iter(&arrow_field.name)
+    let Some((columns_to_deserialize, selected_rows)) =
+        row_group_data.column_chunks(&arrow_field.name)
     else {
         return Ok(Column::full_null(
             arrow_field.name.clone(),
@@ -636,23 +685,16 @@ fn decode_column_prefiltered(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
-
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
-
     let prefilter = !arrow_field.dtype.is_nested();
+    // Nested columns are always fetched in full.
+    assert!(prefilter || selected_rows.is_none());
 
-    let deserialize_filter =
-        prefilter.then(|| polars_parquet::read::Filter::Mask(mask_bitmap.clone()));
+    let deserialize_filter = prefilter.then(|| {
+        polars_parquet::read::Filter::Mask(match selected_rows {
+            Some(rows) => restrict_mask(mask_bitmap, rows),
+            None => mask_bitmap.clone(),
+        })
+    });
 
     let (array, _) = polars_io::prelude::_internal::to_deserializer(
         columns_to_deserialize,
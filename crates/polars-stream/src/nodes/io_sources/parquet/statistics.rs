@@ -1,16 +1,25 @@
 use std::ops::Range;
 
 use arrow::array::{MutablePrimitiveArray, PrimitiveArray};
-use arrow::bitmap::Bitmap;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::pushable::Pushable;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
-use polars_io::predicates::ScanIOPredicate;
+use polars_io::predicates::{ScanIOPredicate, SpecializedColumnPredicate};
 use polars_io::prelude::FileMetadata;
-use polars_parquet::read::RowGroupMetadata;
-use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::read_from_bytes;
+use polars_parquet::parquet::read::indexes::{
+    deserialize_column_index, deserialize_offset_index, deserialize_page_statistics,
+    page_row_ranges,
+};
+use polars_parquet::read::statistics::{
+    ArrowColumnStatisticsArrays, deserialize_all, deserialize_all_pages,
+};
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
 use polars_utils::format_pl_smallstr;
 
+use super::row_group_data_fetch::FetchedBytes;
 use crate::async_executor::{self, TaskPriority};
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 
@@ -169,6 +178,368 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     Ok(Some(skip_row_group_mask))
 }
 
+/// Extends the row group skip mask with the row groups whose bloom filters show that they contain
+/// no rows matching the predicate.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &FileMetadata,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    byte_source: &DynByteSource,
+    row_group_mask: Option<Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    if !use_statistics || row_group_slice.is_empty() {
+        return Ok(row_group_mask);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(row_group_mask);
+    };
+
+    // Every column predicate is a necessary condition for the full predicate, so a row group can
+    // be skipped if a single column predicate is false for all its rows. The columns must not be
+    // cast, as the literals are hashed with the type of the column in the file.
+    let bloom_filter_predicates = projected_arrow_fields
+        .iter()
+        .filter_map(|projection| {
+            let ArrowFieldProjection::Plain(arrow_field) = projection else {
+                return None;
+            };
+            let (_, specialized) = predicate
+                .column_predicates
+                .predicates
+                .get(&arrow_field.name)?;
+            let specialized = specialized.as_ref()?;
+            let idxs = metadata.row_groups[row_group_slice.start]
+                .columns_idxs_under_root_iter(&arrow_field.name)?;
+            match (specialized, idxs) {
+                (
+                    SpecializedColumnPredicate::Equal(_)
+                    | SpecializedColumnPredicate::EqualOneOf(_),
+                    &[idx],
+                ) => Some((idx, specialized)),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if bloom_filter_predicates.is_empty() {
+        return Ok(row_group_mask);
+    }
+
+    let num_row_groups = row_group_slice.len();
+    let mut skip_mask = match row_group_mask {
+        Some(mask) => mask.make_mut(),
+        None => MutableBitmap::from_len_zeroed(num_row_groups),
+    };
+
+    let mut num_skipped = 0;
+    let mut bitset = Vec::new();
+    for (i, rg) in metadata.row_groups[row_group_slice].iter().enumerate() {
+        if skip_mask.get(i) {
+            continue;
+        }
+
+        for &(idx, specialized) in &bloom_filter_predicates {
+            let column = &rg.parquet_columns()[idx];
+            // The bloom filters of encrypted columns are encrypted as well.
+            if column.is_encrypted() {
+                continue;
+            }
+            let column_metadata = column.metadata();
+            let (Some(offset), Some(length)) = (
+                column_metadata.bloom_filter_offset,
+                column_metadata.bloom_filter_length,
+            ) else {
+                continue;
+            };
+
+            let offset = offset as usize;
+            let bytes = byte_source
+                .get_range(offset..offset + length as usize)
+                .await?;
+            read_from_bytes(&bytes, &mut bitset)?;
+
+            if specialized.is_excluded_by_bloom_filter(&bitset, column.physical_type()) {
+                skip_mask.set(i, true);
+                num_skipped += 1;
+                break;
+            }
+        }
+    }
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            skipping {num_skipped} / {num_row_groups} row groups",
+        );
+    }
+
+    Ok(Some(skip_mask.freeze()))
+}
+
+/// Returns the indices of the projected fields whose page indexes are used to skip pages within
+/// the row groups.
+pub(super) fn page_index_field_indices(
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    projected_arrow_fields: &[ArrowFieldProjection],
+) -> Arc<[usize]> {
+    let Some(predicate) = predicate.filter(|p| use_statistics && p.skip_batch_predicate.is_some())
+    else {
+        return Arc::default();
+    };
+
+    projected_arrow_fields
+        .iter()
+        .enumerate()
+        .filter(|(_, projection)| {
+            predicate.live_columns.contains(projection.output_name())
+                && !projection.arrow_field().dtype().is_nested()
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Returns the column chunk of `projection` in `row_group` together with the byte ranges of its
+/// column index and offset index, if it has both and they are not encrypted.
+fn page_index_byte_ranges<'a>(
+    row_group: &'a RowGroupMetadata,
+    projection: &ArrowFieldProjection,
+) -> Option<(&'a ColumnChunkMetadata, Range<usize>, Range<usize>)> {
+    let &[idx] = row_group.columns_idxs_under_root_iter(&projection.arrow_field().name)? else {
+        return None;
+    };
+
+    let column = &row_group.parquet_columns()[idx];
+    if column.is_encrypted() {
+        return None;
+    }
+    let column_index = column.column_index_byte_range()?;
+    let offset_index = column.offset_index_byte_range()?;
+
+    Some((
+        column,
+        column_index.start as usize..column_index.end as usize,
+        offset_index.start as usize..offset_index.end as usize,
+    ))
+}
+
+/// Returns the column chunk of `projection` in `row_group` together with the byte range of its
+/// offset index, if only some of its pages can be read. This requires a non-nested and unencrypted
+/// column with an offset index.
+pub(super) fn offset_index_byte_range<'a>(
+    row_group: &'a RowGroupMetadata,
+    projection: &ArrowFieldProjection,
+) -> Option<(&'a ColumnChunkMetadata, Range<usize>)> {
+    if projection.arrow_field().dtype().is_nested() {
+        return None;
+    }
+
+    let &[idx] = row_group.columns_idxs_under_root_iter(&projection.arrow_field().name)? else {
+        return None;
+    };
+
+    let column = &row_group.parquet_columns()[idx];
+    if column.is_encrypted() {
+        return None;
+    }
+    let offset_index = column.offset_index_byte_range()?;
+
+    Some((
+        column,
+        offset_index.start as usize..offset_index.end as usize,
+    ))
+}
+
+/// The byte ranges of the page indexes that are used to calculate the page mask of `row_group`.
+pub(super) fn row_group_page_index_byte_ranges<'a>(
+    row_group: &'a RowGroupMetadata,
+    projected_arrow_fields: &'a [ArrowFieldProjection],
+    page_index_field_indices: &'a [usize],
+) -> impl Iterator<Item = Range<usize>> + 'a {
+    page_index_field_indices.iter().flat_map(|&i| {
+        page_index_byte_ranges(row_group, &projected_arrow_fields[i])
+            .map(|(_, column_index, offset_index)| [column_index, offset_index])
+            .into_iter()
+            .flatten()
+    })
+}
+
+/// Calculates a mask of the rows of a row group that lie in pages which may contain rows matching
+/// the predicate, based on the page indexes of the live columns. Returns `None` if no rows can be
+/// skipped.
+///
+/// The pages of different columns generally don't start at the same rows, so the predicate is
+/// evaluated for every range of rows in which none of the columns crosses a page boundary. This
+/// way the resulting mask applies to all columns.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn calculate_page_index_row_mask(
+    row_group: &RowGroupMetadata,
+    row_offset: usize,
+    fetched_bytes: &FetchedBytes,
+    predicate: &ScanIOPredicate,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    page_index_field_indices: &[usize],
+    row_index: Option<&RowIndex>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    let Some(sbp) = predicate.skip_batch_predicate.as_ref() else {
+        return Ok(None);
+    };
+
+    let num_rows = row_group.num_rows();
+
+    let mut page_statistics = Vec::with_capacity(page_index_field_indices.len());
+    for &i in page_index_field_indices {
+        let projection = &projected_arrow_fields[i];
+        let arrow_field = projection.arrow_field();
+
+        let Some((column, column_index, offset_index)) =
+            page_index_byte_ranges(row_group, projection)
+        else {
+            continue;
+        };
+
+        let column_index = deserialize_column_index(&fetched_bytes.get_range(column_index))?;
+        let offset_index = deserialize_offset_index(&fetched_bytes.get_range(offset_index))?;
+
+        let page_rows = page_row_ranges(&offset_index, num_rows)?;
+        let primitive_type = &column.descriptor().descriptor.primitive_type;
+        let statistics = deserialize_page_statistics(&column_index, primitive_type)?;
+
+        if page_rows.is_empty() || page_rows.len() != statistics.len() {
+            continue;
+        }
+
+        let Some(statistics) = deserialize_all_pages(arrow_field, primitive_type, statistics)?
+        else {
+            continue;
+        };
+
+        let mut statistics = StatisticsColumns::from_arrow_statistics(statistics, arrow_field)?;
+        statistics.min = projection.apply_transform(statistics.min)?;
+        statistics.max = projection.apply_transform(statistics.max)?;
+
+        let page_starts = page_rows.iter().map(|r| r.start).collect::<Vec<_>>();
+        page_statistics.push((i, page_starts, statistics));
+    }
+
+    if page_statistics.is_empty() {
+        return Ok(None);
+    }
+
+    // Split the row group at the page boundaries of all columns.
+    let mut boundaries = page_statistics
+        .iter()
+        .flat_map(|(_, page_starts, _)| page_starts.iter().copied())
+        .filter(|&start| start < num_rows)
+        .collect::<Vec<_>>();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let segments = boundaries
+        .iter()
+        .zip(boundaries.iter().skip(1).chain([&num_rows]))
+        .map(|(&start, &end)| start..end)
+        .collect::<Vec<_>>();
+    let num_segments = segments.len();
+
+    let mut columns = Vec::with_capacity(1 + predicate.live_columns.len() * 3);
+
+    let lengths: Vec<IdxSize> = segments.iter().map(|r| r.len() as IdxSize).collect();
+    columns.push(Column::new("len".into(), lengths));
+
+    for (i, projection) in projected_arrow_fields.iter().enumerate() {
+        let c = projection.output_name();
+
+        if !predicate.live_columns.contains(c) {
+            continue;
+        }
+
+        let statistics = if let Some((_, page_starts, statistics)) =
+            page_statistics.iter().find(|(idx, ..)| *idx == i)
+        {
+            let page_idxs = IdxCa::from_vec(
+                PlSmallStr::EMPTY,
+                segments
+                    .iter()
+                    .map(|r| (page_starts.partition_point(|&s| s <= r.start) - 1) as IdxSize)
+                    .collect(),
+            );
+
+            StatisticsColumns {
+                min: statistics.min.take(&page_idxs)?,
+                max: statistics.max.take(&page_idxs)?,
+                null_count: statistics.null_count.take(&page_idxs)?,
+            }
+        } else {
+            let mut statistics = StatisticsColumns::new_null(
+                &DataType::from_arrow_field(projection.arrow_field()),
+                num_segments,
+            );
+            statistics.min = projection.apply_transform(statistics.min)?;
+            statistics.max = projection.apply_transform(statistics.max)?;
+            statistics
+        };
+
+        let statistics = statistics.with_base_column_name(c);
+
+        columns.extend([statistics.min, statistics.max, statistics.null_count]);
+    }
+
+    if let Some(row_index) = row_index {
+        let offset = row_index
+            .offset
+            .saturating_add(IdxSize::try_from(row_offset).unwrap_or(IdxSize::MAX));
+        let bound = |row: usize| offset.checked_add(IdxSize::try_from(row).ok()?);
+
+        let statistics = StatisticsColumns {
+            min: IdxCa::from_iter_options(
+                PlSmallStr::EMPTY,
+                segments.iter().map(|r| bound(r.start)),
+            )
+            .into_column(),
+            max: IdxCa::from_iter_options(
+                PlSmallStr::EMPTY,
+                segments.iter().map(|r| bound(r.end - 1)),
+            )
+            .into_column(),
+            null_count: Column::new_scalar(PlSmallStr::EMPTY, Scalar::new_idxsize(0), num_segments),
+        }
+        .with_base_column_name(&row_index.name);
+
+        columns.extend([statistics.min, statistics.max, statistics.null_count]);
+    }
+
+    let statistics_df = DataFrame::new_with_height(num_segments, columns)?;
+    let skip_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+    if skip_mask.set_bits() == 0 {
+        return Ok(None);
+    }
+
+    let mut row_mask = MutableBitmap::with_capacity(num_rows);
+    for (r, skip) in segments.iter().zip(skip_mask.iter()) {
+        row_mask.extend_constant(r.len(), !skip);
+    }
+    let row_mask = row_mask.freeze();
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Page index pushdown: \
+            reading {} / {} rows of row group",
+            row_mask.set_bits(),
+            num_rows,
+        );
+    }
+
+    Ok(Some(row_mask))
+}
+
 fn load_parquet_column_statistics(
     row_groups: &[RowGroupMetadata],
     projection: &ArrowFieldProjection,
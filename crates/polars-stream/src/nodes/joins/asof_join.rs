@@ -0,0 +1,672 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_ops::frame::{AsOfOptions, AsofJoinBy, AsofStrategy, DataFrameJoinOps, JoinArgs};
+use polars_ops::prelude::JoinType;
+use polars_ops::series::SeriesMethods;
+
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::distributor_channel::{Sender, distributor_channel};
+use crate::expression::StreamExpr;
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+
+/// The name under which the evaluated right key is buffered alongside the right rows.
+const RIGHT_KEY_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_ASOF_KEY");
+const PROBE_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_ASOF_PROBE");
+
+struct AsOfJoinParams {
+    left_key_selector: StreamExpr,
+    right_key_selector: StreamExpr,
+    right_key_name: PlSmallStr,
+    /// The arguments for joining a single left morsel, without a slice and sortedness checks.
+    args: JoinArgs,
+    options: AsOfOptions,
+}
+
+impl AsOfJoinParams {
+    async fn evaluate_key(
+        selector: &StreamExpr,
+        df: &DataFrame,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<Series> {
+        let key = selector.evaluate(df, &state.in_memory_exec_state).await?;
+        Ok(key.take_materialized_series())
+    }
+
+    fn by(&self) -> Option<(&Vec<PlSmallStr>, &Vec<PlSmallStr>)> {
+        self.options
+            .left_by
+            .as_ref()
+            .zip(self.options.right_by.as_ref())
+    }
+
+    /// Checks that the non-null values of `key` continue the ascending order of the keys seen
+    /// so far, and updates `last` to the greatest key seen.
+    ///
+    /// With `by` groups the key only needs to be sorted within each group, which isn't checked,
+    /// just like in the in-memory engine.
+    fn check_sorted(&self, key: &Series, last: &mut Option<Series>) -> PolarsResult<()> {
+        let non_null = key.drop_nulls();
+        if non_null.is_empty() || self.by().is_some() {
+            return Ok(());
+        }
+
+        if self.options.check_sortedness {
+            let sorted = non_null.is_sorted(SortOptions::default())?
+                && match last {
+                    Some(last) => !last.gt(&non_null.head(Some(1)))?.any(),
+                    None => true,
+                };
+            polars_ensure!(
+                sorted,
+                InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first"
+            );
+        }
+        *last = Some(non_null.tail(Some(1)));
+        Ok(())
+    }
+}
+
+fn last_non_null(key: &Series) -> Option<Series> {
+    let non_null = key.drop_nulls();
+    (!non_null.is_empty()).then(|| non_null.tail(Some(1)))
+}
+
+/// The `by` columns of `df` together with `key` as the last column, skipping the rows with a
+/// null key.
+fn with_probe_key(df: &DataFrame, by: &[PlSmallStr], key: &Series) -> PolarsResult<DataFrame> {
+    let mut out = df.select(by.iter().cloned())?;
+    out.with_column(key.clone().with_name(PROBE_NAME))?;
+    let mask = key.is_not_null();
+    if mask.all() {
+        Ok(out)
+    } else {
+        out.filter(&mask)
+    }
+}
+
+/// Keeps the last row of every `by` group of a frame built by [`with_probe_key`], which has the
+/// greatest key of the group as the key is sorted within each group.
+fn last_per_group(df: DataFrame, by: &[PlSmallStr]) -> PolarsResult<DataFrame> {
+    df.unique_impl(true, Some(by.to_vec()), UniqueKeepStrategy::Last, None)
+}
+
+/// Whether each row of `df` has a row in the same `by` group of `probe` with a greater key, or
+/// an equal one if `allow_eq`. The `probe` is built by [`with_probe_key`]. Rows with a null key
+/// never have one.
+fn has_following_in_group(
+    df: &DataFrame,
+    key: &Series,
+    by: &[PlSmallStr],
+    probe: &DataFrame,
+    probe_by: &[PlSmallStr],
+    allow_eq: bool,
+) -> PolarsResult<BooleanChunked> {
+    let probe_key = probe.column(&PROBE_NAME)?.as_materialized_series();
+    let out = df.select(by.iter().cloned())?._join_asof_by(
+        probe,
+        key,
+        probe_key,
+        by.to_vec(),
+        probe_by.to_vec(),
+        AsofStrategy::Forward,
+        None,
+        None,
+        None,
+        false,
+        allow_eq,
+        false,
+    )?;
+    Ok(out.column(&PROBE_NAME)?.is_not_null())
+}
+
+struct JoinJob {
+    left: DataFrame,
+    left_key: Series,
+    right: DataFrame,
+    right_key: Series,
+    seq: MorselSeq,
+    source_token: SourceToken,
+}
+
+/// The merge state of the sweep over both sorted inputs.
+struct SweepState {
+    seq: MorselSeq,
+    right_done: bool,
+
+    /// Left morsels which are not yet joined, together with their evaluated keys.
+    left_unjoined: VecDeque<(DataFrame, Series)>,
+    /// The greatest left key seen so far.
+    left_last: Option<Series>,
+
+    /// The right rows which may still be matched, with the evaluated key as the last column.
+    right_buffer: DataFrame,
+    /// The greatest right key seen so far. All right rows still to come have a key at least
+    /// this large.
+    right_last: Option<Series>,
+
+    /// With `by` groups the keys are only sorted within each group, so the progress of both
+    /// inputs is tracked per group instead. These hold the `by` columns of a side followed by
+    /// the greatest key of the group, of all right rows seen and of all left rows joined.
+    right_last_per_group: Option<DataFrame>,
+    left_joined_last_per_group: Option<DataFrame>,
+}
+
+impl SweepState {
+    async fn buffer_left(
+        &mut self,
+        morsel: Morsel,
+        params: &AsOfJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let df = morsel.into_df();
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let key = AsOfJoinParams::evaluate_key(&params.left_key_selector, &df, state).await?;
+        params.check_sorted(&key, &mut self.left_last)?;
+        self.left_unjoined.push_back((df, key));
+        Ok(())
+    }
+
+    async fn buffer_right(
+        &mut self,
+        morsel: Morsel,
+        params: &AsOfJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let mut df = morsel.into_df();
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let key = AsOfJoinParams::evaluate_key(&params.right_key_selector, &df, state).await?;
+        params.check_sorted(&key, &mut self.right_last)?;
+        if let Some((_, right_by)) = params.by() {
+            let mut last = with_probe_key(&df, right_by, &key)?;
+            if let Some(prev) = self.right_last_per_group.take() {
+                last = prev.vstack(&last)?;
+            }
+            self.right_last_per_group = Some(last_per_group(last, right_by)?);
+        }
+
+        // Right rows with a null key never match.
+        let mask = key.is_not_null();
+        df.with_column(key.with_name(RIGHT_KEY_NAME))?;
+        if !mask.all() {
+            df = df.filter(&mask)?;
+        }
+        self.right_buffer.vstack_mut_owned(df)?;
+        self.right_buffer.rechunk_mut();
+        Ok(())
+    }
+
+    /// Request the ports to stop producing morsels and buffer the ones already produced.
+    async fn buffer_remaining(
+        &mut self,
+        left: Option<&mut PortReceiver>,
+        right: Option<&mut PortReceiver>,
+        params: &AsOfJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        if let Some(port) = left {
+            if let Ok(morsel) = port.recv().await {
+                morsel.source_token().stop();
+                self.buffer_left(morsel, params, state).await?;
+                while let Ok(morsel) = port.recv().await {
+                    self.buffer_left(morsel, params, state).await?;
+                }
+            }
+        }
+        if let Some(port) = right {
+            if let Ok(morsel) = port.recv().await {
+                morsel.source_token().stop();
+                self.buffer_right(morsel, params, state).await?;
+                while let Ok(morsel) = port.recv().await {
+                    self.buffer_right(morsel, params, state).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn right_key(&self) -> &Series {
+        self.right_buffer
+            .get_columns()
+            .last()
+            .unwrap()
+            .as_materialized_series()
+    }
+
+    /// Whether every row of the first unjoined left morsel has seen all right rows it can
+    /// match, so that joining it against the buffered right rows gives the final result.
+    fn can_join_front(&self, params: &AsOfJoinParams) -> PolarsResult<bool> {
+        let (left, left_key) = self.left_unjoined.front().unwrap();
+        if self.right_done {
+            return Ok(true);
+        }
+        if let Some((left_by, right_by)) = params.by() {
+            return self.can_join_front_by(left, left_key, left_by, right_by, params);
+        }
+        let Some(left_max) = last_non_null(left_key) else {
+            return Ok(true);
+        };
+        let Some(right_last) = &self.right_last else {
+            return Ok(false);
+        };
+        if !right_last.gt(&left_max)?.all() {
+            return Ok(false);
+        }
+
+        // Every right row with a key up to the left keys has been seen, which decides a
+        // backward or forward match.
+        let options = &params.options;
+        if options.strategy != AsofStrategy::Nearest {
+            return Ok(true);
+        }
+
+        // Rows still to come are beyond the tolerance of every left key.
+        if let Some(tolerance) = &options.tolerance {
+            let right_last = right_last.to_physical_repr();
+            if right_last.dtype().is_primitive_numeric() {
+                let distance = (&*right_last - &*left_max.to_physical_repr())?;
+                let tolerance = tolerance
+                    .clone()
+                    .into_series(PlSmallStr::EMPTY)
+                    .cast(distance.dtype())?;
+                if distance.gt(&tolerance)?.all() {
+                    return Ok(true);
+                }
+            }
+        }
+
+        // A nearest match can be the last of the right rows sharing the first following key,
+        // so we need to have seen a greater key than that.
+        let right_key = self.right_key();
+        let between = right_key.gt(&left_max)? & right_key.lt(right_last)?;
+        Ok(between.any())
+    }
+
+    /// [`Self::can_join_front`] for joins with `by` groups, which applies the same conditions
+    /// within the group of every left row.
+    fn can_join_front_by(
+        &self,
+        left: &DataFrame,
+        left_key: &Series,
+        left_by: &[PlSmallStr],
+        right_by: &[PlSmallStr],
+        params: &AsOfJoinParams,
+    ) -> PolarsResult<bool> {
+        let n_non_null = left_key.len() - left_key.null_count();
+        if n_non_null == 0 {
+            return Ok(true);
+        }
+        let Some(right_last) = &self.right_last_per_group else {
+            return Ok(false);
+        };
+
+        // Once a group has seen a right key greater than a left key, it has seen every right
+        // row that can be a backward or forward match.
+        if params.options.strategy != AsofStrategy::Nearest {
+            let ready =
+                has_following_in_group(left, left_key, left_by, right_last, right_by, false)?;
+            return Ok(ready.num_trues() as usize == n_non_null);
+        }
+
+        // A nearest match can be the last of the right rows sharing the first following key, so
+        // the group needs to have seen a greater key than that. Only right rows below the
+        // greatest key of their group are considered, as more rows with that key may still
+        // follow.
+        let right_key = self.right_key();
+        let complete = has_following_in_group(
+            &self.right_buffer,
+            right_key,
+            right_by,
+            right_last,
+            right_by,
+            false,
+        )?;
+        let probe = with_probe_key(
+            &self.right_buffer.filter(&complete)?,
+            right_by,
+            &right_key.filter(&complete)?,
+        )?;
+        let ready = has_following_in_group(left, left_key, left_by, &probe, right_by, false)?;
+        Ok(ready.num_trues() as usize == n_non_null)
+    }
+
+    /// Drop the buffered right rows which no left key from `left_min` onward can match.
+    fn prune_right(&mut self, left_min: &Series, params: &AsOfJoinParams) -> PolarsResult<()> {
+        let n_before = self.right_key().lt(left_min)?.num_trues();
+        if n_before == 0 {
+            return Ok(());
+        }
+
+        let (before, after) = self.right_buffer.split_at(n_before as i64);
+        let options = &params.options;
+        self.right_buffer = match options.strategy {
+            AsofStrategy::Forward => after,
+            // Only the last preceding row can be a backward match.
+            _ => {
+                let mut last = before.slice(n_before as i64 - 1, 1);
+                last.vstack_mut_owned(after)?;
+                last
+            },
+        };
+        self.right_buffer.rechunk_mut();
+        Ok(())
+    }
+
+    /// Drop the buffered right rows which no left row still to be joined can match. Left rows
+    /// to come have a key at least as large as the joined left rows of their `by` group, groups
+    /// without joined left rows keep all their right rows.
+    fn prune_right_by(
+        &mut self,
+        left_by: &[PlSmallStr],
+        right_by: &[PlSmallStr],
+        params: &AsOfJoinParams,
+    ) -> PolarsResult<()> {
+        let Some(left_joined_last) = &self.left_joined_last_per_group else {
+            return Ok(());
+        };
+        let before = has_following_in_group(
+            &self.right_buffer,
+            self.right_key(),
+            right_by,
+            left_joined_last,
+            left_by,
+            false,
+        )?;
+        if !before.any() {
+            return Ok(());
+        }
+
+        let after = self.right_buffer.filter(&!&before)?;
+        self.right_buffer = match params.options.strategy {
+            AsofStrategy::Forward => after,
+            // Only the last preceding row of each group can be a backward match.
+            _ => {
+                let mut last = self.right_buffer.filter(&before)?.unique_impl(
+                    true,
+                    Some(right_by.to_vec()),
+                    UniqueKeepStrategy::Last,
+                    None,
+                )?;
+                last.vstack_mut_owned(after)?;
+                last
+            },
+        };
+        self.right_buffer.rechunk_mut();
+        Ok(())
+    }
+
+    fn next_job(
+        &mut self,
+        params: &AsOfJoinParams,
+        source_token: &SourceToken,
+    ) -> PolarsResult<JoinJob> {
+        let (left, left_key) = self.left_unjoined.pop_front().unwrap();
+        if let Some((left_by, right_by)) = params.by() {
+            self.prune_right_by(left_by, right_by, params)?;
+            let mut last = with_probe_key(&left, left_by, &left_key)?;
+            if let Some(prev) = self.left_joined_last_per_group.take() {
+                last = prev.vstack(&last)?;
+            }
+            self.left_joined_last_per_group = Some(last_per_group(last, left_by)?);
+        } else {
+            let non_null = left_key.drop_nulls();
+            if !non_null.is_empty() {
+                self.prune_right(&non_null.head(Some(1)), params)?;
+            }
+        }
+
+        let mut right = self.right_buffer.clone();
+        let right_key = right
+            .drop_in_place(&RIGHT_KEY_NAME)?
+            .take_materialized_series()
+            .with_name(params.right_key_name.clone());
+
+        let seq = self.seq;
+        self.seq = self.seq.successor();
+        Ok(JoinJob {
+            left,
+            left_key,
+            right,
+            right_key,
+            seq,
+            source_token: source_token.clone(),
+        })
+    }
+
+    async fn sweep(
+        &mut self,
+        mut left: Option<PortReceiver>,
+        mut right: Option<PortReceiver>,
+        mut distributor: Sender<JoinJob>,
+        params: &AsOfJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let source_token = SourceToken::new();
+
+        loop {
+            if source_token.stop_requested() {
+                return self
+                    .buffer_remaining(left.as_mut(), right.as_mut(), params, state)
+                    .await;
+            }
+
+            if self.left_unjoined.is_empty() {
+                let morsel = match &mut left {
+                    Some(port) => port.recv().await.ok(),
+                    None => None,
+                };
+                match morsel {
+                    Some(morsel) => self.buffer_left(morsel, params, state).await?,
+                    None => {
+                        return self
+                            .buffer_remaining(None, right.as_mut(), params, state)
+                            .await;
+                    },
+                }
+                continue;
+            }
+
+            if self.can_join_front(params)? {
+                let job = self.next_job(params, &source_token)?;
+                if distributor.send(job).await.is_err() {
+                    return Ok(());
+                }
+                continue;
+            }
+
+            // We need more right rows to join the next left morsel.
+            let morsel = match &mut right {
+                Some(port) => port.recv().await.ok(),
+                None => None,
+            };
+            match morsel {
+                Some(morsel) => self.buffer_right(morsel, params, state).await?,
+                None => {
+                    return self
+                        .buffer_remaining(left.as_mut(), None, params, state)
+                        .await;
+                },
+            }
+        }
+    }
+}
+
+/// As-of join over inputs which are sorted by their keys.
+///
+/// Both inputs are swept in order. A left morsel is joined as soon as all right rows that could
+/// match it have arrived, after which only the right rows that can still match later left rows
+/// are retained. Every left morsel is joined with the in-memory as-of join against the retained
+/// right rows, so the result is the same as for the in-memory engine.
+///
+/// With `by` groups the keys only need to be sorted within each group, so both inputs are swept
+/// per group. Left morsels are still joined in order, so a left morsel waits until every group
+/// in it has seen enough right rows, and right rows of a group are only dropped once left rows
+/// of that group have been joined. Inputs whose groups progress very unevenly can therefore
+/// buffer a large part of either side.
+pub struct AsOfJoinNode {
+    params: AsOfJoinParams,
+    sweep: SweepState,
+}
+
+impl AsOfJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key_selector: StreamExpr,
+        right_key_selector: StreamExpr,
+        right_key: Field,
+        args: &JoinArgs,
+    ) -> Self {
+        let JoinType::AsOf(options) = &args.how else {
+            unreachable!()
+        };
+        let options = options.as_ref().clone();
+        if options.check_sortedness && options.left_by.is_some() {
+            polars_warn!("Sortedness of columns cannot be checked when 'by' groups provided");
+        }
+
+        // The sortedness is checked over the whole input instead of per morsel, and the slice is
+        // lowered to a separate node.
+        let mut args = args.clone();
+        args.how = JoinType::AsOf(Box::new(AsOfOptions {
+            check_sortedness: false,
+            ..options.clone()
+        }));
+        args.slice = None;
+
+        let mut right_buffer = DataFrame::empty_with_schema(&right_input_schema);
+        right_buffer
+            .with_column(Column::new_empty(RIGHT_KEY_NAME, right_key.dtype()))
+            .unwrap();
+
+        Self {
+            params: AsOfJoinParams {
+                left_key_selector,
+                right_key_selector,
+                right_key_name: right_key.name,
+                args,
+                options,
+            },
+            sweep: SweepState {
+                seq: MorselSeq::default(),
+                right_done: false,
+                left_unjoined: VecDeque::new(),
+                left_last: None,
+                right_buffer,
+                right_last: None,
+                right_last_per_group: None,
+                left_joined_last_per_group: None,
+            },
+        }
+    }
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        if recv[1] == PortState::Done {
+            self.sweep.right_done = true;
+        }
+
+        // We're done once the output is done or all left rows are joined.
+        let left_done = recv[0] == PortState::Done && self.sweep.left_unjoined.is_empty();
+        if send[0] == PortState::Done || left_done {
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            send[0] = PortState::Done;
+            return Ok(());
+        }
+
+        let send_blocked = send[0] == PortState::Blocked;
+        let left_blocked = recv[0] == PortState::Blocked && self.sweep.left_unjoined.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        send[0] = if left_blocked || right_blocked {
+            PortState::Blocked
+        } else {
+            PortState::Ready
+        };
+        if recv[0] != PortState::Done {
+            recv[0] = if send_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        if recv[1] != PortState::Done {
+            recv[1] = if send_blocked || left_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        let senders = send_ports[0].take().unwrap().parallel();
+        let left = recv_ports[0].take().map(|p| p.serial());
+        let right = recv_ports[1].take().map(|p| p.serial());
+        let (distributor, dist_recv) =
+            distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let params = &self.params;
+        join_handles.push(scope.spawn_task(
+            TaskPriority::Low,
+            self.sweep.sweep(left, right, distributor, params, state),
+        ));
+
+        join_handles.extend(
+            dist_recv
+                .into_iter()
+                .zip(senders)
+                .map(|(mut recv, mut send)| {
+                    scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(job) = recv.recv().await {
+                            let out = job.left._join_impl(
+                                &job.right,
+                                vec![job.left_key],
+                                vec![job.right_key],
+                                params.args.clone(),
+                                None,
+                                true,
+                                false,
+                            )?;
+                            if send
+                                .send(Morsel::new(out, job.seq, job.source_token))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                        Ok(())
+                    })
+                }),
+        );
+    }
+}
@@ -1,18 +1,21 @@
 use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, VecDeque};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use parking_lot::Mutex;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_core::{POOL, config};
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
 use polars_io::pl_async::get_runtime;
-use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
+use polars_ops::frame::{JoinArgs, JoinType, JoinValidation, MaintainOrderJoin};
 use polars_ops::series::coalesce_columns;
+use polars_plan::dsl::JoinBuildSide;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
@@ -30,6 +33,8 @@ use crate::expression::StreamExpr;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::pipe::port_channel;
+use crate::utils::spill::{PartitionedSpill, SpillFile, SpillReader};
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -37,7 +42,6 @@ struct EquiJoinParams {
     preserve_order_probe: bool,
     left_key_schema: Arc<Schema>,
     left_key_selectors: Vec<StreamExpr>,
-    #[allow(dead_code)]
     right_key_schema: Arc<Schema>,
     right_key_selectors: Vec<StreamExpr>,
     left_payload_select: Vec<Option<PlSmallStr>>,
@@ -46,6 +50,7 @@ struct EquiJoinParams {
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+    memory_budget: Option<usize>,
 }
 
 impl EquiJoinParams {
@@ -66,6 +71,125 @@ impl EquiJoinParams {
             self.args.how == JoinType::Left || self.args.how == JoinType::Full
         }
     }
+
+    /// Must the keys of the left (or right) input be unique to pass the join validation?
+    fn must_be_unique(&self, is_left: bool) -> bool {
+        match self.args.validation {
+            JoinValidation::ManyToMany => false,
+            JoinValidation::ManyToOne => !is_left,
+            JoinValidation::OneToMany => is_left,
+            JoinValidation::OneToOne => true,
+        }
+    }
+
+    /// Must the keys of the build side be unique to pass the join validation?
+    fn validate_build(&self) -> bool {
+        self.must_be_unique(self.left_is_build.unwrap())
+    }
+
+    /// Must the keys of the probe side be unique to pass the join validation?
+    fn validate_probe(&self) -> bool {
+        self.must_be_unique(!self.left_is_build.unwrap())
+    }
+}
+
+/// Counts the rows inserted into an [`IdxTable`] to check whether their keys are unique.
+#[derive(Default)]
+struct KeyCounter {
+    rows: usize,
+    null_rows: usize,
+}
+
+impl KeyCounter {
+    fn add(&mut self, keys: &HashKeys, subset: &[IdxSize]) {
+        self.rows += subset.len();
+        if let Some(validity) = keys.validity() {
+            self.null_rows += subset
+                .iter()
+                .filter(|i| !validity.get_bit(**i as usize))
+                .count();
+        }
+    }
+
+    /// Whether the counted rows have distinct keys in the table. Null keys are
+    /// ignored, unless nulls are equal in which case at most one may occur.
+    fn is_unique(&self, table: &dyn IdxTable, nulls_equal: bool) -> bool {
+        table.num_keys() as usize == self.rows - self.null_rows
+            && (!nulls_equal || self.null_rows <= 1)
+    }
+}
+
+/// Returns a row of the subset whose key matches more than one entry of the table.
+///
+/// # Safety
+/// The subset indices must be in-bounds.
+unsafe fn find_duplicate_key(
+    table: &dyn IdxTable,
+    keys: &HashKeys,
+    subset: &[IdxSize],
+) -> Option<IdxSize> {
+    let mut table_match = Vec::new();
+    let mut probe_match = Vec::new();
+    unsafe {
+        table.probe_subset(
+            keys,
+            subset,
+            &mut table_match,
+            &mut probe_match,
+            false,
+            false,
+            IdxSize::MAX,
+        )
+    };
+    probe_match.windows(2).find(|w| w[0] == w[1]).map(|w| w[0])
+}
+
+/// Creates the error of a failed join validation for the given build keys,
+/// finding a duplicate the same way the hash table compares keys.
+fn build_validation_error(
+    keys: &DataFrame,
+    params: &EquiJoinParams,
+    table: &dyn IdxTable,
+) -> PolarsError {
+    let hash_keys = hash_key_df(keys, params);
+    let subset = (0..keys.height() as IdxSize).collect_vec();
+    let mut p_table = table.new_empty();
+    let row = unsafe {
+        p_table.insert_keys_subset(&hash_keys, &subset, false);
+        find_duplicate_key(&*p_table, &hash_keys, &subset)
+    };
+    validation_error(keys, row, params.left_is_build.unwrap(), params)
+}
+
+/// Creates the error of a failed join validation, naming the duplicate key in the
+/// given row of the keys if it is known.
+fn validation_error(
+    keys: &DataFrame,
+    row: Option<IdxSize>,
+    is_left: bool,
+    params: &EquiJoinParams,
+) -> PolarsError {
+    let (side, key_schema) = if is_left {
+        ("left", &params.left_key_schema)
+    } else {
+        ("right", &params.right_key_schema)
+    };
+    let validation = params.args.validation;
+    match row.and_then(|r| keys.get_row(r as usize).ok()) {
+        Some(row) => {
+            let key = key_schema
+                .iter_names()
+                .zip(row.0)
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect_vec()
+                .join(", ");
+            polars_err!(
+                ComputeError: "join keys did not fulfill {validation} validation: \
+                the {side} key ({key}) occurs more than once"
+            )
+        },
+        None => polars_err!(ComputeError: "join keys did not fulfill {validation} validation"),
+    }
 }
 
 /// A payload selector contains for each column whether that column should be
@@ -165,23 +289,35 @@ fn select_schema(schema: &Schema, selector: &[Option<PlSmallStr>]) -> Schema {
         .collect()
 }
 
-async fn select_keys(
+async fn select_key_df(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &EquiJoinParams,
     state: &ExecutionState,
-) -> PolarsResult<HashKeys> {
+) -> PolarsResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
-    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
-    Ok(HashKeys::from_df(
-        &keys,
+    DataFrame::new_with_broadcast_len(key_columns, df.height())
+}
+
+fn hash_key_df(keys: &DataFrame, params: &EquiJoinParams) -> HashKeys {
+    HashKeys::from_df(
+        keys,
         params.random_state.clone(),
         params.args.nulls_equal,
         false,
-    ))
+    )
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<HashKeys> {
+    let keys = select_key_df(df, key_selectors, state).await?;
+    Ok(hash_key_df(&keys, params))
 }
 
 fn select_payload(df: DataFrame, selector: &[Option<PlSmallStr>]) -> DataFrame {
@@ -365,6 +501,7 @@ impl SampleState {
             state.num_pipelines,
             state.num_pipelines,
             sampled_probe_morsels,
+            params.memory_budget,
         );
 
         // Simulate the sample build morsels flowing into the build side.
@@ -381,6 +518,7 @@ impl SampleState {
                         BuildState::partition_and_sink(
                             recv,
                             local_builder,
+                            build_state.spill.as_ref(),
                             partitioner.clone(),
                             params,
                             state,
@@ -415,11 +553,141 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // If spilling is enabled or the build keys are validated, key_dfs[i] contains
+    // the keys of morsels[i] in the layout they are spilled in, and num_bytes the
+    // size of the buffered morsels.
+    key_dfs: Vec<DataFrame>,
+    num_bytes: usize,
+}
+
+impl LocalBuilder {
+    /// Moves the buffered rows of all spilled partitions to disk.
+    fn flush_spilled_partitions(&mut self, spill: &BuildSpill) -> PolarsResult<()> {
+        let num_partitions = self.sketch_per_p.len();
+        let first_spilled = spill.first_spilled_partition();
+        if first_spilled == num_partitions {
+            return Ok(());
+        }
+
+        let morsels = core::mem::take(&mut self.morsels);
+        let key_dfs = core::mem::take(&mut self.key_dfs);
+        let idxs_values_per_p = core::mem::replace(
+            &mut self.morsel_idxs_values_per_p,
+            vec![Vec::new(); num_partitions],
+        );
+        let idxs_offsets_per_p =
+            core::mem::replace(&mut self.morsel_idxs_offsets_per_p, vec![0; num_partitions]);
+        self.num_bytes = 0;
+
+        let mut kept_idxs = Vec::new();
+        for (i, ((seq, payload, keys), key_df)) in morsels.into_iter().zip(key_dfs).enumerate() {
+            let idxs_in_p = |p: usize| {
+                let start = idxs_offsets_per_p[i * num_partitions + p];
+                let stop = idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                &idxs_values_per_p[p][start..stop]
+            };
+
+            let mut spill_df: Option<DataFrame> = None;
+            for p in first_spilled..num_partitions {
+                let idxs = idxs_in_p(p);
+                if idxs.is_empty() {
+                    continue;
+                }
+                let spill_df = spill_df.get_or_insert_with(|| {
+                    let mut df = payload.clone();
+                    unsafe { df.hstack_mut_unchecked(key_df.get_columns()) };
+                    df
+                });
+                spill.partitions.write(p, &unsafe {
+                    spill_df.take_slice_unchecked_impl(idxs, false)
+                })?;
+            }
+
+            kept_idxs.clear();
+            for p in 0..first_spilled {
+                kept_idxs.extend_from_slice(idxs_in_p(p));
+            }
+            if kept_idxs.is_empty() {
+                continue;
+            }
+
+            let (payload, keys, key_df) = if spill_df.is_none() {
+                // Nothing was spilled from this morsel, keep it as-is.
+                for (p, values) in self.morsel_idxs_values_per_p.iter_mut().enumerate() {
+                    values.extend_from_slice(idxs_in_p(p));
+                }
+                (payload, keys, key_df)
+            } else {
+                // Only keep the rows of in-memory partitions, which are now consecutive.
+                let mut offset = 0;
+                for p in 0..first_spilled {
+                    let len = idxs_in_p(p).len() as IdxSize;
+                    self.morsel_idxs_values_per_p[p].extend(offset..offset + len);
+                    offset += len;
+                }
+                unsafe {
+                    (
+                        payload.take_slice_unchecked_impl(&kept_idxs, false),
+                        keys.gather_unchecked(&kept_idxs),
+                        key_df.take_slice_unchecked_impl(&kept_idxs, false),
+                    )
+                }
+            };
+
+            self.morsel_idxs_offsets_per_p
+                .extend(self.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
+            self.num_bytes += payload.estimated_size() + key_df.estimated_size();
+            self.morsels.push((seq, payload, keys));
+            self.key_dfs.push(key_df);
+        }
+        Ok(())
+    }
+}
+
+/// Grace hash join state for the build side.
+///
+/// Once a local builder exceeds its share of the memory budget, the last
+/// `num_spilled` partitions are moved to disk. These partitions are built and
+/// probed one at a time after the in-memory partitions are done.
+struct BuildSpill {
+    budget_per_local: usize,
+    num_spilled: AtomicUsize,
+    partitions: PartitionedSpill,
+}
+
+impl BuildSpill {
+    fn first_spilled_partition(&self) -> usize {
+        self.partitions.num_partitions() - self.num_spilled.load(Ordering::Relaxed)
+    }
+
+    /// Spills partitions until the local builder is within its memory budget.
+    fn spill_until_within_budget(&self, local: &mut LocalBuilder) -> PolarsResult<()> {
+        loop {
+            local.flush_spilled_partitions(self)?;
+            let num_spilled = self.num_spilled.load(Ordering::Relaxed);
+            if local.num_bytes <= self.budget_per_local
+                || num_spilled == self.partitions.num_partitions()
+            {
+                return Ok(());
+            }
+
+            // Another builder might have spilled a partition concurrently, in
+            // which case we simply try again.
+            _ = self.num_spilled.compare_exchange(
+                num_spilled,
+                num_spilled + 1,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
+    }
 }
 
 struct BuildState {
     local_builders: Vec<LocalBuilder>,
     sampled_probe_morsels: BufferedStream,
+    spill: Option<BuildSpill>,
 }
 
 impl BuildState {
@@ -427,6 +695,7 @@ impl BuildState {
         num_pipelines: usize,
         num_partitions: usize,
         sampled_probe_morsels: BufferedStream,
+        memory_budget: Option<usize>,
     ) -> Self {
         let local_builders = (0..num_pipelines)
             .map(|_| LocalBuilder {
@@ -434,17 +703,26 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                key_dfs: Vec::new(),
+                num_bytes: 0,
             })
             .collect();
+        let spill = memory_budget.map(|budget| BuildSpill {
+            budget_per_local: budget / num_pipelines,
+            num_spilled: AtomicUsize::new(0),
+            partitions: PartitionedSpill::new(num_partitions),
+        });
         Self {
             local_builders,
             sampled_probe_morsels,
+            spill,
         }
     }
 
     async fn partition_and_sink(
         mut recv: PortReceiver,
         local: &mut LocalBuilder,
+        spill: Option<&BuildSpill>,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
@@ -462,13 +740,9 @@ impl BuildState {
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let keys =
+                select_key_df(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys = hash_key_df(&keys, params);
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
@@ -482,13 +756,89 @@ impl BuildState {
             local
                 .morsel_idxs_offsets_per_p
                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
+
+            if spill.is_some() || params.validate_build() {
+                let key_df = keys
+                    .take_columns()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, c)| c.with_name(format_pl_smallstr!("__POLARS_SPILL_KEYCOL_{i}")))
+                    .collect::<DataFrame>();
+                local.num_bytes += payload.estimated_size() + key_df.estimated_size();
+                local.key_dfs.push(key_df);
+            }
             local.morsels.push((morsel.seq(), payload, hash_keys));
+
+            if let Some(spill) = spill {
+                if local.num_bytes > spill.budget_per_local {
+                    spill.spill_until_within_budget(local)?;
+                }
+            }
         }
         Ok(())
     }
 
-    fn finalize_ordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
+    /// Moves all remaining rows of spilled partitions to disk.
+    ///
+    /// Returns `None` if no partitions were spilled.
+    fn finish_spill(&mut self) -> PolarsResult<Option<ProbeSpill>> {
+        let Some(spill) = self.spill.take() else {
+            return Ok(None);
+        };
+        let first_spilled = spill.first_spilled_partition();
+        if first_spilled == spill.partitions.num_partitions() {
+            return Ok(None);
+        }
+
+        POOL.install(|| {
+            self.local_builders
+                .par_iter_mut()
+                .with_max_len(1)
+                .try_for_each(|l| l.flush_spilled_partitions(&spill))
+        })?;
+
+        let num_partitions = spill.partitions.num_partitions();
+        Ok(Some(ProbeSpill {
+            first_spilled,
+            build: spill.partitions.finish()?,
+            probe: PartitionedSpill::new(num_partitions),
+        }))
+    }
+
+    /// Fails the join validation if partition `duplicate_partition` has duplicate
+    /// keys, naming one of them.
+    fn check_unique_keys(
+        &self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+        duplicate_partition: usize,
+    ) -> PolarsResult<()> {
+        if duplicate_partition == usize::MAX {
+            return Ok(());
+        }
+
+        let p = duplicate_partition;
+        let num_partitions = self.local_builders[0].sketch_per_p.len();
+        let key_dfs = self.local_builders.iter().flat_map(|l| {
+            l.key_dfs.iter().enumerate().map(move |(i, key_df)| {
+                let start = l.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let stop = l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let idxs = &l.morsel_idxs_values_per_p[p][start..stop];
+                unsafe { key_df.take_slice_unchecked_impl(idxs, false) }
+            })
+        });
+        let keys = accumulate_dataframes_vertical_unchecked(key_dfs);
+        Err(build_validation_error(&keys, params, table))
+    }
+
+    fn finalize_ordered(
+        &mut self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeState> {
         let track_unmatchable = params.emit_unmatched_build();
+        let validate = params.validate_build();
+        let duplicate_partition = AtomicUsize::new(usize::MAX);
         let payload_schema = if params.left_is_build.unwrap() {
             &params.left_payload_schema
         } else {
@@ -502,6 +852,7 @@ impl BuildState {
         POOL.scope(|s| {
             for p in 0..num_partitions {
                 let probe_tables = &probe_tables;
+                let duplicate_partition = &duplicate_partition;
                 s.spawn(move |_| {
                     // TODO: every thread does an identical linearize, we can do a single parallel one.
                     let mut kmerge = BinaryHeap::with_capacity(local_builders.len());
@@ -533,6 +884,7 @@ impl BuildState {
                     if track_unmatchable {
                         p_seq_ids.reserve(payload_rows);
                     }
+                    let mut key_counter = KeyCounter::default();
 
                     // Linearize and build.
                     unsafe {
@@ -554,6 +906,9 @@ impl BuildState {
                                 [p_morsel_idxs_start..p_morsel_idxs_stop];
                             p_table.insert_keys_subset(keys, p_morsel_idxs, track_unmatchable);
                             p_payload.gather_extend(payload, p_morsel_idxs, ShareStrategy::Never);
+                            if validate {
+                                key_counter.add(keys, p_morsel_idxs);
+                            }
 
                             if track_unmatchable {
                                 p_seq_ids.resize(p_payload.len(), norm_seq_id);
@@ -562,6 +917,10 @@ impl BuildState {
                         }
                     }
 
+                    if validate && !key_counter.is_unique(&*p_table, params.args.nulls_equal) {
+                        duplicate_partition.fetch_min(p, Ordering::Relaxed);
+                    }
+
                     probe_tables
                         .try_set(
                             p,
@@ -577,16 +936,26 @@ impl BuildState {
             }
         });
 
-        ProbeState {
+        self.check_unique_keys(params, table, duplicate_partition.into_inner())?;
+        Ok(ProbeState {
             table_per_partition: probe_tables.try_assume_init().ok().unwrap(),
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
-        }
+            spill: None,
+            spilled_input: None,
+            seen_probe_keys: new_seen_probe_keys(params, table, num_partitions),
+        })
     }
 
-    fn finalize_unordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
+    fn finalize_unordered(
+        &mut self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeState> {
         let track_unmatchable = params.emit_unmatched_build();
+        let validate = params.validate_build();
+        let duplicate_partition = AtomicUsize::new(usize::MAX);
         let payload_schema = if params.left_is_build.unwrap() {
             &params.left_payload_schema
         } else {
@@ -618,6 +987,7 @@ impl BuildState {
                 let morsel_drop_q_send = morsel_drop_q_send.clone();
                 let morsel_drop_q_recv = morsel_drop_q_recv.clone();
                 let probe_tables = &probe_tables;
+                let duplicate_partition = &duplicate_partition;
                 join_handles.push(s.spawn_task(TaskPriority::High, async move {
                     // Extract from outer arc and drop outer arc.
                     let morsels_per_local_builder =
@@ -639,6 +1009,7 @@ impl BuildState {
                     p_table.reserve(sketch.estimate() * 5 / 4);
                     let mut p_payload = DataFrameBuilder::new(payload_schema.clone());
                     p_payload.reserve(payload_rows);
+                    let mut key_counter = KeyCounter::default();
 
                     // Build.
                     let mut skip_drop_attempt = false;
@@ -663,6 +1034,9 @@ impl BuildState {
                                     p_morsel_idxs,
                                     ShareStrategy::Never,
                                 );
+                                if validate {
+                                    key_counter.add(keys, p_morsel_idxs);
+                                }
                             }
                         }
 
@@ -683,6 +1057,10 @@ impl BuildState {
                         drop(l_morsels);
                     }
 
+                    if validate && !key_counter.is_unique(&*p_table, params.args.nulls_equal) {
+                        duplicate_partition.fetch_min(p, Ordering::Relaxed);
+                    }
+
                     probe_tables
                         .try_set(
                             p,
@@ -711,12 +1089,16 @@ impl BuildState {
             });
         });
 
-        ProbeState {
+        self.check_unique_keys(params, table, duplicate_partition.into_inner())?;
+        Ok(ProbeState {
             table_per_partition: probe_tables.try_assume_init().ok().unwrap(),
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
-        }
+            spill: None,
+            spilled_input: None,
+            seen_probe_keys: new_seen_probe_keys(params, table, num_partitions),
+        })
     }
 }
 
@@ -726,6 +1108,157 @@ struct ProbeTable {
     seq_ids: Vec<IdxSize>,
 }
 
+/// Grace hash join state for the probe side, probe rows belonging to spilled
+/// partitions are spilled as well.
+struct ProbeSpill {
+    first_spilled: usize,
+    build: Vec<Option<SpillFile>>,
+    probe: PartitionedSpill,
+}
+
+impl ProbeSpill {
+    fn finish(self) -> PolarsResult<VecDeque<SpilledPartition>> {
+        let probe = self.probe.finish()?;
+        Ok(self
+            .build
+            .into_iter()
+            .zip(probe)
+            .enumerate()
+            .skip(self.first_spilled)
+            .map(|(partition_idx, (build, probe))| SpilledPartition {
+                partition_idx,
+                build,
+                probe,
+            })
+            .collect())
+    }
+}
+
+/// A partition which was spilled to disk, it is built and probed after all
+/// in-memory partitions are done.
+struct SpilledPartition {
+    partition_idx: usize,
+    build: Option<SpillFile>,
+    probe: Option<SpillFile>,
+}
+
+impl SpilledPartition {
+    fn build_table(
+        &self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeTable> {
+        let track_unmatchable = params.emit_unmatched_build();
+        let payload_schema = if params.left_is_build.unwrap() {
+            &params.left_payload_schema
+        } else {
+            &params.right_payload_schema
+        };
+
+        let validate = params.validate_build();
+        let mut key_counter = KeyCounter::default();
+        let mut validated_keys = Vec::new();
+        let mut p_table = table.new_empty();
+        let mut p_payload = DataFrameBuilder::new(payload_schema.clone());
+        if let Some(build) = &self.build {
+            p_payload.reserve(build.num_rows());
+            let mut reader = build.reader()?;
+            let mut subset = Vec::new();
+            while let Some(df) = reader.next_df()? {
+                // The keys are stored after the payload columns.
+                let height = df.height();
+                let mut payload_cols = df.take_columns();
+                let key_cols = payload_cols.split_off(payload_schema.len());
+                let (payload, keys) = unsafe {
+                    (
+                        DataFrame::new_no_checks(height, payload_cols),
+                        DataFrame::new_no_checks(height, key_cols),
+                    )
+                };
+
+                subset.clear();
+                subset.extend(0..height as IdxSize);
+                let hash_keys = hash_key_df(&keys, params);
+                unsafe {
+                    p_table.insert_keys_subset(&hash_keys, &subset, track_unmatchable);
+                    p_payload.gather_extend(&payload, &subset, ShareStrategy::Never);
+                }
+                if validate {
+                    key_counter.add(&hash_keys, &subset);
+                    validated_keys.push(keys);
+                }
+            }
+        }
+
+        if validate && !key_counter.is_unique(&*p_table, params.args.nulls_equal) {
+            let keys = accumulate_dataframes_vertical_unchecked(validated_keys);
+            return Err(build_validation_error(&keys, params, table));
+        }
+
+        Ok(ProbeTable {
+            hash_table: p_table,
+            payload: p_payload.freeze(),
+            seq_ids: Vec::new(),
+        })
+    }
+}
+
+/// The spilled probe morsels of the spilled partition which is being processed.
+struct SpilledProbeInput {
+    reader: Mutex<SpillReader>,
+    exhausted: RelaxedCell<bool>,
+    _file: SpillFile,
+}
+
+impl SpilledProbeInput {
+    fn new(file: SpillFile) -> PolarsResult<Self> {
+        Ok(Self {
+            reader: Mutex::new(file.reader()?),
+            exhausted: RelaxedCell::from(false),
+            _file: file,
+        })
+    }
+
+    /// Spawns tasks reading the spilled morsels into the returned receivers,
+    /// acting like an InMemorySource node.
+    fn spawn_readers<'s, 'env>(
+        &'s self,
+        num_pipelines: usize,
+        scope: &'s TaskScope<'s, 'env>,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) -> Vec<PortReceiver> {
+        let source_token = SourceToken::new();
+        (0..num_pipelines)
+            .map(|_| {
+                let (mut send, recv) = port_channel(None);
+                let source_token = source_token.clone();
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    let wait_group = WaitGroup::default();
+                    loop {
+                        let Some(df) = self.reader.lock().next_df()? else {
+                            self.exhausted.store(true);
+                            break;
+                        };
+                        let mut morsel =
+                            Morsel::new(df, MorselSeq::default(), source_token.clone());
+                        morsel.set_consume_token(wait_group.token());
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+
+                        wait_group.wait().await;
+                        if source_token.stop_requested() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+                recv
+            })
+            .collect()
+    }
+}
+
 struct ProbeState {
     table_per_partition: Vec<ProbeTable>,
     max_seq_sent: MorselSeq,
@@ -733,14 +1266,81 @@ struct ProbeState {
 
     // For unordered joins we relabel output morsels to speed up the linearizer.
     unordered_morsel_seq: AtomicU64,
+
+    spill: Option<ProbeSpill>,
+    spilled_input: Option<Box<SpilledProbeInput>>,
+
+    // If the probe keys are validated, the keys seen so far per partition.
+    seen_probe_keys: Option<Vec<Mutex<SeenKeys>>>,
+}
+
+/// The probe keys seen so far in a partition, used to check they are unique.
+struct SeenKeys {
+    table: Box<dyn IdxTable>,
+    counter: KeyCounter,
+}
+
+fn new_seen_probe_keys(
+    params: &EquiJoinParams,
+    table: &dyn IdxTable,
+    num_partitions: usize,
+) -> Option<Vec<Mutex<SeenKeys>>> {
+    params.validate_probe().then(|| {
+        (0..num_partitions)
+            .map(|_| {
+                Mutex::new(SeenKeys {
+                    table: table.new_empty(),
+                    counter: KeyCounter::default(),
+                })
+            })
+            .collect()
+    })
 }
 
 impl ProbeState {
+    /// Adds the keys of a probe morsel to the seen keys of their partitions,
+    /// failing the join validation if any key was seen before.
+    fn check_unique_keys(
+        keys: &DataFrame,
+        hash_keys: &HashKeys,
+        seen_keys: &[Mutex<SeenKeys>],
+        partitioner: &HashPartitioner,
+        idxs_per_p: &mut [Vec<IdxSize>],
+        params: &EquiJoinParams,
+    ) -> PolarsResult<()> {
+        for idxs in idxs_per_p.iter_mut() {
+            idxs.clear();
+        }
+        hash_keys.gen_idxs_per_partition(partitioner, idxs_per_p, &mut [], false);
+
+        for (seen, idxs) in seen_keys.iter().zip(idxs_per_p.iter()) {
+            if idxs.is_empty() {
+                continue;
+            }
+            let mut seen = seen.lock();
+            let seen = &mut *seen;
+            unsafe { seen.table.insert_keys_subset(hash_keys, idxs, false) };
+            seen.counter.add(hash_keys, idxs);
+            if !seen
+                .counter
+                .is_unique(&*seen.table, params.args.nulls_equal)
+            {
+                let row = unsafe { find_duplicate_key(&*seen.table, hash_keys, idxs) };
+                let is_left = !params.left_is_build.unwrap();
+                return Err(validation_error(keys, row, is_left, params));
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the max morsel sequence sent.
+    #[allow(clippy::too_many_arguments)]
     async fn partition_and_probe(
         mut recv: PortReceiver,
         mut send: PortSender,
         partitions: &[ProbeTable],
+        spill: Option<&ProbeSpill>,
+        seen_probe_keys: Option<&[Mutex<SeenKeys>]>,
         unordered_morsel_seq: &AtomicU64,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
@@ -787,8 +1387,19 @@ impl ProbeState {
                 continue;
             }
 
-            let hash_keys =
-                select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
+            let keys = select_key_df(&df, key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys = hash_key_df(&keys, params);
+            if let Some(seen_keys) = seen_probe_keys {
+                Self::check_unique_keys(
+                    &keys,
+                    &hash_keys,
+                    seen_keys,
+                    &partitioner,
+                    &mut partition_idxs,
+                    params,
+                )?;
+            }
+            let spill_df = spill.map(|_| df.clone());
             let mut payload = select_payload(df, payload_selector);
             let mut payload_rechunked = false; // We don't eagerly rechunk because there might be no matches.
             let mut total_matches = 0;
@@ -910,7 +1521,20 @@ impl ProbeState {
                         emit_unmatched,
                     );
 
-                    for (p, idxs_in_p) in partitions.iter().zip(&partition_idxs) {
+                    for (p_idx, (p, idxs_in_p)) in
+                        partitions.iter().zip(&partition_idxs).enumerate()
+                    {
+                        if let Some(spill) = spill {
+                            if p_idx >= spill.first_spilled {
+                                let spill_df = spill_df.as_ref().unwrap();
+                                spill.probe.write(
+                                    p_idx,
+                                    &spill_df.take_slice_unchecked_impl(idxs_in_p, false),
+                                )?;
+                                continue;
+                            }
+                        }
+
                         let mut offset = 0;
                         while offset < idxs_in_p.len() {
                             let matches_before_limit = probe_limit - probe_match.len() as IdxSize;
@@ -1161,6 +1785,7 @@ pub struct EquiJoinNode {
     state: EquiJoinState,
     params: EquiJoinParams,
     table: Box<dyn IdxTable>,
+    spilled_partitions: VecDeque<SpilledPartition>,
 }
 
 impl EquiJoinNode {
@@ -1174,15 +1799,23 @@ impl EquiJoinNode {
         left_key_selectors: Vec<StreamExpr>,
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
+        build_side: Option<JoinBuildSide>,
         num_pipelines: usize,
+        memory_budget: Option<usize>,
     ) -> PolarsResult<Self> {
         let left_is_build = match args.maintain_order {
-            MaintainOrderJoin::None => {
-                if *JOIN_SAMPLE_LIMIT == 0 {
-                    Some(true)
-                } else {
-                    None
-                }
+            // If only one side must have unique keys we build on it, so only the
+            // hash table has to be checked and not every probe key tracked.
+            MaintainOrderJoin::None => match args.validation {
+                JoinValidation::OneToMany => Some(true),
+                JoinValidation::ManyToOne => Some(false),
+                // Otherwise trust the side the optimizer estimated to be smaller,
+                // and only sample the inputs if it had no estimate.
+                _ => match build_side {
+                    Some(side) => Some(side == JoinBuildSide::Left),
+                    None if *JOIN_SAMPLE_LIMIT == 0 => Some(true),
+                    None => None,
+                },
             },
             MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight => Some(false),
             MaintainOrderJoin::Right | MaintainOrderJoin::RightLeft => Some(true),
@@ -1211,11 +1844,16 @@ impl EquiJoinNode {
             &args,
         )?;
 
+        // Spilled partitions are processed one at a time, which is incompatible
+        // with maintaining the order.
+        let memory_budget = memory_budget.filter(|_| !preserve_order_probe);
+
         let state = if left_is_build.is_some() {
             EquiJoinState::Build(BuildState::new(
                 num_pipelines,
                 num_pipelines,
                 BufferedStream::default(),
+                memory_budget,
             ))
         } else {
             EquiJoinState::Sample(SampleState::default())
@@ -1240,10 +1878,83 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::default(),
+                memory_budget,
             },
             table: new_idx_table(unique_key_schema),
+            spilled_partitions: VecDeque::new(),
         })
     }
+
+    /// Transitions to processing the next spilled partition, or to being done
+    /// if there are none left.
+    fn next_spilled_partition(
+        &mut self,
+        morsel_seq: MorselSeq,
+        num_partitions: usize,
+    ) -> PolarsResult<()> {
+        while let Some(spilled) = self.spilled_partitions.pop_front() {
+            let probe_needed = spilled.probe.is_some()
+                && (spilled.build.is_some() || self.params.emit_unmatched_probe());
+            let emit_unmatched_build =
+                spilled.build.is_some() && self.params.emit_unmatched_build();
+            if !probe_needed && !emit_unmatched_build {
+                // The table is still built to validate its keys.
+                if self.params.validate_build() {
+                    spilled.build_table(&self.params, &*self.table)?;
+                }
+                continue;
+            }
+
+            if config::verbose() {
+                eprintln!(
+                    "[EquiJoinNode]: processing spilled partition {}",
+                    spilled.partition_idx
+                );
+            }
+
+            let table = spilled.build_table(&self.params, &*self.table)?;
+            let Some(probe) = spilled.probe.filter(|_| probe_needed) else {
+                self.state = EquiJoinState::EmitUnmatchedBuild(EmitUnmatchedState {
+                    partitions: vec![table],
+                    active_partition_idx: 0,
+                    offset_in_active_p: 0,
+                    morsel_seq,
+                });
+                return Ok(());
+            };
+
+            // Only the spilled partition is non-empty, the spilled probe rows
+            // all belong to it.
+            let build_payload_schema = if self.params.left_is_build.unwrap() {
+                &self.params.left_payload_schema
+            } else {
+                &self.params.right_payload_schema
+            };
+            let mut table_per_partition = (0..num_partitions)
+                .map(|_| ProbeTable {
+                    hash_table: self.table.new_empty(),
+                    payload: DataFrame::empty_with_schema(build_payload_schema),
+                    seq_ids: Vec::new(),
+                })
+                .collect_vec();
+            table_per_partition[spilled.partition_idx] = table;
+
+            self.state = EquiJoinState::Probe(ProbeState {
+                table_per_partition,
+                max_seq_sent: morsel_seq,
+                sampled_probe_morsels: BufferedStream::default(),
+                unordered_morsel_seq: AtomicU64::new(morsel_seq.to_u64()),
+                spill: None,
+                spilled_input: Some(Box::new(SpilledProbeInput::new(probe)?)),
+                // The spilled probe keys were already validated when first seen.
+                seen_probe_keys: None,
+            });
+            return Ok(());
+        }
+
+        self.state = EquiJoinState::Done;
+        Ok(())
+    }
 }
 
 impl ComputeNode for EquiJoinNode {
@@ -1283,20 +1994,30 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
-                let probe_state = if self.params.preserve_order_build {
-                    build_state.finalize_ordered(&self.params, &*self.table)
+                let spill = build_state.finish_spill()?;
+                let mut probe_state = if self.params.preserve_order_build {
+                    build_state.finalize_ordered(&self.params, &*self.table)?
                 } else {
-                    build_state.finalize_unordered(&self.params, &*self.table)
+                    build_state.finalize_unordered(&self.params, &*self.table)?
                 };
+                probe_state.spill = spill;
                 self.state = EquiJoinState::Probe(probe_state);
             }
         }
 
         // If we are probing and the probe input is done, emit unmatched if
-        // necessary, otherwise we're done.
+        // necessary, otherwise continue with the spilled partitions.
         if let EquiJoinState::Probe(probe_state) = &mut self.state {
             let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
-            if samples_consumed && recv[probe_idx] == PortState::Done {
+            let input_done = match &probe_state.spilled_input {
+                Some(input) => input.exhausted.load(),
+                None => recv[probe_idx] == PortState::Done,
+            };
+            if samples_consumed && input_done {
+                if let Some(spill) = probe_state.spill.take() {
+                    self.spilled_partitions = spill.finish()?;
+                }
+
                 if self.params.emit_unmatched_build() {
                     if self.params.preserve_order_build {
                         let unmatched = probe_state.ordered_unmatched(&self.params);
@@ -1314,7 +2035,8 @@ impl ComputeNode for EquiJoinNode {
                         });
                     }
                 } else {
-                    self.state = EquiJoinState::Done;
+                    let morsel_seq = probe_state.max_seq_sent.successor();
+                    self.next_spilled_partition(morsel_seq, state.num_pipelines)?;
                 }
             }
         }
@@ -1322,7 +2044,8 @@ impl ComputeNode for EquiJoinNode {
         // Finally, check if we are done emitting unmatched keys.
         if let EquiJoinState::EmitUnmatchedBuild(emit_state) = &mut self.state {
             if emit_state.active_partition_idx >= emit_state.partitions.len() {
-                self.state = EquiJoinState::Done;
+                let morsel_seq = emit_state.morsel_seq;
+                self.next_spilled_partition(morsel_seq, state.num_pipelines)?;
             }
         }
 
@@ -1358,7 +2081,11 @@ impl ComputeNode for EquiJoinNode {
                     core::mem::swap(&mut send[0], &mut recv[probe_idx]);
                 } else {
                     let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
-                    send[0] = if samples_consumed {
+                    let spilled_input_consumed = probe_state
+                        .spilled_input
+                        .as_ref()
+                        .is_none_or(|input| input.exhausted.load());
+                    send[0] = if samples_consumed && spilled_input_consumed {
                         PortState::Done
                     } else {
                         PortState::Ready
@@ -1464,6 +2191,7 @@ impl ComputeNode for EquiJoinNode {
                         BuildState::partition_and_sink(
                             recv,
                             local_builder,
+                            build_state.spill.as_ref(),
                             partitioner.clone(),
                             &self.params,
                             state,
@@ -1474,15 +2202,18 @@ impl ComputeNode for EquiJoinNode {
             EquiJoinState::Probe(probe_state) => {
                 assert!(recv_ports[build_idx].is_none());
                 let senders = send_ports[0].take().unwrap().parallel();
-                let receivers = probe_state
-                    .sampled_probe_morsels
-                    .reinsert(
-                        state.num_pipelines,
-                        recv_ports[probe_idx].take(),
-                        scope,
-                        join_handles,
-                    )
-                    .unwrap();
+                let receivers = match &probe_state.spilled_input {
+                    Some(input) => input.spawn_readers(state.num_pipelines, scope, join_handles),
+                    None => probe_state
+                        .sampled_probe_morsels
+                        .reinsert(
+                            state.num_pipelines,
+                            recv_ports[probe_idx].take(),
+                            scope,
+                            join_handles,
+                        )
+                        .unwrap(),
+                };
 
                 let partitioner = HashPartitioner::new(state.num_pipelines, 0);
                 let probe_tasks = receivers
@@ -1495,6 +2226,8 @@ impl ComputeNode for EquiJoinNode {
                                 recv,
                                 send,
                                 &probe_state.table_per_partition,
+                                probe_state.spill.as_ref(),
+                                probe_state.seen_probe_keys.as_deref(),
                                 &probe_state.unordered_morsel_seq,
                                 partitioner.clone(),
                                 &self.params,
@@ -0,0 +1,271 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::frame::{FuzzyJoinIndex, FuzzyJoinOptions, JoinArgs, JoinType};
+use polars_utils::format_pl_smallstr;
+use polars_utils::itertools::Itertools;
+
+use crate::expression::StreamExpr;
+use crate::morsel::MorselSeq;
+use crate::nodes::compute_node_prelude::*;
+
+struct FuzzyJoinParams {
+    left_key_selector: StreamExpr,
+    right_key_selector: StreamExpr,
+    right_rename: Vec<Option<PlSmallStr>>,
+    options: FuzzyJoinOptions,
+}
+
+/// Joins rows whose string keys are similar. The right side is fully collected and indexed,
+/// after which the left side is streamed through the index.
+pub struct FuzzyJoinNode {
+    params: FuzzyJoinParams,
+    state: FuzzyJoinState,
+}
+
+impl FuzzyJoinNode {
+    pub fn new(
+        left_input_schema: Arc<Schema>,
+        right_input_schema: Arc<Schema>,
+        left_key_selector: StreamExpr,
+        right_key_selector: StreamExpr,
+        args: &JoinArgs,
+        num_pipelines: usize,
+    ) -> Self {
+        let JoinType::Fuzzy(options) = &args.how else {
+            unreachable!()
+        };
+        let right_rename = right_input_schema
+            .iter_names()
+            .map(|rname| {
+                if left_input_schema.contains(rname) {
+                    Some(format_pl_smallstr!("{}{}", rname, args.suffix()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            params: FuzzyJoinParams {
+                left_key_selector,
+                right_key_selector,
+                right_rename,
+                options: options.as_ref().clone(),
+            },
+            state: FuzzyJoinState::Build(BuildState {
+                local_builders: (0..num_pipelines).map(|_| Vec::new()).collect(),
+            }),
+        }
+    }
+}
+
+enum FuzzyJoinState {
+    Build(BuildState),
+    Probe(ProbeState),
+    Done,
+}
+
+struct BuildState {
+    /// The morsels seen by every pipeline, together with their evaluated keys.
+    local_builders: Vec<Vec<(MorselSeq, DataFrame, Column)>>,
+}
+
+impl BuildState {
+    async fn sink(
+        mut recv: PortReceiver,
+        local: &mut Vec<(MorselSeq, DataFrame, Column)>,
+        params: &FuzzyJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        while let Ok(morsel) = recv.recv().await {
+            let key = params
+                .right_key_selector
+                .evaluate(morsel.df(), &state.in_memory_exec_state)
+                .await?;
+            let seq = morsel.seq();
+            local.push((seq, morsel.into_df(), key));
+        }
+        Ok(())
+    }
+
+    /// Returns `None` if the build side is empty, in which case nothing can match.
+    fn finalize(&mut self, params: &FuzzyJoinParams) -> PolarsResult<Option<ProbeState>> {
+        // Restore the input order so that the matches of every left row are ordered.
+        let mut parts = self.local_builders.drain(..).flatten().collect_vec();
+        if parts.is_empty() {
+            return Ok(None);
+        }
+        parts.sort_by_key(|(seq, _, _)| *seq);
+
+        let mut keys = StringChunked::full_null(PlSmallStr::EMPTY, 0);
+        let mut dfs = Vec::with_capacity(parts.len());
+        for (_, df, key) in parts {
+            keys.append(key.as_materialized_series().str()?)?;
+            dfs.push(df);
+        }
+        let build_df = accumulate_dataframes_vertical_unchecked(dfs);
+        let index = FuzzyJoinIndex::new(&keys, params.options.clone())?;
+        Ok(Some(ProbeState { build_df, index }))
+    }
+}
+
+struct ProbeState {
+    build_df: DataFrame,
+    index: FuzzyJoinIndex,
+}
+
+impl ProbeState {
+    async fn probe(
+        &self,
+        mut recv: PortReceiver,
+        mut send: PortSender,
+        params: &FuzzyJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        while let Ok(morsel) = recv.recv().await {
+            let (df, seq, source_token, consume_token) = morsel.into_inner();
+            let key = params
+                .left_key_selector
+                .evaluate(&df, &state.in_memory_exec_state)
+                .await?;
+            let matches = self.index.probe(key.as_materialized_series().str()?);
+            if matches.is_empty() {
+                continue;
+            }
+
+            let score = matches.score_column(&params.options);
+            // SAFETY: the indices are produced by probing the keys of these frames.
+            let out_df = unsafe {
+                let mut left_df = df.take_slice_unchecked(&matches.left);
+                let mut right_df = self.build_df.take_slice_unchecked(&matches.right);
+                for (col, opt_rename) in right_df
+                    .get_columns_mut()
+                    .iter_mut()
+                    .zip(&params.right_rename)
+                {
+                    if let Some(rename) = opt_rename {
+                        col.rename(rename.clone());
+                    }
+                }
+                left_df.hstack_mut_unchecked(right_df.get_columns());
+                left_df.hstack_mut_unchecked(&[score]);
+                left_df
+            };
+
+            let mut morsel = Morsel::new(out_df, seq, source_token);
+            if let Some(token) = consume_token {
+                morsel.set_consume_token(token);
+            }
+            if send.send(morsel).await.is_err() {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ComputeNode for FuzzyJoinNode {
+    fn name(&self) -> &str {
+        "fuzzy-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done {
+            self.state = FuzzyJoinState::Done;
+        }
+
+        // If we are building and the build input is done, transition to probing.
+        if let FuzzyJoinState::Build(build_state) = &mut self.state {
+            if recv[1] == PortState::Done {
+                self.state = match build_state.finalize(&self.params)? {
+                    Some(probe_state) => FuzzyJoinState::Probe(probe_state),
+                    None => FuzzyJoinState::Done,
+                };
+            }
+        }
+
+        // If we are probing and the probe input is done, we're done.
+        if let FuzzyJoinState::Probe(_) = &self.state {
+            if recv[0] == PortState::Done {
+                self.state = FuzzyJoinState::Done;
+            }
+        }
+
+        match &self.state {
+            FuzzyJoinState::Build(_) => {
+                send[0] = PortState::Blocked;
+                if recv[1] != PortState::Done {
+                    recv[1] = PortState::Ready;
+                }
+                if recv[0] != PortState::Done {
+                    recv[0] = PortState::Blocked;
+                }
+            },
+            FuzzyJoinState::Probe(_) => {
+                core::mem::swap(&mut send[0], &mut recv[0]);
+                recv[1] = PortState::Done;
+            },
+            FuzzyJoinState::Done => {
+                send[0] = PortState::Done;
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, FuzzyJoinState::Build(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        match &mut self.state {
+            FuzzyJoinState::Build(build_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[0].is_none());
+                let receivers = recv_ports[1].take().unwrap().parallel();
+
+                for (local, recv) in build_state.local_builders.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        BuildState::sink(recv, local, &self.params, state),
+                    ));
+                }
+            },
+            FuzzyJoinState::Probe(probe_state) => {
+                assert!(recv_ports[1].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+
+                let probe_state = &*probe_state;
+                for (recv, send) in receivers.into_iter().zip(senders) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        probe_state.probe(recv, send, &self.params, state),
+                    ));
+                }
+            },
+            FuzzyJoinState::Done => unreachable!(),
+        }
+    }
+}
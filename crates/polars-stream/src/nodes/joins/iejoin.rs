@@ -0,0 +1,374 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::series::IsSorted;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::frame::{IEJoinOptions, InequalityOperator, JoinArgs, iejoin_row_indices};
+use polars_utils::format_pl_smallstr;
+use polars_utils::itertools::Itertools;
+
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::compute_node_prelude::*;
+
+type KeyRange = (AnyValue<'static>, AnyValue<'static>);
+
+struct IEJoinParams {
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    right_rename: Vec<Option<PlSmallStr>>,
+    options: IEJoinOptions,
+}
+
+impl IEJoinParams {
+    fn operators(&self) -> impl Iterator<Item = InequalityOperator> {
+        std::iter::once(self.options.operator1).chain(self.options.operator2)
+    }
+
+    /// Whether the first key is sorted descending in the build partitions, this is the order the
+    /// in-memory engine uses as well.
+    fn descending(&self) -> bool {
+        matches!(
+            self.options.operator1,
+            InequalityOperator::Gt | InequalityOperator::GtEq
+        )
+    }
+}
+
+/// Returns the minimum and maximum of the non-null values, or `None` if all values are null.
+fn key_range(s: &Series) -> PolarsResult<Option<KeyRange>> {
+    let min = s.min_reduce()?.into_value();
+    if min.is_null() {
+        return Ok(None);
+    }
+    let max = s.max_reduce()?.into_value();
+    Ok(Some((min, max)))
+}
+
+/// Whether `l op r` can hold for any pair of values taken from the ranges `l` and `r`.
+fn ranges_may_match(op: InequalityOperator, l: &KeyRange, r: &KeyRange) -> bool {
+    match op {
+        InequalityOperator::Lt => l.0 < r.1,
+        InequalityOperator::LtEq => l.0 <= r.1,
+        InequalityOperator::Gt => l.1 > r.0,
+        InequalityOperator::GtEq => l.1 >= r.0,
+    }
+}
+
+/// Joins rows on one or two inequality predicates. The right side is fully collected, sorted
+/// on its first key and split into partitions of consecutive key ranges. The left side is then
+/// streamed, and each morsel is only joined with the partitions whose key ranges can match it.
+pub struct IEJoinNode {
+    params: IEJoinParams,
+    state: IEJoinState,
+}
+
+impl IEJoinNode {
+    pub fn new(
+        left_input_schema: Arc<Schema>,
+        right_input_schema: Arc<Schema>,
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        args: &JoinArgs,
+        options: IEJoinOptions,
+        num_pipelines: usize,
+    ) -> Self {
+        let right_rename = right_input_schema
+            .iter_names()
+            .map(|rname| {
+                if left_input_schema.contains(rname) {
+                    Some(format_pl_smallstr!("{}{}", rname, args.suffix()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            params: IEJoinParams {
+                left_key_selectors,
+                right_key_selectors,
+                right_rename,
+                options,
+            },
+            state: IEJoinState::Build(BuildState {
+                local_builders: (0..num_pipelines).map(|_| Vec::new()).collect(),
+            }),
+        }
+    }
+}
+
+enum IEJoinState {
+    Build(BuildState),
+    Probe(ProbeState),
+    Done,
+}
+
+struct BuildState {
+    /// The morsels seen by every pipeline, together with a frame of their evaluated keys.
+    local_builders: Vec<Vec<(DataFrame, DataFrame)>>,
+}
+
+impl BuildState {
+    async fn sink(
+        mut recv: PortReceiver,
+        local: &mut Vec<(DataFrame, DataFrame)>,
+        params: &IEJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        while let Ok(morsel) = recv.recv().await {
+            let df = morsel.into_df();
+            let mut keys = Vec::with_capacity(params.right_key_selectors.len());
+            for selector in &params.right_key_selectors {
+                keys.push(selector.evaluate(&df, &state.in_memory_exec_state).await?);
+            }
+            let keys = DataFrame::new_with_height(df.height(), keys)?;
+            local.push((df, keys));
+        }
+        Ok(())
+    }
+
+    /// Returns `None` if no row of the build side can match, in which case the join is empty.
+    fn finalize(&mut self, params: &IEJoinParams) -> PolarsResult<Option<ProbeState>> {
+        let (dfs, keys): (Vec<_>, Vec<_>) = self.local_builders.drain(..).flatten().unzip();
+        if dfs.is_empty() {
+            return Ok(None);
+        }
+        let build_df = accumulate_dataframes_vertical_unchecked(dfs);
+        let keys = accumulate_dataframes_vertical_unchecked(keys);
+
+        // Sort on the first key, skipping the null keys as these cannot be matches.
+        let descending = params.descending();
+        let first_key = keys.get_columns()[0].as_materialized_series();
+        let sort_options = SortOptions::default()
+            .with_nulls_last(false)
+            .with_order_descending(descending);
+        let order = first_key.arg_sort(sort_options).slice(
+            first_key.null_count() as i64,
+            first_key.len() - first_key.null_count(),
+        );
+        if order.is_empty() {
+            return Ok(None);
+        }
+        // SAFETY: the order is computed from the keys, which have the height of the frame.
+        let (build_df, keys) =
+            unsafe { (build_df.take_unchecked(&order), keys.take_unchecked(&order)) };
+
+        let partition_size = get_ideal_morsel_size();
+        let mut partitions = Vec::new();
+        for offset in (0..build_df.height()).step_by(partition_size) {
+            let mut keys = keys
+                .slice(offset as i64, partition_size)
+                .take_columns()
+                .into_iter()
+                .map(|c| c.take_materialized_series().rechunk())
+                .collect_vec();
+            let Some(ranges) = keys
+                .iter()
+                .map(key_range)
+                .collect::<PolarsResult<Option<Vec<_>>>>()?
+            else {
+                continue;
+            };
+            keys[0].set_sorted_flag(if descending {
+                IsSorted::Descending
+            } else {
+                IsSorted::Ascending
+            });
+            partitions.push(BuildPartition {
+                df: build_df.slice(offset as i64, partition_size),
+                keys,
+                ranges,
+            });
+        }
+
+        if partitions.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ProbeState { partitions }))
+    }
+}
+
+/// A slice of the build side covering a range of its first key.
+struct BuildPartition {
+    df: DataFrame,
+    keys: Vec<Series>,
+    /// The range of the non-null values of every key.
+    ranges: Vec<KeyRange>,
+}
+
+struct ProbeState {
+    partitions: Vec<BuildPartition>,
+}
+
+impl ProbeState {
+    async fn probe(
+        &self,
+        mut recv: PortReceiver,
+        mut send: PortSender,
+        params: &IEJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let ideal_morsel_size = get_ideal_morsel_size();
+        while let Ok(morsel) = recv.recv().await {
+            let (df, seq, source_token, _consume_token) = morsel.into_inner();
+            let mut keys = Vec::with_capacity(params.left_key_selectors.len());
+            for selector in &params.left_key_selectors {
+                let key = selector.evaluate(&df, &state.in_memory_exec_state).await?;
+                keys.push(key.as_materialized_series().rechunk());
+            }
+            let Some(ranges) = keys
+                .iter()
+                .map(key_range)
+                .collect::<PolarsResult<Option<Vec<_>>>>()?
+            else {
+                continue;
+            };
+
+            for partition in &self.partitions {
+                let may_match = params
+                    .operators()
+                    .zip(ranges.iter().zip(&partition.ranges))
+                    .all(|(op, (l, r))| ranges_may_match(op, l, r));
+                if !may_match {
+                    continue;
+                }
+
+                let (left_idx, right_idx) =
+                    iejoin_row_indices(keys.clone(), partition.keys.clone(), &params.options)?;
+
+                // A single partition can produce many matches per row, so split the output.
+                for offset in (0..left_idx.len()).step_by(ideal_morsel_size) {
+                    let left_idx = left_idx.slice(offset as i64, ideal_morsel_size);
+                    let right_idx = right_idx.slice(offset as i64, ideal_morsel_size);
+                    // SAFETY: the indices are produced by joining the keys of these frames.
+                    let out_df = unsafe {
+                        let mut left_df = df.take_unchecked_impl(&left_idx, false);
+                        let mut right_df = partition.df.take_unchecked_impl(&right_idx, false);
+                        for (col, opt_rename) in right_df
+                            .get_columns_mut()
+                            .iter_mut()
+                            .zip(&params.right_rename)
+                        {
+                            if let Some(rename) = opt_rename {
+                                col.rename(rename.clone());
+                            }
+                        }
+                        left_df.hstack_mut_unchecked(right_df.get_columns());
+                        left_df
+                    };
+
+                    let morsel = Morsel::new(out_df, seq, source_token.clone());
+                    if send.send(morsel).await.is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ComputeNode for IEJoinNode {
+    fn name(&self) -> &str {
+        "iejoin"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done {
+            self.state = IEJoinState::Done;
+        }
+
+        // If we are building and the build input is done, transition to probing.
+        if let IEJoinState::Build(build_state) = &mut self.state {
+            if recv[1] == PortState::Done {
+                self.state = match build_state.finalize(&self.params)? {
+                    Some(probe_state) => IEJoinState::Probe(probe_state),
+                    None => IEJoinState::Done,
+                };
+            }
+        }
+
+        // If we are probing and the probe input is done, we're done.
+        if let IEJoinState::Probe(_) = &self.state {
+            if recv[0] == PortState::Done {
+                self.state = IEJoinState::Done;
+            }
+        }
+
+        match &self.state {
+            IEJoinState::Build(_) => {
+                send[0] = PortState::Blocked;
+                if recv[1] != PortState::Done {
+                    recv[1] = PortState::Ready;
+                }
+                if recv[0] != PortState::Done {
+                    recv[0] = PortState::Blocked;
+                }
+            },
+            IEJoinState::Probe(_) => {
+                core::mem::swap(&mut send[0], &mut recv[0]);
+                recv[1] = PortState::Done;
+            },
+            IEJoinState::Done => {
+                send[0] = PortState::Done;
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, IEJoinState::Build(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        match &mut self.state {
+            IEJoinState::Build(build_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[0].is_none());
+                let receivers = recv_ports[1].take().unwrap().parallel();
+
+                for (local, recv) in build_state.local_builders.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        BuildState::sink(recv, local, &self.params, state),
+                    ));
+                }
+            },
+            IEJoinState::Probe(probe_state) => {
+                assert!(recv_ports[1].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+
+                let probe_state = &*probe_state;
+                for (recv, send) in receivers.into_iter().zip(senders) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        probe_state.probe(recv, send, &self.params, state),
+                    ));
+                }
+            },
+            IEJoinState::Done => unreachable!(),
+        }
+    }
+}
@@ -11,8 +11,14 @@ use crate::async_primitives::wait_group::WaitGroup;
 use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::pipe::{PortReceiver, RecvPort, port_channel};
 
+#[cfg(feature = "asof_join")]
+pub mod asof_join;
 pub mod cross_join;
 pub mod equi_join;
+#[cfg(feature = "fuzzy_join")]
+pub mod fuzzy_join;
+#[cfg(feature = "iejoin")]
+pub mod iejoin;
 pub mod in_memory;
 #[cfg(feature = "semi_anti_join")]
 pub mod semi_anti_join;
@@ -30,6 +30,8 @@ pub mod rolling_group_by;
 pub mod select;
 pub mod shift;
 pub mod simple_projection;
+#[cfg(feature = "merge_sorted")]
+pub mod sort;
 pub mod streaming_slice;
 pub mod top_k;
 pub mod with_row_index;
@@ -0,0 +1,561 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use polars_core::prelude::row_encode::_get_rows_encoded_ca;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets};
+use polars_ops::frame::_merge_sorted_dfs;
+
+use super::compute_node_prelude::*;
+use super::in_memory_source::InMemorySourceNode;
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::utils::spill::{SpillFile, SpillReader, SpillWriter};
+
+const SORT_KEY_NAME: &str = "__POLARS_SORT_KEY";
+
+/// Returns the row-encoded sort key, which is always the last column.
+fn sort_key(df: &DataFrame) -> &BinaryChunked {
+    df.get_columns().last().unwrap().binary().unwrap()
+}
+
+fn remove_sort_key(df: &mut DataFrame) {
+    // SAFETY:
+    // - We only pop so height stays same.
+    // - We only pop so no new name collisions.
+    // - We clear schema afterwards.
+    unsafe { df.get_columns_mut().pop().unwrap() };
+    df.clear_schema();
+}
+
+fn sort_by_key(df: &DataFrame, multithreaded: bool) -> DataFrame {
+    let idx = sort_key(df).arg_sort(SortOptions::default().with_multithreaded(multithreaded));
+    unsafe { df.take_unchecked(&idx) }
+}
+
+/// Splits a [`DataFrame`] in slices of at most `chunk_size` rows.
+fn split_chunks(df: &DataFrame, chunk_size: usize) -> impl Iterator<Item = DataFrame> + '_ {
+    (0..df.height())
+        .step_by(chunk_size.max(1))
+        .map(move |offset| df.slice(offset as i64, chunk_size))
+}
+
+/// Merges sorted [`DataFrame`]s pair-wise on their sort key until a single one remains.
+fn merge_sorted_pieces(mut pieces: Vec<DataFrame>) -> PolarsResult<DataFrame> {
+    while pieces.len() > 1 {
+        pieces = pieces
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => {
+                    let left_key = sort_key(left).clone().into_series();
+                    let right_key = sort_key(right).clone().into_series();
+                    _merge_sorted_dfs(left, right, &left_key, &right_key, false)
+                },
+                [df] => Ok(df.clone()),
+                _ => unreachable!(),
+            })
+            .collect::<PolarsResult<_>>()?;
+    }
+    Ok(pieces.pop().unwrap())
+}
+
+/// The number of leading rows in a sorted key column which are `<= cutoff`.
+fn num_rows_until(key: &BinaryChunked, cutoff: &[u8]) -> usize {
+    let (mut lo, mut hi) = (0, key.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if key.get(mid).unwrap() <= cutoff {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+struct SortParams {
+    key_selectors: Vec<StreamExpr>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    maintain_order: bool,
+    slice: Option<(i64, usize)>,
+    memory_budget: usize,
+    /// The input schema with the sort key column appended.
+    buffer_schema: Arc<Schema>,
+}
+
+impl SortParams {
+    /// Evaluates the sort keys and appends them as a single row-encoded column.
+    async fn append_sort_key(
+        &self,
+        mut df: DataFrame,
+        seq: MorselSeq,
+        state: &ExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        let height = df.height();
+        let mut keys = Vec::with_capacity(self.key_selectors.len() + 2);
+        for selector in &self.key_selectors {
+            keys.push(selector.evaluate(&df, state).await?);
+        }
+        let mut keys = DataFrame::new_with_broadcast_len(keys, height)?.take_columns();
+
+        let mut descending = self.descending.clone();
+        let mut nulls_last = self.nulls_last.clone();
+        if self.maintain_order {
+            // Tie-break on the position in the input stream, this makes the sort stable even
+            // across spilled runs.
+            keys.push(Column::new_scalar(
+                PlSmallStr::from_static("__POLARS_SORT_SEQ"),
+                Scalar::from(seq.to_u64()),
+                height,
+            ));
+            keys.push(Column::new_row_index(
+                PlSmallStr::from_static("__POLARS_SORT_ROW_IDX"),
+                0,
+                height,
+            )?);
+            descending.extend([false, false]);
+            nulls_last.extend([false, false]);
+        }
+
+        let key = _get_rows_encoded_ca(
+            PlSmallStr::from_static(SORT_KEY_NAME),
+            &keys,
+            &descending,
+            &nulls_last,
+        )?
+        .cast(&DataType::Binary)?;
+        unsafe { df.with_column_unchecked(key.into_column()) };
+        Ok(df)
+    }
+}
+
+#[derive(Default)]
+struct SinkBuffer {
+    dfs: Vec<DataFrame>,
+    num_bytes: usize,
+}
+
+#[derive(Default)]
+struct SortSink {
+    buffer: Mutex<SinkBuffer>,
+    runs: Mutex<Vec<SpillFile>>,
+}
+
+impl SortSink {
+    /// Buffers a morsel, returning the buffered data if it should be spilled.
+    fn push(&self, df: DataFrame, memory_budget: usize) -> Option<Vec<DataFrame>> {
+        let mut buffer = self.buffer.lock();
+        buffer.num_bytes += df.estimated_size();
+        buffer.dfs.push(df);
+        if buffer.num_bytes > memory_budget {
+            let buffer = std::mem::take(&mut *buffer);
+            Some(buffer.dfs)
+        } else {
+            None
+        }
+    }
+
+    /// Sorts the given data and writes it to disk as a new sorted run.
+    fn spill(&self, dfs: Vec<DataFrame>, params: &SortParams) -> PolarsResult<()> {
+        let df = sort_by_key(&accumulate_dataframes_vertical_unchecked(dfs), false);
+        let mut writer = SpillWriter::new(params.buffer_schema.clone())?;
+        for chunk in split_chunks(&df, get_ideal_morsel_size()) {
+            writer.write(&chunk)?;
+        }
+        let run = writer.finish()?;
+
+        if polars_core::config::verbose() {
+            eprintln!("[SortNode]: spilled sorted run of {} rows", run.num_rows());
+        }
+
+        self.runs.lock().push(run);
+        Ok(())
+    }
+}
+
+/// A sorted sequence of rows, either in-memory or spilled to disk.
+struct SortedRun {
+    pending: VecDeque<DataFrame>,
+    reader: Option<SpillReader>,
+    _file: Option<SpillFile>,
+}
+
+impl SortedRun {
+    fn in_memory(df: DataFrame) -> Self {
+        Self {
+            pending: split_chunks(&df, get_ideal_morsel_size()).collect(),
+            reader: None,
+            _file: None,
+        }
+    }
+
+    fn spilled(file: SpillFile) -> PolarsResult<Self> {
+        Ok(Self {
+            pending: VecDeque::new(),
+            reader: Some(file.reader()?),
+            _file: Some(file),
+        })
+    }
+
+    fn num_rows(&self) -> usize {
+        self.pending.iter().map(|df| df.height()).sum::<usize>()
+            + self._file.as_ref().map_or(0, |f| f.num_rows())
+    }
+
+    /// The first non-empty buffered part of this run, reading from disk if needed.
+    fn front(&mut self) -> PolarsResult<Option<&DataFrame>> {
+        loop {
+            match self.pending.front() {
+                Some(df) if df.height() > 0 => break,
+                Some(_) => {
+                    self.pending.pop_front();
+                },
+                None => {
+                    let next = match &mut self.reader {
+                        Some(reader) => reader.next_df()?,
+                        None => None,
+                    };
+                    match next {
+                        Some(df) => self.pending.push_back(df),
+                        None => {
+                            // Exhausted, clean up the spill file as soon as possible.
+                            self.reader = None;
+                            self._file = None;
+                            return Ok(None);
+                        },
+                    }
+                },
+            }
+        }
+        Ok(self.pending.front())
+    }
+
+    /// Takes all leading rows whose key is `<= cutoff`.
+    fn take_until(&mut self, cutoff: &[u8]) -> PolarsResult<Option<DataFrame>> {
+        let Some(df) = self.front()? else {
+            return Ok(None);
+        };
+        let n = num_rows_until(sort_key(df), cutoff);
+        if n == 0 {
+            return Ok(None);
+        }
+
+        let df = self.pending.pop_front().unwrap();
+        let (head, tail) = df.split_at(n as i64);
+        if tail.height() > 0 {
+            self.pending.push_front(tail);
+        }
+        Ok(Some(head))
+    }
+}
+
+/// K-way merge state over all sorted runs.
+struct MergeState {
+    runs: Vec<SortedRun>,
+    seq: MorselSeq,
+    /// The number of rows that have been taken out of the runs.
+    position: usize,
+    slice_start: usize,
+    slice_end: usize,
+    exhausted: bool,
+}
+
+impl MergeState {
+    /// Takes the pieces of all runs which can be merged without looking at any further data,
+    /// returns `None` once all runs are exhausted.
+    fn next_mergeable(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        // Everything up to the smallest last key of the buffered parts can safely be merged.
+        let mut cutoff: Option<Vec<u8>> = None;
+        for run in &mut self.runs {
+            if let Some(df) = run.front()? {
+                let key = sort_key(df);
+                let last = key.get(key.len() - 1).unwrap();
+                if cutoff.as_ref().is_none_or(|c| last < c.as_slice()) {
+                    cutoff = Some(last.to_vec());
+                }
+            }
+        }
+        let Some(cutoff) = cutoff else {
+            return Ok(None);
+        };
+
+        let mut pieces = Vec::with_capacity(self.runs.len());
+        for run in &mut self.runs {
+            if let Some(piece) = run.take_until(&cutoff)? {
+                pieces.push(piece);
+            }
+        }
+        Ok(Some(pieces))
+    }
+}
+
+enum SortState {
+    Sink(SortSink),
+    InMemorySource(InMemorySourceNode),
+    Merge(MergeState),
+    Done,
+}
+
+/// A sort which buffers its input until it exceeds the memory budget, after which the buffered
+/// data is sorted and spilled to disk as a sorted run. Once the input is exhausted the runs are
+/// merged k-way on their row-encoded sort key.
+pub struct SortNode {
+    params: SortParams,
+    state: SortState,
+}
+
+impl SortNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        key_selectors: Vec<StreamExpr>,
+        sort_options: &SortMultipleOptions,
+        slice: Option<(i64, usize)>,
+        memory_budget: usize,
+    ) -> Self {
+        let num_keys = key_selectors.len();
+        let descending = sort_options
+            .descending
+            .iter()
+            .copied()
+            .cycle()
+            .take(num_keys)
+            .collect();
+        let nulls_last = sort_options
+            .nulls_last
+            .iter()
+            .copied()
+            .cycle()
+            .take(num_keys)
+            .collect();
+
+        let mut buffer_schema = input_schema.as_ref().clone();
+        buffer_schema.insert(PlSmallStr::from_static(SORT_KEY_NAME), DataType::Binary);
+
+        Self {
+            params: SortParams {
+                key_selectors,
+                descending,
+                nulls_last,
+                maintain_order: sort_options.maintain_order,
+                slice,
+                memory_budget,
+                buffer_schema: Arc::new(buffer_schema),
+            },
+            state: SortState::Sink(SortSink::default()),
+        }
+    }
+
+    fn finalize_sink(&mut self) -> PolarsResult<()> {
+        let SortState::Sink(sink) = &mut self.state else {
+            unreachable!()
+        };
+        let buffered = std::mem::take(&mut sink.buffer.get_mut().dfs);
+        let spilled = std::mem::take(sink.runs.get_mut());
+
+        let buffered = if buffered.is_empty() {
+            None
+        } else {
+            Some(sort_by_key(
+                &accumulate_dataframes_vertical_unchecked(buffered),
+                true,
+            ))
+        };
+
+        if spilled.is_empty() {
+            let mut df = buffered
+                .unwrap_or_else(|| DataFrame::empty_with_schema(&self.params.buffer_schema));
+            remove_sort_key(&mut df);
+            if let Some((offset, len)) = self.params.slice {
+                df = df.slice(offset, len);
+            }
+            self.state = SortState::InMemorySource(InMemorySourceNode::new(
+                Arc::new(df),
+                MorselSeq::default(),
+            ));
+            return Ok(());
+        }
+
+        let mut runs = spilled
+            .into_iter()
+            .map(SortedRun::spilled)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        runs.extend(buffered.map(SortedRun::in_memory));
+
+        let num_rows = runs.iter().map(SortedRun::num_rows).sum();
+        let (slice_start, slice_len) = self.params.slice.map_or((0, num_rows), |(offset, len)| {
+            slice_offsets(offset, len, num_rows)
+        });
+
+        self.state = SortState::Merge(MergeState {
+            runs,
+            seq: MorselSeq::default(),
+            position: 0,
+            slice_start,
+            slice_end: slice_start + slice_len,
+            exhausted: slice_len == 0,
+        });
+        Ok(())
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => self.state = SortState::Done,
+            // Input is done, transition to being a source.
+            SortState::Sink(_) if recv[0] == PortState::Done => self.finalize_sink()?,
+            SortState::Merge(merge) if merge.exhausted => self.state = SortState::Done,
+            _ => {},
+        }
+
+        // Communicate our state.
+        match &mut self.state {
+            SortState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::InMemorySource(src) => {
+                recv[0] = PortState::Done;
+                src.update_state(&mut [], send, state)?;
+            },
+            SortState::Merge(_) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match &mut self.state {
+            SortState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+
+                let sink = &*sink;
+                let params = &self.params;
+                for mut recv in receivers {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let seq = morsel.seq();
+                            let df = params
+                                .append_sort_key(morsel.into_df(), seq, &state.in_memory_exec_state)
+                                .await?;
+
+                            if let Some(to_spill) = sink.push(df, params.memory_budget) {
+                                sink.spill(to_spill, params)?;
+                            }
+                        }
+
+                        Ok(())
+                    }));
+                }
+            },
+
+            SortState::InMemorySource(src) => {
+                assert!(recv_ports[0].is_none());
+                src.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+
+            SortState::Merge(merge) => {
+                assert!(recv_ports[0].is_none());
+                let senders = send_ports[0].take().unwrap().parallel();
+                let (mut distributor, dist_recv) =
+                    distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+                // Serially determine which parts of the runs can be merged next.
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    let source_token = SourceToken::new();
+                    while !merge.exhausted && !source_token.stop_requested() {
+                        let Some(pieces) = merge.next_mergeable()? else {
+                            merge.exhausted = true;
+                            break;
+                        };
+
+                        let start = merge.position;
+                        merge.position += pieces.iter().map(|df| df.height()).sum::<usize>();
+
+                        // Only the part which intersects with the slice has to be merged.
+                        let lo = start.max(merge.slice_start);
+                        let hi = merge.position.min(merge.slice_end);
+                        merge.exhausted = merge.position >= merge.slice_end;
+                        if lo >= hi {
+                            continue;
+                        }
+
+                        let seq = merge.seq;
+                        merge.seq = seq.successor();
+                        let task = (pieces, lo - start, hi - lo, seq, source_token.clone());
+                        if distributor.send(task).await.is_err() {
+                            break;
+                        }
+                    }
+
+                    Ok(())
+                }));
+
+                // Merge the pieces in parallel.
+                join_handles.extend(dist_recv.into_iter().zip(senders).map(
+                    |(mut recv, mut send)| {
+                        let ideal_morsel_size = get_ideal_morsel_size();
+                        scope.spawn_task(TaskPriority::High, async move {
+                            while let Ok((pieces, offset, len, seq, source_token)) =
+                                recv.recv().await
+                            {
+                                let mut df = merge_sorted_pieces(pieces)?.slice(offset as i64, len);
+                                remove_sort_key(&mut df);
+
+                                // MorselSeq have to be monotonely non-decreasing so we can pass
+                                // the same sequence token several times.
+                                for chunk in split_chunks(&df, ideal_morsel_size) {
+                                    let morsel = Morsel::new(chunk, seq, source_token.clone());
+                                    if send.send(morsel).await.is_err() {
+                                        return Ok(());
+                                    }
+                                }
+                            }
+
+                            Ok(())
+                        })
+                    },
+                ));
+            },
+
+            SortState::Done => unreachable!(),
+        }
+    }
+}
@@ -1785,7 +1785,8 @@ fn lower_exprs_with_ctx(
                     IRFunctionExpr::Boolean(
                         IRBooleanFunction::Any { .. } | IRBooleanFunction::All { .. },
                     )
-                    | IRFunctionExpr::NullCount,
+                    | IRFunctionExpr::NullCount
+                    | IRFunctionExpr::ApproxQuantile { .. },
                 ..
             } => {
                 let (trans_stream, trans_expr) = lower_unary_reduce_node(input, expr, ctx)?;
@@ -261,7 +261,8 @@ fn try_lower_elementwise_scalar_agg_expr(
         AExpr::Function {
             function:
                 IRFunctionExpr::Boolean(IRBooleanFunction::Any { .. } | IRBooleanFunction::All { .. })
-                | IRFunctionExpr::NullCount,
+                | IRFunctionExpr::NullCount
+                | IRFunctionExpr::ApproxQuantile { .. },
             ..
         } => replace_agg_input!(expr),
 
@@ -17,7 +17,9 @@ use polars_plan::dsl::{
     PartitionVariantIR, SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
-use polars_plan::plans::{AExpr, FunctionIR, IR, IRAggExpr, LiteralValue, write_ir_non_recursive};
+use polars_plan::plans::{
+    AExpr, FunctionIR, HintIR, IR, IRAggExpr, LiteralValue, write_ir_non_recursive,
+};
 use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
@@ -433,6 +435,11 @@ pub fn lower_ir(
                     offset,
                 },
 
+                // Only the sorted hint has an effect at runtime, by setting the sorted flag.
+                FunctionIR::Hint(hint) if !matches!(hint, HintIR::Sorted(_)) => {
+                    return Ok(phys_input);
+                },
+
                 function if function.is_streamable() => {
                     let map = Arc::new(move |df| function.evaluate(df));
                     PhysNodeKind::Map {
@@ -632,6 +639,11 @@ pub fn lower_ir(
                     first_metadata: first_metadata.clone(),
                 }) as _,
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro {
+                    options: polars_io::avro::AvroScanOptions {},
+                } => Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder) as _,
+
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { options } => Arc::new(Arc::new(options.clone())) as _,
 
@@ -989,10 +1001,21 @@ pub fn lower_ir(
             let left_on = left_on.clone();
             let right_on = right_on.clone();
             let args = options.args.clone();
+            let build_side = options.build_side;
             let options = options.options.clone();
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
-            if (args.how.is_equi() || args.how.is_semi_anti()) && !args.validation.needs_checks() {
+            let is_fuzzy = cfg!(feature = "fuzzy_join") && args.how.is_fuzzy();
+            // The in-memory as-of join only supports a single key.
+            let is_asof = cfg!(feature = "asof_join") && args.how.is_asof() && left_on.len() == 1;
+            let is_ie = cfg!(feature = "iejoin") && args.how.is_ie();
+            // The equi-join validates its keys while building and probing, which requires seeing
+            // all rows, so a sliced join with validation still goes to the in-memory engine.
+            let validation_ok =
+                !args.validation.needs_checks() || (args.how.is_equi() && args.slice.is_none());
+            if (args.how.is_equi() || args.how.is_semi_anti() || is_fuzzy || is_asof || is_ie)
+                && validation_ok
+            {
                 // When lowering the expressions for the keys we need to ensure we keep around the
                 // payload columns, otherwise the input nodes can get replaced by input-independent
                 // nodes since the lowering code does not see we access any non-literal expressions.
@@ -1035,8 +1058,63 @@ pub fn lower_ir(
                             left_on: trans_left_on,
                             right_on: trans_right_on,
                             args: args.clone(),
+                            build_side,
                         },
                     ))
+                } else if is_fuzzy {
+                    #[cfg(feature = "fuzzy_join")]
+                    {
+                        phys_sm.insert(PhysNode::new(
+                            output_schema,
+                            PhysNodeKind::FuzzyJoin {
+                                input_left: trans_input_left,
+                                input_right: trans_input_right,
+                                left_on: trans_left_on,
+                                right_on: trans_right_on,
+                                args: args.clone(),
+                            },
+                        ))
+                    }
+                    #[cfg(not(feature = "fuzzy_join"))]
+                    unreachable!()
+                } else if is_asof {
+                    #[cfg(feature = "asof_join")]
+                    {
+                        phys_sm.insert(PhysNode::new(
+                            output_schema,
+                            PhysNodeKind::AsOfJoin {
+                                input_left: trans_input_left,
+                                input_right: trans_input_right,
+                                left_on: trans_left_on,
+                                right_on: trans_right_on,
+                                args: args.clone(),
+                            },
+                        ))
+                    }
+                    #[cfg(not(feature = "asof_join"))]
+                    unreachable!()
+                } else if is_ie {
+                    #[cfg(feature = "iejoin")]
+                    {
+                        let Some(polars_plan::dsl::JoinTypeOptionsIR::IEJoin(ie_options)) =
+                            &options
+                        else {
+                            unreachable!()
+                        };
+                        phys_sm.insert(PhysNode::new(
+                            output_schema,
+                            PhysNodeKind::IEJoin {
+                                input_left: trans_input_left,
+                                input_right: trans_input_right,
+                                left_on: trans_left_on,
+                                right_on: trans_right_on,
+                                args: args.clone(),
+                                options: ie_options.clone(),
+                            },
+                        ))
+                    }
+                    #[cfg(not(feature = "iejoin"))]
+                    unreachable!()
                 } else {
                     phys_sm.insert(PhysNode::new(
                         output_schema,
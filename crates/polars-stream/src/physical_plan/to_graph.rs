@@ -330,10 +330,11 @@ fn to_graph_rec<'a>(
                     [(input_key, input.port)],
                 ),
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
+                FileType::Json(json_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
                         target.clone(),
                         sink_options,
+                        *json_writer_options,
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -361,11 +362,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "avro")]
+                FileType::Avro(avro_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::avro::AvroSinkNode::new(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        avro_writer_options.clone(),
+                        cloud_options.clone(),
+                    )),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "avro"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -502,34 +515,68 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: *slice,
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                Some(crate::dispatch::build_streaming_query_executor),
-            )?);
 
-            let input_key = to_graph_rec(input.node, ctx)?;
-            ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
-                    input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
-                ),
-                [(input_key, input.port)],
-            )
+            // With a memory budget we use the native sort node which can spill sorted runs to
+            // disk, otherwise we defer to the in-memory engine.
+            let key_schema = compute_output_schema(&input_schema, by_column, ctx.expr_arena)?;
+            let spill_budget = crate::utils::spill::spill_budget([&*input_schema, &*key_schema])?;
+
+            match spill_budget {
+                #[cfg(feature = "merge_sorted")]
+                Some(memory_budget) => {
+                    // Sort keys are used by position, so we assign them unique names.
+                    let key_selectors = by_column
+                        .iter()
+                        .enumerate()
+                        .map(|(i, expr)| {
+                            let expr = expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                            create_stream_expr(&expr, ctx, &input_schema)
+                        })
+                        .try_collect_vec()?;
+
+                    let input_key = to_graph_rec(input.node, ctx)?;
+                    ctx.graph.add_node(
+                        nodes::sort::SortNode::new(
+                            input_schema,
+                            key_selectors,
+                            sort_options,
+                            *slice,
+                            memory_budget,
+                        ),
+                        [(input_key, input.port)],
+                    )
+                },
+                _ => {
+                    let lmdf = Arc::new(LateMaterializedDataFrame::default());
+                    let mut lp_arena = Arena::default();
+                    let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+                    let sort_node = lp_arena.add(IR::Sort {
+                        input: df_node,
+                        by_column: by_column.clone(),
+                        slice: *slice,
+                        sort_options: sort_options.clone(),
+                    });
+                    let executor = Mutex::new(create_physical_plan(
+                        sort_node,
+                        &mut lp_arena,
+                        ctx.expr_arena,
+                        Some(crate::dispatch::build_streaming_query_executor),
+                    )?);
+
+                    let input_key = to_graph_rec(input.node, ctx)?;
+                    ctx.graph.add_node(
+                        nodes::in_memory_map::InMemoryMapNode::new(
+                            input_schema,
+                            Arc::new(move |df| {
+                                lmdf.set_materialized_dataframe(df);
+                                let mut state = ExecutionState::new();
+                                executor.lock().execute(&mut state)
+                            }),
+                        ),
+                        [(input_key, input.port)],
+                    )
+                },
+            }
         },
 
         TopK {
@@ -773,6 +820,9 @@ fn to_graph_rec<'a>(
                 grouped_reduction_cols.push(col.clone());
             }
 
+            // Cold morsels are spilled together with their keys.
+            let memory_budget = crate::utils::spill::spill_budget([&**input_schema, &*key_schema])?;
+
             ctx.graph.add_node(
                 nodes::group_by::GroupByNode::new(
                     key_schema,
@@ -784,6 +834,7 @@ fn to_graph_rec<'a>(
                     PlRandomState::default(),
                     ctx.num_pipelines,
                     has_order_sensitive_agg,
+                    memory_budget,
                 ),
                 [(input_key, input.port)],
             )
@@ -853,6 +904,7 @@ fn to_graph_rec<'a>(
                     force_parallel: false,
                     args: args.clone(),
                     options: options.clone(),
+                    build_side: None,
                 }),
             });
 
@@ -887,6 +939,7 @@ fn to_graph_rec<'a>(
             left_on,
             right_on,
             args,
+            ..
         }
         | SemiAntiJoin {
             input_left,
@@ -943,6 +996,12 @@ fn to_graph_rec<'a>(
 
             let unique_key_schema =
                 compute_output_schema(&right_input_schema, &unique_left_on, ctx.expr_arena)?;
+            let memory_budget = crate::utils::spill::spill_budget([
+                &*left_input_schema,
+                &*right_input_schema,
+                &*left_key_schema,
+                &*right_key_schema,
+            ])?;
 
             match node.kind {
                 #[cfg(feature = "semi_anti_join")]
@@ -960,7 +1019,7 @@ fn to_graph_rec<'a>(
                         (right_input_key, input_right.port),
                     ],
                 ),
-                _ => ctx.graph.add_node(
+                EquiJoin { build_side, .. } => ctx.graph.add_node(
                     nodes::joins::equi_join::EquiJoinNode::new(
                         left_input_schema,
                         right_input_schema,
@@ -970,16 +1029,153 @@ fn to_graph_rec<'a>(
                         left_key_selectors,
                         right_key_selectors,
                         args,
+                        build_side,
                         ctx.num_pipelines,
+                        memory_budget,
                     )?,
                     [
                         (left_input_key, input_left.port),
                         (right_input_key, input_right.port),
                     ],
                 ),
+                _ => unreachable!(),
             }
         },
 
+        #[cfg(feature = "fuzzy_join")]
+        FuzzyJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let args = args.clone();
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let [left_on] = left_on.as_slice() else {
+                unreachable!()
+            };
+            let [right_on] = right_on.as_slice() else {
+                unreachable!()
+            };
+            let key_name = PlSmallStr::from_static("__POLARS_KEYCOL_0");
+            let left_key_selector = create_stream_expr(
+                &left_on.with_alias(key_name.clone()),
+                ctx,
+                &left_input_schema,
+            )?;
+            let right_key_selector =
+                create_stream_expr(&right_on.with_alias(key_name), ctx, &right_input_schema)?;
+
+            ctx.graph.add_node(
+                nodes::joins::fuzzy_join::FuzzyJoinNode::new(
+                    left_input_schema,
+                    right_input_schema,
+                    left_key_selector,
+                    right_key_selector,
+                    &args,
+                    ctx.num_pipelines,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let [left_on] = left_on.as_slice() else {
+                unreachable!()
+            };
+            let [right_on] = right_on.as_slice() else {
+                unreachable!()
+            };
+            let right_key = right_on.field(&right_input_schema, ctx.expr_arena)?;
+            let left_key_selector = create_stream_expr(left_on, ctx, &left_input_schema)?;
+            let right_key_selector = create_stream_expr(right_on, ctx, &right_input_schema)?;
+
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    right_input_schema,
+                    left_key_selector,
+                    right_key_selector,
+                    right_key,
+                    args,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "iejoin")]
+        IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let args = args.clone();
+            let options = options.clone();
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            // The keys are used by position, so assign arbitrary unique names for the selectors.
+            let left_key_selectors = left_on
+                .iter()
+                .enumerate()
+                .map(|(i, expr)| {
+                    let expr = expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                    create_stream_expr(&expr, ctx, &left_input_schema)
+                })
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .enumerate()
+                .map(|(i, expr)| {
+                    let expr = expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                    create_stream_expr(&expr, ctx, &right_input_schema)
+                })
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                nodes::joins::iejoin::IEJoinNode::new(
+                    left_input_schema,
+                    right_input_schema,
+                    left_key_selectors,
+                    right_key_selectors,
+                    &args,
+                    options,
+                    ctx.num_pipelines,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
         CrossJoin {
             input_left,
             input_right,
@@ -303,6 +303,7 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                 left_on,
                 right_on,
                 args,
+                build_side: _,
             } => {
                 phys_node_inputs.push(input_left.node);
                 phys_node_inputs.push(input_right.node);
@@ -944,6 +945,116 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                     ..Default::default()
                 }
             },
+            #[cfg(feature = "fuzzy_join")]
+            PhysNodeKind::FuzzyJoin {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                args,
+            } => {
+                phys_node_inputs.push(input_left.node);
+                phys_node_inputs.push(input_right.node);
+
+                let JoinType::Fuzzy(fuzzy_options) = &args.how else {
+                    unreachable!()
+                };
+
+                let properties = PhysNodeProperties::FuzzyJoin {
+                    left_on: expr_list(left_on, self.expr_arena),
+                    right_on: expr_list(right_on, self.expr_arena),
+                    metric: format_pl_smallstr!("{:?}", fuzzy_options.metric),
+                    threshold: format_pl_smallstr!("{:?}", fuzzy_options.threshold),
+                    score_name: fuzzy_options.score_name.clone(),
+                    suffix: args.suffix.clone(),
+                };
+
+                PhysNodeInfo {
+                    title: properties.variant_name(),
+                    properties,
+                    ..Default::default()
+                }
+            },
+            #[cfg(feature = "asof_join")]
+            PhysNodeKind::AsOfJoin {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                args,
+            } => {
+                use polars_ops::frame::AsOfOptions;
+
+                phys_node_inputs.push(input_left.node);
+                phys_node_inputs.push(input_right.node);
+
+                let JoinType::AsOf(asof_options) = &args.how else {
+                    unreachable!()
+                };
+                #[expect(unused_variables)]
+                let AsOfOptions {
+                    strategy,
+                    tolerance,
+                    tolerance_str,
+                    left_by,
+                    right_by,
+                    allow_eq,
+                    check_sortedness,
+                } = asof_options.as_ref();
+
+                let properties = PhysNodeProperties::AsOfJoin {
+                    left_on: format_pl_smallstr!("{}", left_on[0].display(self.expr_arena)),
+                    right_on: format_pl_smallstr!("{}", right_on[0].display(self.expr_arena)),
+                    left_by: left_by.clone(),
+                    right_by: right_by.clone(),
+                    strategy: *strategy,
+                    tolerance: tolerance.as_ref().map(|scalar| {
+                        [
+                            format_pl_smallstr!("{}", scalar.value()),
+                            format_pl_smallstr!("{:?}", scalar.dtype()),
+                        ]
+                    }),
+                    suffix: args.suffix.clone(),
+                    coalesce: args.coalesce,
+                    allow_eq: *allow_eq,
+                    check_sortedness: *check_sortedness,
+                };
+
+                PhysNodeInfo {
+                    title: properties.variant_name(),
+                    properties,
+                    ..Default::default()
+                }
+            },
+            #[cfg(feature = "iejoin")]
+            PhysNodeKind::IEJoin {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                args,
+                options,
+            } => {
+                phys_node_inputs.push(input_left.node);
+                phys_node_inputs.push(input_right.node);
+
+                let properties = PhysNodeProperties::IEJoin {
+                    left_on: expr_list(left_on, self.expr_arena),
+                    right_on: expr_list(right_on, self.expr_arena),
+                    inequality_operators: if let Some(operator2) = options.operator2 {
+                        vec![options.operator1, operator2]
+                    } else {
+                        vec![options.operator1]
+                    },
+                    suffix: args.suffix.clone(),
+                };
+
+                PhysNodeInfo {
+                    title: properties.variant_name(),
+                    properties,
+                    ..Default::default()
+                }
+            },
             #[cfg(feature = "merge_sorted")]
             PhysNodeKind::MergeSorted {
                 input_left,
@@ -271,6 +271,36 @@ pub enum PhysNodeProperties {
         nulls_equal: bool,
         output_as_bool: bool,
     },
+    #[cfg(feature = "fuzzy_join")]
+    FuzzyJoin {
+        left_on: Vec<PlSmallStr>,
+        right_on: Vec<PlSmallStr>,
+        metric: PlSmallStr,
+        threshold: PlSmallStr,
+        score_name: PlSmallStr,
+        suffix: Option<PlSmallStr>,
+    },
+    #[cfg(feature = "asof_join")]
+    AsOfJoin {
+        left_on: PlSmallStr,
+        right_on: PlSmallStr,
+        left_by: Option<Vec<PlSmallStr>>,
+        right_by: Option<Vec<PlSmallStr>>,
+        strategy: polars_ops::frame::AsofStrategy,
+        /// [value, dtype_str]
+        tolerance: Option<[PlSmallStr; 2]>,
+        suffix: Option<PlSmallStr>,
+        coalesce: JoinCoalesce,
+        allow_eq: bool,
+        check_sortedness: bool,
+    },
+    #[cfg(feature = "iejoin")]
+    IEJoin {
+        left_on: Vec<PlSmallStr>,
+        right_on: Vec<PlSmallStr>,
+        inequality_operators: Vec<polars_ops::frame::InequalityOperator>,
+        suffix: Option<PlSmallStr>,
+    },
     #[cfg(feature = "merge_sorted")]
     MergeSorted,
     #[cfg(feature = "python")]
@@ -0,0 +1,236 @@
+//! Spilling of intermediate data to local disk.
+//!
+//! Spilled data is written as Arrow IPC files in the Polars temporary directory (see
+//! `POLARS_TEMP_DIR`). Columns are stored in their physical representation so that logical types
+//! (categoricals, enums, decimals, ...) roundtrip without having to write dictionaries.
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+
+use arrow::io::ipc::read::{FileReader, read_file_metadata};
+use arrow::io::ipc::write::{FileWriter, WriteOptions};
+use parking_lot::Mutex;
+use polars_core::prelude::{CompatLevel, DataFrame};
+use polars_core::schema::{Schema, SchemaExt, SchemaRef};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+
+static SPILL_DIR: LazyLock<Box<Path>> = LazyLock::new(|| {
+    let path = POLARS_TEMP_DIR_BASE_PATH
+        .join("streaming-spill/")
+        .into_boxed_path();
+
+    if let Err(err) = std::fs::create_dir_all(path.as_ref()) {
+        if !path.is_dir() {
+            panic!(
+                "failed to create spill directory: path = {}, err = {}",
+                path.display(),
+                err
+            );
+        }
+    }
+
+    path
+});
+
+/// The number of bytes a memory-intensive node may buffer before it starts spilling to disk.
+///
+/// Configured through `POLARS_STREAMING_MEMORY_BUDGET`, `None` means nodes never spill. This is
+/// read when the graph is built, so an invalid value fails the query instead of the process.
+pub fn memory_budget() -> PolarsResult<Option<usize>> {
+    let Ok(v) = std::env::var("POLARS_STREAMING_MEMORY_BUDGET") else {
+        return Ok(None);
+    };
+    let budget = v.parse().map_err(|_| {
+        polars_err!(
+            InvalidOperation:
+            "invalid POLARS_STREAMING_MEMORY_BUDGET: '{}', expected a number of bytes", v
+        )
+    })?;
+    Ok(Some(budget))
+}
+
+/// The memory budget for a node which spills data with the given schemas.
+///
+/// Returns `None` if spilling is disabled, or if the data contains objects which can't be
+/// written to disk.
+pub fn spill_budget<'a>(
+    schemas: impl IntoIterator<Item = &'a Schema>,
+) -> PolarsResult<Option<usize>> {
+    let Some(budget) = memory_budget()? else {
+        return Ok(None);
+    };
+    let has_objects = schemas
+        .into_iter()
+        .flat_map(|s| s.iter_values())
+        .any(|dt| dt.contains_objects());
+    Ok((!has_objects).then_some(budget))
+}
+
+fn new_spill_path() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let idx = COUNTER.fetch_add(1, Ordering::Relaxed);
+    SPILL_DIR.join(format!(
+        "{}-{:016x}-{idx}.arrow",
+        std::process::id(),
+        rand::random::<u64>()
+    ))
+}
+
+/// A spilled file on local disk, which is removed when dropped.
+pub struct SpillFile {
+    path: PathBuf,
+    schema: SchemaRef,
+    num_rows: usize,
+}
+
+impl SpillFile {
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Open a reader which yields the spilled [`DataFrame`]s in the order they were written.
+    pub fn reader(&self) -> PolarsResult<SpillReader> {
+        let mut file = BufReader::new(File::open(&self.path)?);
+        let metadata = read_file_metadata(&mut file)?;
+        let phys_schema = Schema::from_iter(
+            self.schema
+                .iter()
+                .map(|(name, dtype)| (name.clone(), dtype.to_physical())),
+        );
+        Ok(SpillReader {
+            reader: FileReader::new(file, metadata, None, None),
+            schema: self.schema.clone(),
+            phys_schema,
+        })
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        _ = std::fs::remove_file(&self.path);
+    }
+}
+
+pub struct SpillWriter {
+    file: SpillFile,
+    writer: FileWriter<BufWriter<File>>,
+}
+
+impl SpillWriter {
+    pub fn new(schema: SchemaRef) -> PolarsResult<Self> {
+        let path = new_spill_path();
+        let writer = BufWriter::new(File::create(&path)?);
+        // Make sure the file is removed if anything below fails.
+        let file = SpillFile {
+            path,
+            schema,
+            num_rows: 0,
+        };
+
+        let phys_schema = Schema::from_iter(
+            file.schema
+                .iter()
+                .map(|(name, dtype)| (name.clone(), dtype.to_physical())),
+        );
+        let writer = FileWriter::try_new(
+            writer,
+            Arc::new(phys_schema.to_arrow(CompatLevel::newest())),
+            None,
+            WriteOptions { compression: None },
+        )?;
+
+        Ok(Self { file, writer })
+    }
+
+    pub fn write(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+
+        let mut phys_df = df
+            .get_columns()
+            .iter()
+            .map(|c| c.to_physical_repr())
+            .collect::<DataFrame>();
+        phys_df.align_chunks();
+        for batch in phys_df.iter_chunks(CompatLevel::newest(), false) {
+            self.writer.write(&batch, None)?;
+        }
+        self.file.num_rows += df.height();
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> PolarsResult<SpillFile> {
+        self.writer.finish()?;
+        Ok(self.file)
+    }
+}
+
+pub struct SpillReader {
+    reader: FileReader<BufReader<File>>,
+    schema: SchemaRef,
+    phys_schema: Schema,
+}
+
+impl SpillReader {
+    /// Read the next spilled [`DataFrame`], returns `None` once the file is exhausted.
+    pub fn next_df(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let Some(batch) = self.reader.next().transpose()? else {
+            return Ok(None);
+        };
+
+        let mut phys_df = DataFrame::empty_with_schema(&self.phys_schema);
+        phys_df.append_record_batch(batch)?;
+        let height = phys_df.height();
+        let columns = phys_df
+            .take_columns()
+            .into_iter()
+            .zip(self.schema.iter_values())
+            .map(|(c, dtype)| unsafe { c.from_physical_unchecked(dtype) })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Some(unsafe { DataFrame::new_no_checks(height, columns) }))
+    }
+}
+
+/// One spill file per hash partition, which can be appended to concurrently.
+///
+/// The files are created lazily on the first write to a partition, using the schema of the
+/// first written [`DataFrame`].
+pub struct PartitionedSpill {
+    writers: Vec<Mutex<Option<SpillWriter>>>,
+}
+
+impl PartitionedSpill {
+    pub fn new(num_partitions: usize) -> Self {
+        Self {
+            writers: (0..num_partitions).map(|_| Mutex::default()).collect(),
+        }
+    }
+
+    pub fn num_partitions(&self) -> usize {
+        self.writers.len()
+    }
+
+    pub fn write(&self, partition: usize, df: &DataFrame) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+
+        let mut writer = self.writers[partition].lock();
+        if writer.is_none() {
+            *writer = Some(SpillWriter::new(df.schema().clone())?);
+        }
+        writer.as_mut().unwrap().write(df)
+    }
+
+    /// Finish writing, returns the spill file for each partition that was written to.
+    pub fn finish(self) -> PolarsResult<Vec<Option<SpillFile>>> {
+        self.writers
+            .into_iter()
+            .map(|w| w.into_inner().map(SpillWriter::finish).transpose())
+            .collect()
+    }
+}
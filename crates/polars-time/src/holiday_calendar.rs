@@ -0,0 +1,516 @@
+//! Rule-based holiday calendars for the business-day functions.
+//!
+//! A calendar is a list of [`HolidayRule`]s that is expanded into concrete dates for the years
+//! a query actually touches, so calendars are unbounded in time and cost nothing for years that
+//! are never looked at.
+use std::borrow::Cow;
+
+use arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+use chrono::{Datelike, NaiveDate, Weekday};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// When a holiday that falls on a weekend is observed.
+///
+/// Weekends are always Saturday and Sunday here, independent of the week mask of the query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum Observance {
+    /// The holiday is only observed on its actual date.
+    #[default]
+    Actual,
+    /// A Saturday holiday is observed on the Friday before, a Sunday holiday on the Monday after.
+    NearestWeekday,
+    /// A Sunday holiday is observed on the Monday after, a Saturday holiday is not observed.
+    SundayToMonday,
+    /// A weekend holiday is observed on the next weekday that is not already a holiday, like the
+    /// substitute days of UK bank holidays.
+    NextFreeWeekday,
+}
+
+/// The date a holiday falls on in a given year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HolidayDate {
+    /// A fixed day of the year, e.g. December 25th.
+    Fixed { month: u8, day: u8 },
+    /// The `n`-th `weekday` (0 is Monday) of a month. A negative `n` counts from the end of the
+    /// month, so "last Monday of May" is `NthWeekday { month: 5, weekday: 0, n: -1 }`.
+    NthWeekday { month: u8, weekday: u8, n: i8 },
+    /// A number of days relative to (Western) Easter Sunday, e.g. `-2` for Good Friday.
+    Easter { offset: i16 },
+}
+
+/// A recurring holiday.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct HolidayRule {
+    pub date: HolidayDate,
+    pub observance: Observance,
+    /// The first year the holiday exists in, inclusive.
+    pub start_year: Option<i32>,
+    /// The last year the holiday exists in, inclusive.
+    pub end_year: Option<i32>,
+}
+
+impl HolidayRule {
+    pub const fn new(date: HolidayDate) -> Self {
+        Self {
+            date,
+            observance: Observance::Actual,
+            start_year: None,
+            end_year: None,
+        }
+    }
+
+    pub const fn fixed(month: u8, day: u8) -> Self {
+        Self::new(HolidayDate::Fixed { month, day })
+    }
+
+    pub const fn nth_weekday(month: u8, weekday: u8, n: i8) -> Self {
+        Self::new(HolidayDate::NthWeekday { month, weekday, n })
+    }
+
+    pub const fn easter(offset: i16) -> Self {
+        Self::new(HolidayDate::Easter { offset })
+    }
+
+    pub const fn with_observance(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    pub const fn with_start_year(mut self, year: i32) -> Self {
+        self.start_year = Some(year);
+        self
+    }
+
+    pub const fn with_end_year(mut self, year: i32) -> Self {
+        self.end_year = Some(year);
+        self
+    }
+
+    pub fn validate(&self) -> PolarsResult<()> {
+        match self.date {
+            HolidayDate::Fixed { month, day } => polars_ensure!(
+                (1..=12).contains(&month) && day >= 1 && day as u32 <= days_in_month(2000, month),
+                InvalidOperation: "invalid holiday date: month {} day {}", month, day
+            ),
+            HolidayDate::NthWeekday { month, weekday, n } => polars_ensure!(
+                (1..=12).contains(&month) && weekday < 7 && n != 0 && (-5..=5).contains(&n),
+                InvalidOperation: "invalid holiday rule: occurrence {} of weekday {} in month {}", n, weekday, month
+            ),
+            HolidayDate::Easter { .. } => {},
+        }
+        if let (Some(start), Some(end)) = (self.start_year, self.end_year) {
+            polars_ensure!(
+                start <= end,
+                InvalidOperation: "holiday rule ends ({}) before it starts ({})", end, start
+            );
+        }
+        Ok(())
+    }
+
+    /// The date of the holiday in `year`, before the observance is applied.
+    fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        if self.start_year.is_some_and(|start| year < start)
+            || self.end_year.is_some_and(|end| year > end)
+        {
+            return None;
+        }
+        match self.date {
+            HolidayDate::Fixed { month, day } => {
+                NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+            },
+            HolidayDate::NthWeekday { month, weekday, n } => {
+                let month = month as u32;
+                let weekday = weekday as u32;
+                let day = if n > 0 {
+                    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                    let first_weekday = first.weekday().num_days_from_monday();
+                    1 + (weekday + 7 - first_weekday) % 7 + 7 * (n as u32 - 1)
+                } else {
+                    let last_day = days_in_month(year, month as u8);
+                    let last = NaiveDate::from_ymd_opt(year, month, last_day)?;
+                    let last_weekday = last.weekday().num_days_from_monday();
+                    let offset =
+                        (last_weekday + 7 - weekday) % 7 + 7 * (n.unsigned_abs() as u32 - 1);
+                    last_day.checked_sub(offset)?
+                };
+                NaiveDate::from_ymd_opt(year, month, day)
+            },
+            HolidayDate::Easter { offset } => {
+                easter_sunday(year)?.checked_add_signed(chrono::Duration::days(offset as i64))
+            },
+        }
+    }
+}
+
+/// A named set of holidays for the business-day functions.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HolidayCalendar {
+    /// Full-day closures of the New York Stock Exchange, following the rules in effect since 1998.
+    /// Unscheduled closures are included from 2001 onwards.
+    Nyse,
+    /// Bank holidays in England and Wales since 1978, including substitute days and one-off bank
+    /// holidays.
+    Uk,
+    /// Closing days of TARGET2, the euro area payment system, as in effect since 2002.
+    Target2,
+    /// A calendar defined by its rules.
+    Custom(Vec<HolidayRule>),
+}
+
+impl HolidayCalendar {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Nyse => "nyse",
+            Self::Uk => "uk",
+            Self::Target2 => "target2",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    pub fn rules(&self) -> &[HolidayRule] {
+        match self {
+            Self::Nyse => NYSE_RULES,
+            Self::Uk => UK_RULES,
+            Self::Target2 => TARGET2_RULES,
+            Self::Custom(rules) => rules,
+        }
+    }
+
+    pub fn validate(&self) -> PolarsResult<()> {
+        self.rules().iter().try_for_each(HolidayRule::validate)
+    }
+
+    /// One-off holidays and dates that are not holidays in spite of the rules, as `(year, month,
+    /// day)`.
+    fn adjustments(&self) -> (&'static [Ymd], &'static [Ymd]) {
+        match self {
+            Self::Nyse => (NYSE_CLOSURES, &[]),
+            Self::Uk => (UK_EXTRA_HOLIDAYS, UK_MOVED_HOLIDAYS),
+            Self::Target2 | Self::Custom(_) => (&[], &[]),
+        }
+    }
+
+    /// All holidays in the years `start_year..=end_year`, as sorted days since the UNIX epoch.
+    ///
+    /// Observed dates may fall just outside of the requested years.
+    pub fn holidays(&self, start_year: i32, end_year: i32) -> Vec<i32> {
+        let to_days = |date: NaiveDate| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE;
+        let mut out = Vec::new();
+        // Weekend holidays that move to the next free weekday can only be placed once all other
+        // holidays are known.
+        let mut substitutes = Vec::new();
+        for year in start_year..=end_year {
+            for rule in self.rules() {
+                let Some(date) = rule.date_in(year) else {
+                    continue;
+                };
+                let weekday = date.weekday();
+                let observed = match (rule.observance, weekday) {
+                    (
+                        _,
+                        Weekday::Mon | Weekday::Tue | Weekday::Wed | Weekday::Thu | Weekday::Fri,
+                    )
+                    | (Observance::Actual, _) => Some(date),
+                    (Observance::NearestWeekday, Weekday::Sat) => date.pred_opt(),
+                    (Observance::NearestWeekday | Observance::SundayToMonday, Weekday::Sun) => {
+                        date.succ_opt()
+                    },
+                    (Observance::SundayToMonday, Weekday::Sat) => None,
+                    (Observance::NextFreeWeekday, _) => {
+                        substitutes.push(date);
+                        None
+                    },
+                };
+                out.extend(observed.map(to_days));
+            }
+        }
+
+        out.sort_unstable();
+        substitutes.sort_unstable();
+        for date in substitutes {
+            let mut day = to_days(date);
+            while is_weekend(day) || out.binary_search(&day).is_ok() {
+                day += 1;
+            }
+            let idx = out.partition_point(|&x| x < day);
+            out.insert(idx, day);
+        }
+
+        let (extra, moved) = self.adjustments();
+        let in_range = |&&(year, _, _): &&Ymd| (start_year..=end_year).contains(&year);
+        let ymd_to_days = |&(year, month, day): &Ymd| {
+            to_days(NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap())
+        };
+        let moved = moved
+            .iter()
+            .filter(in_range)
+            .map(ymd_to_days)
+            .collect::<Vec<_>>();
+        out.retain(|day| !moved.contains(day));
+        out.extend(extra.iter().filter(in_range).map(ymd_to_days));
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+}
+
+/// Merge the holidays of `calendar` into `holidays`, covering at least the days
+/// `min_date..=max_date` (as days since the UNIX epoch).
+pub fn merge_calendar_holidays<'a>(
+    holidays: &'a [i32],
+    calendar: Option<&HolidayCalendar>,
+    min_date: i32,
+    max_date: i32,
+) -> Cow<'a, [i32]> {
+    let Some(calendar) = calendar else {
+        return Cow::Borrowed(holidays);
+    };
+    let year_of = |days: i32| {
+        NaiveDate::from_num_days_from_ce_opt(days.saturating_add(EPOCH_DAYS_FROM_CE))
+            .unwrap_or(if days < 0 {
+                NaiveDate::MIN
+            } else {
+                NaiveDate::MAX
+            })
+            .year()
+    };
+    // Pad by a year on both sides to cover observed dates that cross a year boundary.
+    let start_year = year_of(min_date) - 1;
+    let end_year = year_of(max_date) + 1;
+    let mut out = calendar.holidays(start_year, end_year);
+    out.extend_from_slice(holidays);
+    Cow::Owned(out)
+}
+
+fn is_weekend(day: i32) -> bool {
+    // 1970-01-01 was a Thursday.
+    (day - 4).rem_euclid(7) >= 5
+}
+
+fn days_in_month(year: i32, month: u8) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if NaiveDate::from_ymd_opt(year, 2, 29).is_some() => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Western Easter Sunday, using the anonymous Gregorian algorithm.
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c.div_euclid(4);
+    let k = c.rem_euclid(4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l).div_euclid(451);
+    let month = (h + l - 7 * m + 114).div_euclid(31);
+    let day = (h + l - 7 * m + 114).rem_euclid(31) + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+const MON: u8 = 0;
+const THU: u8 = 3;
+
+static NYSE_RULES: &[HolidayRule] = &[
+    // New Year's Day. Not observed on the Friday before, as that closes the accounting year.
+    HolidayRule::fixed(1, 1).with_observance(Observance::SundayToMonday),
+    // Martin Luther King Jr. Day.
+    HolidayRule::nth_weekday(1, MON, 3).with_start_year(1998),
+    // Washington's Birthday.
+    HolidayRule::nth_weekday(2, MON, 3),
+    // Good Friday.
+    HolidayRule::easter(-2),
+    // Memorial Day.
+    HolidayRule::nth_weekday(5, MON, -1),
+    // Juneteenth.
+    HolidayRule::fixed(6, 19)
+        .with_observance(Observance::NearestWeekday)
+        .with_start_year(2022),
+    // Independence Day.
+    HolidayRule::fixed(7, 4).with_observance(Observance::NearestWeekday),
+    // Labor Day.
+    HolidayRule::nth_weekday(9, MON, 1),
+    // Thanksgiving.
+    HolidayRule::nth_weekday(11, THU, 4),
+    // Christmas.
+    HolidayRule::fixed(12, 25).with_observance(Observance::NearestWeekday),
+];
+
+/// A calendar date as `(year, month, day)`.
+type Ymd = (i32, u8, u8);
+
+static NYSE_CLOSURES: &[Ymd] = &[
+    (2001, 9, 11),
+    (2001, 9, 12),
+    (2001, 9, 13),
+    (2001, 9, 14),
+    (2004, 6, 11),
+    (2007, 1, 2),
+    (2012, 10, 29),
+    (2012, 10, 30),
+    (2018, 12, 5),
+    (2025, 1, 9),
+];
+
+static UK_RULES: &[HolidayRule] = &[
+    // New Year's Day.
+    HolidayRule::fixed(1, 1)
+        .with_observance(Observance::NextFreeWeekday)
+        .with_start_year(1974),
+    // Good Friday.
+    HolidayRule::easter(-2),
+    // Easter Monday.
+    HolidayRule::easter(1),
+    // Early May bank holiday.
+    HolidayRule::nth_weekday(5, MON, 1).with_start_year(1978),
+    // Spring bank holiday.
+    HolidayRule::nth_weekday(5, MON, -1),
+    // Summer bank holiday.
+    HolidayRule::nth_weekday(8, MON, -1),
+    // Christmas Day.
+    HolidayRule::fixed(12, 25).with_observance(Observance::NextFreeWeekday),
+    // Boxing Day.
+    HolidayRule::fixed(12, 26).with_observance(Observance::NextFreeWeekday),
+];
+
+static UK_EXTRA_HOLIDAYS: &[Ymd] = &[
+    (1981, 7, 29),
+    (1995, 5, 8),
+    (1999, 12, 31),
+    (2002, 6, 3),
+    (2002, 6, 4),
+    (2011, 4, 29),
+    (2012, 6, 4),
+    (2012, 6, 5),
+    (2020, 5, 8),
+    (2022, 6, 2),
+    (2022, 6, 3),
+    (2022, 9, 19),
+    (2023, 5, 8),
+];
+
+/// Bank holidays that were moved to one of the dates in `UK_EXTRA_HOLIDAYS`.
+static UK_MOVED_HOLIDAYS: &[Ymd] = &[
+    (1995, 5, 1),
+    (2002, 5, 27),
+    (2012, 5, 28),
+    (2020, 5, 4),
+    (2022, 5, 30),
+];
+
+static TARGET2_RULES: &[HolidayRule] = &[
+    HolidayRule::fixed(1, 1),
+    HolidayRule::easter(-2),
+    HolidayRule::easter(1),
+    HolidayRule::fixed(5, 1),
+    HolidayRule::fixed(12, 25),
+    HolidayRule::fixed(12, 26),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dates(calendar: &HolidayCalendar, year: i32) -> Vec<String> {
+        calendar
+            .holidays(year, year)
+            .into_iter()
+            .map(|days| {
+                NaiveDate::from_num_days_from_ce_opt(days + EPOCH_DAYS_FROM_CE)
+                    .unwrap()
+                    .format("%m-%d")
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_easter() {
+        for (year, month, day) in [(2000, 4, 23), (2019, 4, 21), (2024, 3, 31), (2025, 4, 20)] {
+            assert_eq!(
+                easter_sunday(year),
+                NaiveDate::from_ymd_opt(year, month, day)
+            );
+        }
+    }
+
+    #[test]
+    fn test_nyse() {
+        assert_eq!(
+            dates(&HolidayCalendar::Nyse, 2021),
+            [
+                "01-01", "01-18", "02-15", "04-02", "05-31", "07-05", "09-06", "11-25", "12-24"
+            ]
+        );
+        // New Year's Day 2022 is on a Saturday and isn't observed.
+        assert_eq!(
+            dates(&HolidayCalendar::Nyse, 2022),
+            [
+                "01-17", "02-21", "04-15", "05-30", "06-20", "07-04", "09-05", "11-24", "12-26"
+            ]
+        );
+        assert!(dates(&HolidayCalendar::Nyse, 2025).contains(&"01-09".to_string()));
+    }
+
+    #[test]
+    fn test_uk_substitute_days() {
+        // Christmas on a Saturday, Boxing Day on a Sunday.
+        assert_eq!(
+            dates(&HolidayCalendar::Uk, 2021),
+            [
+                "01-01", "04-02", "04-05", "05-03", "05-31", "08-30", "12-27", "12-28"
+            ]
+        );
+        // Christmas on a Sunday, Boxing Day on a Monday; moved and extra bank holidays.
+        assert_eq!(
+            dates(&HolidayCalendar::Uk, 2022),
+            [
+                "01-03", "04-15", "04-18", "05-02", "06-02", "06-03", "08-29", "09-19", "12-26",
+                "12-27"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let calendar = HolidayCalendar::Custom(vec![
+            // Last Monday of May.
+            HolidayRule::nth_weekday(5, MON, -1),
+            // Second Thursday of the month, on a month with five Thursdays.
+            HolidayRule::nth_weekday(10, THU, 2),
+            HolidayRule::nth_weekday(10, THU, 5).with_end_year(2024),
+            HolidayRule::fixed(2, 29),
+        ]);
+        calendar.validate().unwrap();
+        assert_eq!(dates(&calendar, 2024), ["02-29", "05-27", "10-10", "10-31"]);
+        assert_eq!(dates(&calendar, 2025), ["05-26", "10-09"]);
+
+        assert!(
+            HolidayCalendar::Custom(vec![HolidayRule::fixed(2, 30)])
+                .validate()
+                .is_err()
+        );
+        assert!(
+            HolidayCalendar::Custom(vec![HolidayRule::nth_weekday(1, 7, 1)])
+                .validate()
+                .is_err()
+        );
+    }
+}
@@ -11,6 +11,8 @@ mod date_range;
 #[cfg(feature = "timezones")]
 mod dst_offset;
 mod group_by;
+#[cfg(feature = "business")]
+mod holiday_calendar;
 #[cfg(feature = "month_end")]
 mod month_end;
 #[cfg(feature = "month_start")]
@@ -34,6 +36,8 @@ pub use date_range::*;
 pub use dst_offset::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
+#[cfg(feature = "business")]
+pub use holiday_calendar::*;
 #[cfg(feature = "month_end")]
 pub use month_end::*;
 #[cfg(feature = "month_start")]
@@ -2,5 +2,6 @@
 
 mod read;
 mod read_async;
+mod scan;
 mod write;
 mod write_async;
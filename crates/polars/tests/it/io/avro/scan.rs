@@ -0,0 +1,196 @@
+use arrow::io::avro::avro_schema::write::{write_block, write_metadata};
+use arrow::io::avro::write;
+use polars::prelude::*;
+
+use super::write::serialize_to_block;
+
+/// Writes `df` to an Avro file with blocks of `block_size` rows.
+fn write_blocks(df: &DataFrame, block_size: usize) -> PolarsResult<Vec<u8>> {
+    let schema = df.schema().to_arrow(CompatLevel::oldest());
+    let mut file = vec![];
+
+    write_metadata(&mut file, write::to_record(&schema, "".to_string())?, None)?;
+
+    for offset in (0..df.height()).step_by(block_size) {
+        let batch = df
+            .slice(offset as i64, block_size)
+            .rechunk_to_record_batch(CompatLevel::oldest());
+        write_block(&mut file, &serialize_to_block(&batch, &schema, None)?)?;
+    }
+
+    Ok(file)
+}
+
+fn scan(files: Vec<Vec<u8>>, args: UnifiedScanArgs) -> PolarsResult<LazyFrame> {
+    let sources = ScanSources::Buffers(files.into_iter().map(Into::into).collect());
+    let args = UnifiedScanArgs {
+        hive_options: polars::io::HiveOptions::new_disabled(),
+        ..args
+    };
+
+    LazyFrame::scan_avro_sources(sources, Default::default(), args)
+}
+
+fn create_df() -> PolarsResult<DataFrame> {
+    df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("b{}", i % 7)).collect::<Vec<_>>(),
+        "c" => (0..1000).map(|i| i as f64 / 2.0).collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn test_scan_avro() -> PolarsResult<()> {
+    let df = create_df()?;
+    let file = write_blocks(&df, 64)?;
+    let collect = |lf: LazyFrame| lf.collect_with_engine(Engine::Streaming);
+
+    let out = collect(scan(vec![file.clone()], Default::default())?)?;
+    assert!(out.equals(&df));
+
+    // Projections are returned in the requested order.
+    let out = collect(scan(vec![file.clone()], Default::default())?.select([col("c"), col("a")]))?;
+    assert!(out.equals(&df.select(["c", "a"])?));
+
+    let out = collect(scan(vec![file.clone()], Default::default())?.slice(130, 200))?;
+    assert!(out.equals(&df.slice(130, 200)));
+
+    let out = collect(scan(vec![file.clone()], Default::default())?.tail(70))?;
+    assert!(out.equals(&df.tail(Some(70))));
+
+    let out =
+        collect(scan(vec![file.clone()], Default::default())?.filter(col("a").gt_eq(lit(900i64))))?;
+    assert!(out.equals(&df.slice(900, 100)));
+
+    let out = collect(scan(vec![file], Default::default())?.select([len()]))?;
+    assert_eq!(out.column("len")?.get(0)?, AnyValue::from(1000 as IdxSize));
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_avro_multiple_files_with_row_index() -> PolarsResult<()> {
+    let df = create_df()?;
+    let files = vec![write_blocks(&df, 100)?, write_blocks(&df, 33)?];
+
+    let args = UnifiedScanArgs {
+        row_index: Some(polars::io::RowIndex {
+            name: "index".into(),
+            offset: 5,
+        }),
+        ..Default::default()
+    };
+    let out = scan(files, args)?
+        .slice(950, 100)
+        .select([col("index"), col("b")])
+        .collect_with_engine(Engine::Streaming)?;
+
+    let expected = df
+        .vstack(&df)?
+        .slice(950, 100)
+        .select(["b"])?
+        .with_row_index("index".into(), Some(955))?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_avro_empty_blocks() -> PolarsResult<()> {
+    let df = create_df()?;
+    let schema = df.schema().to_arrow(CompatLevel::oldest());
+
+    // Files may contain blocks without rows, which must not end the scan.
+    let mut file = vec![];
+    write_metadata(&mut file, write::to_record(&schema, "".to_string())?, None)?;
+    for df in [df.slice(0, 10), df.clear(), df.slice(10, 20)] {
+        let batch = df.rechunk_to_record_batch(CompatLevel::oldest());
+        write_block(&mut file, &serialize_to_block(&batch, &schema, None)?)?;
+    }
+
+    let out = scan(vec![file], Default::default())?.collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&df.slice(0, 30)));
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_avro_predicate() -> PolarsResult<()> {
+    let df = create_df()?;
+    let file = write_blocks(&df, 50)?;
+    let collect = |lf: LazyFrame| lf.collect_with_engine(Engine::Streaming);
+
+    // The predicate column is not part of the output.
+    let out = collect(
+        scan(vec![file.clone()], Default::default())?
+            .filter(col("b").eq(lit("b3")))
+            .select([col("c")]),
+    )?;
+    let expected = df
+        .clone()
+        .lazy()
+        .filter(col("b").eq(lit("b3")))
+        .select([col("c")])
+        .collect()?;
+    assert!(out.equals(&expected));
+
+    // Row indices are assigned before filtering.
+    let args = UnifiedScanArgs {
+        row_index: Some(polars::io::RowIndex {
+            name: "index".into(),
+            offset: 0,
+        }),
+        ..Default::default()
+    };
+    let out = collect(
+        scan(vec![file.clone()], args)?
+            .filter((col("a") % lit(10i64)).eq(lit(0i64)))
+            .select([col("index"), col("a")]),
+    )?;
+    let expected = df
+        .with_row_index("index".into(), None)?
+        .lazy()
+        .filter((col("a") % lit(10i64)).eq(lit(0i64)))
+        .select([col("index"), col("a")])
+        .collect()?;
+    assert_eq!(out.height(), 100);
+    assert!(out.equals(&expected));
+
+    // Slices are applied before filtering.
+    let out = collect(
+        scan(vec![file], Default::default())?
+            .slice(120, 300)
+            .filter(col("a").gt_eq(lit(400i64))),
+    )?;
+    assert!(out.equals(&df.slice(400, 20)));
+
+    Ok(())
+}
+
+#[test]
+fn test_sink_avro() -> PolarsResult<()> {
+    let df = create_df()?;
+    let lf = concat(
+        [
+            scan(vec![write_blocks(&df, 64)?], Default::default())?,
+            scan(vec![write_blocks(&df, 100)?], Default::default())?,
+        ],
+        UnionArgs::default(),
+    )?;
+
+    let path = std::env::temp_dir().join(format!("polars-sink-avro-{}.avro", std::process::id()));
+    lf.sink_avro(
+        SinkTarget::Path(PlPath::new(path.to_str().unwrap())),
+        Default::default(),
+        None,
+        Default::default(),
+    )?
+    .collect_with_engine(Engine::Streaming)?;
+    let file = std::fs::read(&path)?;
+    std::fs::remove_file(&path)?;
+
+    let out = scan(vec![file], Default::default())?.collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&df.vstack(&df)?));
+
+    Ok(())
+}
@@ -432,6 +432,25 @@ fn test_write_and_read_with_compression() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_write_multiple_chunks() -> PolarsResult<()> {
+    let df = df!(
+        "i64" => &[1, 2],
+        "string" => &["a", "b"]
+    )?;
+    let mut write_df = df.vstack(&df)?;
+    assert_eq!(write_df.first_col_n_chunks(), 2);
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    AvroWriter::new(&mut buf).finish(&mut write_df)?;
+    buf.set_position(0);
+
+    let read_df = AvroReader::new(buf).finish()?;
+    assert!(write_df.equals(&read_df));
+
+    Ok(())
+}
+
 #[test]
 fn test_with_projection() -> PolarsResult<()> {
     let mut df = df!(
@@ -206,3 +206,62 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[cfg(feature = "parquet_encryption")]
+struct FooterKeyRetriever;
+
+#[cfg(feature = "parquet_encryption")]
+impl KeyRetriever for FooterKeyRetriever {
+    fn footer_key(
+        &self,
+        _key_metadata: Option<&[u8]>,
+    ) -> polars_parquet::parquet::error::ParquetResult<Vec<u8>> {
+        Ok(vec![7; 16])
+    }
+
+    fn column_key(
+        &self,
+        _path_in_schema: &[String],
+        _key_metadata: Option<&[u8]>,
+    ) -> polars_parquet::parquet::error::ParquetResult<Vec<u8>> {
+        Ok(vec![8; 16])
+    }
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "parquet_encryption"))]
+fn test_encrypted_roundtrip() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("b{}", i % 10)).collect::<Vec<_>>(),
+    }?;
+    let key_retriever = ParquetKeyRetriever::new(FooterKeyRetriever);
+    let mut encryption = ParquetEncryptionOptions::new(key_retriever.clone());
+    encryption.algorithm = ParquetEncryptionAlgorithm::AesGcmCtr;
+    encryption.column_key_metadata = vec![("b".into(), None)];
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(300))
+        .with_encryption(Some(encryption))
+        .finish(&mut df)?;
+    let buf = buf.into_inner();
+
+    assert!(ParquetReader::new(Cursor::new(&buf)).finish().is_err());
+    let read_df = ParquetReader::new(Cursor::new(&buf))
+        .with_key_retriever(Some(key_retriever.clone()))
+        .finish()?;
+    assert!(df.equals(&read_df));
+
+    let args = ScanArgsParquet {
+        hive_options: polars::io::HiveOptions::new_disabled(),
+        key_retriever: Some(key_retriever),
+        ..Default::default()
+    };
+    let sources = ScanSources::Buffers(Arc::new([buf.into()]));
+    let out = LazyFrame::scan_parquet_sources(sources, args)?
+        .filter(col("a").gt_eq(lit(500i64)))
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&df.slice(500, 500)));
+    Ok(())
+}
@@ -1,16 +1,25 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use arrow::array::{ArrayRef, Utf8ViewArray};
+use arrow::array::{Array, ArrayRef, Int64Array, Utf8ViewArray};
 use arrow::datatypes::{ArrowSchema, Field};
 use arrow::record_batch::RecordBatchT;
 use polars_error::PolarsResult;
 use polars_parquet::arrow::write::{FileWriter, WriteOptions};
+use polars_parquet::parquet::bloom_filter;
+#[cfg(feature = "parquet_encryption")]
+use polars_parquet::parquet::error::ParquetResult;
+use polars_parquet::parquet::read::indexes;
 use polars_parquet::read::read_metadata;
+#[cfg(feature = "parquet_encryption")]
+use polars_parquet::read::read_metadata_with_key_retriever;
+use polars_parquet::read::statistics::deserialize_all_pages;
 use polars_parquet::write::{
-    ColumnWriteOptions, CompressionOptions, Encoding, FieldWriteOptions, RowGroupIterator,
-    StatisticsOptions, Version,
+    BloomFilterOptions, ColumnWriteOptions, CompressionOptions, Encoding, FieldWriteOptions,
+    RowGroupIterator, StatisticsOptions, Version, array_to_bloom_filters,
 };
+#[cfg(feature = "parquet_encryption")]
+use polars_parquet::write::{EncryptionAlgorithm, FileEncryptionProperties, KeyRetriever};
 
 use crate::io::parquet::read::file::FileReader;
 
@@ -88,3 +97,257 @@ fn roundtrip_binview() -> PolarsResult<()> {
         ],
     )
 }
+
+#[test]
+fn roundtrip_bloom_filter() -> PolarsResult<()> {
+    let array = Int64Array::from_iter((0..1000).map(|i| (i % 7 != 0).then_some(i * 3))).boxed();
+    let field = Field::new("a1".into(), array.dtype().clone(), true);
+    let schema = ArrowSchema::from_iter([field]);
+    let column_options = vec![
+        FieldWriteOptions {
+            encoding: Encoding::Plain,
+            bloom_filter: Some(BloomFilterOptions::default()),
+        }
+        .into_default_column_write_options(),
+    ];
+
+    let options = WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_page_size: None,
+    };
+
+    let iter = vec![RecordBatchT::try_new(
+        array.len(),
+        Arc::new(schema.clone()),
+        vec![array.clone()],
+    )];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, column_options.clone())?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options, &column_options)?;
+    for group in row_groups {
+        let bloom_filters = array_to_bloom_filters(array.as_ref(), &column_options[0]);
+        writer.write_with_bloom_filters(group?, bloom_filters)?;
+    }
+    writer.end(None, &column_options)?;
+
+    let mut reader = Cursor::new(writer.into_inner().into_inner());
+    let md = read_metadata(&mut reader)?;
+    let column = &md.row_groups[0].parquet_columns()[0];
+    assert!(column.metadata().bloom_filter_length.is_some());
+
+    let mut bitset = vec![];
+    bloom_filter::read(column, &mut reader, &mut bitset)?;
+    assert!(!bitset.is_empty());
+
+    let contains = |v: i64| bloom_filter::is_in_set(&bitset, bloom_filter::hash_native(v));
+    assert!((0..1000).filter(|i| i % 7 != 0).all(|i| contains(i * 3)));
+    // Nulls are not inserted and the false positive rate is bounded.
+    let num_false_positives = (0..1000).filter(|i| i % 7 == 0 && contains(i * 3)).count();
+    assert!(num_false_positives < 20);
+    Ok(())
+}
+
+#[test]
+fn roundtrip_page_index() -> PolarsResult<()> {
+    let array = Int64Array::from_iter((0..10_000).map(|i| (i % 7 != 0).then_some(i))).boxed();
+    let field = Field::new("a1".into(), array.dtype().clone(), true);
+    let schema = ArrowSchema::from_iter([field.clone()]);
+    let column_options = vec![
+        FieldWriteOptions {
+            encoding: Encoding::Plain,
+            bloom_filter: None,
+        }
+        .into_default_column_write_options(),
+    ];
+
+    let options = WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_page_size: Some(1024),
+    };
+
+    let iter = vec![RecordBatchT::try_new(
+        array.len(),
+        Arc::new(schema.clone()),
+        vec![array.clone()],
+    )];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, column_options.clone())?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options, &column_options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None, &column_options)?;
+
+    let data = writer.into_inner().into_inner();
+    let md = read_metadata(&mut Cursor::new(&data))?;
+    let column = &md.row_groups[0].parquet_columns()[0];
+
+    let slice = |range: std::ops::Range<u64>| &data[range.start as usize..range.end as usize];
+    let column_index =
+        indexes::deserialize_column_index(slice(column.column_index_byte_range().unwrap()))?;
+    let offset_index =
+        indexes::deserialize_offset_index(slice(column.offset_index_byte_range().unwrap()))?;
+
+    let page_rows = indexes::page_row_ranges(&offset_index, 10_000)?;
+    assert!(page_rows.len() > 1);
+    assert_eq!(page_rows.first().unwrap().start, 0);
+    assert_eq!(page_rows.last().unwrap().end, 10_000);
+
+    let primitive_type = &column.descriptor().descriptor.primitive_type;
+    let page_statistics = indexes::deserialize_page_statistics(&column_index, primitive_type)?;
+    let page_statistics = deserialize_all_pages(&field, primitive_type, page_statistics)?.unwrap();
+
+    let values = |array: &dyn Array| {
+        let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+        array.iter().map(|v| v.copied()).collect::<Vec<_>>()
+    };
+    let min_value = values(page_statistics.min_value.as_ref());
+    let max_value = values(page_statistics.max_value.as_ref());
+    let null_count = page_statistics.null_count.iter().map(|v| v.copied());
+
+    for (((rows, min), max), null_count) in page_rows
+        .into_iter()
+        .zip(min_value)
+        .zip(max_value)
+        .zip(null_count)
+    {
+        let non_null = rows.clone().filter(|r| r % 7 != 0).map(|r| r as i64);
+        assert_eq!(min, non_null.clone().min());
+        assert_eq!(max, non_null.max());
+        assert_eq!(null_count, Some(rows.filter(|r| r % 7 == 0).count() as _));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "parquet_encryption")]
+struct TestKeyRetriever {
+    footer_key: Vec<u8>,
+}
+
+#[cfg(feature = "parquet_encryption")]
+impl KeyRetriever for TestKeyRetriever {
+    fn footer_key(&self, key_metadata: Option<&[u8]>) -> ParquetResult<Vec<u8>> {
+        assert_eq!(key_metadata, Some(b"footer".as_slice()));
+        Ok(self.footer_key.clone())
+    }
+
+    fn column_key(
+        &self,
+        path_in_schema: &[String],
+        key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Vec<u8>> {
+        assert_eq!(path_in_schema, ["a2"]);
+        assert_eq!(key_metadata, Some(b"a2".as_slice()));
+        Ok(vec![2; 32])
+    }
+}
+
+#[test]
+#[cfg(feature = "parquet_encryption")]
+fn roundtrip_encryption() -> PolarsResult<()> {
+    let a1 = Int64Array::from_iter((0..5000).map(|i| (i % 7 != 0).then_some(i))).boxed();
+    let a2 = (0..5000)
+        .map(|i| format!("v{}", i % 100))
+        .collect::<Vec<_>>();
+    let a2 = Utf8ViewArray::from_slice_values(a2).boxed();
+    let schema = ArrowSchema::from_iter([
+        Field::new("a1".into(), a1.dtype().clone(), true),
+        Field::new("a2".into(), a2.dtype().clone(), true),
+    ]);
+    let column_options = vec![
+        FieldWriteOptions::default_with_encoding(Encoding::Plain)
+            .into_default_column_write_options(),
+        FieldWriteOptions::default_with_encoding(Encoding::RleDictionary)
+            .into_default_column_write_options(),
+    ];
+
+    let options = WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Snappy,
+        version: Version::V2,
+        data_page_size: Some(1024),
+    };
+
+    for algorithm in [
+        EncryptionAlgorithm::AesGcmV1,
+        EncryptionAlgorithm::AesGcmCtrV1,
+    ] {
+        let key_retriever: Arc<dyn KeyRetriever> = Arc::new(TestKeyRetriever {
+            footer_key: vec![1; 16],
+        });
+        let properties = FileEncryptionProperties {
+            algorithm,
+            key_retriever: key_retriever.clone(),
+            footer_key_metadata: Some(b"footer".to_vec()),
+            column_key_metadata: vec![("a2".to_string(), Some(b"a2".to_vec()))],
+            aad_prefix: None,
+        };
+
+        let batches = [0..2500, 2500..5000].map(|range| {
+            RecordBatchT::try_new(
+                range.len(),
+                Arc::new(schema.clone()),
+                vec![
+                    a1.sliced(range.start, range.len()),
+                    a2.sliced(range.start, range.len()),
+                ],
+            )
+        });
+        let row_groups = RowGroupIterator::try_new(
+            batches.into_iter(),
+            &schema,
+            options,
+            column_options.clone(),
+        )?;
+
+        let mut writer = FileWriter::try_new(
+            Cursor::new(vec![]),
+            schema.clone(),
+            options,
+            &column_options,
+        )?
+        .with_encryption(&properties)?;
+        for group in row_groups {
+            writer.write(group?)?;
+        }
+        writer.end(None, &column_options)?;
+        let data = writer.into_inner().into_inner();
+        assert_eq!(&data[..4], b"PARE");
+
+        // The metadata can't be read without the keys.
+        assert!(read_metadata(&mut Cursor::new(&data)).is_err());
+        let wrong_key_retriever: Arc<dyn KeyRetriever> = Arc::new(TestKeyRetriever {
+            footer_key: vec![3; 16],
+        });
+        assert!(
+            read_metadata_with_key_retriever(&mut Cursor::new(&data), Some(&wrong_key_retriever))
+                .is_err()
+        );
+
+        let mut reader = Cursor::new(data);
+        let md = read_metadata_with_key_retriever(&mut reader, Some(&key_retriever))?;
+        assert_eq!(md.row_groups.len(), 2);
+        let column = &md.row_groups[0].parquet_columns()[1];
+        assert!(column.is_encrypted());
+
+        let chunks = FileReader::new(reader, md.row_groups, schema.clone(), None);
+        let mut arrays: [Vec<ArrayRef>; 2] = [vec![], vec![]];
+        for chunk in chunks {
+            for (arrays, array) in arrays.iter_mut().zip(chunk?.into_arrays()) {
+                arrays.push(array);
+            }
+        }
+        for (expected, arrays) in [&a1, &a2].into_iter().zip(arrays) {
+            let arrays = arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+            let actual = arrow::compute::concatenate::concatenate(&arrays)?;
+            assert_eq!(expected.as_ref(), actual.as_ref());
+        }
+    }
+    Ok(())
+}
@@ -35,6 +35,9 @@ SyncOnCloseType: TypeAlias = Literal["none", "data", "all"]
 SinkOptions: TypeAlias = dict[str, Any]
 SinkTarget: TypeAlias = str | Any | PyPartitioning
 AsofStrategy: TypeAlias = Literal["backward", "forward", "nearest"]
+StringSimilarityMetric: TypeAlias = Literal[
+    "levenshtein", "damerau_levenshtein", "hamming", "jaro", "jaro_winkler"
+]
 InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
@@ -68,6 +71,7 @@ QuantileMethod: TypeAlias = Literal[
 ]
 RankMethod: TypeAlias = Literal["min", "max", "average", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
+HolidayCalendar: TypeAlias = Literal["nyse", "uk", "target2"]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
 UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
@@ -1026,6 +1030,19 @@ class PyLazyFrame:
         allow_eq: bool,
         check_sortedness: bool,
     ) -> PyLazyFrame: ...
+    def join_fuzzy(
+        self,
+        other: PyLazyFrame,
+        left_on: PyExpr,
+        right_on: PyExpr,
+        metric: StringSimilarityMetric,
+        threshold: float | None,
+        max_distance: int | None,
+        score_name: str,
+        suffix: str,
+        allow_parallel: bool,
+        force_parallel: bool,
+    ) -> PyLazyFrame: ...
     def join(
         self,
         other: PyLazyFrame,
@@ -1112,6 +1129,13 @@ class PyLazyFrame:
     def hint_sorted(
         self, columns: list[str], descending: list[bool], nulls_last: list[bool]
     ) -> PyLazyFrame: ...
+    def hint(
+        self,
+        unique: list[str] | None,
+        row_count: int | None,
+        distinct_count: list[tuple[str, int]] | None,
+        partitioned_by: list[str] | None,
+    ) -> PyLazyFrame: ...
 
     # exitable
     def collect_concurrently(self) -> PyInProcessQuery: ...
@@ -1450,6 +1474,9 @@ class PyExpr:
     def bin_base64_encode(self) -> PyExpr: ...
     def bin_reinterpret(self, dtype: PyDataTypeExpr, kind: str) -> PyExpr: ...
     def bin_size_bytes(self) -> PyExpr: ...
+    def bin_hash(self, algorithm: str, seed: int) -> PyExpr: ...
+    def bin_compress(self, codec: str, level: int | None) -> PyExpr: ...
+    def bin_decompress(self, codec: str, strict: bool) -> PyExpr: ...
 
     # bitwise
     def bitwise_count_ones(self) -> PyExpr: ...
@@ -1472,7 +1499,20 @@ class PyExpr:
 
     # datetime
     def dt_add_business_days(
-        self, n: PyExpr, week_mask: Sequence[bool], holidays: Sequence[int], roll: Roll
+        self,
+        n: PyExpr,
+        week_mask: Sequence[bool],
+        holidays: Sequence[int],
+        calendar: HolidayCalendar | None,
+        roll: Roll,
+    ) -> PyExpr: ...
+    def dt_add_business_hours(
+        self,
+        n: PyExpr,
+        business_hours: Sequence[int],
+        week_mask: Sequence[bool],
+        holidays: Sequence[int],
+        calendar: HolidayCalendar | None,
     ) -> PyExpr: ...
     def dt_to_string(self, format: str) -> PyExpr: ...
     def dt_offset_by(self, by: PyExpr) -> PyExpr: ...
@@ -1507,7 +1547,10 @@ class PyExpr:
     def dt_century(self) -> PyExpr: ...
     def dt_year(self) -> PyExpr: ...
     def dt_is_business_day(
-        self, week_mask: Sequence[bool], holidays: Sequence[int]
+        self,
+        week_mask: Sequence[bool],
+        holidays: Sequence[int],
+        calendar: HolidayCalendar | None,
     ) -> PyExpr: ...
     def dt_is_leap_year(self) -> PyExpr: ...
     def dt_iso_year(self) -> PyExpr: ...
@@ -2030,6 +2073,10 @@ class PyOptFlags:
     @fast_projection.setter
     def fast_projection(self, value: bool) -> None: ...
     @property
+    def join_reorder(self) -> bool: ...
+    @join_reorder.setter
+    def join_reorder(self, value: bool) -> None: ...
+    @property
     def eager(self) -> bool: ...
     @eager.setter
     def eager(self, value: bool) -> None: ...
@@ -2190,6 +2237,7 @@ def business_day_count(
     end: PyExpr,
     week_mask: Sequence[bool],
     holidays: Sequence[int],
+    calendar: HolidayCalendar | None,
 ) -> PyExpr: ...
 
 # functions.eager
@@ -109,6 +109,7 @@ ColumnNameOrSelector: TypeAlias = Union[str, SelectorType]
 # The following all have an equivalent Rust enum with the same name
 Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+BinaryCodec: TypeAlias = Literal["gzip", "zstd", "lz4", "snappy"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
@@ -121,9 +122,23 @@ ColumnMapping: TypeAlias = tuple[
 DefaultFieldValues: TypeAlias = tuple[
     Literal["iceberg"], dict[int, Union["Series", str]]
 ]
-DeletionFiles: TypeAlias = tuple[
-    Literal["iceberg-position-delete"], dict[int, list[str]]
+DeletionFiles: TypeAlias = Union[
+    tuple[Literal["iceberg-position-delete"], dict[int, list[str]]],
+    # {file_index: (data_sequence_number,
+    #               [(path, sequence_number, equality_ids)],
+    #               position_delete_paths)}
+    tuple[
+        Literal["iceberg-equality-delete"],
+        dict[int, tuple[int, list[tuple[str, int, list[int]]], list[str]]],
+    ],
+    # (table_root, {file_index: (storage_type, path_or_inline_dv, offset,
+    #                            size_in_bytes, cardinality)})
+    tuple[
+        Literal["delta-deletion-vector"],
+        tuple[str, dict[int, tuple[str, str, Union[int, None], int, int]]],
+    ],
 ]
+FileCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
@@ -151,6 +166,9 @@ QuantileMethod: TypeAlias = Literal[
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
+HolidayCalendar: TypeAlias = Union[
+    Literal["nyse", "uk", "target2"], Sequence["HolidayRule"]
+]
 RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero"]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
 Endianness: TypeAlias = Literal["little", "big"]
@@ -177,6 +195,9 @@ StartBy: TypeAlias = Literal[
     "saturday",
     "sunday",
 ]
+StringSimilarityMetric: TypeAlias = Literal[
+    "levenshtein", "damerau_levenshtein", "hamming", "jaro", "jaro_winkler"
+]
 SyncOnCloseMethod: TypeAlias = Literal["data", "all"]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
 UnicodeForm: TypeAlias = Literal["NFC", "NFKC", "NFD", "NFKD"]
@@ -278,6 +299,27 @@ class SeriesBuffers(TypedDict):
     offsets: Series | None
 
 
+class HolidayRule(TypedDict, total=False):
+    """
+    A holiday of a custom holiday calendar.
+
+    The date is given by either `month` and `day`, by the `n`-th `weekday` of
+    `month` (0 is Monday, a negative `n` counts from the end of the month), or
+    by the number of days relative to Easter Sunday.
+    """
+
+    month: int
+    day: int
+    weekday: int
+    n: int
+    easter_offset: int
+    observance: Literal[
+        "actual", "nearest_weekday", "sunday_to_monday", "next_free_weekday"
+    ]
+    start_year: int
+    end_year: int
+
+
 # minimal protocol definitions that can reasonably represent
 # an executable connection, cursor, or equivalent object
 class BasicConnection(Protocol):
@@ -374,6 +416,7 @@ __all__ = [
     "ArrowStreamExportable",
     "AsofJoinStrategy",
     "AvroCompression",
+    "BinaryCodec",
     "BooleanMask",
     "BufferInfo",
     "CategoricalOrdering",
@@ -399,11 +442,14 @@ __all__ = [
     "EpochTimeUnit",
     "ExcelSpreadsheetEngine",
     "ExplainFormat",
+    "FileCompression",
     "FileSource",
     "FillNullStrategy",
     "FloatFmt",
     "FrameInitTypes",
     "FrameType",
+    "HolidayCalendar",
+    "HolidayRule",
     "IndexOrder",
     "InterpolationMethod",
     "IntoExpr",
@@ -452,6 +498,7 @@ __all__ = [
     "SingleNameSelector",
     "SizeUnit",
     "StartBy",
+    "StringSimilarityMetric",
     "SyncOnCloseMethod",
     "TemporalLiteral",
     "TimeUnit",
@@ -177,6 +177,7 @@ if TYPE_CHECKING:
         SingleIndexSelector,
         SizeUnit,
         StartBy,
+        StringSimilarityMetric,
         UniqueKeepStrategy,
         UnstackDirection,
     )
@@ -8348,6 +8349,116 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
+    @unstable()
+    def join_fuzzy(
+        self,
+        other: DataFrame,
+        on: str | Expr | None = None,
+        *,
+        left_on: str | Expr | None = None,
+        right_on: str | Expr | None = None,
+        metric: StringSimilarityMetric = "levenshtein",
+        threshold: float | None = None,
+        max_distance: int | None = None,
+        score_name: str = "score",
+        suffix: str = "_right",
+        allow_parallel: bool = True,
+        force_parallel: bool = False,
+    ) -> DataFrame:
+        """
+        Join on string keys that are similar rather than equal.
+
+        This performs an inner join that pairs every row of this DataFrame with all
+        rows of `other` whose key is within `max_distance` edits of, or at least
+        `threshold` similar to, its own key. The score of every pair is added as a
+        column. Candidate pairs are found through shared q-grams, so the two
+        tables are never fully cross joined. Null keys never match.
+
+        The result is ordered by the rows of this DataFrame, then by the rows of
+        `other`.
+
+        .. warning::
+            This functionality is experimental. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        on
+            Name of the string column to join on, in both tables.
+        left_on
+            Name of the string column to join on in this DataFrame.
+        right_on
+            Name of the string column to join on in `other`.
+        metric
+            The string metric to compare the keys with, one of `levenshtein`,
+            `damerau_levenshtein`, `hamming`, `jaro` or `jaro_winkler`. See
+            :meth:`Expr.str.similarity` for a description of each metric.
+        threshold
+            Minimum similarity, in `[0, 1]`, for two keys to match. The score
+            column holds the similarity as a `Float64`.
+        max_distance
+            Maximum number of edits for two keys to match. Only valid for the
+            edit distance metrics. The score column holds the distance as a
+            `UInt32`.
+        score_name
+            Name of the score column.
+        suffix
+            Suffix to append to columns with a duplicate name.
+        allow_parallel
+            Allow the physical plan to optionally evaluate the computation of both
+            DataFrames up to the join in parallel.
+        force_parallel
+            Force the physical plan to evaluate the computation of both DataFrames up to
+            the join in parallel.
+
+        Examples
+        --------
+        >>> customers = pl.DataFrame(
+        ...     {"id": [1, 2, 3], "name": ["john smith", "jane doe", "bob"]}
+        ... )
+        >>> addresses = pl.DataFrame(
+        ...     {
+        ...         "name": ["jon smith", "jane doe", "jane do", "rob"],
+        ...         "city": ["NYC", "LA", "SF", "SEA"],
+        ...     }
+        ... )
+        >>> customers.join_fuzzy(addresses, on="name", max_distance=1)
+        shape: (4, 5)
+        ┌─────┬────────────┬────────────┬──────┬───────┐
+        │ id  ┆ name       ┆ name_right ┆ city ┆ score │
+        │ --- ┆ ---        ┆ ---        ┆ ---  ┆ ---   │
+        │ i64 ┆ str        ┆ str        ┆ str  ┆ u32   │
+        ╞═════╪════════════╪════════════╪══════╪═══════╡
+        │ 1   ┆ john smith ┆ jon smith  ┆ NYC  ┆ 1     │
+        │ 2   ┆ jane doe   ┆ jane doe   ┆ LA   ┆ 0     │
+        │ 2   ┆ jane doe   ┆ jane do    ┆ SF   ┆ 1     │
+        │ 3   ┆ bob        ┆ rob        ┆ SEA  ┆ 1     │
+        └─────┴────────────┴────────────┴──────┴───────┘
+        """
+        require_same_type(self, other)
+
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .join_fuzzy(
+                other.lazy(),
+                on,
+                left_on=left_on,
+                right_on=right_on,
+                metric=metric,
+                threshold=threshold,
+                max_distance=max_distance,
+                score_name=score_name,
+                suffix=suffix,
+                allow_parallel=allow_parallel,
+                force_parallel=force_parallel,
+            )
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
     def map_rows(
         self,
         function: Callable[[tuple[Any, ...]], Any],
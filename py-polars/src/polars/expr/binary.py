@@ -10,6 +10,7 @@ from polars.datatypes import parse_into_datatype_expr
 if TYPE_CHECKING:
     from polars import DataTypeExpr, Expr
     from polars._typing import (
+        BinaryCodec,
         Endianness,
         IntoExpr,
         PolarsDataType,
@@ -344,3 +345,325 @@ class ExprBinaryNameSpace:
         return wrap_expr(
             self._pyexpr.bin_reinterpret(dtype._pydatatype_expr, endianness)
         )
+
+    def sha256(self) -> Expr:
+        """
+        Compute the SHA-256 digest of binary values.
+
+        Unlike :meth:`Expr.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`, holding a 32-byte digest per value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["alice", "bob", None]})
+        >>> df.with_columns(sha256=pl.col("name").bin.sha256().bin.encode("hex"))
+        shape: (3, 2)
+        ┌───────┬─────────────────────────────────┐
+        │ name  ┆ sha256                          │
+        │ ---   ┆ ---                             │
+        │ str   ┆ str                             │
+        ╞═══════╪═════════════════════════════════╡
+        │ alice ┆ 2bd806c97f0e00af1a1fc3328fa763… │
+        │ bob   ┆ 81b637d8fcd2c6da6359e6963113a1… │
+        │ null  ┆ null                            │
+        └───────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_hash("sha256", 0))
+
+    def sha1(self) -> Expr:
+        """
+        Compute the SHA-1 digest of binary values.
+
+        Unlike :meth:`Expr.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`, holding a 20-byte digest per value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["alice", "bob", None]})
+        >>> df.with_columns(sha1=pl.col("name").bin.sha1().bin.encode("hex"))
+        shape: (3, 2)
+        ┌───────┬─────────────────────────────────┐
+        │ name  ┆ sha1                            │
+        │ ---   ┆ ---                             │
+        │ str   ┆ str                             │
+        ╞═══════╪═════════════════════════════════╡
+        │ alice ┆ 522b276a356bdf39013dfabea2cd43… │
+        │ bob   ┆ 48181acd22b3edaebc8a447868a7df… │
+        │ null  ┆ null                            │
+        └───────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_hash("sha1", 0))
+
+    def md5(self) -> Expr:
+        """
+        Compute the MD5 digest of binary values.
+
+        Unlike :meth:`Expr.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`, holding a 16-byte digest per value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["alice", "bob", None]})
+        >>> df.with_columns(md5=pl.col("name").bin.md5().bin.encode("hex"))
+        shape: (3, 2)
+        ┌───────┬─────────────────────────────────┐
+        │ name  ┆ md5                             │
+        │ ---   ┆ ---                             │
+        │ str   ┆ str                             │
+        ╞═══════╪═════════════════════════════════╡
+        │ alice ┆ 6384e2b2184bcbf58eccf10ca7a656… │
+        │ bob   ┆ 9f9d51bc70ef21ca5c14f307980a29… │
+        │ null  ┆ null                            │
+        └───────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_hash("md5", 0))
+
+    def xxh64(self, seed: int = 0) -> Expr:
+        """
+        Compute the 64-bit xxHash (XXH64) of binary values.
+
+        Unlike :meth:`Expr.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt64`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["alice", "bob", None]})
+        >>> df.with_columns(xxh64=pl.col("name").bin.xxh64())
+        shape: (3, 2)
+        ┌───────┬──────────────────────┐
+        │ name  ┆ xxh64                │
+        │ ---   ┆ ---                  │
+        │ str   ┆ u64                  │
+        ╞═══════╪══════════════════════╡
+        │ alice ┆ 8332761332120969289  │
+        │ bob   ┆ 10558559838520660027 │
+        │ null  ┆ null                 │
+        └───────┴──────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_hash("xxh64", seed))
+
+    def crc32(self) -> Expr:
+        """
+        Compute the CRC-32 checksum of binary values.
+
+        Unlike :meth:`Expr.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        The checksum uses the polynomial of zlib, gzip and PNG.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["alice", "bob", None]})
+        >>> df.with_columns(crc32=pl.col("name").bin.crc32())
+        shape: (3, 2)
+        ┌───────┬────────────┐
+        │ name  ┆ crc32      │
+        │ ---   ┆ ---        │
+        │ str   ┆ u32        │
+        ╞═══════╪════════════╡
+        │ alice ┆ 663665735  │
+        │ bob   ┆ 4123767104 │
+        │ null  ┆ null       │
+        └───────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_hash("crc32", 0))
+
+    def murmur3(self, seed: int = 0) -> Expr:
+        """
+        Compute the 32-bit MurmurHash3 (x86 variant) of binary values.
+
+        Unlike :meth:`Expr.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        This is the reference implementation of MurmurHash3, which differs from the
+        `hash` function of Spark for values whose length is not a multiple of 4. Use
+        :meth:`spark_murmur3` to match Spark.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["alice", "bob", None]})
+        >>> df.with_columns(murmur3=pl.col("name").bin.murmur3())
+        shape: (3, 2)
+        ┌───────┬────────────┐
+        │ name  ┆ murmur3    │
+        │ ---   ┆ ---        │
+        │ str   ┆ u32        │
+        ╞═══════╪════════════╡
+        │ alice ┆ 1280413405 │
+        │ bob   ┆ 2824567794 │
+        │ null  ┆ null       │
+        └───────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_hash("murmur3", seed))
+
+    def spark_murmur3(self, seed: int = 42) -> Expr:
+        """
+        Compute the 32-bit MurmurHash3 of binary values as Spark does.
+
+        With the default seed, this matches `hash` in Spark for a single binary or
+        string column. String values are hashed as their UTF-8 bytes.
+
+        Spark mixes the trailing bytes of values whose length is not a multiple of 4
+        one at a time, so the result differs from :meth:`murmur3` for such values.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function. Spark uses a seed of 42.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["alice", "bob", None]})
+        >>> df.with_columns(spark_murmur3=pl.col("name").bin.spark_murmur3())
+        shape: (3, 2)
+        ┌───────┬───────────────┐
+        │ name  ┆ spark_murmur3 │
+        │ ---   ┆ ---           │
+        │ str   ┆ i32           │
+        ╞═══════╪═══════════════╡
+        │ alice ┆ -577087139    │
+        │ bob   ┆ -791738411    │
+        │ null  ┆ null          │
+        └───────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_hash("spark_murmur3", seed))
+
+    def compress(self, codec: BinaryCodec, *, level: int | None = None) -> Expr:
+        r"""
+        Compress every value into an independent payload of the given format.
+
+        Each value is compressed on its own, so the payloads can be decompressed by any
+        other implementation of the format. String values are compressed as their
+        UTF-8 bytes.
+
+        Parameters
+        ----------
+        codec : {'gzip', 'zstd', 'lz4', 'snappy'}
+            The compression format. `'gzip'` writes a gzip member, `'zstd'` a zstd
+            frame, `'lz4'` an LZ4 frame and `'snappy'` a raw snappy block (without
+            the snappy framing format).
+        level
+            The compression level. Valid levels are 0-9 for `'gzip'`, 1-22 for
+            `'zstd'` and 0-12 for `'lz4'`; `'snappy'` does not support a level. If
+            not given, the default level of the codec is used.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        decompress
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["polars " * 100, "hello", None]})
+        >>> df.select(
+        ...     size=pl.col("text").str.len_bytes(),
+        ...     zstd=pl.col("text").bin.compress("zstd").bin.size(),
+        ...     snappy=pl.col("text").bin.compress("snappy"),
+        ... )
+        shape: (3, 3)
+        ┌──────┬──────┬─────────────────────────────────┐
+        │ size ┆ zstd ┆ snappy                          │
+        │ ---  ┆ ---  ┆ ---                             │
+        │ u32  ┆ u32  ┆ binary                          │
+        ╞══════╪══════╪═════════════════════════════════╡
+        │ 700  ┆ 24   ┆ b"\xbc\x05\x18polars\x20\xfe\x… │
+        │ 5    ┆ 14   ┆ b"\x05\x10hello"                │
+        │ null ┆ null ┆ null                            │
+        └──────┴──────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_compress(codec, level))
+
+    def decompress(self, codec: BinaryCodec, *, strict: bool = True) -> Expr:
+        r"""
+        Decompress values that each hold an independent payload of the given format.
+
+        Parameters
+        ----------
+        codec : {'gzip', 'zstd', 'lz4', 'snappy'}
+            The compression format, as written by :meth:`compress`.
+        strict
+            Raise an error if a value is not a valid payload of the given format,
+            otherwise mask it out with a null value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        compress
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"payload": [b"\x05\x10hello", b"not snappy", None]})
+        >>> df.with_columns(
+        ...     decompressed=pl.col("payload").bin.decompress("snappy", strict=False)
+        ... )
+        shape: (3, 2)
+        ┌──────────────────┬──────────────┐
+        │ payload          ┆ decompressed │
+        │ ---              ┆ ---          │
+        │ binary           ┆ binary       │
+        ╞══════════════════╪══════════════╡
+        │ b"\x05\x10hello" ┆ b"hello"     │
+        │ b"not\x20snappy" ┆ null         │
+        │ null             ┆ null         │
+        └──────────────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_decompress(codec, strict))
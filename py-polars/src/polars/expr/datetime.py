@@ -5,7 +5,7 @@ from typing import TYPE_CHECKING
 
 import polars._reexport as pl
 from polars import functions as F
-from polars._utils.convert import parse_as_duration_string
+from polars._utils.convert import parse_as_duration_string, time_to_int
 from polars._utils.deprecation import deprecate_nonkeyword_arguments, deprecated
 from polars._utils.parse import parse_into_expression, parse_into_list_of_expressions
 from polars._utils.unstable import unstable
@@ -23,6 +23,7 @@ if TYPE_CHECKING:
         EpochTimeUnit,
         IntoExpr,
         IntoExprColumn,
+        HolidayCalendar,
         NonExistent,
         Roll,
         TimeUnit,
@@ -50,6 +51,7 @@ class ExprDateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        calendar: HolidayCalendar | None = None,
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -89,6 +91,20 @@ class ExprDateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            A holiday calendar whose holidays are excluded in addition to
+            `holidays`. Either one of the built-in calendars:
+
+            - `'nyse'`: full-day closures of the New York Stock Exchange
+            - `'uk'`: bank holidays in England and Wales
+            - `'target2'`: closing days of TARGET2, the euro area payment system
+
+            or a custom calendar, given as a sequence of holiday rules. Each
+            rule is a dict (or an object with the same attributes) such as
+            `{"month": 12, "day": 25, "observance": "nearest_weekday"}`,
+            `{"month": 5, "weekday": 0, "n": -1}` (the last Monday of May)
+            or `{"easter_offset": -2}` (Good Friday), optionally limited to
+            `start_year` and `end_year`.
 
         Returns
         -------
@@ -166,10 +182,129 @@ class ExprDateTimeNameSpace:
                 n_pyexpr,
                 list(week_mask),
                 [(holiday - unix_epoch).days for holiday in holidays],
+                calendar,
                 roll,
             )
         )
 
+    @unstable()
+    def add_business_hours(
+        self,
+        n: float | IntoExpr,
+        *,
+        business_hours: tuple[dt.time, dt.time] = (dt.time(9), dt.time(17)),
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        calendar: HolidayCalendar | None = None,
+    ) -> Expr:
+        """
+        Offset datetimes by `n` business hours.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A start time outside of business hours is first moved to the start of the
+        next business period, or to the end of the previous one if `n` is negative.
+        Adding whole business days to the start of a business period ends at the
+        end of a business period rather than at the start of the next one.
+
+        Parameters
+        ----------
+        n
+            Number of business hours to offset by, may be fractional. Can be a single
+            number or an expression.
+        business_hours
+            Start and end of business hours on each business day.
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude.
+        calendar
+            A holiday calendar whose holidays are excluded in addition to
+            `holidays`. Either one of the built-in calendars:
+
+            - `'nyse'`: full-day closures of the New York Stock Exchange
+            - `'uk'`: bank holidays in England and Wales
+            - `'target2'`: closing days of TARGET2, the euro area payment system
+
+            or a custom calendar, given as a sequence of holiday rules. Each
+            rule is a dict (or an object with the same attributes) such as
+            `{"month": 12, "day": 25, "observance": "nearest_weekday"}`,
+            `{"month": 5, "weekday": 0, "n": -1}` (the last Monday of May)
+            or `{"easter_offset": -2}` (Good Friday), optionally limited to
+            `start_year` and `end_year`.
+
+        Returns
+        -------
+        Expr
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import datetime, time
+        >>> df = pl.DataFrame(
+        ...     {"start": [datetime(2020, 1, 3, 16), datetime(2020, 1, 4, 12)]}
+        ... )
+        >>> df.with_columns(result=pl.col("start").dt.add_business_hours(2))
+        shape: (2, 2)
+        ┌─────────────────────┬─────────────────────┐
+        │ start               ┆ result              │
+        │ ---                 ┆ ---                 │
+        │ datetime[μs]        ┆ datetime[μs]        │
+        ╞═════════════════════╪═════════════════════╡
+        │ 2020-01-03 16:00:00 ┆ 2020-01-06 10:00:00 │
+        │ 2020-01-04 12:00:00 ┆ 2020-01-06 11:00:00 │
+        └─────────────────────┴─────────────────────┘
+
+        You can pass custom business hours:
+
+        >>> business_hours = (time(8, 30), time(16, 30))
+        >>> df.with_columns(
+        ...     result=pl.col("start").dt.add_business_hours(
+        ...         2, business_hours=business_hours
+        ...     )
+        ... )
+        shape: (2, 2)
+        ┌─────────────────────┬─────────────────────┐
+        │ start               ┆ result              │
+        │ ---                 ┆ ---                 │
+        │ datetime[μs]        ┆ datetime[μs]        │
+        ╞═════════════════════╪═════════════════════╡
+        │ 2020-01-03 16:00:00 ┆ 2020-01-06 10:00:00 │
+        │ 2020-01-04 12:00:00 ┆ 2020-01-06 10:30:00 │
+        └─────────────────────┴─────────────────────┘
+
+        Holidays from a built-in calendar are skipped:
+
+        >>> df = pl.DataFrame({"start": [datetime(2020, 12, 31, 16)]})
+        >>> df.with_columns(
+        ...     result=pl.col("start").dt.add_business_hours(2, calendar="nyse")
+        ... )
+        shape: (1, 2)
+        ┌─────────────────────┬─────────────────────┐
+        │ start               ┆ result              │
+        │ ---                 ┆ ---                 │
+        │ datetime[μs]        ┆ datetime[μs]        │
+        ╞═════════════════════╪═════════════════════╡
+        │ 2020-12-31 16:00:00 ┆ 2021-01-04 10:00:00 │
+        └─────────────────────┴─────────────────────┘
+        """
+        n_pyexpr = parse_into_expression(n)
+        unix_epoch = dt.date(1970, 1, 1)
+        open_, close = business_hours
+        return wrap_expr(
+            self._pyexpr.dt_add_business_hours(
+                n_pyexpr,
+                [time_to_int(open_), time_to_int(close)],
+                list(week_mask),
+                [(holiday - unix_epoch).days for holiday in holidays],
+                calendar,
+            )
+        )
+
     def truncate(self, every: str | dt.timedelta | Expr) -> Expr:
         """
         Divide the date/datetime range into buckets.
@@ -910,6 +1045,7 @@ class ExprDateTimeNameSpace:
         *,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
+        calendar: HolidayCalendar | None = None,
     ) -> Expr:
         """
         Determine whether each day lands on a business day.
@@ -937,6 +1073,20 @@ class ExprDateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `is_business_day`.
+        calendar
+            A holiday calendar whose holidays are excluded in addition to
+            `holidays`. Either one of the built-in calendars:
+
+            - `'nyse'`: full-day closures of the New York Stock Exchange
+            - `'uk'`: bank holidays in England and Wales
+            - `'target2'`: closing days of TARGET2, the euro area payment system
+
+            or a custom calendar, given as a sequence of holiday rules. Each
+            rule is a dict (or an object with the same attributes) such as
+            `{"month": 12, "day": 25, "observance": "nearest_weekday"}`,
+            `{"month": 5, "weekday": 0, "n": -1}` (the last Monday of May)
+            or `{"easter_offset": -2}` (Good Friday), optionally limited to
+            `start_year` and `end_year`.
 
         Returns
         -------
@@ -996,6 +1146,7 @@ class ExprDateTimeNameSpace:
             self._pyexpr.dt_is_business_day(
                 list(week_mask),
                 [(holiday - unix_epoch).days for holiday in holidays],
+                calendar,
             )
         )
 
@@ -3311,6 +3311,51 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.approx_n_unique())
 
+    def approx_quantile(self, quantile: float) -> Expr:
+        """
+        Approximate quantile value.
+
+        This is done using a t-digest, which needs constant memory per group and can
+        be computed in parallel and in the streaming engine. The estimate is most
+        accurate for quantiles near 0 and 1, such as the 99th percentile.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [5, 1, 4, 2, 3]})
+        >>> df.select(pl.col("n").approx_quantile(0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ n   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.0 │
+        └─────┘
+        >>> df = pl.DataFrame({"n": range(100_000)})
+        >>> df.select(
+        ...     exact=pl.col("n").quantile(0.99, interpolation="linear"),
+        ...     approx=pl.col("n").approx_quantile(0.99),
+        ... )  # doctest: +SKIP
+        shape: (1, 2)
+        ┌──────────┬──────────────┐
+        │ exact    ┆ approx       │
+        │ ---      ┆ ---          │
+        │ f64      ┆ f64          │
+        ╞══════════╪══════════════╡
+        │ 98999.01 ┆ 98998.515625 │
+        └──────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.approx_quantile(quantile))
+
     def null_count(self) -> Expr:
         """
         Count null values.
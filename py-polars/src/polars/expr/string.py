@@ -30,6 +30,7 @@ if TYPE_CHECKING:
         PolarsDataType,
         PolarsIntegerType,
         PolarsTemporalType,
+        StringSimilarityMetric,
         TimeUnit,
         TransferEncoding,
         UnicodeForm,
@@ -2203,6 +2204,211 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_reverse())
 
+    def levenshtein(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Levenshtein distance to another string.
+
+        This is the minimum number of single-character insertions, deletions and
+        substitutions needed to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+
+        See Also
+        --------
+        damerau_levenshtein
+        similarity
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "abc", None],
+        ...         "b": ["sitting", "lawn", "abc", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.levenshtein(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────┐
+        │ a      ┆ b       ┆ dist │
+        │ ---    ┆ ---     ┆ ---  │
+        │ str    ┆ str     ┆ u32  │
+        ╞════════╪═════════╪══════╡
+        │ kitten ┆ sitting ┆ 3    │
+        │ flaw   ┆ lawn    ┆ 2    │
+        │ abc    ┆ abc     ┆ 0    │
+        │ null   ┆ x       ┆ null │
+        └────────┴─────────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other_pyexpr))
+
+    def damerau_levenshtein(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Damerau-Levenshtein distance to another string.
+
+        Like :func:`levenshtein`, but swapping two adjacent characters also counts as
+        a single edit.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+
+        See Also
+        --------
+        levenshtein
+        similarity
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["ab", "ca", "abcd"], "b": ["ba", "abc", "abcd"]})
+        >>> df.with_columns(
+        ...     lev=pl.col("a").str.levenshtein(pl.col("b")),
+        ...     dl=pl.col("a").str.damerau_levenshtein(pl.col("b")),
+        ... )
+        shape: (3, 4)
+        ┌──────┬──────┬─────┬─────┐
+        │ a    ┆ b    ┆ lev ┆ dl  │
+        │ ---  ┆ ---  ┆ --- ┆ --- │
+        │ str  ┆ str  ┆ u32 ┆ u32 │
+        ╞══════╪══════╪═════╪═════╡
+        │ ab   ┆ ba   ┆ 2   ┆ 1   │
+        │ ca   ┆ abc  ┆ 3   ┆ 2   │
+        │ abcd ┆ abcd ┆ 0   ┆ 0   │
+        └──────┴──────┴─────┴─────┘
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_damerau_levenshtein(other_pyexpr))
+
+    def hamming(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Hamming distance to another string.
+
+        This is the number of positions at which the characters differ. The result
+        is null if the strings do not have the same number of characters.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+
+        See Also
+        --------
+        similarity
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["karolin", "kerstin", "abc"]})
+        >>> df.with_columns(dist=pl.col("a").str.hamming("kathrin"))
+        shape: (3, 2)
+        ┌─────────┬──────┐
+        │ a       ┆ dist │
+        │ ---     ┆ ---  │
+        │ str     ┆ u32  │
+        ╞═════════╪══════╡
+        │ karolin ┆ 3    │
+        │ kerstin ┆ 4    │
+        │ abc     ┆ null │
+        └─────────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other_pyexpr))
+
+    def jaro_winkler(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity is between 0 and 1, where 1 means the strings are equal.
+        Strings that share a common prefix get a higher score.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+
+        See Also
+        --------
+        similarity
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["martha", "dixon", "dwayne"],
+        ...         "b": ["marhta", "dicksonx", "duane"],
+        ...     }
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.jaro_winkler(pl.col("b")).round(3))
+        shape: (3, 3)
+        ┌────────┬──────────┬───────┐
+        │ a      ┆ b        ┆ sim   │
+        │ ---    ┆ ---      ┆ ---   │
+        │ str    ┆ str      ┆ f64   │
+        ╞════════╪══════════╪═══════╡
+        │ martha ┆ marhta   ┆ 0.961 │
+        │ dixon  ┆ dicksonx ┆ 0.813 │
+        │ dwayne ┆ duane    ┆ 0.84  │
+        └────────┴──────────┴───────┘
+        """
+        return self.similarity(other, metric="jaro_winkler")
+
+    def similarity(
+        self, other: IntoExpr, metric: StringSimilarityMetric = "levenshtein"
+    ) -> Expr:
+        """
+        Compute a normalized similarity score to another string.
+
+        The score is between 0 and 1, where 1 means the strings are equal.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+        metric
+            The metric to use, one of:
+
+            - `levenshtein` and `damerau_levenshtein`: one minus the distance divided
+              by the number of characters in the longest string.
+            - `hamming`: one minus the distance divided by the number of characters,
+              or null if the strings differ in length.
+            - `jaro` and `jaro_winkler`: the Jaro and Jaro-Winkler similarity.
+
+        See Also
+        --------
+        levenshtein
+        damerau_levenshtein
+        hamming
+        jaro_winkler
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["abcd", "abdc", "xyz"]})
+        >>> df.with_columns(
+        ...     lev=pl.col("a").str.similarity("abdc"),
+        ...     dl=pl.col("a").str.similarity("abdc", metric="damerau_levenshtein"),
+        ... )
+        shape: (3, 3)
+        ┌──────┬─────┬──────┐
+        │ a    ┆ lev ┆ dl   │
+        │ ---  ┆ --- ┆ ---  │
+        │ str  ┆ f64 ┆ f64  │
+        ╞══════╪═════╪══════╡
+        │ abcd ┆ 0.5 ┆ 0.75 │
+        │ abdc ┆ 1.0 ┆ 1.0  │
+        │ xyz  ┆ 0.0 ┆ 0.0  │
+        └──────┴─────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_similarity(other_pyexpr, metric))
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Expr:
@@ -16,7 +16,7 @@ if TYPE_CHECKING:
     from collections.abc import Iterable
 
     from polars import Expr
-    from polars._typing import IntoExprColumn
+    from polars._typing import HolidayCalendar, IntoExprColumn
 
 
 @unstable()
@@ -26,6 +26,7 @@ def business_day_count(
     end: date | IntoExprColumn,
     week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
     holidays: Iterable[date] = (),
+    calendar: HolidayCalendar | None = None,
 ) -> Expr:
     """
     Count the number of business days between `start` and `end` (not including `end`).
@@ -40,9 +41,9 @@ def business_day_count(
     Parameters
     ----------
     start
-        Start dates.
+        Start dates. Datetimes are counted by their (local) date.
     end
-        End dates.
+        End dates. Datetimes are counted by their (local) date.
     week_mask
         Which days of the week to count. The default is Monday to Friday.
         If you wanted to count only Monday to Thursday, you would pass
@@ -60,6 +61,20 @@ def business_day_count(
             my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
         and pass `holidays=my_holidays` when you call `business_day_count`.
+    calendar
+        A holiday calendar whose holidays are excluded in addition to
+        `holidays`. Either one of the built-in calendars:
+
+        - `'nyse'`: full-day closures of the New York Stock Exchange
+        - `'uk'`: bank holidays in England and Wales
+        - `'target2'`: closing days of TARGET2, the euro area payment system
+
+        or a custom calendar, given as a sequence of holiday rules. Each
+        rule is a dict (or an object with the same attributes) such as
+        `{"month": 12, "day": 25, "observance": "nearest_weekday"}`,
+        `{"month": 5, "weekday": 0, "n": -1}` (the last Monday of May)
+        or `{"easter_offset": -2}` (Good Friday), optionally limited to
+        `start_year` and `end_year`.
 
     Returns
     -------
@@ -124,6 +139,21 @@ def business_day_count(
     │ 2020-01-01 ┆ 2020-01-02 ┆ 0                  │
     │ 2020-01-02 ┆ 2020-01-10 ┆ 5                  │
     └────────────┴────────────┴────────────────────┘
+
+    Or use a built-in holiday calendar, such as that of the New York Stock Exchange:
+
+    >>> df.with_columns(
+    ...     business_day_count=pl.business_day_count("start", "end", calendar="nyse")
+    ... )
+    shape: (2, 3)
+    ┌────────────┬────────────┬────────────────────┐
+    │ start      ┆ end        ┆ business_day_count │
+    │ ---        ┆ ---        ┆ ---                │
+    │ date       ┆ date       ┆ i32                │
+    ╞════════════╪════════════╪════════════════════╡
+    │ 2020-01-01 ┆ 2020-01-02 ┆ 0                  │
+    │ 2020-01-02 ┆ 2020-01-10 ┆ 6                  │
+    └────────────┴────────────┴────────────────────┘
     """
     start_pyexpr = parse_into_expression(start)
     end_pyexpr = parse_into_expression(end)
@@ -134,5 +164,6 @@ def business_day_count(
             end_pyexpr,
             list(week_mask),
             [(holiday - unix_epoch).days for holiday in holidays],
+            calendar,
         )
     )
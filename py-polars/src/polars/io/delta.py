@@ -4,6 +4,7 @@ import warnings
 from datetime import datetime
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
+from urllib.parse import unquote
 
 from polars._dependencies import _DELTALAKE_AVAILABLE, deltalake
 from polars.datatypes import Null, Time
@@ -20,6 +21,7 @@ if TYPE_CHECKING:
     from deltalake import DeltaTable
 
     from polars import DataFrame, DataType, LazyFrame
+    from polars._typing import DeletionFiles
     from polars.io.cloud import CredentialProviderFunction
 
 
@@ -369,7 +371,7 @@ def scan_delta(
         and table_protocol.reader_features is not None
     ):
         missing_features = {*table_protocol.reader_features}.difference(
-            SUPPORTED_READER_FEATURES
+            {*SUPPORTED_READER_FEATURES, "deletionVectors"}
         )
         if len(missing_features) > 0:
             msg = f"The table has set these reader features: {missing_features} but these are not yet supported by the polars delta scanner."
@@ -399,11 +401,20 @@ def scan_delta(
     main_schema, hive_schema = _split_schema(polars_schema, partition_columns)
 
     file_uris = dl_tbl.file_uris()
+    table_uri = dl_tbl.table_uri
 
     # LakeFS has an S3 compatible API, for reading therefore it's safe to do this.
     # Deltalake internally has an integration for writing commits
-    if dl_tbl.table_uri.startswith("lakefs://"):
+    if table_uri.startswith("lakefs://"):
         file_uris = [file_uri.replace("lakefs://", "s3://") for file_uri in file_uris]
+        table_uri = table_uri.replace("lakefs://", "s3://")
+
+    deletion_files = (
+        _get_deletion_vectors(dl_tbl, table_uri, file_uris)
+        if table_protocol.reader_features is not None
+        and "deletionVectors" in table_protocol.reader_features
+        else None
+    )
 
     return scan_parquet(
         file_uris,
@@ -416,9 +427,60 @@ def scan_delta(
         storage_options=storage_options,
         credential_provider=credential_provider_builder,  # type: ignore[arg-type]
         rechunk=rechunk or False,
+        _deletion_files=deletion_files,
     )
 
 
+def _get_deletion_vectors(
+    dl_tbl: deltalake.DeltaTable, table_uri: str, file_uris: list[str]
+) -> DeletionFiles | None:
+    """Get the deletion vectors of the data files, keyed by their index in `file_uris`."""
+    from polars import DataFrame
+
+    add_actions = DataFrame(dl_tbl.get_add_actions(flatten=True))
+    columns = [
+        f"deletionVector.{name}"
+        for name in (
+            "storageType",
+            "pathOrInlineDv",
+            "offset",
+            "sizeInBytes",
+            "cardinality",
+        )
+    ]
+    if columns[0] not in add_actions.columns:
+        return None
+
+    # The paths of the add actions are URL-encoded and may be relative to the table.
+    table_root = _normalize_file_path(table_uri).rstrip("/")
+
+    def resolve(path: str) -> str:
+        path = _normalize_file_path(path)
+        if _get_path_scheme(path) is None and not path.startswith("/"):
+            path = f"{table_root}/{path}"
+        return path
+
+    vectors_by_path = {
+        resolve(path): tuple(descriptor)
+        for path, *descriptor in add_actions.select("path", *columns).iter_rows()
+        if descriptor[0] is not None
+    }
+    if not vectors_by_path:
+        return None
+
+    vectors = {
+        i: vectors_by_path[path]
+        for i, uri in enumerate(file_uris)
+        if (path := _normalize_file_path(uri)) in vectors_by_path
+    }
+    return ("delta-deletion-vector", (table_uri, vectors))  # type: ignore[return-value]
+
+
+def _normalize_file_path(path: str) -> str:
+    path = unquote(path)
+    return path.removeprefix("file://")
+
+
 def _resolve_delta_lake_uri(table_uri: str | Path, *, strict: bool = True) -> str:
     resolved_uri = str(
         Path(table_uri).expanduser().resolve(strict)
@@ -37,6 +37,7 @@ if TYPE_CHECKING:
     import pyiceberg.schema
     from pyiceberg.manifest import DataFile
     from pyiceberg.table import Table
+    from pyiceberg.table.snapshots import Snapshot
     from pyiceberg.types import IcebergType
 
     from polars import DataFrame, Series
@@ -96,6 +97,97 @@ def _scan_pyarrow_dataset_impl(
     return from_arrow(scan.to_arrow())
 
 
+# Field ID of the `file_path` column of position delete files.
+ICEBERG_POSITION_DELETE_FILE_PATH_ID: int = 2147483546
+
+
+def _has_equality_deletes(snapshot: Snapshot | None) -> bool:
+    """Whether the snapshot summary reports any equality deletes."""
+    if snapshot is None or snapshot.summary is None:
+        return False
+
+    return int(snapshot.summary.get("total-equality-deletes", "0")) > 0
+
+
+def _plan_files_with_sequence_numbers(
+    tbl: Table, snapshot: Snapshot
+) -> list[tuple[DataFile, int, list[tuple[DataFile, int]]]]:
+    """
+    List the data files of a snapshot along with their deletion files.
+
+    Unlike `DataScan.plan_files()`, this supports equality deletes, and also returns
+    the data sequence numbers of the data and deletion files. The files are not
+    pruned by any filter.
+
+    Returns
+    -------
+    list of (data_file, data_sequence_number, [(deletion_file, sequence_number)])
+    """
+    from pyiceberg.manifest import DataFileContent
+
+    data_entries = []
+    delete_entries = []
+
+    for manifest in snapshot.manifests(tbl.io):
+        for entry in manifest.fetch_manifest_entry(tbl.io, discard_deleted=True):
+            if entry.data_file.content == DataFileContent.DATA:
+                data_entries.append(entry)
+            else:
+                delete_entries.append(entry)
+
+    specs = tbl.specs()
+    out = []
+
+    for data_entry in data_entries:
+        data_file = data_entry.data_file
+        data_sequence_number = data_entry.sequence_number or 0
+        deletion_files = []
+
+        for delete_entry in delete_entries:
+            deletion_file = delete_entry.data_file
+            sequence_number = delete_entry.sequence_number or 0
+
+            same_partition = (
+                deletion_file.spec_id == data_file.spec_id
+                and deletion_file.partition == data_file.partition
+            )
+
+            # Matched according to the "Scan Planning" section of the Iceberg spec.
+            if deletion_file.content == DataFileContent.POSITION_DELETES:
+                applies = (
+                    same_partition
+                    and sequence_number >= data_sequence_number
+                    and _may_reference_data_file(deletion_file, data_file.file_path)
+                )
+            else:
+                # The sequence numbers of equality deletes are checked by the
+                # reader.
+                applies = (
+                    same_partition or specs[deletion_file.spec_id].is_unpartitioned()
+                )
+
+            if applies:
+                deletion_files.append((deletion_file, sequence_number))
+
+        out.append((data_file, data_sequence_number, deletion_files))
+
+    return out
+
+
+def _may_reference_data_file(position_delete_file: DataFile, file_path: str) -> bool:
+    lower = (position_delete_file.lower_bounds or {}).get(
+        ICEBERG_POSITION_DELETE_FILE_PATH_ID
+    )
+    upper = (position_delete_file.upper_bounds or {}).get(
+        ICEBERG_POSITION_DELETE_FILE_PATH_ID
+    )
+
+    if lower is None or upper is None:
+        return True
+
+    return lower <= file_path.encode() <= upper
+
+
 def try_convert_pyarrow_predicate(pyarrow_predicate: str) -> Any | None:
     with contextlib.suppress(Exception):
         expr_ast = _to_ast(pyarrow_predicate)
@@ -13,16 +13,22 @@ from polars.exceptions import ComputeError
 from polars.io.iceberg._utils import (
     IcebergStatisticsLoader,
     IdentityTransformedPartitionValuesBuilder,
+    _has_equality_deletes,
+    _plan_files_with_sequence_numbers,
     _scan_pyarrow_dataset_impl,
     try_convert_pyarrow_predicate,
 )
 from polars.io.scan_options.cast_options import ScanCastOptions
 
 if TYPE_CHECKING:
+    from collections.abc import Iterable, Sequence
+
     import pyarrow as pa
     import pyiceberg.schema
+    from pyiceberg.manifest import DataFile
     from pyiceberg.table import Table
 
+    from polars._typing import DeletionFiles
     from polars.lazyframe.frame import LazyFrame
 
 
@@ -228,7 +234,14 @@ class IcebergDataset:
             if self._use_metadata_statistics and filter_columns is not None
             else None
         )
-        deletion_files: dict[int, list[str]] = {}
+        position_deletes: dict[int, list[str]] = {}
+        # {file_index: (data_sequence_number,
+        #               [(path, sequence_number, equality_ids)],
+        #               position_delete_paths)}
+        equality_deletes: dict[
+            int, tuple[int, list[tuple[str, int, list[int]]], list[str]]
+        ] = {}
+        has_equality_deletes = False
         total_physical_rows: int = 0
         total_deleted_rows: int = 0
 
@@ -251,50 +264,89 @@ class IcebergDataset:
 
             total_deletion_files = 0
 
-            for i, file_info in enumerate(scan.plan_files()):
-                if file_info.file.file_format != FileFormat.PARQUET:
-                    fallback_reason = (
-                        f"non-parquet format: {file_info.file.file_format}"
-                    )
+            # `plan_files()` does not support equality deletes, nor does it return
+            # the sequence numbers that are needed to apply them.
+            snapshot = scan.snapshot()
+            has_equality_deletes = _has_equality_deletes(snapshot)
+
+            planned_files: Iterable[
+                tuple[DataFile, int | None, Sequence[tuple[DataFile, int | None]]]
+            ]
+
+            if snapshot is not None and has_equality_deletes:
+                planned_files = _plan_files_with_sequence_numbers(tbl, snapshot)
+            else:
+                planned_files = (
+                    (task.file, None, [(file, None) for file in task.delete_files])
+                    for task in scan.plan_files()
+                )
+
+            for i, (data_file, data_sequence_number, file_deletes) in enumerate(
+                planned_files
+            ):
+                if data_file.file_format != FileFormat.PARQUET:
+                    fallback_reason = f"non-parquet format: {data_file.file_format}"
                     break
 
-                if file_info.delete_files:
-                    deletion_files[i] = []
+                if file_deletes:
+                    position_deletes[i] = []
+                    equality_delete_files = []
 
-                    for deletion_file in file_info.delete_files:
-                        if deletion_file.content != DataFileContent.POSITION_DELETES:
+                    for deletion_file, sequence_number in file_deletes:
+                        if deletion_file.file_format != FileFormat.PARQUET:
                             fallback_reason = (
-                                "unsupported deletion file type: "
-                                f"{deletion_file.content}"
+                                "unsupported deletion file format: "
+                                f"{deletion_file.file_format}"
                             )
                             break
 
-                        if deletion_file.file_format != FileFormat.PARQUET:
+                        if deletion_file.content == DataFileContent.POSITION_DELETES:
+                            position_deletes[i].append(deletion_file.file_path)
+                            total_deleted_rows += deletion_file.record_count
+
+                        elif (
+                            deletion_file.content == DataFileContent.EQUALITY_DELETES
+                            and sequence_number is not None
+                        ):
+                            equality_delete_files.append(
+                                (
+                                    deletion_file.file_path,
+                                    sequence_number,
+                                    list(deletion_file.equality_ids),
+                                )
+                            )
+
+                        else:
                             fallback_reason = (
-                                "unsupported deletion file format: "
-                                f"{deletion_file.file_format}"
+                                "unsupported deletion file type: "
+                                f"{deletion_file.content}"
                             )
                             break
 
-                        deletion_files[i].append(deletion_file.file_path)
                         total_deletion_files += 1
-                        total_deleted_rows += deletion_file.record_count
+
+                    if data_sequence_number is not None:
+                        equality_deletes[i] = (
+                            data_sequence_number,
+                            equality_delete_files,
+                            position_deletes[i],
+                        )
 
                 if fallback_reason:
                     break
 
                 missing_field_defaults.push_partition_values(
                     current_index=i,
-                    partition_spec_id=file_info.file.spec_id,
-                    partition_values=file_info.file.partition,
+                    partition_spec_id=data_file.spec_id,
+                    partition_values=data_file.partition,
                 )
 
                 if statistics_loader is not None:
-                    statistics_loader.push_file_statistics(file_info.file)
+                    statistics_loader.push_file_statistics(data_file)
 
-                total_physical_rows += file_info.file.record_count
+                total_physical_rows += data_file.record_count
 
-                sources.append(file_info.file.file_path)
+                sources.append(data_file.file_path)
 
             if verbose:
                 elapsed = perf_counter() - start_time
@@ -342,7 +394,11 @@ class IcebergDataset:
                 projected_iceberg_schema=projected_iceberg_schema,
                 column_mapping=column_mapping,
                 default_values=identity_transformed_values,
-                deletion_files=deletion_files,
+                deletion_files=(
+                    ("iceberg-equality-delete", equality_deletes)
+                    if has_equality_deletes
+                    else ("iceberg-position-delete", position_deletes)
+                ),
                 min_max_statistics=min_max_statistics,
                 statistics_loader=statistics_loader,
                 storage_options=storage_options,
@@ -350,6 +406,9 @@ class IcebergDataset:
                     (total_physical_rows, total_deleted_rows)
                     if (
                         self._use_metadata_statistics
+                        # The number of rows deleted by equality deletes is only
+                        # known after reading the data.
+                        and not has_equality_deletes
                         and (self._fast_deletion_count or total_deleted_rows == 0)
                     )
                     else None
@@ -511,7 +570,7 @@ class _NativeIcebergScanData(_ResolvedScanDataBase):
     projected_iceberg_schema: pyiceberg.schema.Schema
     column_mapping: pa.Schema
     default_values: dict[int, pl.Series | str]
-    deletion_files: dict[int, list[str]]
+    deletion_files: DeletionFiles
     min_max_statistics: pl.DataFrame | None
     # This is here for test purposes, as the `min_max_statistics` on this
     # dataclass contain coalesced values from `default_values`, a test may
@@ -534,7 +593,7 @@ class _NativeIcebergScanData(_ResolvedScanDataBase):
             storage_options=self.storage_options,
             _column_mapping=("iceberg-column-mapping", self.column_mapping),
             _default_values=("iceberg", self.default_values),
-            _deletion_files=("iceberg-position-delete", self.deletion_files),
+            _deletion_files=self.deletion_files,
             _table_statistics=self.min_max_statistics,
             _row_count=self.row_count,
         )
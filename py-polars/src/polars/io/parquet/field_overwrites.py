@@ -46,6 +46,9 @@ def _parquet_field_overwrites_to_dict(pqo: ParquetFieldOverwrites) -> dict[str,
     if pqo.required is not None:
         d["required"] = pqo.required
 
+    if pqo.bloom_filter:
+        d["bloom_filter"] = pqo.bloom_filter
+
     return d
 
 
@@ -108,6 +111,7 @@ class ParquetFieldOverwrites:
         dict[str, None | str] | None
     )  #: Arrow metadata added to the field before writing
     required: bool | None = None  #: Is the field not allowed to have missing values
+    bloom_filter: bool = False  #: Write a bloom filter for each row group of the field
 
     def __init__(
         self,
@@ -122,6 +126,7 @@ class ParquetFieldOverwrites:
         field_id: int | None = None,
         metadata: Mapping[str, None | str] | None = None,
         required: bool | None = None,
+        bloom_filter: bool = False,
     ) -> None:
         self.name = name
 
@@ -138,3 +143,4 @@ class ParquetFieldOverwrites:
         else:
             self.metadata = metadata
         self.required = required
+        self.bloom_filter = bloom_filter
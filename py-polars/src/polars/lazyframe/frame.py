@@ -127,11 +127,13 @@ if TYPE_CHECKING:
     from polars._dependencies import numpy as np
     from polars._typing import (
         AsofJoinStrategy,
+        AvroCompression,
         ClosedInterval,
         ColumnNameOrSelector,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
+        FileCompression,
         FillNullStrategy,
         FrameInitTypes,
         IntoExpr,
@@ -151,6 +153,7 @@ if TYPE_CHECKING:
         SchemaDict,
         SerializationFormat,
         StartBy,
+        StringSimilarityMetric,
         SyncOnCloseMethod,
         UniqueKeepStrategy,
     )
@@ -3265,6 +3268,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3297,6 +3302,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3328,6 +3335,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3403,6 +3412,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm. The partitioned sinks add
+            the corresponding extension (`.gz` or `.zst`) to the file names.
+        compression_level
+            The level of compression to use. Higher compression means smaller files on
+            disk.
+
+            - "gzip" : min-level: 0, max-level: 9, default: 6.
+            - "zstd" : min-level: 1, max-level: 22, default: 3.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3545,6 +3563,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            compression=compression,
+            compression_level=compression_level,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -3563,6 +3583,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3581,6 +3603,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3598,6 +3622,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3619,6 +3645,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ----------
         path
             File path to which the file should be written.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm. The partitioned sinks add
+            the corresponding extension (`.gz` or `.zst`) to the file names.
+        compression_level
+            The level of compression to use. Higher compression means smaller files on
+            disk.
+
+            - "gzip" : min-level: 0, max-level: 9, default: 6.
+            - "zstd" : min-level: 1, max-level: 22, default: 3.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3741,6 +3776,216 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         ldf_py = self._ldf.sink_json(
             target=target,
+            compression=compression,
+            compression_level=compression_level,
+            cloud_options=storage_options,
+            credential_provider=credential_provider_builder,
+            retries=retries,
+            sink_options=sink_options,
+        )
+
+        if not lazy:
+            ldf_py = ldf_py.with_optimizations(optimizations._pyoptflags)
+            ldf = LazyFrame._from_pyldf(ldf_py)
+            ldf.collect(engine=engine)
+            return None
+        return LazyFrame._from_pyldf(ldf_py)
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[False] = ...,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None: ...
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[True],
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame: ...
+
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame | None:
+        """
+        Evaluate the query in streaming mode and write to an Avro file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        compression : {'uncompressed', 'snappy', 'deflate'}
+            Compression codec of the blocks of the file.
+        name
+            Name of the record in the schema of the file.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            The cloud providers currently supported are AWS, GCP, and Azure.
+            See supported keys here:
+
+            * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+            * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+            * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+            * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+            `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+            If `storage_options` is not provided, Polars will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        retries
+            Number of retries if accessing a cloud instance fails.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file.
+
+            * `None` does not sync.
+            * `data` syncs the file contents.
+            * `all` syncs the file contents and metadata.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        mkdir: bool
+            Recursively create all the directories in the path.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        lazy: bool
+            Wait to start execution until `collect` is called.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine. Polars will also
+            attempt to use the engine set by the `POLARS_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the polars streaming
+            engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+            This has no effect if `lazy` is set to `True`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_avro("out.avro")  # doctest: +SKIP
+
+        Sink to a `BytesIO` object.
+
+        >>> import io
+        >>> buf = io.BytesIO()  # doctest: +SKIP
+        >>> pl.LazyFrame({"x": [1, 2, 1]}).sink_avro(buf)  # doctest: +SKIP
+
+        Split into a hive-partitioning style partition:
+
+        >>> pl.LazyFrame({"x": [1, 2, 1], "y": [3, 4, 5]}).sink_avro(
+        ...     pl.PartitionByKey("./out/", by="x"),
+        ...     mkdir=True
+        ... )  # doctest: +SKIP
+
+        See Also
+        --------
+        PartitionByKey
+        """
+        engine = _select_engine(engine)
+
+        from polars.io.cloud.credential_provider._builder import (
+            _init_credential_provider_builder,
+        )
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, path, storage_options, "sink_avro"
+        )
+        del credential_provider
+
+        if storage_options:
+            storage_options = list(storage_options.items())  # type: ignore[assignment]
+        else:
+            # Handle empty dict input
+            storage_options = None
+
+        target = _to_sink_target(path)
+        sink_options = {
+            "sync_on_close": sync_on_close or "none",
+            "maintain_order": maintain_order,
+            "mkdir": mkdir,
+        }
+
+        ldf_py = self._ldf.sink_avro(
+            target=target,
+            compression=compression,
+            name=name,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -6121,6 +6366,128 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             )
         )
 
+    @unstable()
+    def join_fuzzy(
+        self,
+        other: LazyFrame,
+        on: str | Expr | None = None,
+        *,
+        left_on: str | Expr | None = None,
+        right_on: str | Expr | None = None,
+        metric: StringSimilarityMetric = "levenshtein",
+        threshold: float | None = None,
+        max_distance: int | None = None,
+        score_name: str = "score",
+        suffix: str = "_right",
+        allow_parallel: bool = True,
+        force_parallel: bool = False,
+    ) -> LazyFrame:
+        """
+        Join on string keys that are similar rather than equal.
+
+        This performs an inner join that pairs every row of this LazyFrame with all
+        rows of `other` whose key is within `max_distance` edits of, or at least
+        `threshold` similar to, its own key. The score of every pair is added as a
+        column. Candidate pairs are found through shared q-grams, so the two
+        tables are never fully cross joined. Null keys never match.
+
+        The result is ordered by the rows of this LazyFrame, then by the rows of
+        `other`.
+
+        .. warning::
+            This functionality is experimental. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            LazyFrame to join with.
+        on
+            Name of the string column to join on, in both tables.
+        left_on
+            Name of the string column to join on in this LazyFrame.
+        right_on
+            Name of the string column to join on in `other`.
+        metric
+            The string metric to compare the keys with, one of `levenshtein`,
+            `damerau_levenshtein`, `hamming`, `jaro` or `jaro_winkler`. See
+            :meth:`Expr.str.similarity` for a description of each metric.
+        threshold
+            Minimum similarity, in `[0, 1]`, for two keys to match. The score
+            column holds the similarity as a `Float64`.
+        max_distance
+            Maximum number of edits for two keys to match. Only valid for the
+            edit distance metrics. The score column holds the distance as a
+            `UInt32`.
+        score_name
+            Name of the score column.
+        suffix
+            Suffix to append to columns with a duplicate name.
+        allow_parallel
+            Allow the physical plan to optionally evaluate the computation of both
+            LazyFrames up to the join in parallel.
+        force_parallel
+            Force the physical plan to evaluate the computation of both LazyFrames up to
+            the join in parallel.
+
+        Examples
+        --------
+        >>> customers = pl.LazyFrame(
+        ...     {"id": [1, 2, 3], "name": ["john smith", "jane doe", "bob"]}
+        ... )
+        >>> addresses = pl.LazyFrame(
+        ...     {
+        ...         "name": ["jon smith", "jane doe", "jane do", "rob"],
+        ...         "city": ["NYC", "LA", "SF", "SEA"],
+        ...     }
+        ... )
+        >>> customers.join_fuzzy(addresses, on="name", max_distance=1).collect()
+        shape: (4, 5)
+        ┌─────┬────────────┬────────────┬──────┬───────┐
+        │ id  ┆ name       ┆ name_right ┆ city ┆ score │
+        │ --- ┆ ---        ┆ ---        ┆ ---  ┆ ---   │
+        │ i64 ┆ str        ┆ str        ┆ str  ┆ u32   │
+        ╞═════╪════════════╪════════════╪══════╪═══════╡
+        │ 1   ┆ john smith ┆ jon smith  ┆ NYC  ┆ 1     │
+        │ 2   ┆ jane doe   ┆ jane doe   ┆ LA   ┆ 0     │
+        │ 2   ┆ jane doe   ┆ jane do    ┆ SF   ┆ 1     │
+        │ 3   ┆ bob        ┆ rob        ┆ SEA  ┆ 1     │
+        └─────┴────────────┴────────────┴──────┴───────┘
+        """
+        require_same_type(self, other)
+
+        if on is not None:
+            left_on = on
+            right_on = on
+
+        if left_on is None or right_on is None:
+            msg = "you should pass the column to join on as an argument"
+            raise ValueError(msg)
+
+        if (threshold is None) == (max_distance is None):
+            msg = "exactly one of `threshold` and `max_distance` must be set"
+            raise ValueError(msg)
+
+        if not isinstance(left_on, pl.Expr):
+            left_on = F.col(left_on)
+        if not isinstance(right_on, pl.Expr):
+            right_on = F.col(right_on)
+
+        return self._from_pyldf(
+            self._ldf.join_fuzzy(
+                other._ldf,
+                left_on._pyexpr,
+                right_on._pyexpr,
+                metric,
+                threshold,
+                max_distance,
+                score_name,
+                suffix,
+                allow_parallel,
+                force_parallel,
+            )
+        )
+
     def with_columns(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
@@ -8266,6 +8633,75 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             )
         )
 
+    @unstable()
+    def hint(
+        self,
+        *,
+        unique: str | Sequence[str] | None = None,
+        row_count: int | None = None,
+        distinct_count: Mapping[str, int] | None = None,
+        partitioned_by: str | Sequence[str] | None = None,
+    ) -> LazyFrame:
+        """
+        Declare properties of the data that the query optimizer can use.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        unique
+            Column(s) whose combined values are unique. A `unique` on these
+            columns (or a superset of them) is skipped.
+        row_count
+            Approximate number of rows, used to order joins and to decide which
+            side of a join to keep in memory.
+        distinct_count
+            Approximate number of distinct values per column, used to estimate
+            the size of joins and group-bys on these columns.
+        partitioned_by
+            Column(s) by which the data is already partitioned, i.e. rows with
+            the same values in these columns are adjacent. A partitioned sink
+            on these columns then writes every partition as soon as it is
+            complete, instead of buffering all of them.
+
+        Warnings
+        --------
+        The `unique` and `partitioned_by` hints can lead to incorrect results if
+        they do not hold for the data!! Use with care!
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"id": [1, 2, 3], "country": ["NL", "NL", "US"]})
+        >>> lf.hint(unique="id", partitioned_by="country").unique("id").collect()
+        shape: (3, 2)
+        ┌─────┬─────────┐
+        │ id  ┆ country │
+        │ --- ┆ ---     │
+        │ i64 ┆ str     │
+        ╞═════╪═════════╡
+        │ 1   ┆ NL      │
+        │ 2   ┆ NL      │
+        │ 3   ┆ US      │
+        └─────┴─────────┘
+        """
+        if isinstance(unique, str):
+            unique = [unique]
+        if isinstance(partitioned_by, str):
+            partitioned_by = [partitioned_by]
+
+        return self._from_pyldf(
+            self._ldf.hint(
+                unique=None if unique is None else list(unique),
+                row_count=row_count,
+                distinct_count=(
+                    None if distinct_count is None else list(distinct_count.items())
+                ),
+                partitioned_by=None if partitioned_by is None else list(partitioned_by),
+            )
+        )
+
     @unstable()
     def update(
         self,
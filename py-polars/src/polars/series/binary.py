@@ -8,6 +8,7 @@ if TYPE_CHECKING:
     from polars import Series
     from polars._plr import PySeries
     from polars._typing import (
+        BinaryCodec,
         Endianness,
         IntoExpr,
         PolarsDataType,
@@ -252,3 +253,280 @@ class BinaryNameSpace:
         ]
 
         """
+
+    def sha256(self) -> Series:
+        """
+        Compute the SHA-256 digest of binary values.
+
+        Unlike :meth:`Series.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`, holding a 32-byte digest per value.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["alice", "bob"])
+        >>> s.bin.sha256().bin.encode("hex")
+        shape: (2,)
+        Series: 'name' [str]
+        [
+            "2bd806c97f0e00af1a1fc3328fa763…
+            "81b637d8fcd2c6da6359e6963113a1…
+        ]
+        """
+
+    def sha1(self) -> Series:
+        """
+        Compute the SHA-1 digest of binary values.
+
+        Unlike :meth:`Series.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`, holding a 20-byte digest per value.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["alice", "bob"])
+        >>> s.bin.sha1().bin.encode("hex")
+        shape: (2,)
+        Series: 'name' [str]
+        [
+            "522b276a356bdf39013dfabea2cd43…
+            "48181acd22b3edaebc8a447868a7df…
+        ]
+        """
+
+    def md5(self) -> Series:
+        """
+        Compute the MD5 digest of binary values.
+
+        Unlike :meth:`Series.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`, holding a 16-byte digest per value.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["alice", "bob"])
+        >>> s.bin.md5().bin.encode("hex")
+        shape: (2,)
+        Series: 'name' [str]
+        [
+            "6384e2b2184bcbf58eccf10ca7a656…
+            "9f9d51bc70ef21ca5c14f307980a29…
+        ]
+        """
+
+    def xxh64(self, seed: int = 0) -> Series:
+        """
+        Compute the 64-bit xxHash (XXH64) of binary values.
+
+        Unlike :meth:`Series.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt64`.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["alice", "bob"])
+        >>> s.bin.xxh64()
+        shape: (2,)
+        Series: 'name' [u64]
+        [
+            8332761332120969289
+            10558559838520660027
+        ]
+        """
+
+    def crc32(self) -> Series:
+        """
+        Compute the CRC-32 checksum of binary values.
+
+        Unlike :meth:`Series.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        The checksum uses the polynomial of zlib, gzip and PNG.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["alice", "bob"])
+        >>> s.bin.crc32()
+        shape: (2,)
+        Series: 'name' [u32]
+        [
+            663665735
+            4123767104
+        ]
+        """
+
+    def murmur3(self, seed: int = 0) -> Series:
+        """
+        Compute the 32-bit MurmurHash3 (x86 variant) of binary values.
+
+        Unlike :meth:`Series.hash`, the result is stable across Polars versions and
+        platforms and matches other implementations of the same function. String
+        values are hashed as their UTF-8 bytes.
+
+        This is the reference implementation of MurmurHash3, which differs from the
+        `hash` function of Spark for values whose length is not a multiple of 4. Use
+        :meth:`spark_murmur3` to match Spark.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["alice", "bob"])
+        >>> s.bin.murmur3()
+        shape: (2,)
+        Series: 'name' [u32]
+        [
+            1280413405
+            2824567794
+        ]
+        """
+
+    def spark_murmur3(self, seed: int = 42) -> Series:
+        """
+        Compute the 32-bit MurmurHash3 of binary values as Spark does.
+
+        With the default seed, this matches `hash` in Spark for a single binary or
+        string column. String values are hashed as their UTF-8 bytes.
+
+        Spark mixes the trailing bytes of values whose length is not a multiple of 4
+        one at a time, so the result differs from :meth:`murmur3` for such values.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function. Spark uses a seed of 42.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["alice", "bob"])
+        >>> s.bin.spark_murmur3()
+        shape: (2,)
+        Series: 'name' [i32]
+        [
+            -577087139
+            -791738411
+        ]
+        """
+
+    def compress(self, codec: BinaryCodec, *, level: int | None = None) -> Series:
+        r"""
+        Compress every value into an independent payload of the given format.
+
+        Each value is compressed on its own, so the payloads can be decompressed by any
+        other implementation of the format. String values are compressed as their
+        UTF-8 bytes.
+
+        Parameters
+        ----------
+        codec : {'gzip', 'zstd', 'lz4', 'snappy'}
+            The compression format. `'gzip'` writes a gzip member, `'zstd'` a zstd
+            frame, `'lz4'` an LZ4 frame and `'snappy'` a raw snappy block (without
+            the snappy framing format).
+        level
+            The compression level. Valid levels are 0-9 for `'gzip'`, 1-22 for
+            `'zstd'` and 0-12 for `'lz4'`; `'snappy'` does not support a level. If
+            not given, the default level of the codec is used.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        See Also
+        --------
+        decompress
+
+        Examples
+        --------
+        >>> s = pl.Series("payload", [b"hello", None])
+        >>> s.bin.compress("snappy")
+        shape: (2,)
+        Series: 'payload' [binary]
+        [
+            b"\x05\x10hello"
+            null
+        ]
+        """
+
+    def decompress(self, codec: BinaryCodec, *, strict: bool = True) -> Series:
+        r"""
+        Decompress values that each hold an independent payload of the given format.
+
+        Parameters
+        ----------
+        codec : {'gzip', 'zstd', 'lz4', 'snappy'}
+            The compression format, as written by :meth:`compress`.
+        strict
+            Raise an error if a value is not a valid payload of the given format,
+            otherwise mask it out with a null value.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        See Also
+        --------
+        compress
+
+        Examples
+        --------
+        >>> s = pl.Series("payload", [b"polars " * 100, b"hello"])
+        >>> s.bin.compress("gzip").bin.decompress("gzip").equals(s)
+        True
+
+        Set `strict=False` to set invalid payloads to null instead of raising an error.
+
+        >>> s = pl.Series("payload", [b"\x05\x10hello", b"not snappy"])
+        >>> s.bin.decompress("snappy", strict=False)
+        shape: (2,)
+        Series: 'payload' [binary]
+        [
+            b"hello"
+            null
+        ]
+        """
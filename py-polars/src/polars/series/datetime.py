@@ -19,6 +19,7 @@ if TYPE_CHECKING:
         EpochTimeUnit,
         IntoExpr,
         IntoExprColumn,
+        HolidayCalendar,
         NonExistent,
         Roll,
         TemporalLiteral,
@@ -52,6 +53,7 @@ class DateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        calendar: HolidayCalendar | None = None,
     ) -> Series:
         """
         Offset by `n` business days.
@@ -91,6 +93,20 @@ class DateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            A holiday calendar whose holidays are excluded in addition to
+            `holidays`. Either one of the built-in calendars:
+
+            - `'nyse'`: full-day closures of the New York Stock Exchange
+            - `'uk'`: bank holidays in England and Wales
+            - `'target2'`: closing days of TARGET2, the euro area payment system
+
+            or a custom calendar, given as a sequence of holiday rules. Each
+            rule is a dict (or an object with the same attributes) such as
+            `{"month": 12, "day": 25, "observance": "nearest_weekday"}`,
+            `{"month": 5, "weekday": 0, "n": -1}` (the last Monday of May)
+            or `{"easter_offset": -2}` (Good Friday), optionally limited to
+            `start_year` and `end_year`.
 
         Returns
         -------
@@ -144,6 +160,84 @@ class DateTimeNameSpace:
         ]
         """
 
+    @unstable()
+    def add_business_hours(
+        self,
+        n: float | IntoExpr,
+        *,
+        business_hours: tuple[dt.time, dt.time] = (dt.time(9), dt.time(17)),
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        calendar: HolidayCalendar | None = None,
+    ) -> Series:
+        """
+        Offset datetimes by `n` business hours.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A start time outside of business hours is first moved to the start of the
+        next business period, or to the end of the previous one if `n` is negative.
+        Adding whole business days to the start of a business period ends at the
+        end of a business period rather than at the start of the next one.
+
+        Parameters
+        ----------
+        n
+            Number of business hours to offset by, may be fractional. Can be a single
+            number or an expression.
+        business_hours
+            Start and end of business hours on each business day.
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude.
+        calendar
+            A holiday calendar whose holidays are excluded in addition to
+            `holidays`. Either one of the built-in calendars:
+
+            - `'nyse'`: full-day closures of the New York Stock Exchange
+            - `'uk'`: bank holidays in England and Wales
+            - `'target2'`: closing days of TARGET2, the euro area payment system
+
+            or a custom calendar, given as a sequence of holiday rules. Each
+            rule is a dict (or an object with the same attributes) such as
+            `{"month": 12, "day": 25, "observance": "nearest_weekday"}`,
+            `{"month": 5, "weekday": 0, "n": -1}` (the last Monday of May)
+            or `{"easter_offset": -2}` (Good Friday), optionally limited to
+            `start_year` and `end_year`.
+
+        Returns
+        -------
+        Series
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> s = pl.Series("start", [datetime(2020, 1, 3, 16), datetime(2020, 1, 4, 12)])
+        >>> s.dt.add_business_hours(2)
+        shape: (2,)
+        Series: 'start' [datetime[μs]]
+        [
+                2020-01-06 10:00:00
+                2020-01-06 11:00:00
+        ]
+
+        Holidays from a built-in calendar are skipped:
+
+        >>> s = pl.Series("start", [datetime(2020, 12, 31, 16)])
+        >>> s.dt.add_business_hours(2, calendar="nyse")
+        shape: (1,)
+        Series: 'start' [datetime[μs]]
+        [
+                2021-01-04 10:00:00
+        ]
+        """
+
     def min(self) -> dt.date | dt.datetime | dt.timedelta | None:
         """
         Return minimum as Python datetime.
@@ -496,6 +590,7 @@ class DateTimeNameSpace:
         *,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
+        calendar: HolidayCalendar | None = None,
     ) -> Series:
         """
         Determine whether each day lands on a business day.
@@ -523,6 +618,20 @@ class DateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `is_business_day`.
+        calendar
+            A holiday calendar whose holidays are excluded in addition to
+            `holidays`. Either one of the built-in calendars:
+
+            - `'nyse'`: full-day closures of the New York Stock Exchange
+            - `'uk'`: bank holidays in England and Wales
+            - `'target2'`: closing days of TARGET2, the euro area payment system
+
+            or a custom calendar, given as a sequence of holiday rules. Each
+            rule is a dict (or an object with the same attributes) such as
+            `{"month": 12, "day": 25, "observance": "nearest_weekday"}`,
+            `{"month": 5, "weekday": 0, "n": -1}` (the last Monday of May)
+            or `{"easter_offset": -2}` (Good Friday), optionally limited to
+            `start_year` and `end_year`.
 
         Returns
         -------
@@ -26,6 +26,7 @@ if TYPE_CHECKING:
         PolarsDataType,
         PolarsIntegerType,
         PolarsTemporalType,
+        StringSimilarityMetric,
         TimeUnit,
         TransferEncoding,
         UnicodeForm,
@@ -1719,6 +1720,171 @@ class StringNameSpace:
         ]
         """
 
+    def levenshtein(self, other: IntoExpr) -> Series:
+        """
+        Compute the Levenshtein distance to another string.
+
+        This is the minimum number of single-character insertions, deletions and
+        substitutions needed to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+
+        See Also
+        --------
+        damerau_levenshtein
+        similarity
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["kitten", "flaw", "abc", None])
+        >>> s.str.levenshtein(pl.Series(["sitting", "lawn", "abc", "x"]))
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+            3
+            2
+            0
+            null
+        ]
+        """
+
+    def damerau_levenshtein(self, other: IntoExpr) -> Series:
+        """
+        Compute the Damerau-Levenshtein distance to another string.
+
+        Like :func:`levenshtein`, but swapping two adjacent characters also counts as
+        a single edit.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+
+        See Also
+        --------
+        levenshtein
+        similarity
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["ab", "ca", "abcd"])
+        >>> s.str.damerau_levenshtein(pl.Series(["ba", "abc", "abcd"]))
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+            1
+            2
+            0
+        ]
+        """
+
+    def hamming(self, other: IntoExpr) -> Series:
+        """
+        Compute the Hamming distance to another string.
+
+        This is the number of positions at which the characters differ. The result
+        is null if the strings do not have the same number of characters.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+
+        See Also
+        --------
+        similarity
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["karolin", "kerstin", "abc"])
+        >>> s.str.hamming("kathrin")
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+            3
+            4
+            null
+        ]
+        """
+
+    def jaro_winkler(self, other: IntoExpr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity is between 0 and 1, where 1 means the strings are equal.
+        Strings that share a common prefix get a higher score.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+
+        See Also
+        --------
+        similarity
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["martha", "dixon", "dwayne"])
+        >>> s.str.jaro_winkler(pl.Series(["marhta", "dicksonx", "duane"])).round(3)
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            0.961
+            0.813
+            0.84
+        ]
+        """
+
+    def similarity(
+        self, other: IntoExpr, metric: StringSimilarityMetric = "levenshtein"
+    ) -> Series:
+        """
+        Compute a normalized similarity score to another string.
+
+        The score is between 0 and 1, where 1 means the strings are equal.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input; strings are parsed
+            as literals.
+        metric
+            The metric to use, one of:
+
+            - `levenshtein` and `damerau_levenshtein`: one minus the distance divided
+              by the number of characters in the longest string.
+            - `hamming`: one minus the distance divided by the number of characters,
+              or null if the strings differ in length.
+            - `jaro` and `jaro_winkler`: the Jaro and Jaro-Winkler similarity.
+
+        See Also
+        --------
+        levenshtein
+        damerau_levenshtein
+        hamming
+        jaro_winkler
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["abcd", "abdc", "xyz"])
+        >>> s.str.similarity("abdc", metric="damerau_levenshtein")
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            0.75
+            1.0
+            0.0
+        ]
+        """
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Series:
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import datetime as dt
-from datetime import date
+from datetime import date, datetime
 
 import hypothesis.strategies as st
 import numpy as np
@@ -136,6 +136,55 @@ def test_business_day_count_w_holidays() -> None:
     assert_series_equal(result, expected)
 
 
+def test_business_day_count_w_calendar() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [date(2020, 1, 1), date(2020, 12, 20)],
+            "end": [date(2020, 1, 31), date(2021, 1, 5)],
+        }
+    )
+    result = df.select(
+        business_day_count=pl.business_day_count("start", "end", calendar="nyse"),
+    )["business_day_count"]
+    expected = pl.Series("business_day_count", [20, 9], pl.Int32)
+    assert_series_equal(result, expected)
+
+    # Calendar holidays are combined with explicit holidays.
+    result = df.select(
+        business_day_count=pl.business_day_count(
+            "start", "end", holidays=[date(2020, 1, 2)], calendar="nyse"
+        ),
+    )["business_day_count"]
+    expected = pl.Series("business_day_count", [19, 9], pl.Int32)
+    assert_series_equal(result, expected)
+
+    result = df.select(
+        business_day_count=pl.business_day_count("start", "end", calendar="uk"),
+    )["business_day_count"]
+    expected = pl.Series("business_day_count", [21, 8], pl.Int32)
+    assert_series_equal(result, expected)
+
+
+def test_business_day_count_invalid_calendar() -> None:
+    with pytest.raises(ValueError, match="`calendar` must be one of"):
+        pl.business_day_count("start", "end", calendar="nl")  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize("time_zone", [None, "Asia/Kathmandu"])
+def test_business_day_count_datetime(time_zone: str | None) -> None:
+    df = pl.DataFrame(
+        {
+            "start": [datetime(2020, 1, 1, 23), datetime(2020, 1, 2)],
+            "end": [datetime(2020, 1, 2, 1), datetime(2020, 1, 10, 12)],
+        }
+    ).with_columns(pl.all().dt.replace_time_zone(time_zone))
+    result = df.select(
+        business_day_count=pl.business_day_count("start", "end"),
+    )["business_day_count"]
+    expected = pl.Series("business_day_count", [1, 6], pl.Int32)
+    assert_series_equal(result, expected)
+
+
 @given(
     start=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
     end=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
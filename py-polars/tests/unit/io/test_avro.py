@@ -89,3 +89,82 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_scan_avro(compression: AvroCompression, tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": range(100),
+            "b": [f"b{i % 7}" for i in range(100)],
+            "c": [i / 2 for i in range(100)],
+        }
+    )
+    paths = [tmp_path / "1.avro", tmp_path / "2.avro"]
+    for path in paths:
+        df.write_avro(path, compression=compression)
+
+    lf = pl.scan_avro(tmp_path / "*.avro")
+    expected = pl.concat([df, df])
+
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(lf.select("c", "a").collect(), expected.select("c", "a"))
+    assert_frame_equal(lf.slice(90, 20).collect(), expected.slice(90, 20))
+    assert_frame_equal(lf.tail(5).collect(), expected.tail(5))
+    assert_frame_equal(
+        lf.filter(pl.col("a") >= 95).collect(),
+        expected.filter(pl.col("a") >= 95),
+    )
+    assert lf.select(pl.len()).collect().item() == 200
+
+
+@pytest.mark.write_disk
+def test_scan_avro_row_index_and_file_paths(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    paths = [tmp_path / "1.avro", tmp_path / "2.avro"]
+    for path in paths:
+        df.write_avro(path)
+
+    out = pl.scan_avro(
+        paths,
+        row_index_name="index",
+        row_index_offset=10,
+        include_file_paths="path",
+        n_rows=4,
+    ).collect()
+
+    expected = pl.DataFrame(
+        {
+            "index": pl.Series([10, 11, 12, 13], dtype=pl.get_index_type()),
+            "a": [1, 2, 3, 1],
+            "path": [str(paths[0])] * 3 + [str(paths[1])],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_sink_avro(compression: AvroCompression) -> None:
+    df = pl.DataFrame({"a": range(1000), "b": [f"b{i % 7}" for i in range(1000)]})
+    lf = pl.concat([df.lazy(), df.lazy()], rechunk=False)
+
+    f = io.BytesIO()
+    lf.sink_avro(f, compression=compression, name="record")
+    f.seek(0)
+
+    assert_frame_equal(pl.read_avro(f), pl.concat([df, df]))
+
+
+@pytest.mark.write_disk
+def test_sink_avro_partition_by_key(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 1, 3], "b": ["x", "y", "z", "w"]})
+
+    df.lazy().sink_avro(
+        pl.PartitionByKey(tmp_path, by="a", include_key=False),
+        compression="deflate",
+        mkdir=True,
+    )
+
+    out = pl.scan_avro(tmp_path / "a=1" / "*.avro").collect()
+    assert_frame_equal(out, pl.DataFrame({"b": ["x", "z"]}))
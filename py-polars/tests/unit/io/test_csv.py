@@ -1,7 +1,9 @@
 from __future__ import annotations
 
+import bz2
 import gzip
 import io
+import lzma
 import os
 import sys
 import textwrap
@@ -23,6 +25,7 @@ from polars.io.csv import BatchedCsvReader
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
     from pathlib import Path
 
     from polars._typing import CsvQuoteStyle, TimeUnit
@@ -660,6 +663,51 @@ def test_compressed_csv(io_files_path: Path, monkeypatch: pytest.MonkeyPatch) ->
     assert_frame_equal(out2, expected)
 
 
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("extension", "compress"),
+    [
+        ("gz", gzip.compress),
+        ("bz2", bz2.compress),
+        ("xz", lzma.compress),
+        ("zst", zstandard.compress),
+    ],
+)
+@pytest.mark.parametrize("chunk_size", [64, 4096])
+def test_scan_csv_streaming_decompression(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    extension: str,
+    compress: Callable[[bytes], bytes],
+    chunk_size: int,
+) -> None:
+    monkeypatch.setenv("POLARS_FORCE_DECOMPRESSED_CHUNK_SIZE", str(chunk_size))
+
+    df = pl.DataFrame(
+        {
+            "idx": range(2_000),
+            "txt": [f"line\n{i}" if i % 7 == 0 else f"text {i}" for i in range(2_000)],
+        }
+    )
+    buf = io.BytesIO()
+    df.write_csv(buf)
+
+    path = tmp_path / f"data.csv.{extension}"
+    # Includes a comment and no trailing newline.
+    path.write_bytes(compress(b"# comment\n" + buf.getvalue()[:-1]))
+
+    q = pl.scan_csv(path, comment_prefix="#")
+
+    assert_frame_equal(q.collect(), df)
+    assert_frame_equal(q.select("txt").collect(), df.select("txt"))
+    assert q.select(pl.len()).collect().item() == 2_000
+
+    q = pl.scan_csv(path, skip_rows=1, skip_rows_after_header=3)
+
+    assert_frame_equal(q.head(10).collect(), df.slice(3, 10))
+    assert_frame_equal(q.slice(1_500, 100).collect(), df.slice(1_503, 100))
+
+
 def test_partial_decompression(foods_file_path: Path) -> None:
     f_out = io.BytesIO()
     with gzip.GzipFile(fileobj=f_out, mode="w") as f:
@@ -760,3 +760,32 @@ endpoint_url = http://localhost:333
 
     with pytest.raises(DeltaError, match="http://localhost:333"):
         pl.DataFrame({"x": 1}).write_delta("s3://.../...", mode="append")
+
+
+@pytest.mark.parametrize("table_uri", ["/table", "file:///table/"])
+def test_scan_delta_deletion_vectors_matched_by_path(table_uri: str) -> None:
+    from polars.io.delta import _get_deletion_vectors
+
+    class Table:
+        def get_add_actions(self, flatten: bool) -> dict[str, list[Any]]:
+            return {
+                "path": ["b%20c.parquet", "a.parquet", "/table/d.parquet"],
+                "deletionVector.storageType": [None, "i", "p"],
+                "deletionVector.pathOrInlineDv": [None, "abc", "/dv.bin"],
+                "deletionVector.offset": [None, None, 1],
+                "deletionVector.sizeInBytes": [None, 10, 20],
+                "deletionVector.cardinality": [None, 2, 3],
+            }
+
+    # The data files are not in the order of the add actions.
+    file_uris = ["/table/d.parquet", "/table/b c.parquet", "/table/a.parquet"]
+
+    table: Any = Table()
+
+    assert _get_deletion_vectors(table, table_uri, file_uris) == (
+        "delta-deletion-vector",
+        (
+            table_uri,
+            {0: ("p", "/dv.bin", 1, 20, 3), 2: ("i", "abc", None, 10, 2)},
+        ),
+    )
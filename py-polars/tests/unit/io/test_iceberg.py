@@ -2058,6 +2058,79 @@ def test_scan_iceberg_fast_count(tmp_path: Path) -> None:
     )
 
 
+@pytest.mark.write_disk
+def test_scan_iceberg_equality_deletes(tmp_path: Path) -> None:
+    from pyiceberg.manifest import DataFile, DataFileContent, FileFormat
+    from pyiceberg.typedef import Record
+
+    catalog = SqlCatalog(
+        "default",
+        uri="sqlite:///:memory:",
+        warehouse=f"file://{tmp_path}",
+    )
+    catalog.create_namespace("namespace")
+
+    catalog.create_table(
+        "namespace.table",
+        IcebergSchema(
+            NestedField(1, "a", LongType()),
+            NestedField(2, "b", StringType(), required=False),
+        ),
+    )
+
+    tbl = catalog.load_table("namespace.table")
+
+    pl.DataFrame({"a": [0, 1, 2], "b": ["x", "y", None]}).write_iceberg(
+        tbl, mode="append"
+    )
+    pl.DataFrame({"a": [3, 4, 5], "b": ["x", "y", "z"]}).write_iceberg(
+        tbl, mode="append"
+    )
+
+    with tbl.update_schema() as sch:
+        sch.rename_column("a", "key")
+
+    # The data files contain field ID 1 as "a", the delete file as "key".
+    delete_path = tmp_path / "equality-deletes.parquet"
+    pq.write_table(
+        pa.Table.from_pydict(
+            {"key": [1, 4, 7]},
+            schema=schema_to_pyarrow(tbl.schema().select("key")),
+        ),
+        delete_path,
+    )
+
+    with tbl.transaction() as tx, tx.update_snapshot().fast_append() as append:
+        append.append_data_file(
+            DataFile.from_args(
+                content=DataFileContent.EQUALITY_DELETES,
+                file_path=f"file://{delete_path}",
+                file_format=FileFormat.PARQUET,
+                partition=Record(),
+                record_count=3,
+                file_size_in_bytes=delete_path.stat().st_size,
+                equality_ids=[1],
+            )
+        )
+
+    # Data files with a higher sequence number are not affected by the deletes.
+    pl.DataFrame({"key": [1, 6], "b": ["w", "w"]}).write_iceberg(tbl, mode="append")
+
+    expect = pl.DataFrame(
+        {"key": [0, 2, 3, 5, 1, 6], "b": ["x", None, "x", "z", "w", "w"]}
+    )
+
+    q = pl.scan_iceberg(tbl, reader_override="native")
+
+    assert_frame_equal(q.collect(), expect, check_row_order=False)
+    assert q.select(pl.len()).collect().item() == 6
+    assert_frame_equal(
+        q.filter(pl.col("key") <= 3).collect(),
+        expect.filter(pl.col("key") <= 3),
+        check_row_order=False,
+    )
+
+
 def test_scan_iceberg_idxsize_limit() -> None:
     if isinstance(pl.get_index_type(), pl.UInt64):
         assert (
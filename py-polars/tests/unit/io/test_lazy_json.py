@@ -1,5 +1,9 @@
 from __future__ import annotations
 
+import bz2
+import gzip
+import io
+import lzma
 import re
 from typing import TYPE_CHECKING
 
@@ -9,6 +13,7 @@ import polars as pl
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
     from pathlib import Path
 
 
@@ -331,3 +336,36 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     q = pl.scan_ndjson(buf, schema_overrides={"a": pl.String})
     assert q.collect_schema() == {"a": pl.String}
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("extension", "compress"),
+    [("gz", gzip.compress), ("bz2", bz2.compress), ("xz", lzma.compress)],
+)
+@pytest.mark.parametrize("chunk_size", [64, 4096])
+def test_scan_ndjson_streaming_decompression(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    extension: str,
+    compress: Callable[[bytes], bytes],
+    chunk_size: int,
+) -> None:
+    monkeypatch.setenv("POLARS_FORCE_DECOMPRESSED_CHUNK_SIZE", str(chunk_size))
+
+    df = pl.DataFrame({"a": range(2_000), "b": [f"text {i}" for i in range(2_000)]})
+    buf = io.BytesIO()
+    df.write_ndjson(buf)
+
+    path = tmp_path / f"data.ndjson.{extension}"
+    path.write_bytes(compress(buf.getvalue()))
+
+    q = pl.scan_ndjson(path)
+
+    assert_frame_equal(q.collect(), df)
+    assert q.select(pl.len()).collect().item() == 2_000
+    assert_frame_equal(q.slice(1_500, 100).collect(), df.slice(1_500, 100))
+    assert_frame_equal(q.tail(10).collect(), df.tail(10))
+    assert_frame_equal(
+        q.with_row_index().head(5).collect(), df.with_row_index().head(5)
+    )
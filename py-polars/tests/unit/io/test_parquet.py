@@ -3,6 +3,7 @@ from __future__ import annotations
 import decimal
 import functools
 import io
+import re
 import warnings
 from datetime import date, datetime, time, timezone
 from decimal import Decimal
@@ -3599,3 +3600,85 @@ def test_empty_struct_roundtrip(df: pl.DataFrame, monkeypatch: Any) -> None:
 
     f.seek(0)
     assert_frame_equal(df, pl.read_parquet(f))
+
+
+@pytest.mark.parametrize("parallel", ["auto", "none"])
+@pytest.mark.may_fail_cloud  # reason: looks at stdout
+def test_scan_parquet_skip_pages_with_page_index(
+    parallel: ParallelStrategy,
+    monkeypatch: pytest.MonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": range(20_000),
+            "b": [None if i % 7 == 0 else i % 100 for i in range(20_000)],
+            "c": [[i, i + 1] for i in range(20_000)],
+        }
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=20_000, data_page_size=1024)
+
+    for filter_expr in [
+        pl.col("a") == 1234,
+        pl.col("a").is_between(5_000, 5_100),
+        (pl.col("a") < 500) & (pl.col("b") == 3),
+        (pl.col("a") < 10) | (pl.col("a") > 19_990),
+    ]:
+        f.seek(0)
+        q = (
+            pl.scan_parquet(f, parallel=parallel)
+            .with_row_index()
+            .filter(filter_expr)
+        )
+
+        monkeypatch.setenv("POLARS_VERBOSE", "1")
+        capfd.readouterr()
+        out = q.collect()
+        assert "Page index pushdown" in capfd.readouterr().err
+        monkeypatch.delenv("POLARS_VERBOSE")
+
+        assert_frame_equal(out, df.with_row_index().filter(filter_expr))
+
+
+@pytest.mark.parametrize("parallel", ["auto", "none"])
+@pytest.mark.may_fail_cloud  # reason: looks at stdout
+def test_scan_parquet_fetch_selected_pages(
+    parallel: ParallelStrategy,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": range(20_000),
+            "b": [None if i % 7 == 0 else f"b{i % 10}" for i in range(20_000)],
+            "c": [[i, i + 1] for i in range(20_000)],
+        }
+    )
+
+    path = tmp_path / "pages.parquet"
+    df.write_parquet(path, row_group_size=20_000, data_page_size=1024)
+
+    # Fetch through byte ranges instead of memory-mapping the file.
+    monkeypatch.setenv("POLARS_FORCE_ASYNC", "1")
+
+    for filter_expr in [
+        pl.col("a") == 1234,
+        (pl.col("a") < 500) & (pl.col("b") == "b3"),
+        (pl.col("a") < 10) | (pl.col("a") > 19_990),
+    ]:
+        q = pl.scan_parquet(path, parallel=parallel).filter(filter_expr)
+
+        monkeypatch.setenv("POLARS_VERBOSE", "1")
+        capfd.readouterr()
+        out = q.collect()
+        captured = capfd.readouterr().err
+        monkeypatch.delenv("POLARS_VERBOSE")
+
+        m = re.search(r"fetching (\d+) / (\d+) pages", captured)
+        assert m is not None
+        assert 0 < int(m.group(1)) < int(m.group(2))
+
+        assert_frame_equal(out, df.filter(filter_expr))
@@ -1,10 +1,18 @@
+from __future__ import annotations
+
 import io
+import re
+from typing import TYPE_CHECKING
 
 import pyarrow.parquet as pq
 import pytest
 
 import polars as pl
 from polars.io.parquet import ParquetFieldOverwrites
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
 
 
 def test_required_flat() -> None:
@@ -141,3 +149,52 @@ def test_required_struct() -> None:
     schema = pq.read_schema(f)
     assert not schema.field(0).nullable
     assert not schema.field(0).type.fields[0].nullable
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_bloom_filter(
+    engine: EngineType,
+    monkeypatch: pytest.MonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [i * 2 if i % 13 != 0 else None for i in range(1000)],
+            "s": [f"s{i * 3}" for i in range(1000)],
+        }
+    )
+
+    f = io.BytesIO()
+    df.lazy().sink_parquet(
+        f,
+        row_group_size=100,
+        statistics=False,
+        field_overwrites=[
+            ParquetFieldOverwrites(name="a", bloom_filter=True),
+            ParquetFieldOverwrites(name="s", bloom_filter=True),
+        ],
+        engine=engine,
+    )
+
+    # The number of row groups (out of 10) that do not contain a match.
+    for filter_expr, num_excluded in [
+        (pl.col("a") == 402, 9),
+        (pl.col("a") == 401, 10),
+        (pl.col("a").is_in([402, 20]), 8),
+        (pl.col("s") == "s297", 9),
+        (pl.col("s") == "missing", 10),
+    ]:
+        f.seek(0)
+        monkeypatch.setenv("POLARS_VERBOSE", "1")
+        capfd.readouterr()
+        out = pl.scan_parquet(f).filter(filter_expr).collect()
+        m = re.search(
+            r"Bloom filter pushdown: skipping (\d+) / 10", capfd.readouterr().err
+        )
+        monkeypatch.delenv("POLARS_VERBOSE")
+
+        # Allow for a false positive of the bloom filters.
+        assert m is not None
+        assert num_excluded - 1 <= int(m.group(1)) <= num_excluded
+
+        assert_frame_equal(out, df.filter(filter_expr))
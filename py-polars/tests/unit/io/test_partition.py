@@ -34,6 +34,7 @@ io_types: list[IOType] = [
     {"ext": "jsonl", "scan": pl.scan_ndjson, "sink": pl.LazyFrame.sink_ndjson},
     {"ext": "parquet", "scan": pl.scan_parquet, "sink": pl.LazyFrame.sink_parquet},
     {"ext": "ipc", "scan": pl.scan_ipc, "sink": pl.LazyFrame.sink_ipc},
+    {"ext": "avro", "scan": pl.scan_avro, "sink": pl.LazyFrame.sink_avro},
 ]
 
 engines: list[EngineType] = [
@@ -588,3 +589,39 @@ def test_partition_empty_dtype_24545(tmp_path: Path, dtype: pl.DataType) -> None
     extra.write_parquet(Path(tmp_path / "a=" / "000.parquet"), mkdir=True)
 
     assert_frame_equal(pl.read_parquet(tmp_path), pl.concat([extra, df]))
+
+
+@pytest.mark.parametrize(
+    ("sink", "scan", "ext"),
+    [
+        (pl.LazyFrame.sink_csv, pl.scan_csv, "csv"),
+        (pl.LazyFrame.sink_ndjson, pl.scan_ndjson, "jsonl"),
+    ],
+)
+@pytest.mark.parametrize(("compression", "suffix"), [("gzip", "gz"), ("zstd", "zst")])
+@pytest.mark.write_disk
+def test_partition_compressed_extension(
+    tmp_path: Path,
+    sink: Any,
+    scan: Any,
+    ext: str,
+    compression: str,
+    suffix: str,
+) -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, 3], "b": [4, 5, 6, 7]})
+
+    sink(
+        df.lazy(),
+        PartitionByKey(tmp_path, by="a", include_key=False),
+        compression=compression,
+        sync_on_close="data",
+        mkdir=True,
+    )
+
+    for key in [1, 2, 3]:
+        path = tmp_path / f"a={key}" / f"0.{ext}.{suffix}"
+        assert_frame_equal(
+            scan(path).collect(),
+            df.filter(pl.col.a == key).select("b"),
+            check_dtypes=False,
+        )
@@ -7,6 +7,7 @@ from typing import TYPE_CHECKING
 import pytest
 
 import polars as pl
+from polars.io.parquet import ParquetFieldOverwrites
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -450,3 +451,173 @@ def test_scan_row_deletion_skips_file_with_all_rows_deleted(
 
     assert_frame_equal(q.slice(10).collect(), expect.drop("index"))
     assert_frame_equal(q.with_row_index().slice(10).collect(), expect)
+
+
+def _serialize_deletion_vector(positions: list[int]) -> bytes:
+    """Serialize positions (< 65536) as a Delta `RoaringBitmapArray`."""
+    out = bytearray()
+    out += (1681511377).to_bytes(4, "little")  # magic number
+    out += (1).to_bytes(8, "little")  # number of bitmaps
+    out += (0).to_bytes(4, "little")  # key of the bitmap
+    out += (12346).to_bytes(4, "little")  # cookie without run containers
+    out += (1).to_bytes(4, "little")  # number of containers
+    out += (0).to_bytes(2, "little") + (len(positions) - 1).to_bytes(2, "little")
+    out += (0).to_bytes(4, "little")  # container offset
+    for p in positions:
+        out += p.to_bytes(2, "little")
+    return bytes(out)
+
+
+def _z85_encode(data: bytes) -> str:
+    alphabet = (
+        "0123456789abcdefghijklmnopqrstuvwxyz"
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#"
+    )
+    data += b"\x00" * (-len(data) % 4)
+    out = []
+    for i in range(0, len(data), 4):
+        value = int.from_bytes(data[i : i + 4], "big")
+        chunk = []
+        for _ in range(5):
+            value, digit = divmod(value, 85)
+            chunk.append(alphabet[digit])
+        out.extend(reversed(chunk))
+    return "".join(out)
+
+
+@pytest.mark.write_disk
+def test_scan_delta_deletion_vectors(data_files_path: Path, tmp_path: Path) -> None:
+    inline_dv = _serialize_deletion_vector([1, 2])
+
+    on_disk_dv = _serialize_deletion_vector([0, 4])
+    dv_path = tmp_path / "deletion_vector.bin"
+    # Format version, followed by the size-prefixed vector and its (unchecked) CRC.
+    dv_path.write_bytes(
+        b"\x01" + len(on_disk_dv).to_bytes(4, "big") + on_disk_dv + b"\x00" * 4
+    )
+
+    deletion_files = (
+        "delta-deletion-vector",
+        (
+            str(tmp_path),
+            {
+                0: ("i", _z85_encode(inline_dv), None, len(inline_dv), 2),
+                3: ("p", str(dv_path), 1, len(on_disk_dv), 2),
+            },
+        ),
+    )
+
+    q = pl.scan_parquet(
+        data_files_path,
+        _deletion_files=deletion_files,  # type: ignore[arg-type]
+        hive_partitioning=False,
+    )
+
+    expect = [0, 3, 4, *range(5, 15), 16, 17, 18, *range(20, 25)]
+
+    assert q.select(pl.len()).collect().item() == len(expect)
+    assert q.collect().to_series().to_list() == expect
+    assert q.tail(6).collect().to_series().to_list() == expect[-6:]
+
+    deletion_files[1][1][0] = ("i", _z85_encode(inline_dv), None, len(inline_dv), 3)
+
+    with pytest.raises(pl.exceptions.ComputeError, match="expected 3"):
+        pl.scan_parquet(
+            data_files_path,
+            _deletion_files=deletion_files,  # type: ignore[arg-type]
+            hive_partitioning=False,
+        ).collect()
+
+    # Each data file has 5 rows.
+    out_of_bounds_dv = _serialize_deletion_vector([5])
+    deletion_files[1][1][0] = (
+        "i",
+        _z85_encode(out_of_bounds_dv),
+        None,
+        len(out_of_bounds_dv),
+        1,
+    )
+
+    with pytest.raises(pl.exceptions.ComputeError, match="out of bounds"):
+        pl.scan_parquet(
+            data_files_path,
+            _deletion_files=deletion_files,  # type: ignore[arg-type]
+            hive_partitioning=False,
+        ).collect()
+
+
+@pytest.mark.write_disk
+def test_scan_iceberg_equality_deletes(
+    write_position_deletes: WritePositionDeletes,
+    tmp_path: Path,
+) -> None:
+    def write_with_field_ids(path: Path, df: pl.DataFrame) -> str:
+        path.parent.mkdir(exist_ok=True, parents=True)
+        df.lazy().sink_parquet(
+            path,
+            field_overwrites=[
+                ParquetFieldOverwrites(name=name, field_id=i + 1)
+                for i, name in enumerate(df.columns)
+            ],
+        )
+        return str(path)
+
+    # Same data as `data_files_path`, with `physical_index` having field ID 1.
+    data_files_path = tmp_path / "data"
+    df = pl.select(physical_index=pl.int_range(25, dtype=pl.UInt32))
+    for i in [0, 5, 10, 15, 20]:
+        write_with_field_ids(data_files_path / f"{i:02}.parquet", df.slice(i, 5))
+
+    # The delete files use another name for field ID 1, so the columns must be
+    # matched by their field IDs.
+    deletes_a = write_with_field_ids(
+        tmp_path / "a.parquet",
+        pl.DataFrame({"renamed": pl.Series([1, 3, 6], dtype=pl.UInt32)}),
+    )
+    # Differing key dtype, with a value that is not present in any data file.
+    deletes_b = write_with_field_ids(
+        tmp_path / "b.parquet",
+        pl.DataFrame(
+            {
+                "physical_index": pl.Series([11, 99], dtype=pl.Int64),
+                "other": pl.Series([0, 0], dtype=pl.Int64),
+            }
+        ),
+    )
+
+    deletion_files = (
+        "iceberg-equality-delete",
+        {
+            0: (1, [(deletes_a, 2, [1])], [write_position_deletes(pl.Series([0, 1]))]),
+            # Data file is newer than the delete file, so row 6 is not deleted.
+            1: (3, [(deletes_a, 2, [1])], []),
+            2: (1, [(deletes_b, 5, [1])], []),
+            4: (1, [], [write_position_deletes(pl.Series([4]))]),
+        },
+    )
+
+    q = pl.scan_parquet(
+        data_files_path,
+        _deletion_files=deletion_files,  # type: ignore[arg-type]
+    )
+
+    expect = [2, 4, *range(5, 11), 12, 13, 14, *range(15, 24)]
+
+    assert q.select(pl.len()).collect().item() == len(expect)
+    assert q.collect().to_series().to_list() == expect
+    assert q.tail(6).collect().to_series().to_list() == expect[-6:]
+    assert_frame_equal(
+        q.with_row_index().collect(),
+        pl.DataFrame(
+            {"physical_index": pl.Series(expect, dtype=pl.UInt32)}
+        ).with_row_index(),
+    )
+
+    # Field ID 2 only exists in the delete file.
+    deletion_files[1][2] = (1, [(deletes_b, 2, [1, 2])], [])
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError, match="field ID 2"):
+        pl.scan_parquet(
+            data_files_path,
+            _deletion_files=deletion_files,  # type: ignore[arg-type]
+        ).collect()
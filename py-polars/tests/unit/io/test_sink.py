@@ -1,8 +1,13 @@
+from __future__ import annotations
+
+import gzip
 import io
+import zlib
 from pathlib import Path
 from typing import Any
 
 import pytest
+import zstandard
 
 import polars as pl
 from polars._typing import EngineType
@@ -13,6 +18,7 @@ SINKS = [
     (pl.scan_parquet, pl.LazyFrame.sink_parquet),
     (pl.scan_csv, pl.LazyFrame.sink_csv),
     (pl.scan_ndjson, pl.LazyFrame.sink_ndjson),
+    (pl.scan_avro, pl.LazyFrame.sink_avro),
 ]
 
 
@@ -75,6 +81,7 @@ def test_lazy_sinks(tmp_path: Path, scan: Any, sink: Any, engine: EngineType) ->
         pl.LazyFrame.sink_parquet,
         pl.LazyFrame.sink_csv,
         pl.LazyFrame.sink_ndjson,
+        pl.LazyFrame.sink_avro,
     ],
 )
 @pytest.mark.write_disk
@@ -159,3 +166,84 @@ def test_sink_empty(sink: Any, scan: Any) -> None:
     f.truncate()
     f.seek(0)
     assert_frame_equal(scan(f, **kwargs), expected.lazy())
+
+
+@pytest.mark.parametrize(
+    ("scan", "sink"),
+    [
+        (pl.scan_csv, pl.LazyFrame.sink_csv),
+        (pl.scan_ndjson, pl.LazyFrame.sink_ndjson),
+    ],
+)
+@pytest.mark.parametrize(
+    ("compression", "compression_level", "decompress"),
+    [
+        ("gzip", None, gzip.decompress),
+        ("gzip", 9, gzip.decompress),
+        ("zstd", None, zstandard.decompress),
+        ("zstd", 19, zstandard.decompress),
+    ],
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_sink_compressed(
+    tmp_path: Path,
+    scan: Any,
+    sink: Any,
+    compression: str,
+    compression_level: int | None,
+    decompress: Any,
+    engine: EngineType,
+) -> None:
+    df = pl.DataFrame({"a": range(10_000), "b": [f"text {i}" for i in range(10_000)]})
+
+    f = tmp_path / "file"
+    sink(
+        pl.concat([df.lazy()] * 3),
+        f,
+        compression=compression,
+        compression_level=compression_level,
+        engine=engine,
+    )
+
+    expected = pl.concat([df] * 3)
+    uncompressed = tmp_path / "uncompressed"
+    uncompressed.write_bytes(decompress(f.read_bytes()))
+
+    assert_frame_equal(scan(uncompressed).collect(), expected)
+    assert_frame_equal(scan(f).collect(), expected)
+
+
+@pytest.mark.parametrize("sink", [pl.LazyFrame.sink_csv, pl.LazyFrame.sink_ndjson])
+@pytest.mark.parametrize(
+    ("compression", "decompressobj"),
+    [
+        ("gzip", lambda: zlib.decompressobj(wbits=31)),
+        ("zstd", lambda: zstandard.ZstdDecompressor().decompressobj()),
+    ],
+)
+@pytest.mark.write_disk
+def test_sink_compressed_single_stream(
+    tmp_path: Path, sink: Any, compression: str, decompressobj: Any
+) -> None:
+    # The morsels are not compressed one by one, which would produce a file of
+    # concatenated gzip members or zstd frames.
+    lf = pl.concat([pl.LazyFrame({"a": range(100_000)})] * 10)
+
+    f = tmp_path / "file"
+    sink(lf, f, compression=compression, engine="streaming")
+
+    decompressor = decompressobj()
+    decompressor.decompress(f.read_bytes())
+    assert decompressor.eof
+    assert decompressor.unused_data == b""
+
+
+def test_sink_compression_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1]})
+
+    with pytest.raises(ValueError, match="`compression` must be one of"):
+        lf.sink_csv(io.BytesIO(), compression="lz4")  # type: ignore[arg-type]
+
+    with pytest.raises(ValueError, match="valid compression range"):
+        lf.sink_ndjson(io.BytesIO(), compression="gzip", compression_level=10)
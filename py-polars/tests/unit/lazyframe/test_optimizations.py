@@ -413,3 +413,53 @@ def test_slice_pushdown_within_concat_24734() -> None:
     assert plan.index("SLICE[offset: 0, len: 3]") > plan.index("PLAN 0:")
 
     assert_frame_equal(q, pl.LazyFrame({"x": [3, 2]}))
+
+
+def test_hint_unique_removes_unique() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": [1, 1, 2, 2]})
+
+    q = lf.hint(unique="a").filter(pl.col("b") > 0).unique(["a", "b"])
+    assert "UNIQUE" not in q.explain()
+    assert_frame_equal(q.collect(), lf.collect(), check_row_order=False)
+
+    # The hinted columns are not unique on their own.
+    q = lf.hint(unique=["a", "b"]).unique("a")
+    assert "UNIQUE" in q.explain()
+
+
+def test_hint_cardinality_join_order() -> None:
+    sales = pl.LazyFrame({"store": [0, 1], "date": [0, 1], "amount": [10, 20]})
+    stores = pl.LazyFrame({"store_id": [0, 1]}).hint(row_count=100)
+    dates = pl.LazyFrame({"date_id": [0, 1]}).hint(row_count=365)
+
+    def join_order(sales: pl.LazyFrame, *, join_reorder: bool = True) -> list[str]:
+        q = sales.join(stores, left_on="store", right_on="store_id").join(
+            dates, left_on="date", right_on="date_id"
+        )
+        plan = q.explain(optimizations=pl.QueryOptFlags(join_reorder=join_reorder))
+        # The outermost join is printed first.
+        return sorted(
+            ["store_id", "date_id"],
+            key=lambda c: -plan.index(f'RIGHT PLAN ON: [col("{c}")]'),
+        )
+
+    sales = sales.hint(row_count=1_000_000)
+    assert join_order(sales) == ["store_id", "date_id"]
+
+    # Only a tenth of the dates in the sales are in the dates table, so joining it
+    # first shrinks the join with the stores.
+    sales = sales.hint(distinct_count={"date": 3650})
+    assert join_order(sales) == ["date_id", "store_id"]
+
+    # The reordering can be turned off on its own.
+    assert join_order(sales, join_reorder=False) == ["store_id", "date_id"]
+    assert not pl.QueryOptFlags.none().join_reorder
+
+
+def test_hint_missing_column() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError, match="b"):
+        lf.hint(unique="b").collect()
+    with pytest.raises(pl.exceptions.ColumnNotFoundError, match="b"):
+        lf.hint(distinct_count={"b": 3}).collect()
@@ -1035,3 +1035,59 @@ def test_all_any_on_list_raises_error() -> None:
             pl.exceptions.InvalidOperationError, match=r"expected boolean"
         ):
             lf.select(expr).collect(engine="streaming")
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_approx_quantile(engine: Any) -> None:
+    n = 100_000
+    lf = pl.LazyFrame(
+        {
+            "g": pl.Series(range(n)) % 3,
+            "x": (pl.Series(range(n)) * 7919) % n,
+        }
+    )
+
+    for q in [0.01, 0.5, 0.95, 0.99]:
+        out = lf.select(pl.col("x").approx_quantile(q)).collect(engine=engine)
+        assert out.schema == {"x": pl.Float64}
+        assert abs(out.item() / n - q) < 0.005
+
+        out = (
+            lf.group_by("g")
+            .agg(
+                exact=pl.col("x").quantile(q, interpolation="linear"),
+                approx=pl.col("x").approx_quantile(q),
+            )
+            .collect(engine=engine)
+        )
+        assert ((out["approx"] - out["exact"]).abs() / n < 0.005).all()
+
+
+def test_approx_quantile_dtypes() -> None:
+    lf = pl.LazyFrame(
+        {
+            "f32": pl.Series([1.0, 2.0, None, 3.0], dtype=pl.Float32),
+            "i8": pl.Series([1, 2, None, 3], dtype=pl.Int8),
+            "b": [True, False, None, True],
+            "null": [None, None, None, None],
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "f32": pl.Series([2.0], dtype=pl.Float32),
+            "i8": [2.0],
+            "b": [1.0],
+            "null": [None],
+        }
+    )
+
+    q = lf.select(pl.all().approx_quantile(0.5))
+    assert q.collect_schema() == expected.schema
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+
+    with pytest.raises(InvalidOperationError):
+        pl.select(pl.lit("a").approx_quantile(0.5))
+    for quantile in [-0.1, 1.5, float("nan"), float("inf")]:
+        with pytest.raises(InvalidOperationError, match="between 0.0 and 1.0"):
+            pl.select(pl.lit(1).approx_quantile(quantile))
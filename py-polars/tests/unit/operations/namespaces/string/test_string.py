@@ -1905,6 +1905,71 @@ def test_string_reverse() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_string_edit_distances() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "ab", "ca", "", "über", None, "karolin"],
+            "b": ["sitting", "ba", "abc", "abc", "uber", "x", "kathrin"],
+        }
+    )
+    result = df.select(
+        lev=pl.col("a").str.levenshtein(pl.col("b")),
+        dl=pl.col("a").str.damerau_levenshtein(pl.col("b")),
+        ham=pl.col("a").str.hamming(pl.col("b")),
+    )
+    expected = pl.DataFrame(
+        {
+            "lev": [3, 2, 3, 3, 1, None, 3],
+            "dl": [3, 1, 2, 3, 1, None, 3],
+            "ham": [None, 2, None, None, 1, None, 3],
+        },
+        schema={"lev": pl.UInt32, "dl": pl.UInt32, "ham": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    # Broadcast a literal on either side.
+    assert df["a"].str.levenshtein("kitten").to_list() == [0, 6, 6, 6, 5, None, 5]
+    assert_series_equal(
+        df.select(pl.lit("kitten").str.levenshtein(pl.col("a")))["literal"],
+        df["a"].str.levenshtein("kitten").alias("literal"),
+    )
+
+
+def test_string_similarity() -> None:
+    s = pl.Series("a", ["martha", "dixon", "abcd", "", None])
+    other = pl.Series(["marhta", "dicksonx", "abdc", "", "x"])
+
+    assert_series_equal(
+        s.str.jaro_winkler(other).round(3),
+        pl.Series("a", [0.961, 0.813, 0.933, 1.0, None]),
+    )
+    assert_series_equal(
+        s.str.similarity(other, metric="jaro").round(3),
+        pl.Series("a", [0.944, 0.767, 0.917, 1.0, None]),
+    )
+    assert_series_equal(
+        s.str.similarity(other),
+        pl.Series("a", [4 / 6, 0.5, 0.5, 1.0, None]),
+    )
+    assert_series_equal(
+        s.str.similarity(other, metric="damerau_levenshtein"),
+        pl.Series("a", [5 / 6, 0.5, 0.75, 1.0, None]),
+    )
+    assert_series_equal(
+        s.str.similarity(other, metric="hamming"),
+        pl.Series("a", [4 / 6, None, 0.5, 1.0, None]),
+    )
+
+    with pytest.raises(ValueError, match="`metric` must be one of"):
+        s.str.similarity(other, metric="cosine")  # type: ignore[arg-type]
+
+
+def test_string_similarity_wrong_length() -> None:
+    df = pl.DataFrame({"a": ["a", "b", "c"]})
+    with pytest.raises(ShapeError):
+        df.select(pl.col("a").str.levenshtein(pl.Series(["a", "b"])))
+
+
 @pytest.mark.parametrize(
     ("data", "expected_data"),
     [
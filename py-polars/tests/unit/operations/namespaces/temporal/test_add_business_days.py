@@ -14,7 +14,7 @@ from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import Roll, TimeUnit
+    from polars._typing import HolidayCalendar, Roll, TimeUnit
 
 from zoneinfo import ZoneInfo
 
@@ -148,6 +148,34 @@ def test_add_business_days_w_holidays() -> None:
     assert_series_equal(result, expected)
 
 
+@pytest.mark.parametrize(
+    ("calendar", "start", "n", "expected"),
+    [
+        # Boxing Day falls on a Saturday and is substituted by the Monday.
+        ("uk", date(2020, 12, 24), 1, date(2020, 12, 29)),
+        ("uk", date(2020, 12, 29), -1, date(2020, 12, 24)),
+        # New Year's Day on a Saturday is not observed on the Friday before.
+        ("nyse", date(2021, 12, 30), 1, date(2021, 12, 31)),
+        ("nyse", date(2021, 12, 31), 1, date(2022, 1, 3)),
+        # Offsets far beyond the input dates still skip the calendar's holidays.
+        ("nyse", date(2020, 1, 2), 252, date(2020, 12, 31)),
+        ("nyse", date(2021, 1, 4), -252, date(2020, 1, 3)),
+        ("target2", date(2021, 4, 1), 1, date(2021, 4, 6)),
+    ],
+)
+def test_add_business_days_w_calendar(
+    calendar: HolidayCalendar, start: date, n: int, expected: date
+) -> None:
+    result = pl.Series([start]).dt.add_business_days(n, calendar=calendar)
+    assert_series_equal(result, pl.Series([expected]))
+
+    # Time of day is preserved for datetimes.
+    time = dt.time(13, 30)
+    s = pl.Series([datetime.combine(start, time)])
+    result = s.dt.add_business_days(n, calendar=calendar)
+    assert_series_equal(result, pl.Series([datetime.combine(expected, time)]))
+
+
 def test_add_business_days_w_roll() -> None:
     df = pl.DataFrame(
         {
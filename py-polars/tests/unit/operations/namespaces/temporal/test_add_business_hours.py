@@ -0,0 +1,120 @@
+from __future__ import annotations
+
+from datetime import date, datetime, time
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_series_equal
+
+if TYPE_CHECKING:
+    from polars._typing import TimeUnit
+
+
+@pytest.mark.parametrize(
+    ("start", "n", "expected"),
+    [
+        (datetime(2020, 1, 6, 9), 8, datetime(2020, 1, 6, 17)),
+        (datetime(2020, 1, 6, 9), 16, datetime(2020, 1, 7, 17)),
+        (datetime(2020, 1, 6, 12), 0.25, datetime(2020, 1, 6, 12, 15)),
+        (datetime(2020, 1, 6, 12), -0.5, datetime(2020, 1, 6, 11, 30)),
+        # Across a weekend.
+        (datetime(2020, 1, 3, 16), 2, datetime(2020, 1, 6, 10)),
+        (datetime(2020, 1, 6, 12, 30), -4, datetime(2020, 1, 3, 16, 30)),
+        # Outside of business hours.
+        (datetime(2020, 1, 4, 12), 2, datetime(2020, 1, 6, 11)),
+        (datetime(2020, 1, 6, 8), 0, datetime(2020, 1, 6, 9)),
+        (datetime(2020, 1, 6, 17), 0, datetime(2020, 1, 7, 9)),
+        (datetime(2020, 1, 6, 9), -1, datetime(2020, 1, 3, 16)),
+        (datetime(2020, 1, 6, 20), -1, datetime(2020, 1, 6, 16)),
+    ],
+)
+def test_add_business_hours(start: datetime, n: float, expected: datetime) -> None:
+    result = pl.Series([start]).dt.add_business_hours(n)
+    assert_series_equal(result, pl.Series([expected]))
+
+
+def test_add_business_hours_expr() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [datetime(2020, 1, 6, 9), datetime(2020, 1, 6, 12), None],
+            "n": [1.5, None, 1.0],
+        }
+    )
+    result = df.select(result=pl.col("start").dt.add_business_hours("n"))["result"]
+    expected = pl.Series("result", [datetime(2020, 1, 6, 10, 30), None, None])
+    assert_series_equal(result, expected)
+
+    result = df.select(
+        result=pl.lit(datetime(2020, 1, 3, 16)).dt.add_business_hours(pl.col("n"))
+    )["result"]
+    expected = pl.Series(
+        "result", [datetime(2020, 1, 6, 9, 30), None, datetime(2020, 1, 3, 17)]
+    )
+    assert_series_equal(result, expected)
+
+
+def test_add_business_hours_w_business_hours_and_week_mask() -> None:
+    s = pl.Series([datetime(2020, 1, 2, 15), datetime(2020, 1, 3, 12)])
+    result = s.dt.add_business_hours(
+        2,
+        business_hours=(time(8, 30), time(16)),
+        week_mask=(True, True, True, True, False, False, True),
+    )
+    expected = pl.Series([datetime(2020, 1, 5, 9, 30), datetime(2020, 1, 5, 10, 30)])
+    assert_series_equal(result, expected)
+
+
+def test_add_business_hours_w_holidays() -> None:
+    s = pl.Series([datetime(2020, 12, 31, 16)])
+    result = s.dt.add_business_hours(2, holidays=[date(2021, 1, 1)])
+    assert_series_equal(result, pl.Series([datetime(2021, 1, 4, 10)]))
+
+    result = s.dt.add_business_hours(2, calendar="nyse")
+    assert_series_equal(result, pl.Series([datetime(2021, 1, 4, 10)]))
+
+    # Offsets far beyond the input dates still skip the calendar's holidays.
+    result = s.dt.add_business_hours(-8 * 252, calendar="nyse")
+    assert_series_equal(result, pl.Series([datetime(2020, 1, 2, 16)]))
+
+
+@pytest.mark.parametrize("time_zone", [None, "Europe/London", "Asia/Kathmandu"])
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_add_business_hours_datetime(
+    time_zone: str | None, time_unit: TimeUnit
+) -> None:
+    s = pl.Series(
+        [datetime(2020, 1, 3, 16, 15), datetime(2020, 1, 6, 9)],
+        dtype=pl.Datetime(time_unit),
+    ).dt.replace_time_zone(time_zone)
+    result = s.dt.add_business_hours(1.5)
+    expected = pl.Series(
+        [datetime(2020, 1, 6, 9, 45), datetime(2020, 1, 6, 10, 30)],
+        dtype=pl.Datetime(time_unit),
+    ).dt.replace_time_zone(time_zone)
+    assert_series_equal(result, expected)
+
+
+def test_add_business_hours_invalid() -> None:
+    s = pl.Series([datetime(2020, 1, 6, 9)])
+    with pytest.raises(ComputeError, match="business hours must start before"):
+        s.dt.add_business_hours(1, business_hours=(time(17), time(9)))
+    with pytest.raises(ComputeError, match="at least one business day"):
+        s.dt.add_business_hours(1, week_mask=[False] * 7)
+    with pytest.raises(InvalidOperationError, match="expected datetime"):
+        pl.Series([date(2020, 1, 6)]).dt.add_business_hours(1)
+    with pytest.raises(InvalidOperationError, match="expected a numeric"):
+        s.dt.add_business_hours(pl.lit("1"))
+    with pytest.raises(ValueError, match="`calendar` must be one of"):
+        s.dt.add_business_hours(1, calendar="nl")  # type: ignore[arg-type]
+
+
+def test_add_business_hours_schema() -> None:
+    lf = pl.LazyFrame(
+        {"start": [datetime(2020, 1, 6, 9)]}, schema={"start": pl.Datetime("ms")}
+    )
+    result = lf.select(pl.col("start").dt.add_business_hours(1))
+    assert result.collect_schema() == {"start": pl.Datetime("ms")}
+    assert result.collect().schema == {"start": pl.Datetime("ms")}
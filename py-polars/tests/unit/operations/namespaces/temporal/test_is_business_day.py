@@ -1,6 +1,8 @@
 from __future__ import annotations
 
+from dataclasses import dataclass
 from datetime import date, datetime
+from typing import TYPE_CHECKING
 
 import pytest
 
@@ -8,6 +10,9 @@ import polars as pl
 from polars.exceptions import ComputeError
 from polars.testing import assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import HolidayCalendar, HolidayRule
+
 
 @pytest.mark.parametrize(
     ("holidays", "week_mask", "expected_values"),
@@ -74,6 +79,78 @@ def test_is_business_day(
     assert_series_equal(result, expected)
 
 
+@pytest.mark.parametrize(
+    ("calendar", "dates", "expected_values"),
+    [
+        # Independence Day falls on a Sunday and is observed on the Monday.
+        ("nyse", [date(2021, 7, 5), date(2021, 7, 6)], [False, True]),
+        # The 2022 spring bank holiday moved to June for the Platinum Jubilee.
+        (
+            "uk",
+            [date(2022, 5, 30), date(2022, 6, 2), date(2022, 6, 3)],
+            [True, False, False],
+        ),
+        (
+            "target2",
+            [date(2021, 4, 2), date(2021, 4, 5), date(2021, 12, 24)],
+            [False, False, True],
+        ),
+    ],
+)
+def test_is_business_day_w_calendar(
+    calendar: HolidayCalendar, dates: list[date], expected_values: list[bool]
+) -> None:
+    s = pl.Series("date", dates)
+    assert_series_equal(
+        s.dt.is_business_day(calendar=calendar), pl.Series("date", expected_values)
+    )
+    # Datetime
+    result = s.cast(pl.Datetime).dt.is_business_day(calendar=calendar)
+    assert_series_equal(result, pl.Series("date", expected_values))
+
+
+@dataclass
+class Rule:
+    month: int | None = None
+    day: int | None = None
+    weekday: int | None = None
+    n: int | None = None
+    easter_offset: int | None = None
+    observance: str | None = None
+    start_year: int | None = None
+    end_year: int | None = None
+
+
+def test_is_business_day_w_custom_calendar() -> None:
+    calendar: list[HolidayRule] = [
+        {"month": 12, "day": 25, "observance": "nearest_weekday"},
+        {"month": 5, "weekday": 0, "n": -1},
+        {"easter_offset": -2},
+        {"month": 6, "day": 19, "start_year": 2021},
+    ]
+    dates = [
+        date(2021, 12, 24),
+        date(2021, 12, 27),
+        date(2021, 5, 31),
+        date(2021, 4, 2),
+        date(2021, 4, 5),
+        date(2020, 6, 19),
+        date(2023, 6, 19),
+    ]
+    expected = pl.Series("date", [False, True, False, False, True, True, False])
+
+    s = pl.Series("date", dates)
+    assert_series_equal(s.dt.is_business_day(calendar=calendar), expected)
+
+    # The rules can also be given as objects.
+    rules = [Rule(**rule) for rule in calendar]
+    result = s.dt.is_business_day(calendar=rules)  # type: ignore[arg-type]
+    assert_series_equal(result, expected)
+
+    with pytest.raises(ValueError, match="a holiday rule must have"):
+        s.dt.is_business_day(calendar=[{"month": 12}])
+
+
 def test_is_business_day_invalid() -> None:
     df = pl.DataFrame({"date": [date(2020, 1, 1), date(2020, 1, 2)]})
     with pytest.raises(ComputeError):
@@ -1,7 +1,10 @@
 from __future__ import annotations
 
+import gzip
+import hashlib
 import random
 import struct
+import zlib
 from datetime import date, datetime, time, timedelta
 from typing import TYPE_CHECKING, Any
 
@@ -15,7 +18,12 @@ from polars.exceptions import InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import PolarsDataType, SizeUnit, TransferEncoding
+    from polars._typing import (
+        BinaryCodec,
+        PolarsDataType,
+        SizeUnit,
+        TransferEncoding,
+    )
 
 
 def test_binary_conversions() -> None:
@@ -589,3 +597,133 @@ def test_binary_context_nested(maintain_order: bool) -> None:
         {"groups": [1, 2, 3], "vals": [[14, 90], [90, 90], [7, 90]]}
     )
     assert_frame_equal(out, expected, check_row_order=maintain_order)
+
+
+@given(values=st.lists(st.binary(max_size=100), max_size=20))
+def test_bin_hash_digests_match_hashlib(values: list[bytes]) -> None:
+    s = pl.Series("data", values, dtype=pl.Binary)
+
+    assert s.bin.sha256().to_list() == [hashlib.sha256(v).digest() for v in values]
+    assert s.bin.sha1().to_list() == [hashlib.sha1(v).digest() for v in values]
+    assert s.bin.md5().to_list() == [hashlib.md5(v).digest() for v in values]
+    assert s.bin.crc32().to_list() == [zlib.crc32(v) for v in values]
+
+
+def test_bin_hash_reference_values() -> None:
+    df = pl.DataFrame({"data": [b"", b"abc", None]})
+    out = df.select(
+        pl.col("data").bin.xxh64(),
+        pl.col("data").bin.xxh64(seed=42).alias("xxh64_42"),
+        pl.col("data").bin.crc32().alias("crc32"),
+        pl.col("data").bin.murmur3().alias("murmur3"),
+        pl.col("data").bin.murmur3(seed=1).alias("murmur3_1"),
+    )
+    expected = pl.DataFrame(
+        {
+            "data": [0xEF46DB3751D8E999, 0x44BC2CF5AD770999, None],
+            "xxh64_42": [0x98B1582B0977E704, 0x13C1D910702770E6, None],
+            "crc32": [0, 0x352441C2, None],
+            "murmur3": [0, 0xB3DD93FA, None],
+            "murmur3_1": [0x514E28B7, 0xAA75E9FF, None],
+        },
+        schema={
+            "data": pl.UInt64,
+            "xxh64_42": pl.UInt64,
+            "crc32": pl.UInt32,
+            "murmur3": pl.UInt32,
+            "murmur3_1": pl.UInt32,
+        },
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_bin_spark_murmur3_reference_values() -> None:
+    # Values of `hash(<value>)` in Spark.
+    s = pl.Series(["", "Spark", None])
+    assert_series_equal(
+        s.bin.spark_murmur3(),
+        pl.Series([142593372, 228093765, None], dtype=pl.Int32),
+    )
+
+    # Values whose length is a multiple of 4 are hashed like the reference
+    # implementation.
+    s = pl.Series([b"abcd", b"abcdefgh"])
+    assert_series_equal(
+        s.bin.spark_murmur3(seed=0),
+        s.bin.murmur3().cast(pl.Int32, wrap_numerical=True),
+    )
+
+
+@pytest.mark.parametrize(
+    "method", ["sha256", "sha1", "md5", "xxh64", "crc32", "murmur3", "spark_murmur3"]
+)
+def test_bin_hash_string_input(method: str) -> None:
+    s = pl.Series("s", ["alice", "bob", None, ""])
+    expected = getattr(s.cast(pl.Binary).bin, method)()
+    assert_series_equal(getattr(s.bin, method)(), expected)
+    lf = s.to_frame().lazy().select(getattr(pl.col("s").bin, method)())
+    assert_series_equal(lf.collect(engine="streaming").to_series(), expected)
+
+
+def test_bin_hash_invalid() -> None:
+    s = pl.Series([b"abc"])
+    with pytest.raises(ValueError, match="32 bits"):
+        s.bin.murmur3(seed=2**32)
+    with pytest.raises(ValueError, match="32 bits"):
+        s.bin.spark_murmur3(seed=-1)
+    with pytest.raises(pl.exceptions.SchemaError):
+        pl.Series([1, 2]).bin.sha256()
+
+
+@pytest.mark.parametrize("codec", ["gzip", "zstd", "lz4", "snappy"])
+@pytest.mark.parametrize("streaming", [False, True])
+def test_bin_compress_roundtrip(codec: BinaryCodec, streaming: bool) -> None:
+    s = pl.Series("data", [b"", b"abc", None, b"polars " * 1000])
+    lf = s.to_frame().lazy()
+    out = lf.select(
+        compressed=pl.col("data").bin.compress(codec),
+        roundtrip=pl.col("data").bin.compress(codec).bin.decompress(codec),
+    ).collect(engine="streaming" if streaming else "in-memory")
+
+    assert out["compressed"].null_count() == 1
+    assert out["compressed"][3] is not None
+    assert len(out["compressed"][3]) < 1000
+    assert_series_equal(out["roundtrip"], s, check_names=False)
+
+
+@pytest.mark.parametrize(
+    ("codec", "level"), [("gzip", 0), ("gzip", 9), ("zstd", 19), ("lz4", 12)]
+)
+def test_bin_compress_level(codec: BinaryCodec, level: int) -> None:
+    s = pl.Series(["polars " * 100, "hello", None])
+    out = s.bin.compress(codec, level=level).bin.decompress(codec)
+    assert_series_equal(out, s.cast(pl.Binary))
+
+
+def test_bin_compress_gzip_interop() -> None:
+    values = [b"", b"abc", b"polars " * 100]
+    s = pl.Series(values)
+    assert [gzip.decompress(v) for v in s.bin.compress("gzip")] == values
+
+    compressed = pl.Series([gzip.compress(v) for v in values])
+    assert compressed.bin.decompress("gzip").to_list() == values
+
+
+def test_bin_decompress_invalid() -> None:
+    s = pl.Series([b"\x05\x10hello", b"not snappy", None])
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid `snappy` payload"):
+        s.bin.decompress("snappy")
+    assert s.bin.decompress("snappy", strict=False).to_list() == [b"hello", None, None]
+    assert s.bin.decompress("gzip", strict=False).to_list() == [None, None, None]
+
+
+def test_bin_compress_invalid() -> None:
+    s = pl.Series([b"abc"])
+    with pytest.raises(InvalidOperationError, match="compression level"):
+        s.bin.compress("gzip", level=10)
+    with pytest.raises(InvalidOperationError, match="does not support"):
+        s.bin.compress("snappy", level=1)
+    with pytest.raises(ValueError, match="`codec` must be one of"):
+        s.bin.compress("brotli")  # type: ignore[arg-type]
+    with pytest.raises(pl.exceptions.SchemaError):
+        pl.Series([1, 2]).bin.decompress("gzip")
@@ -0,0 +1,118 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError, SchemaError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import StringSimilarityMetric
+
+left = pl.DataFrame(
+    {
+        "id": [1, 2, 3, 4, 5],
+        "name": ["john smith", "jane doe", None, "bob", "alice"],
+    }
+)
+right = pl.DataFrame(
+    {
+        "name": ["jon smith", "jane doe", "jane do", None, "rob", "alicia", "bobby"],
+        "city": ["NYC", "LA", "SF", "BOS", "SEA", "CHI", "ATL"],
+    }
+)
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+def test_fuzzy_join_max_distance(streaming: bool) -> None:
+    out = (
+        left.lazy()
+        .join_fuzzy(right.lazy(), on="name", max_distance=1)
+        .collect(engine="streaming" if streaming else "in-memory")
+    )
+    expected = pl.DataFrame(
+        {
+            "id": [1, 2, 2, 4],
+            "name": ["john smith", "jane doe", "jane doe", "bob"],
+            "name_right": ["jon smith", "jane doe", "jane do", "rob"],
+            "city": ["NYC", "LA", "SF", "SEA"],
+            "score": pl.Series([1, 0, 1, 1], dtype=pl.UInt32),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+@pytest.mark.parametrize(
+    ("metric", "threshold"),
+    [
+        ("levenshtein", 0.73),
+        ("damerau_levenshtein", 0.51),
+        ("hamming", 0.6),
+        ("jaro", 0.81),
+        ("jaro_winkler", 0.87),
+    ],
+)
+def test_fuzzy_join_matches_cross_join(
+    metric: StringSimilarityMetric, threshold: float, streaming: bool
+) -> None:
+    words = ["smith", "smyth", "smithe", "mitsh", "jones", "jonas", "", "s", "ab"]
+    lhs = pl.LazyFrame({"a": words * 3, "i": range(len(words) * 3)})
+    rhs = pl.LazyFrame({"a": words + ["smiths", None], "i": range(len(words) + 2)})
+
+    out = lhs.join_fuzzy(
+        rhs, on="a", metric=metric, threshold=threshold, score_name="sim"
+    ).collect(engine="streaming" if streaming else "in-memory")
+    expected = (
+        lhs.join(rhs, how="cross")
+        .with_columns(sim=pl.col("a").str.similarity(pl.col("a_right"), metric))
+        .filter(pl.col("sim") >= threshold)
+        .collect()
+    )
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+def test_fuzzy_join_left_on_right_on_eager() -> None:
+    other = right.rename({"name": "customer"})
+    out = left.join_fuzzy(
+        other,
+        left_on="name",
+        right_on="customer",
+        metric="jaro_winkler",
+        threshold=0.9,
+        score_name="sim",
+    )
+    assert out.columns == ["id", "name", "customer", "city", "sim"]
+    assert out.schema["sim"] == pl.Float64
+    assert out["id"].to_list() == [1, 2, 2, 4]
+    assert out["customer"].to_list() == ["jon smith", "jane doe", "jane do", "bobby"]
+
+
+def test_fuzzy_join_filter_on_score() -> None:
+    q = (
+        left.lazy()
+        .join_fuzzy(right.lazy(), on="name", max_distance=2)
+        .filter(pl.col("score") == 0, pl.col("id") > 1)
+        .select("id", "city", "score")
+    )
+    expected = pl.DataFrame(
+        {"id": [2], "city": ["LA"], "score": pl.Series([0], dtype=pl.UInt32)}
+    )
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+
+
+def test_fuzzy_join_invalid() -> None:
+    lf = left.lazy()
+    with pytest.raises(ValueError, match="exactly one of"):
+        lf.join_fuzzy(lf, on="name")
+    with pytest.raises(ValueError, match="exactly one of"):
+        lf.join_fuzzy(lf, on="name", threshold=0.5, max_distance=1)
+    with pytest.raises(InvalidOperationError):
+        lf.join_fuzzy(lf, on="name", threshold=1.5).collect()
+    with pytest.raises(InvalidOperationError):
+        lf.join_fuzzy(lf, on="name", metric="jaro", max_distance=1).collect()
+    with pytest.raises(SchemaError):
+        lf.join_fuzzy(lf, on="id", max_distance=1).collect()
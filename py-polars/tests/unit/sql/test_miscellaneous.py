@@ -353,6 +353,28 @@ def test_nested_cte_column_aliasing() -> None:
     }
 
 
+def test_recursive_cte() -> None:
+    employees = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "manager_id": [None, 1, 1, 2, 4],
+        }
+    )
+    df = pl.sql(
+        """
+        WITH RECURSIVE chain(id, depth) AS (
+            SELECT id, 0 FROM employees WHERE manager_id IS NULL
+            UNION ALL
+            SELECT e.id, c.depth + 1
+            FROM employees e JOIN chain c ON e.manager_id = c.id
+        )
+        SELECT id, depth FROM chain ORDER BY id
+        """,
+        eager=True,
+    )
+    assert df.rows() == [(1, 0), (2, 1), (3, 1), (4, 2), (5, 3)]
+
+
 def test_invalid_derived_table_column_aliases() -> None:
     values_query = "SELECT * FROM (VALUES (1,2), (3,4))"
 
@@ -598,3 +620,44 @@ def test_count_partition_22665(query: str, result: list[Any]) -> None:
     out = df.sql(query).select("b")
     expected = pl.DataFrame({"b": result}).cast({"b": pl.get_index_type()})
     assert_frame_equal(out, expected)
+
+
+def test_window_frames() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [3, 1, 6, 2, 5, 4],
+            "grp": ["a", "a", "b", "a", "b", "b"],
+            "value": [30, 10, 60, 20, 50, 40],
+            "day": [date(2020, 1, d) for d in (5, 1, 9, 2, 8, 6)],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT
+          id,
+          SUM(value) OVER (
+            PARTITION BY grp ORDER BY id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+          ) AS s,
+          AVG(value) OVER (w ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS m,
+          SUM(value) OVER (
+            ORDER BY day RANGE BETWEEN INTERVAL '3 days' PRECEDING AND CURRENT ROW
+          ) AS r
+        FROM self
+        WINDOW w AS (PARTITION BY grp ORDER BY id)
+        ORDER BY id
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "id": [1, 2, 3, 4, 5, 6],
+        "s": [10, 30, 50, 40, 90, 110],
+        "m": [15.0, 20.0, 25.0, 45.0, 50.0, 55.0],
+        "r": [10, 30, 50, 70, 120, 150],
+    }
+
+    with pytest.raises(SQLInterfaceError, match="window 'w' is not defined"):
+        df.sql("SELECT SUM(value) OVER w FROM self")
+
+    with pytest.raises(SQLInterfaceError, match="GROUPS"):
+        df.sql(
+            "SELECT SUM(value) OVER (ORDER BY id GROUPS 1 PRECEDING) AS s FROM self"
+        )
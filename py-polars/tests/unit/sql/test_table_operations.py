@@ -6,7 +6,7 @@ from datetime import date
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError
+from polars.exceptions import ColumnNotFoundError, SQLInterfaceError
 from polars.testing import assert_frame_equal
 
 
@@ -89,6 +89,83 @@ def test_explain_query(test_frame: pl.LazyFrame) -> None:
         )
 
 
+def test_insert_into(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute("INSERT INTO frame VALUES (4, 'ddd', '2024-01-01')")
+        ctx.execute("INSERT INTO frame (y, x) SELECT y, x + 10 FROM frame WHERE x < 3")
+        res = ctx.execute("SELECT * FROM frame")
+
+    assert_frame_equal(
+        res,
+        pl.DataFrame(
+            {
+                "x": [1, 2, 3, 4, 11, 12],
+                "y": ["aaa", "bbb", "ccc", "ddd", "aaa", "bbb"],
+                "z": [
+                    date(2000, 12, 31),
+                    date(1978, 11, 15),
+                    date(2077, 10, 20),
+                    date(2024, 1, 1),
+                    None,
+                    None,
+                ],
+            },
+            schema_overrides={"x": pl.UInt8},
+        ),
+    )
+
+
+def test_insert_into_errors(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame) as ctx:
+        with pytest.raises(
+            SQLInterfaceError, match=r"INSERT expects 3 value\(s\) per row; found 2"
+        ):
+            ctx.execute("INSERT INTO frame VALUES (4, 'ddd')")
+
+        with pytest.raises(SQLInterfaceError, match="table 'nope' does not exist"):
+            ctx.execute("INSERT INTO nope VALUES (4, 'ddd', '2024-01-01')")
+
+
+def test_merge_into() -> None:
+    target = pl.LazyFrame(
+        {
+            "id": [1, 2, 3, 4],
+            "qty": [10, 20, 30, 40],
+            "status": ["open", "open", "open", "open"],
+        }
+    )
+    updates = pl.LazyFrame(
+        {
+            "id": [2, 3, 5, 6],
+            "qty": [25, 0, 50, 60],
+        }
+    )
+    with pl.SQLContext(target=target, updates=updates, eager=True) as ctx:
+        ctx.execute(
+            """
+            MERGE INTO target AS t
+            USING updates AS u
+            ON t.id = u.id
+            WHEN MATCHED AND u.qty = 0 THEN DELETE
+            WHEN MATCHED THEN UPDATE SET qty = u.qty, status = 'updated'
+            WHEN NOT MATCHED BY TARGET THEN INSERT VALUES (u.id, u.qty, 'new')
+            WHEN NOT MATCHED BY SOURCE AND t.id > 3 THEN UPDATE SET status = 'stale'
+            """
+        )
+        res = ctx.execute("SELECT * FROM target")
+
+    assert_frame_equal(
+        res,
+        pl.DataFrame(
+            {
+                "id": [1, 2, 4, 5, 6],
+                "qty": [10, 25, 40, 50, 60],
+                "status": ["open", "updated", "stale", "new", "new"],
+            }
+        ),
+    )
+
+
 def test_show_tables(test_frame: pl.LazyFrame) -> None:
     # 'show tables' lists all tables registered with the sql context in sorted order
     with pl.SQLContext(
@@ -117,3 +194,21 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_update_table(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute("UPDATE frame SET y = UPPER(y), x = x * 10 WHERE x > 1")
+        res = ctx.execute("SELECT x, y FROM frame")
+        assert_frame_equal(
+            res,
+            pl.DataFrame(
+                {"x": [1, 20, 30], "y": ["aaa", "BBB", "CCC"]},
+                schema_overrides={"x": pl.UInt8},
+            ),
+        )
+
+        with pytest.raises(
+            ColumnNotFoundError, match="column 'w' does not exist in table 'frame'"
+        ):
+            ctx.execute("UPDATE frame SET w = 0")
@@ -506,3 +506,31 @@ def test_streaming_group_by_all_null_21593() -> None:
 
     out = df.lazy().group_by(pl.all()).min().collect(engine="streaming")
     assert_frame_equal(df, out, check_row_order=False)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("budget", ["1", "30000", "300000"])
+def test_streaming_group_by_spill(budget: str, monkeypatch: pytest.MonkeyPatch) -> None:
+    # A tiny budget spills every cold row and the hot tables right away, larger
+    # budgets only spill once enough rows have accumulated.
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", budget)
+
+    n = 20_000
+    df = pl.DataFrame({"idx": range(n)}).with_columns(
+        k=pl.when(pl.col.idx % 11 == 0).then(None).otherwise(pl.col.idx * 7919 % 997),
+        s=(pl.col.idx * 31 % 7).cast(pl.String).cast(pl.Categorical),
+    )
+    lf = pl.concat([df.slice(i, 1_000).lazy() for i in range(0, n, 1_000)])
+
+    q = lf.group_by("k", "s").agg(
+        sum=pl.col.idx.sum(),
+        first=pl.col.idx.first(),
+        last=pl.col.idx.last(),
+        mean=pl.col.idx.mean(),
+        len=pl.len(),
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import JoinStrategy
+    from polars._typing import JoinStrategy, JoinValidation, MaintainOrderJoin
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -386,3 +386,198 @@ def test_invert_order_full_join_22295() -> None:
     lf.join(lf, on=["value", "value_at"], how="full", coalesce=True).collect(
         engine="streaming"
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full"])
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_streaming_join_spill(
+    how: JoinStrategy, nulls_equal: bool, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    # Spill all build-side partitions, making this a grace hash join.
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", "1")
+
+    n = 20_000
+    df = pl.DataFrame({"idx": range(n)}).with_columns(
+        k=pl.when(pl.col.idx % 11 == 0).then(None).otherwise(pl.col.idx * 7919 % 997)
+    )
+    big = pl.concat([df.slice(i, 1_000).lazy() for i in range(0, n, 1_000)])
+    small = pl.LazyFrame({"k": [None, *range(0, 1_200, 3)]}).with_columns(
+        w=pl.int_range(pl.len())
+    )
+
+    for left, right in [(big, small), (small, big)]:
+        q = left.join(right, on="k", how=how, nulls_equal=nulls_equal)
+        assert_frame_equal(
+            q.collect(engine="streaming"),
+            q.collect(engine="in-memory"),
+            check_row_order=False,
+        )
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("by", [None, "g"])
+@pytest.mark.parametrize("tolerance", [None, 5])
+def test_streaming_join_asof(
+    strategy: Literal["backward", "forward", "nearest"],
+    by: str | None,
+    tolerance: int | None,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+
+    rng = np.random.default_rng(0)
+    left = pl.LazyFrame(
+        {
+            "t": np.cumsum(rng.integers(0, 5, 300)),
+            "g": rng.integers(0, 4, 300),
+            "a": range(300),
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "t": np.cumsum(rng.integers(0, 15, 100)),
+            "g": rng.integers(0, 4, 100),
+            "b": range(100),
+        }
+    )
+
+    q = left.join_asof(
+        right,
+        on="t",
+        by=by,
+        strategy=strategy,
+        tolerance=tolerance,
+        check_sortedness=by is None,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+def test_streaming_join_asof_sorted_per_group_only(
+    strategy: Literal["backward", "forward", "nearest"],
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+
+    # The key is only sorted within each group, and the groups progress unevenly.
+    rng = np.random.default_rng(0)
+    left = pl.LazyFrame(
+        {
+            "g": rng.integers(0, 4, 300),
+            "t": rng.integers(0, 5, 300),
+            "a": range(300),
+        }
+    ).with_columns(pl.col("t").cum_sum().over("g") * (pl.col("g") + 1))
+    right = pl.LazyFrame(
+        {
+            "g": rng.integers(0, 4, 100),
+            "t": rng.integers(0, 15, 100),
+            "b": range(100),
+        }
+    ).with_columns(pl.col("t").cum_sum().over("g") * (4 - pl.col("g")))
+
+    q = left.join_asof(right, on="t", by="g", strategy=strategy, check_sortedness=False)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    q = left.join_asof(right, on="t", by="g", strategy=strategy)
+    with pytest.warns(UserWarning, match="cannot be checked"):
+        q.collect(engine="streaming")
+    with pytest.warns(UserWarning, match="cannot be checked"):
+        q.collect(engine="in-memory")
+
+
+@pytest.mark.parametrize(
+    "predicates",
+    [
+        [pl.col("start") <= pl.col("ts"), pl.col("ts") < pl.col("end")],
+        [pl.col("start") > pl.col("ts"), pl.col("end") >= pl.col("ts")],
+        [pl.col("end") > pl.col("ts")],
+        [pl.col("start") <= pl.col("ts")],
+    ],
+)
+def test_streaming_join_where(
+    predicates: list[pl.Expr], monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+
+    rng = np.random.default_rng(0)
+    start = rng.integers(0, 100, 150)
+    intervals = pl.LazyFrame(
+        {"start": start, "end": start + rng.integers(0, 15, 150), "id": range(150)}
+    ).with_columns(start=pl.when(pl.col.id % 13 != 0).then(pl.col.start))
+    events = pl.LazyFrame({"ts": rng.integers(0, 120, 200), "id": range(200)})
+
+    q = intervals.join_where(events, *predicates)
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.parametrize("validate", ["1:1", "1:m", "m:1"])
+@pytest.mark.parametrize("how", ["inner", "left", "full"])
+@pytest.mark.parametrize("maintain_order", ["none", "left", "right_left"])
+def test_streaming_join_validate(
+    validate: JoinValidation,
+    how: JoinStrategy,
+    maintain_order: MaintainOrderJoin,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+
+    left = pl.LazyFrame({"a": range(100), "x": range(100)})
+    right = pl.LazyFrame({"a": range(50, 150), "y": range(100)})
+    q = left.join(
+        right, on="a", how=how, validate=validate, maintain_order=maintain_order
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.parametrize(
+    ("validate", "side"),
+    [("1:m", "left"), ("m:1", "right"), ("1:1", "left"), ("1:1", "right")],
+)
+@pytest.mark.parametrize("maintain_order", ["none", "left", "right_left"])
+def test_streaming_join_validate_duplicate_key(
+    validate: JoinValidation,
+    side: str,
+    maintain_order: MaintainOrderJoin,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+
+    unique = pl.LazyFrame({"a": range(100), "x": range(100)})
+    duplicate = unique.with_columns(
+        a=pl.when(pl.col.x == 70).then(20).otherwise(pl.col.a)
+    )
+    left, right = (duplicate, unique) if side == "left" else (unique, duplicate)
+    q = left.join(right, on="a", validate=validate, maintain_order=maintain_order)
+
+    with pytest.raises(
+        pl.exceptions.ComputeError,
+        match=rf"did not fulfill {validate} validation: the {side} key \(a=20\)",
+    ):
+        q.collect(engine="streaming")
+
+
+def test_streaming_join_validate_nulls() -> None:
+    left = pl.LazyFrame({"a": [1, None, None, 2], "x": [1, 2, 3, 4]})
+    right = pl.LazyFrame({"a": [1, 2, 2, None], "y": [1, 2, 3, 4]})
+
+    # Null keys are ignored, unless they compare equal.
+    q = left.join(right, on="a", how="left", validate="1:m")
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+    q = left.join(right, on="a", how="left", validate="1:m", nulls_equal=True)
+    with pytest.raises(pl.exceptions.ComputeError, match=r"left key \(a=null\)"):
+        q.collect(engine="streaming")
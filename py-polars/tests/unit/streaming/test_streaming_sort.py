@@ -183,3 +183,58 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("maintain_order", [False, True])
+@pytest.mark.parametrize("slice", [None, (5, 100), (-50, 20)])
+def test_streaming_sort_spill(
+    maintain_order: bool,
+    slice: tuple[int, int] | None,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    # Spill every buffered morsel as a sorted run.
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", "1")
+
+    rng = np.random.default_rng(0)
+    n = 10_000
+    df = pl.DataFrame(
+        {
+            "a": rng.integers(0, 10, n),
+            "b": pl.Series(rng.integers(0, 100, n)).cast(pl.String),
+            "c": rng.random(n),
+            "idx": range(n),
+        }
+    ).with_columns(
+        a=pl.when(pl.col.idx % 7 == 0).then(None).otherwise(pl.col.a),
+        b=pl.col.b.cast(pl.Categorical),
+    )
+
+    # Feed the data as separate frames so multiple sorted runs get merged.
+    lf = pl.concat([df.slice(i, 1_000).lazy() for i in range(0, n, 1_000)])
+    q = lf.sort(
+        "a",
+        "b",
+        descending=[True, False],
+        nulls_last=True,
+        maintain_order=maintain_order,
+    )
+    if slice is not None:
+        q = q.slice(*slice)
+
+    result = q.collect(engine="streaming")
+    expected = q.collect(engine="in-memory")
+    if maintain_order:
+        assert_frame_equal(result, expected)
+    else:
+        assert_frame_equal(result.select("a", "b"), expected.select("a", "b"))
+
+
+def test_streaming_sort_invalid_memory_budget(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", "lots")
+
+    lf = pl.LazyFrame({"a": [3, 1, 2]}).sort("a")
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="POLARS_STREAMING_MEMORY_BUDGET"
+    ):
+        lf.collect(engine="streaming")